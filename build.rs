@@ -0,0 +1,105 @@
+//! Generates the [`GameType`] enum and the table of `util::games` from the checked-in
+//! `/resources/games` snapshot in `src/api/fixtures/resources_games.json`.
+//!
+//! To add or rename a game, update the snapshot and rebuild. Variants are named after the
+//! type name in PascalCase (`"SURVIVAL_GAMES"` becomes `SurvivalGames`), unless listed in
+//! [`VARIANT_NAMES`].
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use serde_json::Value;
+
+const SNAPSHOT: &str = "src/api/fixtures/resources_games.json";
+
+/// Variant names that do not follow from their type name.
+const VARIANT_NAMES: &[(&str, &str)] = &[
+    ("TNTGAMES", "TntGames"),
+    ("VAMPIREZ", "VampireZ"),
+    ("SKYWARS", "SkyWars"),
+    ("SKYCLASH", "SkyClash"),
+    ("SKYBLOCK", "SkyBlock"),
+];
+
+struct Game {
+    id: Option<u64>,
+    type_name: String,
+    database_name: String,
+    name: String,
+}
+
+impl Game {
+    fn variant(&self) -> String {
+        if let Some((_, variant)) = VARIANT_NAMES.iter().find(|(type_name, _)| *type_name == self.type_name) {
+            return variant.to_string();
+        }
+        self.type_name.split('_').map(|word| {
+            let mut chars = word.chars();
+            chars.next().into_iter().chain(chars.flat_map(char::to_lowercase)).collect::<String>()
+        }).collect()
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SNAPSHOT);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let snapshot: Value = serde_json::from_str(&fs::read_to_string(SNAPSHOT).expect("the games snapshot is readable"))
+        .expect("the games snapshot is valid JSON");
+    let mut games: Vec<Game> = snapshot["games"].as_object().expect("the games snapshot contains games")
+        .iter()
+        .map(|(type_name, game)| Game {
+            id: game["id"].as_u64(),
+            type_name: type_name.clone(),
+            database_name: game["databaseName"].as_str().expect("every game has a database name").to_string(),
+            name: game["name"].as_str().expect("every game has a name").to_string(),
+        })
+        .collect();
+    // games with an id first, lobbies last
+    games.sort_by(|a, b| (a.id.is_none(), a.id, &a.type_name).cmp(&(b.id.is_none(), b.id, &b.type_name)));
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("game_type.rs"), game_type(&games)).expect("OUT_DIR is writable");
+    fs::write(Path::new(&out_dir).join("games.rs"), games_table(&games)).expect("OUT_DIR is writable");
+}
+
+fn game_type(games: &[Game]) -> String {
+    let mut out = String::new();
+    out.push_str("/// The type of a game on the Hypixel network, as used by fields like `gameType` and `mostRecentGameType`.\n");
+    out.push_str("///\n");
+    out.push_str("/// Values introduced by Hypixel after this crate was published are captured in [`GameType::Unknown`].\n");
+    out.push_str("#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]\n");
+    out.push_str("#[serde(from = \"String\")]\n");
+    out.push_str("pub enum GameType {\n");
+    for game in games {
+        writeln!(out, "    /// {}", game.name).unwrap();
+        writeln!(out, "    {},", game.variant()).unwrap();
+    }
+    out.push_str("    Unknown(String),\n}\n\n");
+
+    out.push_str("impl From<String> for GameType {\n    fn from(s: String) -> Self {\n        match s.as_str() {\n");
+    for game in games {
+        writeln!(out, "            {:?} => GameType::{},", game.type_name, game.variant()).unwrap();
+    }
+    out.push_str("            _ => GameType::Unknown(s),\n        }\n    }\n}\n\n");
+
+    out.push_str("impl Display for GameType {\n    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {\n        match self {\n");
+    for game in games {
+        writeln!(out, "            GameType::{} => write!(f, {:?}),", game.variant(), game.type_name).unwrap();
+    }
+    out.push_str("            GameType::Unknown(s) => write!(f, \"{}\", s),\n        }\n    }\n}\n");
+    out
+}
+
+fn games_table(games: &[Game]) -> String {
+    let mut out = String::new();
+    out.push_str("/// Every known game type.\n");
+    out.push_str("pub const GAMES: &[GameInfo] = &[\n");
+    for game in games {
+        let id = game.id.map_or("None".to_string(), |id| format!("Some({})", id));
+        writeln!(out, "    GameInfo::new({}, {:?}, {:?}, {:?}),", id, game.type_name, game.database_name, game.name).unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
@@ -0,0 +1,92 @@
+//! Requests for many players at once, see [`RequestHandler::get_statuses`].
+
+use std::collections::{HashMap, HashSet};
+use futures::StreamExt;
+use uuid::Uuid;
+use crate::api::error::HypixelApiError;
+use crate::api::path::ApiPath;
+use crate::api::reply::{Reply, StatusData, StatusReply};
+use crate::api::request::RequestHandler;
+
+/// The amount of requests of a batch that are queued at the same time.
+const BATCH_CONCURRENCY: usize = 8;
+
+/// The statuses of many players, see [`RequestHandler::get_statuses`].
+#[derive(Debug, Default)]
+pub struct StatusBatch {
+    statuses: HashMap<Uuid, StatusData>,
+    errors: HashMap<Uuid, HypixelApiError>,
+}
+
+impl StatusBatch {
+    /// Returns the statuses of the players whose request succeeded.
+    pub fn statuses(&self) -> &HashMap<Uuid, StatusData> {
+        &self.statuses
+    }
+
+    /// Returns the status of the player `uuid`, if its request succeeded.
+    pub fn status(&self, uuid: &Uuid) -> Option<&StatusData> {
+        self.statuses.get(uuid)
+    }
+
+    /// Returns the errors of the players whose request failed.
+    pub fn errors(&self) -> &HashMap<Uuid, HypixelApiError> {
+        &self.errors
+    }
+
+    /// Returns `true` if the request of every player succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the statuses and the errors.
+    pub fn into_parts(self) -> (HashMap<Uuid, StatusData>, HashMap<Uuid, HypixelApiError>) {
+        (self.statuses, self.errors)
+    }
+}
+
+impl RequestHandler {
+    /// Requests the status of every player in `uuids`, ignoring duplicates.
+    ///
+    /// At most 8 of these requests are queued at a time, see [`RequestHandler::stream_requests`].
+    /// A failed request does not fail the batch, its error is returned per player in
+    /// [`StatusBatch::errors`].
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use hypixel_api::RequestHandler;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// # let friends: Vec<Uuid> = Vec::new();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let batch = request_handler.get_statuses(&friends).await;
+    /// let online = batch.statuses().values().filter(|status| status.online()).count();
+    /// println!("{} of {} friends online, {} failed", online, friends.len(), batch.errors().len());
+    /// # }
+    /// ```
+    pub async fn get_statuses(&self, uuids: &[Uuid]) -> StatusBatch {
+        let mut seen = HashSet::new();
+        let uuids: Vec<Uuid> = uuids.iter().copied().filter(|uuid| seen.insert(*uuid)).collect();
+        let mut replies = futures::stream::iter(uuids)
+            .map(|uuid| async move {
+                let reply = self.request_typed::<StatusReply>(ApiPath::Status { uuid }).await;
+                (uuid, reply)
+            })
+            .buffer_unordered(BATCH_CONCURRENCY);
+        let mut batch = StatusBatch::default();
+        while let Some((uuid, reply)) = replies.next().await {
+            match reply.map_err(HypixelApiError::from).and_then(|reply| reply) {
+                Ok(reply) => {
+                    batch.statuses.insert(uuid, reply.into_data());
+                }
+                Err(error) => {
+                    batch.errors.insert(uuid, error);
+                }
+            }
+        }
+        batch
+    }
+}
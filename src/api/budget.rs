@@ -0,0 +1,147 @@
+use crate::api::error::HypixelApiError;
+use crate::api::request::endpoint;
+
+/// Limits the size of the responses a [`RequestHandler`](crate::RequestHandler)
+/// deserializes, see
+/// [`RequestHandlerBuilder::deserialization_budget`](crate::RequestHandlerBuilder::deserialization_budget).
+///
+/// A response is checked against its length in bytes while it is downloaded: a response that
+/// announces a larger `Content-Length` is rejected before its body is read, otherwise reading
+/// stops as soon as the limit is exceeded, so an oversized response is never held in memory.
+/// This applies to raw bodies, e.g. from
+/// [`RequestHandler::request_bytes`](crate::RequestHandler::request_bytes), as well. The body of
+/// a response with an unexpected status code is only read for its cause, at most 64 KiB of it,
+/// with or without budget.
+///
+/// Before a response is deserialized, it is also checked against its amount of JSON nodes
+/// (every object, array, string, key, number, boolean and `null`). Counting stops as soon as
+/// the limit is exceeded, so a pathological response costs at most one pass over its bytes.
+/// Responses over budget fail with [`HypixelApiError::BudgetExceeded`] and are never deserialized.
+///
+/// The SkyBlock bazaar and auction pages are several megabytes large, give them larger
+/// limits with [`DeserializationBudget::endpoint`]. By default, nothing is limited.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::{DeserializationBudget, RequestHandler};
+/// # use uuid::Uuid;
+///
+/// # fn main() {
+/// # let api_key = Uuid::nil();
+/// const MIB: usize = 1024 * 1024;
+/// let request_handler = RequestHandler::builder(api_key)
+///     .deserialization_budget(DeserializationBudget::new()
+///         .max_bytes(2 * MIB)
+///         .max_nodes(200_000)
+///         .endpoint("skyblock/bazaar", 16 * MIB, 2_000_000)
+///         .endpoint("skyblock/auctions", 16 * MIB, 2_000_000))
+///     .build();
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeserializationBudget {
+    max_bytes: Option<usize>,
+    max_nodes: Option<usize>,
+    endpoints: Vec<(String, usize, usize)>,
+}
+
+impl DeserializationBudget {
+    /// Creates a budget without limits.
+    pub fn new() -> Self {
+        DeserializationBudget::default()
+    }
+
+    /// Sets the maximum length of a response in bytes.
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Sets the maximum amount of JSON nodes in a response.
+    pub fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
+    /// Replaces both limits for the responses of `endpoint` (a path without query, e.g.
+    /// `"skyblock/auctions"`).
+    pub fn endpoint(mut self, endpoint: impl Into<String>, max_bytes: usize, max_nodes: usize) -> Self {
+        let endpoint = endpoint.into().trim_matches('/').to_string();
+        self.endpoints.retain(|(existing, _, _)| *existing != endpoint);
+        self.endpoints.push((endpoint, max_bytes, max_nodes));
+        self
+    }
+
+    /// Returns the limits in bytes and nodes that apply to the responses of `path`.
+    pub fn limits(&self, path: &str) -> (Option<usize>, Option<usize>) {
+        let endpoint = endpoint(path);
+        match self.endpoints.iter().find(|(existing, _, _)| existing == endpoint) {
+            Some((_, max_bytes, max_nodes)) => (Some(*max_bytes), Some(*max_nodes)),
+            None => (self.max_bytes, self.max_nodes),
+        }
+    }
+
+    /// Returns an error if `body`, the response to `path`, exceeds this budget.
+    pub fn check(&self, path: &str, body: &[u8]) -> Result<(), HypixelApiError> {
+        let (_, max_nodes) = self.limits(path);
+        self.check_length(path, body.len())?;
+        if let Some(max_nodes) = max_nodes {
+            if count_nodes(body, max_nodes) > max_nodes {
+                return Err(HypixelApiError::BudgetExceeded(path.to_string(), format!("more than {} nodes", max_nodes)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `length` bytes of a response to `path` exceed this budget.
+    pub(crate) fn check_length(&self, path: &str, length: usize) -> Result<(), HypixelApiError> {
+        match self.limits(path).0 {
+            Some(max_bytes) if length > max_bytes => {
+                Err(HypixelApiError::BudgetExceeded(path.to_string(), format!("{} bytes, at most {} allowed", length, max_bytes)))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Counts the JSON nodes in `body`, stopping once more than `max` were found.
+///
+/// This does not validate `body`, malformed JSON is reported by the deserializer.
+fn count_nodes(body: &[u8], max: usize) -> usize {
+    let mut nodes = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut in_scalar = false;
+    for &byte in body {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match byte {
+            b'"' => {
+                in_string = true;
+                in_scalar = false;
+                nodes += 1;
+            }
+            b'{' | b'[' => {
+                in_scalar = false;
+                nodes += 1;
+            }
+            b'}' | b']' | b',' | b':' | b' ' | b'\t' | b'\n' | b'\r' => in_scalar = false,
+            _ if !in_scalar => {
+                in_scalar = true;
+                nodes += 1;
+            }
+            _ => {}
+        }
+        if nodes > max {
+            break;
+        }
+    }
+    nodes
+}
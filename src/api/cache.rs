@@ -0,0 +1,520 @@
+//! A bounded in-memory cache of responses.
+//!
+//! Enable the cache with [`RequestHandlerBuilder::cache`](crate::RequestHandlerBuilder::cache).
+//! Successful responses are stored as raw bytes, keyed by their request path (including the query),
+//! and reused until they expire. A response is successful if it is valid JSON that does not
+//! report `"success": false`. Since the bytes are deserialized per request, replies of different
+//! types to the same path (e.g. a custom slim player struct and `PlayerReply`)
+//! share one cached response, and concurrent requests to a path that is not cached yet wait for
+//! a single fetch rather than each sending their own request. The memory used by the cache is bounded by a maximum
+//! amount of entries and a maximum total body size, the least recently used entries are
+//! evicted first. Expired responses can also be served while they are refreshed in the
+//! background, see [`CacheConfig::stale_while_revalidate`].
+//!
+//! Responses that carry an `ETag` or `Last-Modified` header (or, for the `resources` endpoints,
+//! a `lastUpdated` field) are revalidated with a conditional request once they expired, a
+//! `304 Not Modified` answer renews the cached response without downloading it again.
+//!
+//! Stale responses can be evicted with [`ResponseCache::invalidate`] and
+//! [`ResponseCache::invalidate_prefix`], [`ResponseCache::stats`] reports the hit rate.
+//! Individual requests can bypass or refresh the cache with a [`CachePolicy`], see
+//! [`RequestHandler::request_with_policy`](crate::RequestHandler::request_with_policy).
+//!
+//! With the `metrics` feature enabled, hits, misses, evictions and the size of the cache are
+//! emitted as well.
+#![cfg_attr(feature = "metrics", doc = "See [`metrics`](crate::metrics).")]
+//!
+//! # Examples
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use hypixel_api::RequestHandler;
+//! use hypixel_api::cache::CacheConfig;
+//! # use uuid::Uuid;
+//!
+//! # fn main() {
+//! # let api_key = Uuid::nil();
+//! let request_handler = RequestHandler::builder(api_key)
+//!     .cache(CacheConfig::new()
+//!         .max_entries(10_000)
+//!         .max_bytes(64 * 1024 * 1024)
+//!         .ttl(Duration::from_secs(120)))
+//!     .build();
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use reqwest::header::{HeaderMap, ETAG, LAST_MODIFIED};
+use serde::Deserialize;
+
+/// The configuration of a [`ResponseCache`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CacheConfig {
+    max_entries: usize,
+    max_bytes: usize,
+    ttl: Duration,
+    max_staleness: Option<Duration>,
+}
+
+impl CacheConfig {
+    /// Creates the default configuration: at most 1024 entries, 32 MiB and a time to live of 60 seconds.
+    pub fn new() -> Self {
+        CacheConfig {
+            max_entries: 1024,
+            max_bytes: 32 * 1024 * 1024,
+            ttl: Duration::from_secs(60),
+            max_staleness: None,
+        }
+    }
+
+    /// Sets the maximum amount of cached responses.
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Sets the maximum total size of the cached bodies in bytes.
+    ///
+    /// Responses larger than this are not cached.
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Sets how long a response is reused after it was received.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Serves expired responses for up to `max_staleness` after they expired, while a refresh
+    /// is queued in the background.
+    ///
+    /// A request for an expired response completes immediately with the stale body, the
+    /// refreshed response waits for the rate limit like any other request and replaces the
+    /// cached response once received. Only one refresh per path is queued at a time.
+    /// Responses older than `ttl + max_staleness` are requested like uncached responses.
+    pub fn stale_while_revalidate(mut self, max_staleness: Duration) -> Self {
+        self.max_staleness = Some(max_staleness);
+        self
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig::new()
+    }
+}
+
+/// How a single request uses the [`ResponseCache`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum CachePolicy {
+    /// Reuse the cached response if it has not expired, cache the response otherwise.
+    #[default]
+    Use,
+    /// Neither reuse nor cache the response.
+    Bypass,
+    /// Always request a fresh response and cache it, replacing the cached response.
+    Refresh,
+}
+
+/// A bounded cache of response bodies with least recently used eviction,
+/// see the [module documentation](self).
+#[derive(Debug)]
+pub struct ResponseCache {
+    config: CacheConfig,
+    inner: Mutex<CacheData>,
+    /// The paths currently being fetched, see [`ResponseCache::fetch_lock`].
+    in_flight: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+/// Held while the response to a path is fetched, see [`ResponseCache::fetch_lock`].
+pub(crate) struct FetchGuard<'a> {
+    cache: &'a ResponseCache,
+    path: String,
+    waited: bool,
+    _guard: tokio::sync::OwnedMutexGuard<()>,
+}
+
+impl FetchGuard<'_> {
+    /// Returns `true` if another fetch of the path was awaited, whose response may now be cached.
+    pub(crate) fn waited(&self) -> bool {
+        self.waited
+    }
+}
+
+impl Drop for FetchGuard<'_> {
+    fn drop(&mut self) {
+        let mut in_flight = self.cache.in_flight.lock();
+        // only the map and this guard hold the lock when nobody else waits for it
+        if in_flight.get(&self.path).is_some_and(|lock| Arc::strong_count(lock) == 2) {
+            in_flight.remove(&self.path);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct CacheData {
+    entries: HashMap<String, CacheEntry>,
+    /// The keys of all entries ordered by their last use.
+    recency: BTreeMap<u64, String>,
+    clock: u64,
+    bytes: usize,
+    hits: u64,
+    stale_hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    body: Bytes,
+    inserted_at: Instant,
+    last_used: u64,
+    revalidating: bool,
+    validators: Validators,
+}
+
+/// The validators of a cached response, sent along when it is requested again.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Validators {
+    /// The `ETag` header, sent back as `If-None-Match`.
+    pub(crate) etag: Option<String>,
+    /// The `Last-Modified` header, sent back as `If-Modified-Since`.
+    pub(crate) last_modified: Option<String>,
+}
+
+impl Validators {
+    /// Reads the validators of a response to `path` from its `headers`.
+    ///
+    /// Responses of the `resources` endpoints without `Last-Modified` header use the
+    /// `lastUpdated` field of their `body` instead.
+    pub(crate) fn new(path: &str, headers: &HeaderMap, body: &[u8]) -> Self {
+        let header = |name| headers.get(name).and_then(|value| value.to_str().ok()).map(str::to_string);
+        let last_modified = header(LAST_MODIFIED).or_else(|| {
+            if !path.starts_with("resources/") {
+                return None;
+            }
+            #[derive(Deserialize)]
+            #[serde(rename_all = "camelCase")]
+            struct LastUpdated {
+                last_updated: Option<u64>,
+            }
+            let last_updated = serde_json::from_slice::<LastUpdated>(body).ok()?.last_updated?;
+            let last_updated = DateTime::<Utc>::from_timestamp_millis(i64::try_from(last_updated).ok()?)?;
+            Some(last_updated.format("%a, %d %b %Y %H:%M:%S GMT").to_string())
+        });
+        Validators {
+            etag: header(ETAG),
+            last_modified,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+impl ResponseCache {
+    pub(crate) fn new(config: CacheConfig) -> Self {
+        ResponseCache {
+            config,
+            inner: Mutex::new(CacheData::default()),
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the configuration of this cache.
+    pub fn config(&self) -> CacheConfig {
+        self.config
+    }
+
+    /// Returns the amount of cached responses, including expired ones that were not evicted yet.
+    pub fn len(&self) -> usize {
+        self.inner.lock().entries.len()
+    }
+
+    /// Returns `true` if no responses are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the total size of the cached bodies in bytes.
+    pub fn size_bytes(&self) -> usize {
+        self.inner.lock().bytes
+    }
+
+    /// Removes the cached response to `path`, returns `true` if it was cached.
+    ///
+    /// Use this to evict a response that is known to be stale, e.g. the guild of a member who left.
+    pub fn invalidate(&self, path: &str) -> bool {
+        let mut data = self.inner.lock();
+        let removed = data.remove(path);
+        record_size(&data);
+        removed
+    }
+
+    /// Removes the cached responses to all paths starting with `prefix`, e.g. `"player"`,
+    /// and returns how many were removed.
+    pub fn invalidate_prefix(&self, prefix: &str) -> usize {
+        let mut data = self.inner.lock();
+        let paths: Vec<String> = data.entries.keys().filter(|path| path.starts_with(prefix)).cloned().collect();
+        for path in &paths {
+            data.remove(path);
+        }
+        record_size(&data);
+        paths.len()
+    }
+
+    /// Removes all cached responses.
+    ///
+    /// The counters of [`ResponseCache::stats`] are kept.
+    pub fn clear(&self) {
+        let mut data = self.inner.lock();
+        data.entries.clear();
+        data.recency.clear();
+        data.bytes = 0;
+        record_size(&data);
+    }
+
+    /// Returns the statistics of this cache since it was created.
+    pub fn stats(&self) -> CacheStats {
+        let data = self.inner.lock();
+        CacheStats {
+            hits: data.hits,
+            stale_hits: data.stale_hits,
+            misses: data.misses,
+            evictions: data.evictions,
+            entries: data.entries.len(),
+            bytes: data.bytes,
+        }
+    }
+
+    /// Returns the cached body of `path` if it has not expired.
+    #[cfg(test)]
+    pub(crate) fn get(&self, path: &str) -> Option<Bytes> {
+        self.lookup(path).map(|(body, _)| body)
+    }
+
+    /// Returns the cached body of `path` if it has not expired or may still be served stale.
+    ///
+    /// The flag is `true` if the body is stale and the caller should refresh it, it is only
+    /// returned once until the response is inserted again or [`ResponseCache::revalidation_failed`].
+    pub(crate) fn lookup(&self, path: &str) -> Option<(Bytes, bool)> {
+        let mut data = self.inner.lock();
+        let age = match data.entries.get(path) {
+            Some(entry) => entry.inserted_at.elapsed(),
+            None => {
+                data.misses += 1;
+                record_miss();
+                return None;
+            }
+        };
+        let stale = age > self.config.ttl;
+        if stale && self.config.max_staleness.is_none_or(|max_staleness| age > self.config.ttl + max_staleness) {
+            // responses with validators are kept to revalidate them with a conditional request
+            if data.entries.get(path).is_some_and(|entry| entry.validators.is_empty()) {
+                data.remove(path);
+            }
+            data.misses += 1;
+            record_miss();
+            record_size(&data);
+            return None;
+        }
+        data.clock += 1;
+        let clock = data.clock;
+        let entry = data.entries.get_mut(path).unwrap();
+        let previous = std::mem::replace(&mut entry.last_used, clock);
+        let body = entry.body.clone();
+        let revalidate = stale && !std::mem::replace(&mut entry.revalidating, true);
+        data.recency.remove(&previous);
+        data.recency.insert(clock, path.to_string());
+        data.hits += 1;
+        if stale {
+            data.stale_hits += 1;
+        }
+        record_hit();
+        Some((body, revalidate))
+    }
+
+    /// Waits until no other request fetches `path`, then holds off further fetches of `path`
+    /// until the returned guard is dropped.
+    ///
+    /// Callers look the path up again if the guard [waited](FetchGuard::waited), so that
+    /// concurrent misses share the response of the first fetch.
+    pub(crate) async fn fetch_lock(&self, path: &str) -> FetchGuard<'_> {
+        let lock = Arc::clone(self.in_flight.lock().entry(path.to_string()).or_default());
+        let (guard, waited) = match Arc::clone(&lock).try_lock_owned() {
+            Ok(guard) => (guard, false),
+            Err(_) => (lock.lock_owned().await, true),
+        };
+        FetchGuard {
+            cache: self,
+            path: path.to_string(),
+            waited,
+            _guard: guard,
+        }
+    }
+
+    /// Returns the validators of the cached response to `path`, expired or not, if it has any.
+    pub(crate) fn validators(&self, path: &str) -> Option<Validators> {
+        self.inner.lock().entries.get(path)
+            .map(|entry| entry.validators.clone())
+            .filter(|validators| !validators.is_empty())
+    }
+
+    /// Marks the cached response to `path` as fresh again after the API answered a
+    /// conditional request with `304 Not Modified`, returns its body if it is still cached.
+    pub(crate) fn revalidated(&self, path: &str) -> Option<Bytes> {
+        let mut data = self.inner.lock();
+        let entry = data.entries.get_mut(path)?;
+        entry.inserted_at = Instant::now();
+        entry.revalidating = false;
+        Some(entry.body.clone())
+    }
+
+    /// Allows the stale response to `path` to be refreshed again after a refresh failed.
+    pub(crate) fn revalidation_failed(&self, path: &str) {
+        if let Some(entry) = self.inner.lock().entries.get_mut(path) {
+            entry.revalidating = false;
+        }
+    }
+
+    /// Caches `body` as the response to `path`, evicting the least recently used entries if necessary.
+    #[cfg(test)]
+    pub(crate) fn insert(&self, path: &str, body: Bytes) {
+        self.insert_validated(path, body, Validators::default());
+    }
+
+    /// Caches `body` like [`ResponseCache::insert`], along with the `validators` of the response.
+    pub(crate) fn insert_validated(&self, path: &str, body: Bytes, validators: Validators) {
+        if body.len() > self.config.max_bytes || self.config.max_entries == 0 {
+            return;
+        }
+        let mut data = self.inner.lock();
+        data.remove(path);
+        while data.entries.len() >= self.config.max_entries || data.bytes + body.len() > self.config.max_bytes {
+            let Some((_, oldest)) = data.recency.pop_first() else { break };
+            if let Some(entry) = data.entries.remove(&oldest) {
+                data.bytes -= entry.body.len();
+            }
+            data.evictions += 1;
+            record_eviction();
+        }
+        data.clock += 1;
+        let clock = data.clock;
+        data.bytes += body.len();
+        data.recency.insert(clock, path.to_string());
+        data.entries.insert(path.to_string(), CacheEntry {
+            body,
+            inserted_at: Instant::now(),
+            last_used: clock,
+            revalidating: false,
+            validators,
+        });
+        record_size(&data);
+    }
+}
+
+impl CacheData {
+    fn remove(&mut self, path: &str) -> bool {
+        match self.entries.remove(path) {
+            Some(entry) => {
+                self.recency.remove(&entry.last_used);
+                self.bytes -= entry.body.len();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The statistics of a [`ResponseCache`], see [`ResponseCache::stats`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    hits: u64,
+    stale_hits: u64,
+    misses: u64,
+    evictions: u64,
+    entries: usize,
+    bytes: usize,
+}
+
+impl CacheStats {
+    /// Returns the amount of requests served from the cache, including stale responses.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the amount of requests served with an expired response,
+    /// see [`CacheConfig::stale_while_revalidate`].
+    pub fn stale_hits(&self) -> u64 {
+        self.stale_hits
+    }
+
+    /// Returns the amount of requests that were not found in the cache or had expired.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Returns the amount of entries evicted to stay within the bounds of the cache.
+    ///
+    /// Invalidated entries are not counted.
+    pub fn evictions(&self) -> u64 {
+        self.evictions
+    }
+
+    /// Returns the amount of cached responses.
+    pub fn entries(&self) -> usize {
+        self.entries
+    }
+
+    /// Returns the total size of the cached bodies in bytes.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Returns the fraction of lookups that were served from the cache,
+    /// or [`Option::None`] if nothing was looked up yet.
+    pub fn hit_rate(&self) -> Option<f64> {
+        let lookups = self.hits + self.misses;
+        (lookups > 0).then(|| self.hits as f64 / lookups as f64)
+    }
+}
+
+#[cfg(feature = "metrics")]
+fn record_hit() {
+    ::metrics::counter!(crate::api::metrics::CACHE_HITS_TOTAL).increment(1);
+}
+
+#[cfg(feature = "metrics")]
+fn record_miss() {
+    ::metrics::counter!(crate::api::metrics::CACHE_MISSES_TOTAL).increment(1);
+}
+
+#[cfg(feature = "metrics")]
+fn record_eviction() {
+    ::metrics::counter!(crate::api::metrics::CACHE_EVICTIONS_TOTAL).increment(1);
+}
+
+#[cfg(feature = "metrics")]
+fn record_size(data: &CacheData) {
+    ::metrics::gauge!(crate::api::metrics::CACHE_ENTRIES).set(data.entries.len() as f64);
+    ::metrics::gauge!(crate::api::metrics::CACHE_BYTES).set(data.bytes as f64);
+}
+
+#[cfg(not(feature = "metrics"))]
+fn record_hit() {}
+
+#[cfg(not(feature = "metrics"))]
+fn record_miss() {}
+
+#[cfg(not(feature = "metrics"))]
+fn record_eviction() {}
+
+#[cfg(not(feature = "metrics"))]
+fn record_size(_data: &CacheData) {}
@@ -0,0 +1,141 @@
+//! Capturing the raw bodies of failed responses.
+//!
+//! When a response fails to deserialize or has an unexpected status code, the raw body is
+//! often the only way to find out what changed. Enable capturing at runtime with
+//! [`RequestHandlerBuilder::capture_failed_bodies`](crate::RequestHandlerBuilder::capture_failed_bodies)
+//! and inspect the most recent failures with
+//! [`RequestHandler::captured_bodies`](crate::RequestHandler::captured_bodies).
+//! With the `tracing` feature enabled, captured bodies are logged as well.
+//!
+//! Bodies are truncated and the `API-Key` header is redacted, so captures can be
+//! shared safely.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use hypixel_api::{RequestHandler, StatusReply};
+//! # use uuid::Uuid;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! # let api_key = Uuid::nil();
+//! let request_handler = RequestHandler::builder(api_key)
+//!     .capture_failed_bodies(4096)
+//!     .build();
+//! if request_handler.request::<StatusReply>("status?uuid=069a79f444e94726a5befca90e38aaf5", true).await.unwrap().is_err() {
+//!     for capture in request_handler.captured_bodies() {
+//!         println!("{}", capture);
+//!     }
+//! }
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use parking_lot::Mutex;
+use reqwest::StatusCode;
+use crate::api::error::HypixelApiError;
+
+/// The amount of most recent captures that are kept.
+const CAPTURE_HISTORY: usize = 32;
+/// The value that replaces the `API-Key` header.
+const REDACTED: &str = "<redacted>";
+
+/// The raw response of a failed request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedBody {
+    path: String,
+    status: StatusCode,
+    request_headers: Vec<(String, String)>,
+    body: String,
+    truncated: bool,
+    error: String,
+}
+
+impl CapturedBody {
+    /// Returns the path of the request.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the HTTP status of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns the headers sent by this crate along with the request, the `API-Key` header is redacted.
+    pub fn request_headers(&self) -> &[(String, String)] {
+        &self.request_headers
+    }
+
+    /// Returns the body of the response, truncated to the configured length.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Returns whether [`CapturedBody::body`] was truncated.
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the error the request failed with.
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+}
+
+impl Display for CapturedBody {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}): {}\n{}", self.path, self.status, self.error, self.body)?;
+        if self.truncated {
+            write!(f, "...")?;
+        }
+        Ok(())
+    }
+}
+
+/// Stores the most recent [`CapturedBody`]s.
+#[derive(Debug)]
+pub(crate) struct BodyCapture {
+    max_length: usize,
+    captures: Mutex<VecDeque<CapturedBody>>,
+}
+
+impl BodyCapture {
+    pub(crate) fn new(max_length: usize) -> Self {
+        BodyCapture {
+            max_length,
+            captures: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub(crate) fn capture(&self, path: &str, authenticated: bool, status: StatusCode, body: &[u8], error: &HypixelApiError) {
+        let body = String::from_utf8_lossy(body);
+        let mut end = body.len().min(self.max_length);
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut request_headers = Vec::new();
+        if authenticated {
+            request_headers.push(("API-Key".to_string(), REDACTED.to_string()));
+        }
+        let capture = CapturedBody {
+            path: path.to_string(),
+            status,
+            request_headers,
+            body: body[..end].to_string(),
+            truncated: end < body.len(),
+            error: error.to_string(),
+        };
+        #[cfg(feature = "tracing")]
+        warn!(%capture, "Captured the body of a failed response");
+        let mut captures = self.captures.lock();
+        if captures.len() == CAPTURE_HISTORY {
+            captures.pop_front();
+        }
+        captures.push_back(capture);
+    }
+
+    pub(crate) fn captures(&self) -> Vec<CapturedBody> {
+        self.captures.lock().iter().cloned().collect()
+    }
+}
@@ -0,0 +1,268 @@
+//! A high-level client that groups the typed endpoints by domain, see [`HypixelClient`].
+
+use futures::Stream;
+use serde::de::DeserializeOwned;
+use uuid::Uuid;
+use crate::api::batch::StatusBatch;
+use crate::api::error::HypixelApiError;
+use crate::api::key::SecretKey;
+use crate::api::path::ApiPath;
+use crate::api::reply::{AchievementsResourcesReply, Auction, BingoResourcesReply, CollectionsResourcesReply, CountsReply, ElectionResourcesReply, GamesResourcesReply, GuildData, GuildReply, ItemsResourcesReply, LeaderboardsReply, PlayerReply, QuestsResourcesReply, SkillsResourcesReply, SkyblockAuctionsReply, SkyblockBazaarReply, SkyblockBingoReply, SkyblockProfilesReply, StatusReply, VanityResourcesReply};
+use crate::api::request::RequestHandler;
+use crate::api::roster::{GuildRoster, RosterOptions};
+use crate::api::util::auctions::{scan_all_auctions, snipe, AuctionFilter, AuctionScan};
+
+/// A client for the Hypixel API with the typed endpoints grouped by domain, e.g.
+/// `client.player()`, `client.guild()`, `client.skyblock().auctions()` and `client.resources()`.
+///
+/// Every request is sent through the [`RequestHandler`] of the client, so the rate limit, cache
+/// and every other configuration of the handler apply. Endpoints without a method here are
+/// still available through [`HypixelClient::request`] or the handler itself, see
+/// [`HypixelClient::handler`].
+///
+/// # Examples
+/// ```rust,no_run
+/// use hypixel_api::HypixelClient;
+/// # use uuid::Uuid;
+/// # use std::str::FromStr;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let api_key = Uuid::nil();
+/// let client = HypixelClient::new(api_key);
+/// let uuid = Uuid::from_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap();
+///
+/// let status = client.player().status(uuid).await.unwrap();
+/// if let Some(guild) = client.guild().by_player(uuid).await.unwrap().guild() {
+///     println!("online: {}, guild: {}", status.online(), guild.name());
+/// }
+/// let page = client.skyblock().auctions().page(0).await.unwrap();
+/// println!("{} auctions on the first page", page.auctions().len());
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HypixelClient {
+    handler: RequestHandler,
+}
+
+impl HypixelClient {
+    /// Creates a client with a new [`RequestHandler`] for `api_key`, see [`RequestHandler::new`].
+    pub fn new(api_key: impl Into<SecretKey>) -> Self {
+        HypixelClient::from_handler(RequestHandler::new(api_key))
+    }
+
+    /// Creates a client that sends its requests through `handler`, e.g. one configured with
+    /// [`RequestHandler::builder`].
+    pub fn from_handler(handler: RequestHandler) -> Self {
+        HypixelClient { handler }
+    }
+
+    /// Returns the handler that sends the requests of this client.
+    pub fn handler(&self) -> &RequestHandler {
+        &self.handler
+    }
+
+    /// Requests `path` and waits for the reply, see [`RequestHandler::request`].
+    pub async fn request<T: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool) -> Result<T, HypixelApiError> {
+        self.handler.request(path, authenticated).await?
+    }
+
+    /// Returns the player endpoints.
+    pub fn player(&self) -> PlayerRequests<'_> {
+        PlayerRequests { handler: &self.handler }
+    }
+
+    /// Returns the guild endpoints.
+    pub fn guild(&self) -> GuildRequests<'_> {
+        GuildRequests { handler: &self.handler }
+    }
+
+    /// Returns the SkyBlock endpoints.
+    pub fn skyblock(&self) -> SkyblockRequests<'_> {
+        SkyblockRequests { handler: &self.handler }
+    }
+
+    /// Returns the resources endpoints.
+    pub fn resources(&self) -> ResourceRequests<'_> {
+        ResourceRequests { handler: &self.handler }
+    }
+
+    /// Requests the amount of players online, in total and per game.
+    pub async fn counts(&self) -> Result<CountsReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::Counts).await?
+    }
+
+    /// Requests the leaderboards of every game.
+    pub async fn leaderboards(&self) -> Result<LeaderboardsReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::Leaderboards).await?
+    }
+}
+
+impl From<RequestHandler> for HypixelClient {
+    fn from(handler: RequestHandler) -> Self {
+        HypixelClient::from_handler(handler)
+    }
+}
+
+/// The player endpoints, see [`HypixelClient::player`].
+#[derive(Debug, Copy, Clone)]
+pub struct PlayerRequests<'a> {
+    handler: &'a RequestHandler,
+}
+
+impl PlayerRequests<'_> {
+    /// Requests the data of the player `uuid`.
+    pub async fn get(&self, uuid: Uuid) -> Result<PlayerReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::Player { uuid }).await?
+    }
+
+    /// Requests the online status of the player `uuid`.
+    pub async fn status(&self, uuid: Uuid) -> Result<StatusReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::Status { uuid }).await?
+    }
+
+    /// Requests the status of every player in `uuids`, see [`RequestHandler::get_statuses`].
+    pub async fn statuses(&self, uuids: &[Uuid]) -> StatusBatch {
+        self.handler.get_statuses(uuids).await
+    }
+}
+
+/// The guild endpoints, see [`HypixelClient::guild`].
+#[derive(Debug, Copy, Clone)]
+pub struct GuildRequests<'a> {
+    handler: &'a RequestHandler,
+}
+
+impl GuildRequests<'_> {
+    /// Requests the guild with the id `id`, see [`RequestHandler::get_guild_by_id`].
+    pub async fn by_id(&self, id: &str) -> Result<GuildReply, HypixelApiError> {
+        self.handler.get_guild_by_id(id).await?
+    }
+
+    /// Requests the guild named `name`, ignoring case, see [`RequestHandler::get_guild_by_name`].
+    pub async fn by_name(&self, name: &str) -> Result<GuildReply, HypixelApiError> {
+        self.handler.get_guild_by_name(name).await?
+    }
+
+    /// Requests the guild the player `uuid` is a member of, see [`RequestHandler::get_guild_by_player`].
+    pub async fn by_player(&self, uuid: Uuid) -> Result<GuildReply, HypixelApiError> {
+        self.handler.get_guild_by_player(uuid).await?
+    }
+
+    /// Requests the player data of every member of `guild`, see [`RequestHandler::get_guild_roster`].
+    pub async fn roster(&self, guild: &GuildData, options: RosterOptions) -> GuildRoster {
+        self.handler.get_guild_roster(guild, options).await
+    }
+}
+
+/// The SkyBlock endpoints, see [`HypixelClient::skyblock`].
+#[derive(Debug, Copy, Clone)]
+pub struct SkyblockRequests<'a> {
+    handler: &'a RequestHandler,
+}
+
+impl<'a> SkyblockRequests<'a> {
+    /// Returns the auction house endpoints.
+    pub fn auctions(&self) -> AuctionRequests<'a> {
+        AuctionRequests { handler: self.handler }
+    }
+
+    /// Requests the SkyBlock profiles of the player `uuid`.
+    pub async fn profiles(&self, uuid: Uuid) -> Result<SkyblockProfilesReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::SkyblockProfiles { uuid }).await?
+    }
+
+    /// Requests the products of the bazaar.
+    pub async fn bazaar(&self) -> Result<SkyblockBazaarReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::SkyblockBazaar).await?
+    }
+
+    /// Requests the bingo events the player `uuid` took part in.
+    pub async fn bingo(&self, uuid: Uuid) -> Result<SkyblockBingoReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::SkyblockBingo { uuid }).await?
+    }
+}
+
+/// The auction house endpoints, see [`SkyblockRequests::auctions`].
+#[derive(Debug, Copy, Clone)]
+pub struct AuctionRequests<'a> {
+    handler: &'a RequestHandler,
+}
+
+impl<'a> AuctionRequests<'a> {
+    /// Requests the page `page` of the active auctions, starting at `0`.
+    pub async fn page(&self, page: u32) -> Result<SkyblockAuctionsReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::SkyblockAuctionsPage { page }).await?
+    }
+
+    /// Requests every page of the active auctions, see [`scan_all_auctions`].
+    pub async fn scan(&self, concurrency: usize) -> Result<AuctionScan, HypixelApiError> {
+        scan_all_auctions(self.handler, concurrency).await
+    }
+
+    /// Yields the active auctions that match `filter` as their pages are received, see [`snipe`].
+    pub fn snipe(&self, filter: AuctionFilter, concurrency: usize) -> impl Stream<Item = Result<Auction, HypixelApiError>> + 'a {
+        snipe(self.handler, filter, concurrency)
+    }
+}
+
+/// The resources endpoints, see [`HypixelClient::resources`].
+///
+/// Resources change rarely, configure a cache on the handler to avoid requesting them
+/// repeatedly, see [`RequestHandlerBuilder::cache`](crate::RequestHandlerBuilder::cache).
+#[derive(Debug, Copy, Clone)]
+pub struct ResourceRequests<'a> {
+    handler: &'a RequestHandler,
+}
+
+impl ResourceRequests<'_> {
+    /// Requests the information of every game.
+    pub async fn games(&self) -> Result<GamesResourcesReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::GamesResources).await?
+    }
+
+    /// Requests the achievements of every game.
+    pub async fn achievements(&self) -> Result<AchievementsResourcesReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::AchievementsResources).await?
+    }
+
+    /// Requests the quests of every game.
+    pub async fn quests(&self) -> Result<QuestsResourcesReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::QuestsResources).await?
+    }
+
+    /// Requests every pet.
+    pub async fn vanity_pets(&self) -> Result<VanityResourcesReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::VanityPetsResources).await?
+    }
+
+    /// Requests every companion.
+    pub async fn vanity_companions(&self) -> Result<VanityResourcesReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::VanityCompanionsResources).await?
+    }
+
+    /// Requests the SkyBlock skills.
+    pub async fn skyblock_skills(&self) -> Result<SkillsResourcesReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::SkyblockSkillsResources).await?
+    }
+
+    /// Requests the SkyBlock collections.
+    pub async fn skyblock_collections(&self) -> Result<CollectionsResourcesReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::SkyblockCollectionsResources).await?
+    }
+
+    /// Requests the SkyBlock items.
+    pub async fn skyblock_items(&self) -> Result<ItemsResourcesReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::SkyblockItemsResources).await?
+    }
+
+    /// Requests the current SkyBlock mayor and election.
+    pub async fn skyblock_election(&self) -> Result<ElectionResourcesReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::SkyblockElectionResources).await?
+    }
+
+    /// Requests the goals of the current SkyBlock bingo event.
+    pub async fn skyblock_bingo(&self) -> Result<BingoResourcesReply, HypixelApiError> {
+        self.handler.request_typed(ApiPath::SkyblockBingoResources).await?
+    }
+}
@@ -0,0 +1,291 @@
+//! Diagnostics of the deserialization of responses.
+//!
+//! When Hypixel adds or changes fields, the typed data structures of this crate either ignore
+//! the new fields or fail to deserialize. A [`DiagnosticsReport`] lists both, so these
+//! changes can be detected early. Enable diagnostics for every response of a
+//! [`RequestHandler`](crate::RequestHandler) with
+//! [`RequestHandlerBuilder::diagnostics`](crate::RequestHandlerBuilder::diagnostics), or
+//! diagnose a single body with [`deserialize_with_report`].
+//!
+//! Fields captured by the catch-all `other` maps of the reply structs (see e.g.
+//! `PlayerData::property_value`) are not ignored and thus not reported.
+//!
+//! ### Strict mode
+//! [`deserialize_strict`] and
+//! [`RequestHandlerBuilder::strict`](crate::RequestHandlerBuilder::strict) turn ignored fields
+//! into [`HypixelApiError::UnknownFields`] instead of reporting them. Fields captured by the
+//! catch-all maps of the data structures of this crate (e.g. `PlayerData`, `GuildData` and
+//! the stats of some games) are unknown as well, wherever these structures are nested. Run tests against recorded responses (e.g. a [`Cassette`](crate::Cassette)) in strict
+//! mode to notice as soon as the data structures of this crate drift from the API.
+//!
+//! # Examples
+//! ```rust
+//! use hypixel_api::SkillsResourcesReply;
+//! use hypixel_api::diagnostics::deserialize_with_report;
+//!
+//! let json = r#"{"success":true,"skills":{"FARMING":{"name":"Farming","maxLevel":60,"newField":1}}}"#;
+//! let (reply, report) = deserialize_with_report::<SkillsResourcesReply>("resources/skyblock/skills", json);
+//! assert!(reply.is_ok());
+//! assert_eq!(report.ignored_fields(), ["skills.FARMING.newField"]);
+//! ```
+
+use std::cell::RefCell;
+#[cfg(feature = "reply")]
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+#[cfg(feature = "reply")]
+use serde::{Deserialize, Deserializer};
+use serde::de::DeserializeOwned;
+#[cfg(feature = "reply")]
+use serde::de::{SeqAccess, Visitor};
+#[cfg(feature = "reply")]
+use serde_json::Value;
+use crate::api::error::HypixelApiError;
+
+/// The fields of a body that serde does not report itself, see [`deserialize_recorded`].
+#[derive(Debug, Default)]
+struct Unseen {
+    /// The fields captured by catch-all maps.
+    captured: Vec<String>,
+    /// The fields ignored within parts of the body that are deserialized separately.
+    ignored: Vec<String>,
+}
+
+thread_local! {
+    /// The unseen fields so far, while a body is deserialized with a report on this thread.
+    static UNSEEN: RefCell<Option<Unseen>> = const { RefCell::new(None) };
+}
+
+/// A callback that receives every non-empty [`DiagnosticsReport`].
+pub(crate) type DiagnosticsCallback = Arc<dyn Fn(&DiagnosticsReport) + Send + Sync>;
+
+/// The fields that were ignored or failed to deserialize in a single response.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiagnosticsReport {
+    path: String,
+    ignored_fields: Vec<String>,
+    error: Option<FieldError>,
+}
+
+/// A field that failed to deserialize.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    field: String,
+    message: String,
+}
+
+impl DiagnosticsReport {
+    /// Returns the request path of the response.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the fields that were present in the response but not deserialized,
+    /// in dotted notation (e.g. `"session.newField"`).
+    pub fn ignored_fields(&self) -> &[String] {
+        &self.ignored_fields
+    }
+
+    /// Returns the field that failed to deserialize, if any.
+    pub fn error(&self) -> Option<&FieldError> {
+        self.error.as_ref()
+    }
+
+    /// Returns `true` if nothing was ignored and deserialization succeeded.
+    pub fn is_empty(&self) -> bool {
+        self.ignored_fields.is_empty() && self.error.is_none()
+    }
+}
+
+impl Display for DiagnosticsReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        if let Some(error) = &self.error {
+            write!(f, "{}; ", error)?;
+        }
+        write!(f, "ignored fields: [{}]", self.ignored_fields.join(", "))
+    }
+}
+
+impl FieldError {
+    /// Returns the field in dotted notation (e.g. `"session.online"`), `"."` for the root.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Deserializes `json`, the body of a response to `path`, and reports ignored fields
+/// and the field that failed to deserialize.
+pub fn deserialize_with_report<T: DeserializeOwned>(path: &str, json: &str) -> (Result<T, HypixelApiError>, DiagnosticsReport) {
+    let (result, report, _) = deserialize_recorded(path, json.as_bytes());
+    (result, report)
+}
+
+/// Like [`deserialize_with_report`], for a body that has not been validated as UTF-8, and
+/// also returns the fields captured by catch-all maps, which serde does not report as ignored.
+fn deserialize_recorded<T: DeserializeOwned>(path: &str, json: &[u8]) -> (Result<T, HypixelApiError>, DiagnosticsReport, Vec<String>) {
+    let previous = UNSEEN.replace(Some(Unseen::default()));
+    let mut ignored_fields = Vec::new();
+    let mut deserializer = serde_json::Deserializer::from_slice(json);
+    let result = {
+        let mut track = |field: serde_ignored::Path| ignored_fields.push(field.to_string());
+        let deserializer = serde_ignored::Deserializer::new(&mut deserializer, &mut track);
+        serde_path_to_error::deserialize::<_, T>(deserializer)
+    };
+    let (result, error) = match result {
+        Ok(value) => match deserializer.end() {
+            Ok(()) => (Ok(value), None),
+            Err(error) => {
+                let field = FieldError { field: ".".to_string(), message: error.to_string() };
+                (Err(error.into()), Some(field))
+            }
+        },
+        Err(error) => {
+            let field = FieldError { field: error.path().to_string(), message: error.inner().to_string() };
+            (Err(error.into_inner().into()), Some(field))
+        }
+    };
+    let unseen = UNSEEN.replace(previous).unwrap_or_default();
+    ignored_fields.extend(unseen.ignored);
+    (result, DiagnosticsReport { path: path.to_string(), ignored_fields, error }, unseen.captured)
+}
+
+/// Deserializes `json`, the body of a response to `path`, and fails if any field was ignored.
+///
+/// # Errors
+///
+/// Fails with [`HypixelApiError::UnknownFields`] listing the ignored fields and the fields
+/// captured by catch-all maps, or with the error of the field that failed to deserialize.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::SkillsResourcesReply;
+/// use hypixel_api::diagnostics::deserialize_strict;
+/// use hypixel_api::error::HypixelApiError;
+///
+/// let json = r#"{"success":true,"skills":{"FARMING":{"name":"Farming","maxLevel":60,"newField":1}}}"#;
+/// match deserialize_strict::<SkillsResourcesReply>("resources/skyblock/skills", json) {
+///     Err(HypixelApiError::UnknownFields(_, fields)) => assert_eq!(fields, ["skills.FARMING.newField"]),
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn deserialize_strict<T: DeserializeOwned>(path: &str, json: &str) -> Result<T, HypixelApiError> {
+    deserialize_reported(path, json.as_bytes(), None, true)
+}
+
+/// Deserializes `json` and passes a non-empty report to `callback`, if any.
+///
+/// If `strict` is set, ignored fields and fields captured by catch-all maps fail the deserialization.
+pub(crate) fn deserialize_reported<T: DeserializeOwned>(path: &str, json: &[u8], callback: Option<&DiagnosticsCallback>, strict: bool) -> Result<T, HypixelApiError> {
+    let (result, report, captured) = deserialize_recorded(path, json);
+    if let (false, Some(callback)) = (report.is_empty(), callback) {
+        #[cfg(feature = "tracing")]
+        warn!(%report, "Response did not match the data structure");
+        callback(&report);
+    }
+    match result {
+        Ok(value) if strict => {
+            let mut fields = report.ignored_fields;
+            fields.extend(captured);
+            match fields.is_empty() {
+                true => Ok(value),
+                false => Err(HypixelApiError::UnknownFields(report.path, fields)),
+            }
+        }
+        result => result,
+    }
+}
+
+/// Deserializes a catch-all map, recording its keys as captured fields.
+#[cfg(feature = "reply")]
+pub(crate) fn capture<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashMap<String, Value>, D::Error> {
+    let other = HashMap::<String, Value>::deserialize(deserializer)?;
+    UNSEEN.with_borrow_mut(|unseen| {
+        if let Some(unseen) = unseen {
+            let mut keys: Vec<_> = other.keys().cloned().collect();
+            keys.sort();
+            unseen.captured.extend(keys);
+        }
+    });
+    Ok(other)
+}
+
+/// Deserializes `value`, a part of a body that was deserialized into a [`Value`] first, and
+/// records the fields ignored within it.
+///
+/// The error names the field within `value` that failed to deserialize.
+#[cfg(feature = "reply")]
+pub(crate) fn deserialize_part<'de, T: Deserialize<'de>>(value: &'de Value) -> Result<T, serde_path_to_error::Error<serde_json::Error>> {
+    if UNSEEN.with_borrow(Option::is_none) {
+        return serde_path_to_error::deserialize(value);
+    }
+    let mut ignored = Vec::new();
+    let result = {
+        let mut track = |field: serde_ignored::Path| ignored.push(field.to_string());
+        serde_path_to_error::deserialize(serde_ignored::Deserializer::new(value, &mut track))
+    };
+    UNSEEN.with_borrow_mut(|unseen| {
+        if let Some(unseen) = unseen {
+            unseen.ignored.extend(ignored);
+        }
+    });
+    result
+}
+
+/// Runs `deserialize`, which deserializes the field `name`, and prefixes the unseen fields
+/// within it with `name`.
+#[cfg(feature = "reply")]
+pub(crate) fn within<T, E>(name: &str, deserialize: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+    let start = UNSEEN.with_borrow(|unseen| unseen.as_ref().map(|unseen| (unseen.captured.len(), unseen.ignored.len())));
+    let value = deserialize()?;
+    if let Some((captured, ignored)) = start {
+        UNSEEN.with_borrow_mut(|unseen| {
+            if let Some(unseen) = unseen {
+                let fields = unseen.captured.iter_mut().skip(captured).chain(unseen.ignored.iter_mut().skip(ignored));
+                for field in fields {
+                    *field = format!("{}.{}", name, field);
+                }
+            }
+        });
+    }
+    Ok(value)
+}
+
+/// Deserializes a sequence, prefixing the unseen fields within each element with its index.
+#[cfg(feature = "reply")]
+pub(crate) fn elements<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    struct ElementsVisitor<T>(std::marker::PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for ElementsVisitor<T> {
+        type Value = Vec<T>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("a sequence")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<T>, A::Error> {
+            let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(element) = within(&elements.len().to_string(), || seq.next_element())? {
+                elements.push(element);
+            }
+            Ok(elements)
+        }
+    }
+
+    deserializer.deserialize_seq(ElementsVisitor(std::marker::PhantomData))
+}
@@ -0,0 +1,165 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll};
+use tokio::sync::{mpsc, oneshot, Notify};
+use crate::api::error::HypixelApiError;
+
+/// The amount of workers of a [`RequestHandler`](crate::RequestHandler) if not configured otherwise.
+pub(crate) const DEFAULT_WORKERS: usize = 32;
+
+/// The queue length below which [`RequestHandler::request_when_ready`](crate::RequestHandler::request_when_ready)
+/// queues a request if not configured otherwise.
+pub(crate) const DEFAULT_QUEUE_CAPACITY: usize = 1024;
+
+/// A queued request, it is not polled until a worker picks it up.
+type Job = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Executes queued requests on a bounded set of worker tasks.
+///
+/// Workers are spawned on the runtime of the first request that is queued, and again if the
+/// runtime they ran on was shut down. They stop once the dispatcher is dropped and the queue
+/// is empty, so requests that were queued before are still completed.
+///
+/// Clones share the queue and the workers.
+#[derive(Debug, Clone)]
+pub(crate) struct Dispatcher {
+    queue: mpsc::UnboundedSender<Job>,
+    receiver: Arc<tokio::sync::Mutex<mpsc::UnboundedReceiver<Job>>>,
+    workers: usize,
+    running: Arc<AtomicUsize>,
+    queued: Arc<AtomicUsize>,
+    dequeued: Arc<Notify>,
+    capacity: usize,
+}
+
+impl Dispatcher {
+    pub(crate) fn new(workers: usize, capacity: usize) -> Self {
+        let (queue, receiver) = mpsc::unbounded_channel();
+        Dispatcher {
+            queue,
+            receiver: Arc::new(tokio::sync::Mutex::new(receiver)),
+            workers: workers.max(1),
+            running: Arc::new(AtomicUsize::new(0)),
+            queued: Arc::new(AtomicUsize::new(0)),
+            dequeued: Arc::new(Notify::new()),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Returns the maximum amount of requests that are executed concurrently.
+    pub(crate) fn workers(&self) -> usize {
+        self.workers
+    }
+
+    /// Returns the amount of requests that are queued but not picked up by a worker yet.
+    pub(crate) fn queued(&self) -> usize {
+        self.queued.load(Ordering::Acquire)
+    }
+
+    /// Returns the queue length [`Dispatcher::ready`] waits to drop below.
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Waits until fewer than [`Dispatcher::capacity`] requests are queued.
+    pub(crate) async fn ready(&self) {
+        loop {
+            // created before the check, so a dequeue in between is not missed
+            let dequeued = self.dequeued.notified();
+            if self.queued() < self.capacity {
+                return;
+            }
+            dequeued.await;
+        }
+    }
+
+    /// Queues `job` for execution by the next idle worker.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime while no workers are running.
+    pub(crate) fn submit(&self, job: impl Future<Output = ()> + Send + 'static) {
+        self.spawn_workers();
+        self.queued.fetch_add(1, Ordering::AcqRel);
+        // the receiver is owned by this dispatcher as well, the queue is never closed
+        let _ = self.queue.send(Box::pin(job));
+    }
+
+    fn spawn_workers(&self) {
+        let mut running = self.running.load(Ordering::Acquire);
+        while running < self.workers {
+            match self.running.compare_exchange_weak(running, running + 1, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => {
+                    let guard = WorkerGuard(Arc::clone(&self.running));
+                    tokio::spawn(Dispatcher::work(Arc::clone(&self.receiver), Arc::clone(&self.queued), Arc::clone(&self.dequeued), guard));
+                    running += 1;
+                }
+                Err(actual) => running = actual,
+            }
+        }
+    }
+
+    async fn work(
+        receiver: Arc<tokio::sync::Mutex<mpsc::UnboundedReceiver<Job>>>,
+        queued: Arc<AtomicUsize>,
+        dequeued: Arc<Notify>,
+        _guard: WorkerGuard,
+    ) {
+        loop {
+            let job = receiver.lock().await.recv().await;
+            // `None` once the dispatcher was dropped and the queue is empty
+            match job {
+                Some(job) => {
+                    queued.fetch_sub(1, Ordering::AcqRel);
+                    dequeued.notify_waiters();
+                    job.await
+                }
+                None => return,
+            }
+        }
+    }
+}
+
+/// Counts a running worker, also when its task is dropped with the runtime.
+struct WorkerGuard(Arc<AtomicUsize>);
+
+impl Drop for WorkerGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// The future of a request queued by a [`RequestHandler`](crate::RequestHandler), resolving to its result.
+///
+/// The request is executed whether or not this future is polled or dropped.
+/// It resolves to an error if the request was dropped before it completed,
+/// e.g. because the runtime it was executed on was shut down.
+#[derive(Debug)]
+pub struct RequestFuture<T> {
+    receiver: oneshot::Receiver<Result<T, HypixelApiError>>,
+}
+
+impl<T> RequestFuture<T> {
+    /// Creates a future that resolves to `result` immediately, e.g. to implement
+    /// [`HypixelApi`](crate::HypixelApi) for a mock.
+    pub fn ready(result: Result<T, HypixelApiError>) -> Self {
+        let (sender, future) = RequestFuture::channel();
+        let _ = sender.send(result);
+        future
+    }
+
+    pub(crate) fn channel() -> (oneshot::Sender<Result<T, HypixelApiError>>, Self) {
+        let (sender, receiver) = oneshot::channel();
+        (sender, RequestFuture { receiver })
+    }
+}
+
+impl<T> Future for RequestFuture<T> {
+    type Output = Result<Result<T, HypixelApiError>, oneshot::error::RecvError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver).poll(cx)
+    }
+}
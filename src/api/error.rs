@@ -5,11 +5,36 @@ use std::time::Duration;
 use reqwest::StatusCode;
 use serde::Deserialize;
 use thiserror::Error;
+use crate::api::pagination::LimitReached;
 
 #[derive(Debug, Error)]
 pub enum HypixelApiError {
     #[error("Could not parse {0} into integer")]
     IntFromStrError(String),
+    #[error("Unknown color code: {0}")]
+    UnknownColorCode(String),
+    #[error("Could not decode NBT data: {0}")]
+    NbtDecodeError(String),
+    #[error("No recorded response for path: {0}")]
+    CassetteMiss(String),
+    #[error("Response to {0} was rejected: {1}")]
+    BodyRejected(String, String),
+    #[error("Response to {0} exceeds the deserialization budget: {1}")]
+    BudgetExceeded(String, String),
+    #[error("Stopped after {0}, see PageLimits")]
+    LimitReached(LimitReached),
+    #[error("Response to {0} has fields the data structure does not know: {1:?}")]
+    UnknownFields(String, Vec<String>),
+    #[error("The API key was rejected, replace it with RequestHandler::set_api_key")]
+    InvalidApiKey,
+    #[error("Environment variable {0} is not set")]
+    MissingEnvVar(String),
+    #[error("Environment variable {0} is malformed: {1}")]
+    InvalidEnvVar(String, String),
+    #[error("Response did not include the rate limit header: {0}")]
+    MissingRateLimitHeader(String),
+    #[error("The API reported an unsuccessful reply: {}", .0.as_deref().unwrap_or("no cause given"))]
+    Unsuccessful(Option<String>),
     #[error("Unexpected response code received: {0}, {1:?}")]
     UnexpectedResponseCode(StatusCode, Option<ErrorReply>),
     #[error("Error from reqwest!")]
@@ -20,13 +45,28 @@ pub enum HypixelApiError {
     #[error("Could not send time to dedicated thread")]
     TokioSendTime {
         #[from]
-        source: tokio::sync::mpsc::error::TrySendError<Option<Duration>>,
+        source: tokio::sync::mpsc::error::TrySendError<Duration>,
     },
     #[error("Error while receiving watcher update")]
     TokioReceive {
         #[from]
         source: tokio::sync::watch::error::RecvError,
     },
+    #[error("Request task failed")]
+    TokioJoin {
+        #[from]
+        source: tokio::task::JoinError,
+    },
+    #[error("Request was dropped before it completed")]
+    RequestDropped {
+        #[from]
+        source: tokio::sync::oneshot::error::RecvError,
+    },
+    #[error("I/O error")]
+    Io {
+        #[from]
+        source: std::io::Error,
+    },
     #[error("Error while deserializing from json")]
     SerdeJsonError {
         #[from]
@@ -0,0 +1,70 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+use reqwest::StatusCode;
+
+/// The amount of events a lagging subscriber can fall behind before it misses events.
+pub(crate) const EVENT_CAPACITY: usize = 1024;
+
+/// Identifies a single request queued by a [`RequestHandler`](crate::RequestHandler).
+///
+/// Ids are unique per `RequestHandler` and increase in the order requests are queued.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RequestId(pub(crate) u64);
+
+impl RequestId {
+    /// Returns the numeric value of this id.
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for RequestId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// An event in the lifecycle of a single request, see [`RequestHandler::subscribe`](crate::RequestHandler::subscribe).
+///
+/// Every request emits [`RequestEvent::Queued`] first and [`RequestEvent::Completed`] or
+/// [`RequestEvent::Failed`] last. [`RequestEvent::Sent`] is emitted for every attempt to
+/// send the request, requests that are rate limited by the API are sent again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestEvent {
+    /// The request was queued for execution.
+    Queued {
+        id: RequestId,
+        path: String,
+    },
+    /// The request was allowed by the rate limit and sent to the API.
+    Sent {
+        id: RequestId,
+        attempt: u32,
+    },
+    /// The request completed and its response was deserialized.
+    Completed {
+        id: RequestId,
+        /// The time since the request was queued.
+        latency: Duration,
+        status: StatusCode,
+    },
+    /// The request failed, see [`HypixelApiError`](crate::error::HypixelApiError).
+    Failed {
+        id: RequestId,
+        /// The time since the request was queued.
+        latency: Duration,
+        error: String,
+    },
+}
+
+impl RequestEvent {
+    /// Returns the id of the request this event belongs to.
+    pub fn id(&self) -> RequestId {
+        match self {
+            RequestEvent::Queued { id, .. }
+            | RequestEvent::Sent { id, .. }
+            | RequestEvent::Completed { id, .. }
+            | RequestEvent::Failed { id, .. } => *id,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Anonymized response fixtures of the Hypixel API, for use in tests.
+//!
+//! Every function returns the raw JSON body of a response, which can be deserialized
+//! into the data structures of this crate or your own:
+//! ```rust
+//! use hypixel_api::{fixtures, PlayerReply};
+//!
+//! let reply: PlayerReply = serde_json::from_str(fixtures::player_full()).unwrap();
+//! assert!(reply.success());
+//! ```
+//!
+//! Names, UUIDs and ids in these fixtures have been replaced, the structure of the responses
+//! is kept as returned by the API (including fields this crate does not capture).
+
+/// A `/player` response with ranks, rewards, quests, parkour completions and game stats.
+pub fn player_full() -> &'static str {
+    include_str!("player_full.json")
+}
+
+/// A `/status` response of a player that is online in Bed Wars.
+pub fn status_online() -> &'static str {
+    include_str!("status_online.json")
+}
+
+/// A `/status` response of a player that is offline.
+pub fn status_offline() -> &'static str {
+    include_str!("status_offline.json")
+}
+
+/// A `/guild` response with ranks, members and their experience history.
+pub fn guild() -> &'static str {
+    include_str!("guild.json")
+}
+
+/// A `/skyblock/bazaar` response with two products.
+pub fn bazaar() -> &'static str {
+    include_str!("bazaar.json")
+}
+
+/// A `/skyblock/auctions` page with a Buy It Now auction and a regular auction.
+pub fn auctions() -> &'static str {
+    include_str!("auctions.json")
+}
+
+/// A `/skyblock/profiles` response with a normal profile and a selected ironman profile
+/// shared by two members, including skills, slayer, dungeons and banking data.
+pub fn skyblock_profiles() -> &'static str {
+    include_str!("skyblock_profiles.json")
+}
+
+/// A `/resources/quests` response.
+pub fn resources_quests() -> &'static str {
+    include_str!("resources_quests.json")
+}
+
+/// A `/resources/skyblock/skills` response.
+pub fn resources_skills() -> &'static str {
+    include_str!("resources_skills.json")
+}
+
+/// A `/resources/games` response.
+///
+/// This snapshot is the source of [`GameType`](crate::GameType) and the table of
+/// `util::games`, which are generated from it at build time.
+pub fn resources_games() -> &'static str {
+    include_str!("resources_games.json")
+}
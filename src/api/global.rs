@@ -0,0 +1,85 @@
+//! A global [`RequestHandler`] for small tools and examples.
+//!
+//! Call [`init`] once at startup, then use the free functions such as [`get_player`] from
+//! anywhere without passing a handler around. Libraries should take an
+//! [`HypixelApi`](crate::HypixelApi) instead, so applications keep control over the handler.
+//!
+//! # Examples
+//! ```rust,no_run
+//! # use uuid::Uuid;
+//! # use std::str::FromStr;
+//! # #[tokio::main]
+//! # async fn main() {
+//! let api_key = Uuid::from_str(env!("HYPIXEL_API_KEY")).unwrap();
+//! hypixel_api::init(api_key);
+//!
+//! let uuid = Uuid::from_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap();
+//! let player = hypixel_api::get_player(uuid).await.unwrap();
+//! # }
+//! ```
+
+use std::sync::OnceLock;
+use serde::de::DeserializeOwned;
+use uuid::Uuid;
+use crate::api::error::HypixelApiError;
+use crate::api::key::SecretKey;
+use crate::api::path::ApiPath;
+use crate::api::reply::{PlayerReply, StatusReply};
+use crate::api::request::RequestHandler;
+
+static HANDLER: OnceLock<RequestHandler> = OnceLock::new();
+
+/// Initializes the global handler with `api_key`, see [`RequestHandler::new`].
+///
+/// Returns `false` if the global handler was already initialized, in which case it is kept.
+pub fn init(api_key: impl Into<SecretKey>) -> bool {
+    let mut initialized = false;
+    HANDLER.get_or_init(|| {
+        initialized = true;
+        RequestHandler::new(api_key)
+    });
+    initialized
+}
+
+/// Initializes the global handler with a configured `handler`, see [`RequestHandler::builder`].
+///
+/// Returns `false` if the global handler was already initialized, in which case it is kept.
+pub fn init_with(handler: RequestHandler) -> bool {
+    HANDLER.set(handler).is_ok()
+}
+
+/// Returns the global handler.
+///
+/// # Panics
+///
+/// Panics if the global handler was not initialized with [`init`] or [`init_with`].
+pub fn handler() -> &'static RequestHandler {
+    HANDLER.get().expect("the global RequestHandler is not initialized, call hypixel_api::init first")
+}
+
+/// Requests `path` with the global handler and waits for the reply, see [`RequestHandler::request`].
+///
+/// # Panics
+///
+/// Panics if the global handler was not initialized.
+pub async fn request<T: DeserializeOwned + Send + 'static>(path: &str, authenticated: bool) -> Result<T, HypixelApiError> {
+    handler().request(path, authenticated).await?
+}
+
+/// Requests the data of the player `uuid` with the global handler.
+///
+/// # Panics
+///
+/// Panics if the global handler was not initialized.
+pub async fn get_player(uuid: Uuid) -> Result<PlayerReply, HypixelApiError> {
+    handler().request_typed(ApiPath::Player { uuid }).await?
+}
+
+/// Requests the online status of the player `uuid` with the global handler.
+///
+/// # Panics
+///
+/// Panics if the global handler was not initialized.
+pub async fn get_status(uuid: Uuid) -> Result<StatusReply, HypixelApiError> {
+    handler().request_typed(ApiPath::Status { uuid }).await?
+}
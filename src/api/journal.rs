@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use crate::api::error::HypixelApiError;
+
+/// A file of the requests of a [`RequestHandler`](crate::RequestHandler) that were queued but
+/// did not finish yet, to resume long jobs after a crash.
+///
+/// Every queued request is appended to the journal and marked as finished once it completed or
+/// failed. When a journal is opened, the requests that never finished in the previous run are
+/// [recovered](Journal::recovered), so they can be queued again. A recovered request stays in the
+/// journal until a request to the same path finished, so crashing again before it was queued
+/// does not lose it. Writing to the journal is best effort, a failed write does not fail the request.
+///
+/// The journal is written by a dedicated thread, so queueing a request never waits for the disk.
+/// Dropping the journal, i.e. the last clone of its handler, waits until every entry was written.
+///
+/// The journal is compacted to the unfinished requests whenever it is opened. The compacted
+/// journal is written next to it and then replaces it, so a crash while opening loses nothing.
+///
+/// # Examples
+/// ```rust,no_run
+/// use hypixel_api::{Journal, RequestHandler, SkyblockAuctionsReply};
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), hypixel_api::error::HypixelApiError> {
+/// # let api_key = Uuid::nil();
+/// let journal = Journal::open("scan.journal")?;
+/// let recovered = journal.recovered();
+/// let request_handler = RequestHandler::builder(api_key).journal(journal).build();
+/// for entry in recovered {
+///     let handler = match entry.tag() {
+///         Some(tag) => request_handler.tagged(tag.to_string()),
+///         None => request_handler.clone(),
+///     };
+///     let reply = handler.request::<SkyblockAuctionsReply>(entry.path(), entry.authenticated()).await.unwrap()?;
+///     // continue the scan ...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Journal {
+    path: PathBuf,
+    /// The recovered requests per path that were not queued again yet.
+    recovered: Mutex<HashMap<String, (JournalEntry, usize)>>,
+    lines: Option<mpsc::Sender<String>>,
+    writer: Option<JoinHandle<()>>,
+}
+
+/// A request written to a [`Journal`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct JournalEntry {
+    path: String,
+    authenticated: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tag: Option<String>,
+}
+
+impl JournalEntry {
+    /// Returns the path of the request, including its query.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns whether the request requires the API key.
+    pub fn authenticated(&self) -> bool {
+        self.authenticated
+    }
+
+    /// Returns the tag of the handler that queued the request, see
+    /// [`RequestHandler::tagged`](crate::RequestHandler::tagged).
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+}
+
+/// A line of the journal file.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum Record {
+    Queued(JournalEntry),
+    Finished { path: String },
+}
+
+impl Journal {
+    /// Opens the journal at `path`, creating it if it does not exist.
+    ///
+    /// A last line that was only partially written when the previous run crashed is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file could not be read or written.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, HypixelApiError> {
+        let path = path.into();
+        let mut unfinished: HashMap<String, (JournalEntry, usize)> = HashMap::new();
+        if path.exists() {
+            for line in BufReader::new(File::open(&path)?).lines() {
+                match serde_json::from_str(&line?) {
+                    Ok(Record::Queued(entry)) => unfinished.entry(entry.path.clone()).or_insert((entry, 0)).1 += 1,
+                    Ok(Record::Finished { path }) => {
+                        if let Some((_, count)) = unfinished.get_mut(&path) {
+                            *count -= 1;
+                            if *count == 0 {
+                                unfinished.remove(&path);
+                            }
+                        }
+                    }
+                    Err(_) => continue,
+                }
+            }
+        } else if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut compacted = String::new();
+        for (entry, count) in unfinished.values() {
+            let line = serde_json::to_string(&Record::Queued(entry.clone()))?;
+            for _ in 0..*count {
+                compacted.push_str(&line);
+                compacted.push('\n');
+            }
+        }
+        let mut temporary = OsString::from(path.as_os_str());
+        temporary.push(".tmp");
+        let temporary = PathBuf::from(temporary);
+        let mut file = File::create(&temporary)?;
+        file.write_all(compacted.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temporary, &path)?;
+
+        let file = OpenOptions::new().append(true).open(&path)?;
+        let (lines, lines_rx) = mpsc::channel();
+        let writer = std::thread::spawn(move || write_lines(file, lines_rx));
+        Ok(Journal {
+            path,
+            recovered: Mutex::new(unfinished),
+            lines: Some(lines),
+            writer: Some(writer),
+        })
+    }
+
+    /// Returns the path of the file backing this journal.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the requests that did not finish in the previous run and were not queued again
+    /// yet, ordered by path.
+    ///
+    /// A request that was queued more than once is returned as often.
+    pub fn recovered(&self) -> Vec<JournalEntry> {
+        let mut recovered: Vec<JournalEntry> = self.recovered.lock().values()
+            .flat_map(|(entry, count)| std::iter::repeat_n(entry.clone(), *count))
+            .collect();
+        recovered.sort_by(|a, b| a.path.cmp(&b.path));
+        recovered
+    }
+
+    /// Appends a queued request to the journal.
+    ///
+    /// A recovered request to the same path is already in the journal and is taken over instead.
+    pub(crate) fn queued(&self, path: &str, authenticated: bool, tag: Option<&str>) {
+        let mut recovered = self.recovered.lock();
+        if let Some((_, count)) = recovered.get_mut(path) {
+            *count -= 1;
+            if *count == 0 {
+                recovered.remove(path);
+            }
+            return;
+        }
+        let entry = JournalEntry {
+            path: path.to_string(),
+            authenticated,
+            tag: tag.map(str::to_string),
+        };
+        self.append(&Record::Queued(entry));
+    }
+
+    /// Marks a request to `path` as finished.
+    pub(crate) fn finished(&self, path: &str) {
+        self.append(&Record::Finished { path: path.to_string() });
+    }
+
+    /// Hands `record` to the writer thread.
+    fn append(&self, record: &Record) {
+        match serde_json::to_string(record) {
+            Ok(line) => {
+                if let Some(lines) = &self.lines {
+                    // the writer only stops once the journal is dropped
+                    let _ = lines.send(format!("{}\n", line));
+                }
+            }
+            Err(_error) => {
+                #[cfg(feature = "tracing")]
+                warn!(error = %_error, "Could not write to the request journal");
+            }
+        }
+    }
+}
+
+impl Drop for Journal {
+    fn drop(&mut self) {
+        // closing the channel stops the writer once every line was written
+        self.lines.take();
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+/// Appends every line received from `lines` to `file` until the channel is closed.
+fn write_lines(mut file: File, lines: mpsc::Receiver<String>) {
+    for line in lines {
+        if let Err(_error) = file.write_all(line.as_bytes()) {
+            #[cfg(feature = "tracing")]
+            warn!(error = %_error, "Could not write to the request journal");
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// The maximum amount of trailing characters [`SecretKey::reveal`] shows.
+const MAX_REVEAL: usize = 4;
+
+/// An `ApiKey` that is redacted from its [`Debug`] and [`Display`] output.
+///
+/// The [`RequestHandler`](crate::RequestHandler) only ever stores the key in this wrapper, and
+/// sends it in a header marked as sensitive, so logging the handler, its builder or the
+/// underlying requests never exposes the key. Use [`SecretKey::expose`] to get the key itself.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::SecretKey;
+/// # use uuid::Uuid;
+/// # use std::str::FromStr;
+///
+/// # fn main() {
+/// let key = SecretKey::new(Uuid::from_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap());
+/// assert_eq!(format!("{:?}", key), "SecretKey(<redacted>)");
+/// // show a hint to tell keys apart in diagnostics
+/// assert_eq!(format!("{:?}", key.reveal(4)), "SecretKey(…aaf5)");
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SecretKey {
+    key: Uuid,
+    reveal: usize,
+}
+
+impl SecretKey {
+    /// Wraps `key`, revealing none of it.
+    pub fn new(key: Uuid) -> Self {
+        SecretKey {
+            key,
+            reveal: 0,
+        }
+    }
+
+    /// Reveals the last `chars` characters of the key in its [`Debug`] and [`Display`]
+    /// output, at most 4.
+    pub fn reveal(mut self, chars: usize) -> Self {
+        self.reveal = chars.min(MAX_REVEAL);
+        self
+    }
+
+    /// Returns the key itself.
+    pub fn expose(&self) -> Uuid {
+        self.key
+    }
+}
+
+impl From<Uuid> for SecretKey {
+    fn from(key: Uuid) -> Self {
+        SecretKey::new(key)
+    }
+}
+
+impl Display for SecretKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.reveal == 0 {
+            return write!(f, "<redacted>");
+        }
+        let simple = self.key.simple().to_string();
+        write!(f, "…{}", &simple[simple.len() - self.reveal..])
+    }
+}
+
+impl Debug for SecretKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretKey({})", self)
+    }
+}
+
+/// The usage of an `ApiKey`, derived from the `ratelimit-*` headers that Hypixel
+/// sends along with every authenticated response.
+///
+/// This replaces the data previously returned by the `/key` endpoint, which
+/// has been removed from the API. See [`RequestHandler::key_usage`](crate::RequestHandler::key_usage).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct KeyUsage {
+    limit: u32,
+    remaining: u32,
+    reset: Duration,
+}
+
+impl KeyUsage {
+    /// Creates a new key usage, e.g. to fake responses in tests.
+    ///
+    /// Usually this is obtained through [`RequestHandler::key_usage`](crate::RequestHandler::key_usage).
+    pub fn new(limit: u32, remaining: u32, reset: Duration) -> Self {
+        KeyUsage {
+            limit,
+            remaining,
+            reset,
+        }
+    }
+
+    /// Returns the amount of requests this key is allowed to send per window.
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    /// Returns the amount of requests that were left in the window
+    /// at the time the response was received.
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// Returns the time that was left before the window resets
+    /// at the time the response was received.
+    pub fn reset(&self) -> Duration {
+        self.reset
+    }
+}
@@ -0,0 +1,47 @@
+//! Typed lookups of guilds, see [`RequestHandler::get_guild_by_id`].
+
+use uuid::Uuid;
+use crate::api::dispatch::RequestFuture;
+use crate::api::path::ApiPath;
+use crate::api::reply::GuildReply;
+use crate::api::request::RequestHandler;
+
+impl RequestHandler {
+    /// Queues a request for the guild with the id `id`.
+    ///
+    /// The guild of the reply is missing if no guild has this id.
+    pub fn get_guild_by_id(&self, id: &str) -> RequestFuture<GuildReply> {
+        self.request_typed(ApiPath::GuildById { id: id.to_string() })
+    }
+
+    /// Queues a request for the guild named `name`, ignoring case.
+    ///
+    /// The name is percent-encoded, so names with spaces or other special characters can be
+    /// passed as they are. The guild of the reply is missing if no guild has this name.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use hypixel_api::RequestHandler;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let reply = request_handler.get_guild_by_name("Mini Squid").await.unwrap().unwrap();
+    /// if let Some(guild) = reply.guild() {
+    ///     println!("{} has {} members", guild.name(), guild.members().len());
+    /// }
+    /// # }
+    /// ```
+    pub fn get_guild_by_name(&self, name: &str) -> RequestFuture<GuildReply> {
+        self.request_typed(ApiPath::GuildByName { name: name.to_string() })
+    }
+
+    /// Queues a request for the guild the player `uuid` is a member of.
+    ///
+    /// The guild of the reply is missing if the player is not in a guild.
+    pub fn get_guild_by_player(&self, uuid: Uuid) -> RequestFuture<GuildReply> {
+        self.request_typed(ApiPath::GuildByPlayer { uuid })
+    }
+}
@@ -6,4 +6,52 @@ macro_rules! display_enum_with_case {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Implements [`Reply`](crate::reply::Reply) for a reply with a `success` field, either with
+/// the payload in `$field` or as its own payload.
+#[cfg(feature = "reply")]
+macro_rules! impl_reply {
+    ($reply:ty, $field:ident: $data:ty) => {
+        impl $crate::api::reply::Reply for $reply {
+            type Data = $data;
+
+            fn success(&self) -> bool {
+                self.success
+            }
+
+            fn cause(&self) -> Option<&str> {
+                self.cause.as_deref()
+            }
+
+            fn data(&self) -> &Self::Data {
+                &self.$field
+            }
+
+            fn into_data(self) -> Self::Data {
+                self.$field
+            }
+        }
+    };
+    ($reply:ty) => {
+        impl $crate::api::reply::Reply for $reply {
+            type Data = Self;
+
+            fn success(&self) -> bool {
+                self.success
+            }
+
+            fn cause(&self) -> Option<&str> {
+                self.cause.as_deref()
+            }
+
+            fn data(&self) -> &Self::Data {
+                self
+            }
+
+            fn into_data(self) -> Self::Data {
+                self
+            }
+        }
+    };
+}
@@ -0,0 +1,95 @@
+//! Metrics of the requests sent by a [`RequestHandler`](crate::RequestHandler).
+//!
+//! Metrics are emitted through the [`metrics`](https://docs.rs/metrics) facade, install a
+//! recorder such as `metrics-exporter-prometheus` to scrape them. Without a recorder,
+//! emitting metrics does nothing.
+//!
+//! | Name | Type | Labels | Description |
+//! |------|------|--------|-------------|
+//! | [`REQUESTS_TOTAL`] | counter | `endpoint`, `status` | responses received from the API |
+//! | [`DESERIALIZATION_FAILURES_TOTAL`] | counter | `endpoint` | responses that failed to deserialize |
+//! | [`THROTTLE_WAITS_TOTAL`] | counter | | requests that had to wait for the rate limit |
+//! | [`QUEUE_DEPTH`] | gauge | | requests currently waiting for the rate limit |
+//! | [`REQUEST_DURATION_SECONDS`] | histogram | `endpoint` | latency of a single HTTP request |
+//! | [`TAGGED_REQUESTS_TOTAL`] | counter | `tag`, `outcome` | finished requests of [tagged](crate::RequestHandler::tagged) handlers |
+//! | [`TAGGED_REQUEST_DURATION_SECONDS`] | histogram | `tag` | latency of tagged requests, including the rate limit |
+//! | [`CACHE_HITS_TOTAL`] | counter | | requests served from the [`cache`](crate::cache) |
+//! | [`CACHE_MISSES_TOTAL`] | counter | | requests not found in the cache |
+//! | [`CACHE_EVICTIONS_TOTAL`] | counter | | entries evicted to stay within the cache bounds |
+//! | [`CACHE_ENTRIES`] | gauge | | responses in the cache |
+//! | [`CACHE_BYTES`] | gauge | | total size of the cached bodies |
+//! | [`PLAYERS`] | gauge | | players online, sampled by a [`CountsSampler`](crate::watch::CountsSampler) |
+//! | [`GAME_PLAYERS`] | gauge | `game` | players per game, sampled by a [`CountsSampler`](crate::watch::CountsSampler) |
+//!
+//! The `endpoint` label is the request path without its query, e.g. `"skyblock/auctions"`.
+//! The `outcome` label is either `"completed"` or `"failed"`.
+
+use std::time::Duration;
+use reqwest::StatusCode;
+
+/// Counter of the responses received from the API.
+pub const REQUESTS_TOTAL: &str = "hypixel_api_requests_total";
+/// Counter of the responses that failed to deserialize.
+pub const DESERIALIZATION_FAILURES_TOTAL: &str = "hypixel_api_deserialization_failures_total";
+/// Counter of the requests that had to wait for the rate limit.
+pub const THROTTLE_WAITS_TOTAL: &str = "hypixel_api_throttle_waits_total";
+/// Gauge of the requests currently waiting for the rate limit.
+pub const QUEUE_DEPTH: &str = "hypixel_api_queue_depth";
+/// Histogram of the latency of a single HTTP request, in seconds.
+pub const REQUEST_DURATION_SECONDS: &str = "hypixel_api_request_duration_seconds";
+/// Counter of the finished requests of tagged handlers.
+pub const TAGGED_REQUESTS_TOTAL: &str = "hypixel_api_tagged_requests_total";
+/// Histogram of the latency of tagged requests from queueing until their response was deserialized, in seconds.
+pub const TAGGED_REQUEST_DURATION_SECONDS: &str = "hypixel_api_tagged_request_duration_seconds";
+/// Counter of the requests served from the cache.
+pub const CACHE_HITS_TOTAL: &str = "hypixel_api_cache_hits_total";
+/// Counter of the requests not found in the cache.
+pub const CACHE_MISSES_TOTAL: &str = "hypixel_api_cache_misses_total";
+/// Counter of the entries evicted to stay within the cache bounds.
+pub const CACHE_EVICTIONS_TOTAL: &str = "hypixel_api_cache_evictions_total";
+/// Gauge of the responses in the cache.
+pub const CACHE_ENTRIES: &str = "hypixel_api_cache_entries";
+/// Gauge of the total size of the cached bodies in bytes.
+pub const CACHE_BYTES: &str = "hypixel_api_cache_bytes";
+/// Gauge of the players online on the whole network.
+pub const PLAYERS: &str = "hypixel_api_players";
+/// Gauge of the players per game.
+pub const GAME_PLAYERS: &str = "hypixel_api_game_players";
+
+pub(crate) fn record_response(endpoint: &str, status: StatusCode, duration: Duration) {
+    ::metrics::counter!(REQUESTS_TOTAL, "endpoint" => endpoint.to_string(), "status" => status.as_u16().to_string()).increment(1);
+    ::metrics::histogram!(REQUEST_DURATION_SECONDS, "endpoint" => endpoint.to_string()).record(duration.as_secs_f64());
+}
+
+#[cfg(feature = "reply")]
+pub(crate) fn record_counts(sample: &crate::api::watch::CountsSample) {
+    ::metrics::gauge!(PLAYERS).set(sample.total() as f64);
+    for (game, players) in sample.games() {
+        ::metrics::gauge!(GAME_PLAYERS, "game" => game.clone()).set(*players as f64);
+    }
+}
+
+pub(crate) fn record_tagged(tag: &str, success: bool, latency: Duration) {
+    let outcome = if success { "completed" } else { "failed" };
+    ::metrics::counter!(TAGGED_REQUESTS_TOTAL, "tag" => tag.to_string(), "outcome" => outcome).increment(1);
+    ::metrics::histogram!(TAGGED_REQUEST_DURATION_SECONDS, "tag" => tag.to_string()).record(latency.as_secs_f64());
+}
+
+pub(crate) fn record_deserialization_failure(endpoint: &str) {
+    ::metrics::counter!(DESERIALIZATION_FAILURES_TOTAL, "endpoint" => endpoint.to_string()).increment(1);
+}
+
+/// Counts a request waiting for the rate limit until the returned guard is dropped.
+pub(crate) fn throttle_wait() -> QueueGuard {
+    ::metrics::counter!(THROTTLE_WAITS_TOTAL).increment(1);
+    ::metrics::gauge!(QUEUE_DEPTH).increment(1.0);
+    QueueGuard
+}
+
+pub(crate) struct QueueGuard;
+
+impl Drop for QueueGuard {
+    fn drop(&mut self) {
+        ::metrics::gauge!(QUEUE_DEPTH).decrement(1.0);
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use parking_lot::Mutex;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+use crate::api::dispatch::RequestFuture;
+use crate::api::error::HypixelApiError;
+use crate::api::key::KeyUsage;
+use crate::api::request::{HypixelApi, RequestHandler};
+
+/// A [`HypixelApi`] implementation that serves canned JSON replies from memory.
+///
+/// Requests to paths without a reply fail with a `404 Not Found`
+/// [`UnexpectedResponseCode`](HypixelApiError::UnexpectedResponseCode).
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::{HypixelApi, MockHypixelApi, StatusReply};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let api = MockHypixelApi::new()
+///     .with_reply("status?uuid=069a79f4-44e9-4726-a5be-fca90e38aaf5", r#"{"success":true,"uuid":"069a79f444e94726a5befca90e38aaf5","session":{"online":true}}"#);
+///
+/// let reply = api.request::<StatusReply>("status?uuid=069a79f4-44e9-4726-a5be-fca90e38aaf5", true).await.unwrap().unwrap();
+/// assert!(reply.online());
+/// assert_eq!(api.requests(), vec!["status?uuid=069a79f4-44e9-4726-a5be-fca90e38aaf5"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockHypixelApi {
+    replies: HashMap<String, String>,
+    key_usage: Option<KeyUsage>,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockHypixelApi {
+    /// Creates a mock without any replies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves `json` for requests to `path` (without leading `/`, including the query).
+    pub fn with_reply(mut self, path: impl Into<String>, json: impl Into<String>) -> Self {
+        self.replies.insert(path.into(), json.into());
+        self
+    }
+
+    /// Sets the value returned by [`HypixelApi::key_usage`].
+    pub fn with_key_usage(mut self, key_usage: KeyUsage) -> Self {
+        self.key_usage = Some(key_usage);
+        self
+    }
+
+    /// Returns the paths of all requests made so far, in order.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().clone()
+    }
+}
+
+impl HypixelApi for MockHypixelApi {
+    fn request<T: DeserializeOwned + Send + 'static>(&self, path: &str, _authenticated: bool) -> RequestFuture<T> {
+        self.requests.lock().push(path.to_string());
+        RequestFuture::ready(match self.replies.get(path) {
+            Some(json) => serde_json::from_str(json).map_err(|e| e.into()),
+            None => Err(HypixelApiError::UnexpectedResponseCode(StatusCode::NOT_FOUND, None)),
+        })
+    }
+
+    fn key_usage(&self) -> Option<KeyUsage> {
+        self.key_usage
+    }
+}
+
+/// A local HTTP server that imitates the Hypixel API, serving canned JSON replies per path.
+///
+/// The server sends the `ratelimit-*` headers configured with [`MockHypixelServer::rate_limit`]
+/// and can answer with `429 Too Many Requests` on demand, which allows integration tests of
+/// the throttling behavior of [`RequestHandler`](crate::RequestHandler).
+/// Requests to paths without a reply are answered with `404 Not Found`.
+///
+/// Successful replies carry an `ETag` derived from their body, a request with a matching
+/// `If-None-Match` header is answered with `304 Not Modified`.
+///
+/// The server stops when it is dropped.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::{MockHypixelServer, StatusReply};
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let server = MockHypixelServer::start().await;
+/// server.reply("status", r#"{"success":true,"uuid":"069a79f444e94726a5befca90e38aaf5","session":{"online":true}}"#);
+/// server.rate_limit(120, 119, 60);
+///
+/// let request_handler = server.request_handler(Uuid::nil());
+/// let reply = request_handler.request::<StatusReply>("status?uuid=069a79f4-44e9-4726-a5be-fca90e38aaf5", true).await.unwrap().unwrap();
+/// assert!(reply.online());
+/// assert_eq!(request_handler.key_usage().unwrap().limit(), 120);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MockHypixelServer {
+    address: SocketAddr,
+    state: Arc<Mutex<ServerState>>,
+    task: JoinHandle<()>,
+}
+
+#[derive(Debug, Default)]
+struct ServerState {
+    replies: HashMap<String, (StatusCode, String)>,
+    rate_limit: Option<(u32, u32, u64)>,
+    too_many_requests: u32,
+    connection_failures: u32,
+    requests: Vec<String>,
+}
+
+impl MockHypixelServer {
+    /// Starts a new server on a random local port.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no local port could be bound.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("could not bind mock server");
+        let address = listener.local_addr().expect("could not get mock server address");
+        let state = Arc::new(Mutex::new(ServerState::default()));
+        let task_state = Arc::clone(&state);
+        let task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve(stream, Arc::clone(&task_state)));
+            }
+        });
+        MockHypixelServer { address, state, task }
+    }
+
+    /// Returns the base URL of this server, see [`RequestHandlerBuilder::base_url`](crate::RequestHandlerBuilder::base_url).
+    pub fn url(&self) -> String {
+        format!("http://{}/", self.address)
+    }
+
+    /// Creates a [`RequestHandler`](crate::RequestHandler) that sends its requests to this server.
+    pub fn request_handler(&self, api_key: Uuid) -> RequestHandler {
+        RequestHandler::builder(api_key).base_url(self.url()).build()
+    }
+
+    /// Serves `json` with `200 OK` for requests to `path` (without leading `/`).
+    ///
+    /// If `path` has no query, it matches requests to this path with any query.
+    pub fn reply(&self, path: impl Into<String>, json: impl Into<String>) -> &Self {
+        self.reply_with_status(path, 200, json)
+    }
+
+    /// Serves `json` with status code `status` for requests to `path` (without leading `/`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `status` is not a valid status code.
+    pub fn reply_with_status(&self, path: impl Into<String>, status: u16, json: impl Into<String>) -> &Self {
+        let status = StatusCode::from_u16(status).expect("invalid status code");
+        self.state.lock().replies.insert(path.into(), (status, json.into()));
+        self
+    }
+
+    /// Sends `ratelimit-limit`, `ratelimit-remaining` and `ratelimit-reset` headers with every response.
+    ///
+    /// `remaining` decreases with every request, down to `0`.
+    pub fn rate_limit(&self, limit: u32, remaining: u32, reset_secs: u64) -> &Self {
+        self.state.lock().rate_limit = Some((limit, remaining, reset_secs));
+        self
+    }
+
+    /// Answers the next `count` requests with `429 Too Many Requests`.
+    pub fn inject_too_many_requests(&self, count: u32) -> &Self {
+        self.state.lock().too_many_requests += count;
+        self
+    }
+
+    /// Closes the connection of the next `count` requests without answering them.
+    pub fn inject_connection_failures(&self, count: u32) -> &Self {
+        self.state.lock().connection_failures += count;
+        self
+    }
+
+    /// Returns the paths (including query) of all requests received so far, in order.
+    pub fn requests(&self) -> Vec<String> {
+        self.state.lock().requests.clone()
+    }
+}
+
+impl Drop for MockHypixelServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn serve(mut stream: TcpStream, state: Arc<Mutex<ServerState>>) {
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        match stream.read(&mut buffer).await {
+            Ok(0) | Err(_) => return,
+            Ok(n) => request.extend_from_slice(&buffer[..n]),
+        }
+    }
+    let request = String::from_utf8_lossy(&request);
+    let path = request.split(' ').nth(1).unwrap_or("/").trim_start_matches('/').to_string();
+    let if_none_match = request.lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("if-none-match"))
+        .map(|(_, value)| value.trim().to_string());
+
+    let (status, body, rate_limit) = {
+        let mut state = state.lock();
+        state.requests.push(path.clone());
+        if state.connection_failures > 0 {
+            state.connection_failures -= 1;
+            return;
+        }
+        let rate_limit = state.rate_limit;
+        if let Some((_, remaining, _)) = state.rate_limit.as_mut() {
+            *remaining = remaining.saturating_sub(1);
+        }
+        if state.too_many_requests > 0 {
+            state.too_many_requests -= 1;
+            (StatusCode::TOO_MANY_REQUESTS, r#"{"success":false,"cause":"Key throttle"}"#.to_string(), rate_limit.map(|(limit, _, reset)| (limit, 0, reset)))
+        } else {
+            let without_query = path.split('?').next().unwrap_or_default();
+            let reply = state.replies.get(&path).or_else(|| state.replies.get(without_query)).cloned();
+            let (status, body) = reply.unwrap_or_else(|| (StatusCode::NOT_FOUND, r#"{"success":false,"cause":"Not found"}"#.to_string()));
+            (status, body, rate_limit)
+        }
+    };
+
+    let etag = (status == StatusCode::OK).then(|| {
+        let mut hasher = DefaultHasher::new();
+        body.hash(&mut hasher);
+        format!("\"{:x}\"", hasher.finish())
+    });
+    let (status, body) = match &etag {
+        Some(etag) if if_none_match.as_ref() == Some(etag) => (StatusCode::NOT_MODIFIED, String::new()),
+        _ => (status, body),
+    };
+    let mut response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        status.as_u16(), status.canonical_reason().unwrap_or_default(), body.len(),
+    );
+    if let Some(etag) = etag {
+        response.push_str(&format!("ETag: {}\r\n", etag));
+    }
+    if let Some((limit, remaining, reset)) = rate_limit {
+        response.push_str(&format!("ratelimit-limit: {}\r\nratelimit-remaining: {}\r\nratelimit-reset: {}\r\n", limit, remaining, reset));
+    }
+    response.push_str("\r\n");
+    response.push_str(&body);
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
@@ -1,26 +1,89 @@
+#[macro_use]
+pub(crate) mod macros;
 pub(crate) mod throttler;
+pub(crate) mod retry;
+pub(crate) mod budget;
+pub(crate) mod pagination;
+pub(crate) mod key;
+pub(crate) mod path;
+pub(crate) mod raw;
+pub(crate) mod timed;
 #[cfg(feature = "reply")]
 pub mod reply;
 pub(crate) mod request;
-#[macro_use]
-pub(crate) mod macros;
+#[cfg(feature = "reply")]
+pub(crate) mod batch;
+#[cfg(feature = "reply")]
+pub(crate) mod roster;
+#[cfg(feature = "reply")]
+pub(crate) mod client;
+#[cfg(feature = "reply")]
+pub(crate) mod lookup;
+pub(crate) mod dispatch;
+pub(crate) mod vcr;
+pub(crate) mod journal;
+pub(crate) mod events;
+pub(crate) mod stats;
+pub(crate) mod stream;
+pub mod cache;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "body-capture")]
+pub mod capture;
+#[cfg(feature = "tower")]
+pub mod service;
+#[cfg(feature = "global")]
+pub mod global;
+#[cfg(feature = "test-util")]
+pub(crate) mod mock;
 pub mod error;
 #[cfg(feature = "util")]
 pub mod util;
+#[cfg(feature = "reply")]
+pub mod watch;
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
 mod tests;
 
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use serde::Deserialize;
+use crate::api::error::HypixelApiError;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
+/// The monthly rank of a player, `MVP++` is [`MonthlyPackageRank::Superstar`].
+///
+/// Values introduced by Hypixel after this crate was published are captured in [`MonthlyPackageRank::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash, Deserialize)]
+#[serde(from = "String")]
 pub enum MonthlyPackageRank {
     None,
     Superstar,
+    Unknown(String)
+}
+
+impl From<String> for MonthlyPackageRank {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "NONE" => MonthlyPackageRank::None,
+            "SUPERSTAR" => MonthlyPackageRank::Superstar,
+            _ => MonthlyPackageRank::Unknown(s)
+        }
+    }
+}
+
+impl Display for MonthlyPackageRank {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MonthlyPackageRank::None => write!(f, "NONE"),
+            MonthlyPackageRank::Superstar => write!(f, "SUPERSTAR"),
+            MonthlyPackageRank::Unknown(s) => write!(f, "{}", s),
+        }
+    }
 }
-display_enum_with_case!(MonthlyPackageRank, Upper);
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash, Deserialize)]
 #[serde(rename_all = "UPPERCASE", from="String")]
 pub enum StaffLevel {
     Normal,
@@ -54,8 +117,12 @@ impl Display for StaffLevel {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// The rank a player has bought.
+#[cfg_attr(feature = "reply", doc = "See [`PlayerData::package_rank`](crate::PlayerData::package_rank).")]
+///
+/// Values introduced by Hypixel after this crate was published are captured in [`PackageRank::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash, Deserialize)]
+#[serde(from = "String")]
 pub enum PackageRank {
     None,
     Vip,
@@ -63,11 +130,45 @@ pub enum PackageRank {
     Mvp,
     MvpPlus,
     MvpPlusPlus,
+    Unknown(String)
+}
+
+impl From<String> for PackageRank {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "NONE" => PackageRank::None,
+            "VIP" => PackageRank::Vip,
+            "VIP_PLUS" => PackageRank::VipPlus,
+            "MVP" => PackageRank::Mvp,
+            "MVP_PLUS" => PackageRank::MvpPlus,
+            "MVP_PLUS_PLUS" => PackageRank::MvpPlusPlus,
+            _ => PackageRank::Unknown(s)
+        }
+    }
+}
+
+impl Display for PackageRank {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PackageRank::None => write!(f, "NONE"),
+            PackageRank::Vip => write!(f, "VIP"),
+            PackageRank::VipPlus => write!(f, "VIP_PLUS"),
+            PackageRank::Mvp => write!(f, "MVP"),
+            PackageRank::MvpPlus => write!(f, "MVP_PLUS"),
+            PackageRank::MvpPlusPlus => write!(f, "MVP_PLUS_PLUS"),
+            PackageRank::Unknown(s) => write!(f, "{}", s),
+        }
+    }
 }
-display_enum_with_case!(PackageRank, ScreamingSnake);
+
+// generated by build.rs from the `/resources/games` snapshot in fixtures/resources_games.json
+include!(concat!(env!("OUT_DIR"), "/game_type.rs"));
 
 /// This corresponds to the table on [this wiki](https://minecraft.fandom.com/wiki/Formatting_codes#Color_codes).
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+///
+/// These colors are a fixed palette of Minecraft, so there is no `Unknown` variant. Reply fields
+/// with an unrecognized color are treated as missing and fall back to the default of their getter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ColorCodes {
     Black,
@@ -87,4 +188,100 @@ pub enum ColorCodes {
     Yellow,
     White,
 }
-display_enum_with_case!(ColorCodes, ScreamingSnake);
\ No newline at end of file
+display_enum_with_case!(ColorCodes, ScreamingSnake);
+
+impl ColorCodes {
+    const ALL: [ColorCodes; 16] = [
+        ColorCodes::Black, ColorCodes::DarkBlue, ColorCodes::DarkGreen, ColorCodes::DarkAqua,
+        ColorCodes::DarkRed, ColorCodes::DarkPurple, ColorCodes::Gold, ColorCodes::Gray,
+        ColorCodes::DarkGray, ColorCodes::Blue, ColorCodes::Green, ColorCodes::Aqua,
+        ColorCodes::Red, ColorCodes::LightPurple, ColorCodes::Yellow, ColorCodes::White,
+    ];
+
+    /// Returns the character that follows `§` in the formatting code of this color, e.g. `'c'` for red.
+    pub fn code_char(&self) -> char {
+        match self {
+            ColorCodes::Black => '0',
+            ColorCodes::DarkBlue => '1',
+            ColorCodes::DarkGreen => '2',
+            ColorCodes::DarkAqua => '3',
+            ColorCodes::DarkRed => '4',
+            ColorCodes::DarkPurple => '5',
+            ColorCodes::Gold => '6',
+            ColorCodes::Gray => '7',
+            ColorCodes::DarkGray => '8',
+            ColorCodes::Blue => '9',
+            ColorCodes::Green => 'a',
+            ColorCodes::Aqua => 'b',
+            ColorCodes::Red => 'c',
+            ColorCodes::LightPurple => 'd',
+            ColorCodes::Yellow => 'e',
+            ColorCodes::White => 'f',
+        }
+    }
+
+    /// Returns the color corresponding to the character that follows `§`
+    /// in a formatting code, if present.
+    pub fn from_code_char(code: char) -> Option<ColorCodes> {
+        code.to_digit(16).map(|index| ColorCodes::ALL[index as usize])
+    }
+
+    /// Returns the RGB value of this color, e.g. `0xFF5555` for red.
+    pub fn hex(&self) -> u32 {
+        match self {
+            ColorCodes::Black => 0x000000,
+            ColorCodes::DarkBlue => 0x0000AA,
+            ColorCodes::DarkGreen => 0x00AA00,
+            ColorCodes::DarkAqua => 0x00AAAA,
+            ColorCodes::DarkRed => 0xAA0000,
+            ColorCodes::DarkPurple => 0xAA00AA,
+            ColorCodes::Gold => 0xFFAA00,
+            ColorCodes::Gray => 0xAAAAAA,
+            ColorCodes::DarkGray => 0x555555,
+            ColorCodes::Blue => 0x5555FF,
+            ColorCodes::Green => 0x55FF55,
+            ColorCodes::Aqua => 0x55FFFF,
+            ColorCodes::Red => 0xFF5555,
+            ColorCodes::LightPurple => 0xFF55FF,
+            ColorCodes::Yellow => 0xFFFF55,
+            ColorCodes::White => 0xFFFFFF,
+        }
+    }
+
+    /// Returns the closest of the 16 standard ANSI foreground colors, as an SGR parameter
+    /// (e.g. `91` for red, to be used as `"\x1b[91m"`).
+    pub fn ansi(&self) -> u8 {
+        match self {
+            ColorCodes::Black => 30,
+            ColorCodes::DarkBlue => 34,
+            ColorCodes::DarkGreen => 32,
+            ColorCodes::DarkAqua => 36,
+            ColorCodes::DarkRed => 31,
+            ColorCodes::DarkPurple => 35,
+            ColorCodes::Gold => 33,
+            ColorCodes::Gray => 37,
+            ColorCodes::DarkGray => 90,
+            ColorCodes::Blue => 94,
+            ColorCodes::Green => 92,
+            ColorCodes::Aqua => 96,
+            ColorCodes::Red => 91,
+            ColorCodes::LightPurple => 95,
+            ColorCodes::Yellow => 93,
+            ColorCodes::White => 97,
+        }
+    }
+}
+
+/// Parses either the name of a color as used by the API (e.g. `"RED"`)
+/// or a formatting code (e.g. `"§c"`).
+impl FromStr for ColorCodes {
+    type Err = HypixelApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some('§'), Some(code), None) => ColorCodes::from_code_char(code.to_ascii_lowercase()),
+            _ => ColorCodes::ALL.iter().copied().find(|c| c.to_string() == s),
+        }.ok_or_else(|| HypixelApiError::UnknownColorCode(s.to_string()))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,187 @@
+use std::fmt::{Display, Formatter};
+
+/// Limits for the helpers that send many requests on their own, such as `scan_all_auctions`,
+/// `snipe` and `Leaderboard::hydrate_leaders`.
+#[cfg_attr(feature = "reply", doc = "See [`scan_all_auctions`](crate::util::auctions::scan_all_auctions), [`snipe`](crate::util::auctions::snipe) and [`Leaderboard::hydrate_leaders`](crate::Leaderboard::hydrate_leaders).")]
+///
+/// Limits set with [`RequestHandlerBuilder::page_limits`](crate::RequestHandlerBuilder::page_limits)
+/// apply to every call, the `*_with_limits` variants of the helpers take limits per call that
+/// replace the ones of the handler. A call that reaches a limit stops requesting, the
+/// `*_with_limits` variants return what was fetched so far as a [`PartialResult`], the others
+/// fail with [`HypixelApiError::LimitReached`](crate::error::HypixelApiError::LimitReached).
+///
+/// By default, nothing is limited.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::{PageLimits, RequestHandler};
+/// # use uuid::Uuid;
+///
+/// # fn main() {
+/// # let api_key = Uuid::nil();
+/// let request_handler = RequestHandler::builder(api_key)
+///     .page_limits(PageLimits::new().max_pages(20).max_requests(100))
+///     .build();
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PageLimits {
+    max_pages: Option<usize>,
+    max_items: Option<usize>,
+    max_requests: Option<usize>,
+}
+
+impl PageLimits {
+    /// Creates limits that limit nothing.
+    pub fn new() -> Self {
+        PageLimits::default()
+    }
+
+    /// Sets the maximum amount of pages a single call fetches.
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Sets the amount of items after which a single call stops requesting.
+    ///
+    /// Items are counted per received page, so the last pages may exceed this limit.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Sets the maximum amount of requests a single call sends, i.e. the amount of tickets
+    /// of the rate limit it may spend.
+    pub fn max_requests(mut self, max_requests: usize) -> Self {
+        self.max_requests = Some(max_requests);
+        self
+    }
+
+    /// Returns these limits, with the limits that are not set taken from `fallback`.
+    pub fn or(self, fallback: PageLimits) -> PageLimits {
+        PageLimits {
+            max_pages: self.max_pages.or(fallback.max_pages),
+            max_items: self.max_items.or(fallback.max_items),
+            max_requests: self.max_requests.or(fallback.max_requests),
+        }
+    }
+
+    /// Returns the maximum amount of pages, if limited.
+    pub fn pages(&self) -> Option<usize> {
+        self.max_pages
+    }
+
+    /// Returns the maximum amount of items, if limited.
+    pub fn items(&self) -> Option<usize> {
+        self.max_items
+    }
+
+    /// Returns the maximum amount of requests, if limited.
+    pub fn requests(&self) -> Option<usize> {
+        self.max_requests
+    }
+
+    /// Returns how many of `total` requests of one page each may be sent, and the limit that
+    /// prevents sending the rest.
+    #[cfg(feature = "reply")]
+    pub(crate) fn allowed_pages(&self, total: usize) -> (usize, Option<LimitReached>) {
+        smallest_limit(total, [self.max_pages.map(LimitReached::Pages), self.max_requests.map(LimitReached::Requests)])
+    }
+
+    /// Returns how many of `total` items of one request each may be fetched, and the limit that
+    /// prevents fetching the rest.
+    #[cfg(feature = "reply")]
+    pub(crate) fn allowed_items(&self, total: usize) -> (usize, Option<LimitReached>) {
+        smallest_limit(total, [self.max_items.map(LimitReached::Items), self.max_requests.map(LimitReached::Requests)])
+    }
+
+    /// Returns the item limit if `items` reached it.
+    #[cfg(feature = "reply")]
+    pub(crate) fn items_reached(&self, items: usize) -> Option<LimitReached> {
+        self.max_items.filter(|max| items >= *max).map(LimitReached::Items)
+    }
+}
+
+/// Returns how many of `total` are allowed by the smallest of `limits`, and that limit if it is
+/// smaller than `total`.
+#[cfg(feature = "reply")]
+fn smallest_limit(total: usize, limits: [Option<LimitReached>; 2]) -> (usize, Option<LimitReached>) {
+    match limits.into_iter().flatten().filter(|limit| limit.max() < total).min_by_key(LimitReached::max) {
+        Some(limit) => (limit.max(), Some(limit)),
+        None => (total, None),
+    }
+}
+
+/// The limit of [`PageLimits`] that stopped a call.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LimitReached {
+    /// The maximum amount of pages was fetched.
+    Pages(usize),
+    /// At least the maximum amount of items was fetched.
+    Items(usize),
+    /// The maximum amount of requests was sent.
+    Requests(usize),
+}
+
+impl LimitReached {
+    /// Returns the value of the limit.
+    pub fn max(&self) -> usize {
+        match self {
+            LimitReached::Pages(max) | LimitReached::Items(max) | LimitReached::Requests(max) => *max,
+        }
+    }
+}
+
+impl Display for LimitReached {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LimitReached::Pages(max) => write!(f, "at most {} pages", max),
+            LimitReached::Items(max) => write!(f, "at most {} items", max),
+            LimitReached::Requests(max) => write!(f, "at most {} requests", max),
+        }
+    }
+}
+
+/// The outcome of a call with [`PageLimits`]: everything that was fetched, and the limit that
+/// stopped the call before it was complete, if any.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialResult<T> {
+    value: T,
+    limit: Option<LimitReached>,
+}
+
+impl<T> PartialResult<T> {
+    #[cfg(feature = "reply")]
+    pub(crate) fn new(value: T, limit: Option<LimitReached>) -> Self {
+        PartialResult { value, limit }
+    }
+
+    /// Returns what was fetched.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the limit that stopped the call, if any.
+    pub fn limit(&self) -> Option<LimitReached> {
+        self.limit
+    }
+
+    /// Returns `true` if no limit was reached.
+    pub fn is_complete(&self) -> bool {
+        self.limit.is_none()
+    }
+
+    /// Consumes the result and returns what was fetched, complete or not.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Consumes the result and returns what was fetched, or the limit if one was reached.
+    pub fn into_complete(self) -> Result<T, LimitReached> {
+        match self.limit {
+            Some(limit) => Err(limit),
+            None => Ok(self.value),
+        }
+    }
+}
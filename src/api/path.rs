@@ -0,0 +1,166 @@
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// A typed endpoint of the Hypixel API, see [`RequestHandler::request_typed`](crate::RequestHandler::request_typed).
+///
+/// Building paths from this enum rather than strings catches misspelled endpoints and query
+/// keys at compile time, and knows which endpoints require the API key.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::ApiPath;
+/// # use uuid::Uuid;
+/// # use std::str::FromStr;
+///
+/// # fn main() {
+/// let uuid = Uuid::from_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap();
+/// assert_eq!(ApiPath::Player { uuid }.to_path(), "player?uuid=069a79f444e94726a5befca90e38aaf5");
+/// assert_eq!(ApiPath::GuildByName { name: "Mini Squid".to_string() }.to_path(), "guild?name=Mini%20Squid");
+/// assert!(!ApiPath::SkyblockAuctionsPage { page: 3 }.is_authenticated());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ApiPath {
+    /// `/player` of the player `uuid`.
+    Player { uuid: Uuid },
+    /// `/status` of the player `uuid`.
+    Status { uuid: Uuid },
+    /// `/recentgames` of the player `uuid`.
+    RecentGames { uuid: Uuid },
+    /// `/guild` with the id `id`.
+    GuildById { id: String },
+    /// `/guild` the player `uuid` is a member of.
+    GuildByPlayer { uuid: Uuid },
+    /// `/guild` with the name `name`, ignoring case.
+    GuildByName { name: String },
+    /// `/counts`
+    Counts,
+    /// `/boosters`
+    Boosters,
+    /// `/leaderboards`
+    Leaderboards,
+    /// `/punishmentstats`
+    PunishmentStats,
+    /// `/resources/games`
+    GamesResources,
+    /// `/resources/achievements`
+    AchievementsResources,
+    /// `/resources/challenges`
+    ChallengesResources,
+    /// `/resources/quests`
+    QuestsResources,
+    /// `/resources/guilds/achievements`
+    GuildAchievementsResources,
+    /// `/resources/vanity/pets`
+    VanityPetsResources,
+    /// `/resources/vanity/companions`
+    VanityCompanionsResources,
+    /// `/resources/skyblock/skills`
+    SkyblockSkillsResources,
+    /// `/resources/skyblock/collections`
+    SkyblockCollectionsResources,
+    /// `/resources/skyblock/items`
+    SkyblockItemsResources,
+    /// `/resources/skyblock/election`
+    SkyblockElectionResources,
+    /// `/resources/skyblock/bingo`
+    SkyblockBingoResources,
+    /// `/skyblock/news`
+    SkyblockNews,
+    /// `/skyblock/auction` with the auction id `uuid`.
+    SkyblockAuction { uuid: Uuid },
+    /// `/skyblock/auction` of all auctions of the player `uuid`.
+    SkyblockAuctionsByPlayer { uuid: Uuid },
+    /// `/skyblock/auction` of all auctions of the profile `profile`.
+    SkyblockAuctionsByProfile { profile: Uuid },
+    /// `/skyblock/auctions`, the page `page` of all active auctions.
+    SkyblockAuctionsPage { page: u32 },
+    /// `/skyblock/auctions_ended`
+    SkyblockAuctionsEnded,
+    /// `/skyblock/bazaar`
+    SkyblockBazaar,
+    /// `/skyblock/profile` with the id `profile`.
+    SkyblockProfile { profile: Uuid },
+    /// `/skyblock/profiles` of the player `uuid`.
+    SkyblockProfiles { uuid: Uuid },
+    /// `/skyblock/museum` of the profile `profile`.
+    SkyblockMuseum { profile: Uuid },
+    /// `/skyblock/garden` of the profile `profile`.
+    SkyblockGarden { profile: Uuid },
+    /// `/skyblock/bingo` of the player `uuid`.
+    SkyblockBingo { uuid: Uuid },
+    /// `/skyblock/firesales`
+    SkyblockFiresales,
+}
+
+impl ApiPath {
+    /// Returns the path of this endpoint with its query, without leading `/`,
+    /// as accepted by [`RequestHandler::request`](crate::RequestHandler::request).
+    pub fn to_path(&self) -> String {
+        match self {
+            ApiPath::Player { uuid } => format!("player?uuid={}", uuid.simple()),
+            ApiPath::Status { uuid } => format!("status?uuid={}", uuid.simple()),
+            ApiPath::RecentGames { uuid } => format!("recentgames?uuid={}", uuid.simple()),
+            ApiPath::GuildById { id } => format!("guild?id={}", encode(id)),
+            ApiPath::GuildByPlayer { uuid } => format!("guild?player={}", uuid.simple()),
+            ApiPath::GuildByName { name } => format!("guild?name={}", encode(name)),
+            ApiPath::Counts => "counts".to_string(),
+            ApiPath::Boosters => "boosters".to_string(),
+            ApiPath::Leaderboards => "leaderboards".to_string(),
+            ApiPath::PunishmentStats => "punishmentstats".to_string(),
+            ApiPath::GamesResources => "resources/games".to_string(),
+            ApiPath::AchievementsResources => "resources/achievements".to_string(),
+            ApiPath::ChallengesResources => "resources/challenges".to_string(),
+            ApiPath::QuestsResources => "resources/quests".to_string(),
+            ApiPath::GuildAchievementsResources => "resources/guilds/achievements".to_string(),
+            ApiPath::VanityPetsResources => "resources/vanity/pets".to_string(),
+            ApiPath::VanityCompanionsResources => "resources/vanity/companions".to_string(),
+            ApiPath::SkyblockSkillsResources => "resources/skyblock/skills".to_string(),
+            ApiPath::SkyblockCollectionsResources => "resources/skyblock/collections".to_string(),
+            ApiPath::SkyblockItemsResources => "resources/skyblock/items".to_string(),
+            ApiPath::SkyblockElectionResources => "resources/skyblock/election".to_string(),
+            ApiPath::SkyblockBingoResources => "resources/skyblock/bingo".to_string(),
+            ApiPath::SkyblockNews => "skyblock/news".to_string(),
+            ApiPath::SkyblockAuction { uuid } => format!("skyblock/auction?uuid={}", uuid.simple()),
+            ApiPath::SkyblockAuctionsByPlayer { uuid } => format!("skyblock/auction?player={}", uuid.simple()),
+            ApiPath::SkyblockAuctionsByProfile { profile } => format!("skyblock/auction?profile={}", profile.simple()),
+            ApiPath::SkyblockAuctionsPage { page } => format!("skyblock/auctions?page={}", page),
+            ApiPath::SkyblockAuctionsEnded => "skyblock/auctions_ended".to_string(),
+            ApiPath::SkyblockBazaar => "skyblock/bazaar".to_string(),
+            ApiPath::SkyblockProfile { profile } => format!("skyblock/profile?profile={}", profile.simple()),
+            ApiPath::SkyblockProfiles { uuid } => format!("skyblock/profiles?uuid={}", uuid.simple()),
+            ApiPath::SkyblockMuseum { profile } => format!("skyblock/museum?profile={}", profile.simple()),
+            ApiPath::SkyblockGarden { profile } => format!("skyblock/garden?profile={}", profile.simple()),
+            ApiPath::SkyblockBingo { uuid } => format!("skyblock/bingo?uuid={}", uuid.simple()),
+            ApiPath::SkyblockFiresales => "skyblock/firesales".to_string(),
+        }
+    }
+
+    /// Returns `true` if this endpoint requires the API key.
+    ///
+    /// The resources, the active and ended auctions, the bazaar and the fire sales are public.
+    pub fn is_authenticated(&self) -> bool {
+        !matches!(self,
+            ApiPath::GamesResources | ApiPath::AchievementsResources | ApiPath::ChallengesResources
+            | ApiPath::QuestsResources | ApiPath::GuildAchievementsResources | ApiPath::VanityPetsResources
+            | ApiPath::VanityCompanionsResources | ApiPath::SkyblockSkillsResources | ApiPath::SkyblockCollectionsResources
+            | ApiPath::SkyblockItemsResources | ApiPath::SkyblockElectionResources | ApiPath::SkyblockBingoResources
+            | ApiPath::SkyblockAuctionsPage { .. } | ApiPath::SkyblockAuctionsEnded | ApiPath::SkyblockBazaar
+            | ApiPath::SkyblockFiresales
+        )
+    }
+}
+
+impl Display for ApiPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_path())
+    }
+}
+
+/// Percent-encodes `value` for use in a query, keeping only unreserved characters.
+fn encode(value: &str) -> String {
+    value.bytes().map(|byte| match byte {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+        _ => format!("%{:02X}", byte),
+    }).collect()
+}
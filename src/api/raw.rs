@@ -0,0 +1,120 @@
+use std::fmt::Formatter;
+use std::ops::Deref;
+use serde::{Deserialize, Deserializer};
+use serde::de::{DeserializeOwned, Error};
+use serde_json::value::RawValue;
+
+/// A wrapper around any response data structure that keeps the original json alongside it.
+///
+/// Request `WithRaw<T>` instead of `T` to opt in, the typed data is still accessible through [`Deref`].
+/// This allows archiving the exact payload or parsing fields the typed struct doesn't cover
+/// without sending a second request.
+///
+/// # Note
+/// This type can only be deserialized from json text (e.g. with [`serde_json::from_str`]),
+/// not from an already parsed [`serde_json::Value`].
+///
+/// # Examples
+/// ```rust,no_run
+/// use hypixel_api::{RequestHandler, StatusReply, WithRaw};
+/// # use uuid::Uuid;
+/// # use std::str::FromStr;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let request_handler = RequestHandler::new(Uuid::from_str("your-api-key").unwrap());
+/// let reply = request_handler.request::<WithRaw<StatusReply>>("status?uuid=069a79f4-44e9-4726-a5be-fca90e38aaf5", true)
+///     .await.unwrap().unwrap();
+///
+/// println!("online: {}, raw json: {}", reply.online(), reply.raw());
+/// # }
+/// ```
+pub struct WithRaw<T> {
+    data: T,
+    raw: Box<RawValue>,
+}
+
+impl<T> WithRaw<T> {
+    /// Returns the typed data.
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    /// Returns the original json exactly as it was received.
+    pub fn raw(&self) -> &str {
+        self.raw.get()
+    }
+
+    /// Returns the original json as a [`RawValue`].
+    pub fn raw_value(&self) -> &RawValue {
+        &self.raw
+    }
+
+    /// Consumes the wrapper and returns the typed data.
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    /// Consumes the wrapper and returns both the typed data and the original json.
+    pub fn into_parts(self) -> (T, Box<RawValue>) {
+        (self.data, self.raw)
+    }
+}
+
+impl<T> Deref for WithRaw<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+#[cfg(feature = "reply")]
+impl<T: crate::api::reply::Reply> crate::api::reply::Reply for WithRaw<T> {
+    type Data = T::Data;
+
+    fn success(&self) -> bool {
+        self.data.success()
+    }
+
+    fn cause(&self) -> Option<&str> {
+        self.data.cause()
+    }
+
+    fn data(&self) -> &Self::Data {
+        self.data.data()
+    }
+
+    fn into_data(self) -> Self::Data {
+        self.data.into_data()
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for WithRaw<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WithRaw")
+            .field("data", &self.data)
+            .field("raw", &self.raw.get())
+            .finish()
+    }
+}
+
+impl<T: Clone> Clone for WithRaw<T> {
+    fn clone(&self) -> Self {
+        WithRaw {
+            data: self.data.clone(),
+            raw: self.raw.clone(),
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for WithRaw<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = Box::<RawValue>::deserialize(deserializer)?;
+        let data = serde_json::from_str(raw.get()).map_err(D::Error::custom)?;
+        Ok(WithRaw {
+            data,
+            raw,
+        })
+    }
+}
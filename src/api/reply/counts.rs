@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use crate::api::GameType;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/Other/paths/~1counts/get).
+///
+/// Games are keyed by their type name, e.g. `"BEDWARS"`, besides the pseudo games
+/// `"MAIN_LOBBY"`, `"TOURNAMENT_LOBBY"`, `"LIMBO"`, `"IDLE"` and `"QUEUE"`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountsReply {
+    success: bool,
+    cause: Option<String>,
+    #[serde(default)]
+    games: HashMap<String, GameCount>,
+    #[serde(default)]
+    player_count: u64,
+}
+
+impl CountsReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the amount of players online on the whole network.
+    pub fn player_count(&self) -> u64 {
+        self.player_count
+    }
+
+    /// Returns the counts of all games, keyed by type name.
+    pub fn games(&self) -> &HashMap<String, GameCount> {
+        &self.games
+    }
+
+    /// Consumes the reply and returns the player counts of all games like [`CountsReply::games`].
+    pub fn into_games(self) -> HashMap<String, GameCount> {
+        self.games
+    }
+
+    /// Returns the counts of the game `name`, if present.
+    pub fn game(&self, name: &str) -> Option<&GameCount> {
+        self.games.get(name)
+    }
+
+    /// Returns the amount of players in the game `game`, `0` if it is not reported.
+    pub fn players_in(&self, game: &GameType) -> u64 {
+        self.game(&game.to_string()).map_or(0, GameCount::players)
+    }
+
+    /// Returns the amount of players per mode of the game `game`, keyed by mode name, if reported.
+    pub fn modes(&self, game: &GameType) -> Option<&HashMap<String, u64>> {
+        self.game(&game.to_string()).map(GameCount::modes)
+    }
+
+    /// Returns the amount of players in every game, from the most to the least populated.
+    ///
+    /// Games with the same amount of players are ordered by type name. Pseudo games like
+    /// `"IDLE"` and `"QUEUE"` are returned as [`GameType::Unknown`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use hypixel_api::{CountsReply, GameType};
+    ///
+    /// let reply: CountsReply = serde_json::from_str(r#"{"success":true,"playerCount":60,"games":{"SKYWARS":{"players":20},"BEDWARS":{"players":40}}}"#).unwrap();
+    /// let games: Vec<_> = reply.by_population().collect();
+    /// assert_eq!(games, vec![(GameType::Bedwars, 40), (GameType::SkyWars, 20)]);
+    /// ```
+    pub fn by_population(&self) -> impl Iterator<Item = (GameType, u64)> + '_ {
+        let mut games: Vec<(&String, u64)> = self.games.iter().map(|(name, count)| (name, count.players)).collect();
+        games.sort_by(|(a_name, a_players), (b_name, b_players)| b_players.cmp(a_players).then_with(|| a_name.cmp(b_name)));
+        games.into_iter().map(|(name, players)| (GameType::from(name.clone()), players))
+    }
+}
+
+impl_reply!(CountsReply);
+
+/// The amount of players in a single game, see [`CountsReply`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GameCount {
+    #[serde(default)]
+    players: u64,
+    #[serde(default)]
+    modes: HashMap<String, u64>,
+}
+
+impl GameCount {
+    /// Returns the amount of players in this game.
+    pub fn players(&self) -> u64 {
+        self.players
+    }
+
+    /// Returns the amount of players per mode of this game, keyed by mode name
+    /// (e.g. `"eight_one"`), if reported.
+    pub fn modes(&self) -> &HashMap<String, u64> {
+        &self.modes
+    }
+}
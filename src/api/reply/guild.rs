@@ -0,0 +1,280 @@
+use std::collections::{BTreeMap, HashMap};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+use crate::api::{ColorCodes, GameType};
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/Other/paths/~1guild/get).
+///
+/// Response fields are captured in [`GuildData`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GuildReply {
+    success: bool,
+    cause: Option<String>,
+    #[cfg_attr(feature = "diagnostics", serde(default, deserialize_with = "deserialize_guild"))]
+    guild: Option<GuildData>,
+}
+
+impl GuildReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the data associated with the requested guild.
+    ///
+    /// If this function returns [`Option::None`], no guild matched the request.
+    pub fn guild(&self) -> Option<&GuildData> {
+        self.guild.as_ref()
+    }
+
+    /// Consumes the reply and returns the guild data, if present.
+    pub fn into_guild(self) -> Option<GuildData> {
+        self.guild
+    }
+}
+
+impl_reply!(GuildReply, guild: Option<GuildData>);
+
+/// Deserializes the guild of a [`GuildReply`], locating the fields captured by its catch-all
+/// map for strict mode.
+#[cfg(feature = "diagnostics")]
+fn deserialize_guild<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<GuildData>, D::Error> {
+    crate::api::diagnostics::within("guild", || Option::deserialize(deserializer))
+}
+
+/// The response data corresponding to [`this endpoint`](https://api.hypixel.net/#tag/Other/paths/~1guild/get).
+///
+/// ### Members and ranks
+/// Each [`GuildMember`] only stores the name of its rank, use [`GuildData::rank_of`]
+/// to resolve the corresponding [`GuildRank`]. The guild master's rank is never
+/// listed in the guild's ranks, see [`GuildMember::is_guild_master`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuildData {
+    #[serde(rename = "_id")]
+    id: String,
+    name: String,
+    tag: Option<String>,
+    #[serde(default, deserialize_with = "crate::api::util::lenient::deserialize_known")]
+    tag_color: Option<ColorCodes>,
+    description: Option<String>,
+    #[serde(default)]
+    exp: u64,
+    created: Option<u64>,
+    #[serde(default)]
+    publicly_listed: bool,
+    #[serde(default)]
+    preferred_games: Vec<GameType>,
+    #[serde(default)]
+    guild_exp_by_game_type: HashMap<GameType, u64>,
+    #[serde(default)]
+    members: Vec<GuildMember>,
+    #[serde(default)]
+    ranks: Vec<GuildRank>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "diagnostics", serde(deserialize_with = "crate::api::diagnostics::capture"))]
+    other: HashMap<String, Value>,
+}
+
+impl GuildData {
+    /// Returns the unique id of this guild.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the name of this guild.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the tag of this guild, if present.
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+
+    /// Returns the color of this guild's tag.
+    ///
+    /// Defaults to [`ColorCodes::Gray`].
+    pub fn tag_color(&self) -> ColorCodes {
+        self.tag_color.unwrap_or(ColorCodes::Gray)
+    }
+
+    /// Returns the description of this guild, if present.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the total amount of experience this guild has earned.
+    pub fn exp(&self) -> u64 {
+        self.exp
+    }
+
+    /// Returns the date this guild was created.
+    pub fn created(&self) -> Option<DateTime<Utc>> {
+        self.created.and_then(from_millis)
+    }
+
+    /// Returns `true` if this guild shows up in the guild finder.
+    pub fn publicly_listed(&self) -> bool {
+        self.publicly_listed
+    }
+
+    /// Returns the games this guild prefers to play.
+    pub fn preferred_games(&self) -> &[GameType] {
+        &self.preferred_games
+    }
+
+    /// Returns the amount of experience this guild has earned in `game`.
+    pub fn exp_by_game_type(&self, game: &GameType) -> u64 {
+        self.guild_exp_by_game_type.get(game).copied().unwrap_or(0)
+    }
+
+    /// Returns all members of this guild.
+    pub fn members(&self) -> &[GuildMember] {
+        &self.members
+    }
+
+    /// Consumes the guild data and returns all members of this guild.
+    pub fn into_members(self) -> Vec<GuildMember> {
+        self.members
+    }
+
+    /// Returns the member with the given `uuid`, if present.
+    pub fn member(&self, uuid: Uuid) -> Option<&GuildMember> {
+        self.members.iter().find(|m| m.uuid == uuid)
+    }
+
+    /// Returns all rank definitions of this guild.
+    ///
+    /// The guild master rank is never included.
+    pub fn ranks(&self) -> &[GuildRank] {
+        &self.ranks
+    }
+
+    /// Returns the rank definition of `member`, if present.
+    ///
+    /// The guild master and members of removed ranks have no rank definition.
+    pub fn rank_of(&self, member: &GuildMember) -> Option<&GuildRank> {
+        self.ranks.iter().find(|r| r.name.eq_ignore_ascii_case(&member.rank))
+    }
+
+    /// Returns the members with a rank of a higher priority than `priority`.
+    ///
+    /// The guild master is always included.
+    pub fn members_above_priority(&self, priority: u32) -> impl Iterator<Item = &GuildMember> {
+        self.members.iter().filter(move |m| {
+            m.is_guild_master() || self.rank_of(m).is_some_and(|r| r.priority > priority)
+        })
+    }
+
+    /// Returns every member's uuid together with the experience
+    /// they earned for the guild in the past 7 days.
+    pub fn weekly_exp_per_member(&self) -> impl Iterator<Item = (Uuid, u64)> + '_ {
+        self.members.iter().map(|m| (m.uuid, m.weekly_exp()))
+    }
+
+    /// Returns any other property this struct does not capture
+    /// explicitly already, if present.
+    pub fn property_value(&self, name: &str) -> Option<&Value> {
+        self.other.get(name)
+    }
+}
+
+/// A single member of a guild.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuildMember {
+    #[serde(deserialize_with = "crate::api::util::uuid::deserialize")]
+    uuid: Uuid,
+    rank: String,
+    joined: Option<u64>,
+    #[serde(default)]
+    quest_participation: u32,
+    #[serde(default)]
+    exp_history: BTreeMap<NaiveDate, u64>,
+}
+
+impl GuildMember {
+    /// Returns the member's UUID.
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns the name of the member's rank.
+    ///
+    /// See [`GuildData::rank_of`] for the corresponding rank definition.
+    pub fn rank(&self) -> &str {
+        &self.rank
+    }
+
+    /// Returns `true` if this member owns the guild.
+    pub fn is_guild_master(&self) -> bool {
+        self.rank.eq_ignore_ascii_case("Guild Master") || self.rank.eq_ignore_ascii_case("GUILDMASTER")
+    }
+
+    /// Returns the date this member joined the guild.
+    pub fn joined(&self) -> Option<DateTime<Utc>> {
+        self.joined.and_then(from_millis)
+    }
+
+    /// Returns the amount of guild quests this member has contributed to.
+    pub fn quest_participation(&self) -> u32 {
+        self.quest_participation
+    }
+
+    /// Returns the experience this member earned for the guild per day.
+    ///
+    /// The API only includes the past 7 days.
+    pub fn exp_history(&self) -> &BTreeMap<NaiveDate, u64> {
+        &self.exp_history
+    }
+
+    /// Returns the experience this member earned for the guild in the past 7 days.
+    pub fn weekly_exp(&self) -> u64 {
+        self.exp_history.values().sum()
+    }
+}
+
+/// A rank definition of a guild.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct GuildRank {
+    name: String,
+    #[serde(default)]
+    default: bool,
+    tag: Option<String>,
+    created: Option<u64>,
+    #[serde(default)]
+    priority: u32,
+}
+
+impl GuildRank {
+    /// Returns the name of this rank.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns `true` if new members receive this rank.
+    pub fn is_default(&self) -> bool {
+        self.default
+    }
+
+    /// Returns the tag of this rank, if present.
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+
+    /// Returns the date this rank was created.
+    pub fn created(&self) -> Option<DateTime<Utc>> {
+        self.created.and_then(from_millis)
+    }
+
+    /// Returns the priority of this rank, higher ranks have a higher priority.
+    pub fn priority(&self) -> u32 {
+        self.priority
+    }
+}
@@ -5,9 +5,13 @@ use uuid::Uuid;
 /// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/API/paths/~1key/get).
 ///
 /// Response fields are captured in [`KeyData`].
-#[derive(Debug, Copy, Clone, Deserialize)]
+///
+/// This endpoint has been removed from the API, use
+/// [`RequestHandler::key_usage`](crate::RequestHandler::key_usage) instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
 pub struct KeyReply {
     success: bool,
+    cause: Option<String>,
     record: KeyData,
 }
 
@@ -20,6 +24,8 @@ impl KeyReply {
     }
 }
 
+impl_reply!(KeyReply, record: KeyData);
+
 impl Deref for KeyReply {
     type Target = KeyData;
 
@@ -33,7 +39,7 @@ impl Deref for KeyReply {
 /// All fields are captured, except the repetition
 /// of the actual `ApiKey` used to send the request.
 /// (This being due to security reasons)
-#[derive(Debug, Copy, Clone, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct KeyData {
     queries_in_past_min: i32,
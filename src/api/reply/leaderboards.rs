@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::api::error::HypixelApiError;
+use crate::api::pagination::{PageLimits, PartialResult};
+use crate::api::path::ApiPath;
+use crate::api::reply::{PlayerData, PlayerReply, Reply};
+use crate::HypixelApi;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/Other/paths/~1leaderboards/get).
+///
+/// Leaderboards are grouped by the type name of their game, e.g. `"BEDWARS"`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct LeaderboardsReply {
+    success: bool,
+    cause: Option<String>,
+    #[serde(default)]
+    leaderboards: HashMap<String, Vec<Leaderboard>>,
+}
+
+impl LeaderboardsReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns all leaderboards, keyed by the type name of their game.
+    pub fn leaderboards(&self) -> &HashMap<String, Vec<Leaderboard>> {
+        &self.leaderboards
+    }
+
+    /// Consumes the reply and returns all leaderboards keyed by the type name of their game.
+    pub fn into_leaderboards(self) -> HashMap<String, Vec<Leaderboard>> {
+        self.leaderboards
+    }
+
+    /// Returns the leaderboards of the game `game` (e.g. `"BEDWARS"`), in the order they are shown in its lobby.
+    pub fn game(&self, game: &str) -> &[Leaderboard] {
+        self.leaderboards.get(game).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the leaderboard of `game` with the stat path `path` (e.g. `"bedwars_level"`), if present.
+    pub fn leaderboard(&self, game: &str, path: &str) -> Option<&Leaderboard> {
+        self.game(game).iter().find(|leaderboard| leaderboard.path == path)
+    }
+
+    /// Fetches the player data of the first `top_n` leaders of the leaderboard `path` of `game`,
+    /// see [`Leaderboard::hydrate_leaders`].
+    ///
+    /// Returns an empty list if there is no such leaderboard.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use hypixel_api::{LeaderboardsReply, RequestHandler};
+    /// # use uuid::Uuid;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let request_handler = RequestHandler::new(Uuid::nil());
+    /// let reply = request_handler.request::<LeaderboardsReply>("leaderboards", true).await.unwrap().unwrap();
+    /// for (rank, (uuid, player)) in reply.hydrate_leaders(&request_handler, "BEDWARS", "bedwars_level", 10).await.unwrap().iter().enumerate() {
+    ///     println!("#{} {}", rank + 1, player.as_ref().and_then(|player| player.name()).unwrap_or(&uuid.to_string()));
+    /// }
+    /// # }
+    /// ```
+    pub async fn hydrate_leaders(&self, api: &impl HypixelApi, game: &str, path: &str, top_n: usize) -> Result<Vec<(Uuid, Option<PlayerData>)>, HypixelApiError> {
+        match self.leaderboard(game, path) {
+            Some(leaderboard) => leaderboard.hydrate_leaders(api, top_n).await,
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+impl_reply!(LeaderboardsReply);
+
+/// A single leaderboard of a game, see [`LeaderboardsReply`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    path: String,
+    #[serde(default)]
+    prefix: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    location: String,
+    #[serde(default)]
+    count: u32,
+    #[serde(default)]
+    leaders: Vec<Uuid>,
+}
+
+impl Leaderboard {
+    /// Returns the stat this leaderboard ranks, e.g. `"bedwars_level"`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the prefix of the title, e.g. `"Overall"` or `"Weekly"`.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the location of the hologram in the lobby, as `"x,y,z"`.
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// Returns the amount of players shown on the hologram.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Returns the uuids of the leaders, from first to last.
+    pub fn leaders(&self) -> &[Uuid] {
+        &self.leaders
+    }
+
+    /// Fetches the player data of the first `top_n` leaders concurrently, returned in the order
+    /// of the leaderboard.
+    ///
+    /// The requests are queued at once and throttled by `api` like any other request.
+    /// Leaders whose player data is missing are returned with [`Option::None`], the first
+    /// failed request fails the whole call.
+    ///
+    /// Fails with [`HypixelApiError::LimitReached`] if `top_n` exceeds the [`PageLimits`] of
+    /// `api`, before any request is queued.
+    pub async fn hydrate_leaders(&self, api: &impl HypixelApi, top_n: usize) -> Result<Vec<(Uuid, Option<PlayerData>)>, HypixelApiError> {
+        self.hydrate_leaders_with_limits(api, top_n, PageLimits::new()).await?
+            .into_complete()
+            .map_err(HypixelApiError::LimitReached)
+    }
+
+    /// Fetches the player data of the first `top_n` leaders like [`Leaderboard::hydrate_leaders`],
+    /// but at most as many as `limits` (or, where not set, the [`PageLimits`] of `api`) allow.
+    ///
+    /// Every leader is one item and one request. If a limit is smaller than `top_n`, only the
+    /// first leaders up to the limit are fetched and returned along with the limit.
+    pub async fn hydrate_leaders_with_limits(&self, api: &impl HypixelApi, top_n: usize, limits: PageLimits) -> Result<PartialResult<Vec<(Uuid, Option<PlayerData>)>>, HypixelApiError> {
+        let (allowed, limit) = limits.or(api.page_limits()).allowed_items(top_n.min(self.leaders.len()));
+        let leaders = &self.leaders[..allowed];
+        let requests = leaders.iter().map(|uuid| api.request_typed::<PlayerReply>(ApiPath::Player { uuid: *uuid }));
+        let replies = futures::future::join_all(requests).await;
+        let players = leaders.iter().copied().zip(replies)
+            .map(|(uuid, reply)| Ok((uuid, reply??.into_data())))
+            .collect::<Result<_, HypixelApiError>>()?;
+        Ok(PartialResult::new(players, limit))
+    }
+}
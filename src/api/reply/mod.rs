@@ -3,8 +3,81 @@
 
 mod player;
 mod status;
+mod stats;
+mod guild;
+mod quests;
+mod resources;
+mod skyblock;
+mod counts;
+mod leaderboards;
+#[cfg(feature = "legacy-key")]
 mod key;
 
-pub use player::{PlayerReply, PlayerData};
-pub use status::{StatusReply, StatusData};
+pub use player::{PlayerReply, PlayerData, GiftingMeta, ParkourCompletions, ParkourCompletion};
+pub use status::{StatusReply, StatusData, SessionData};
+pub use stats::{GameStats, Arcade, ArcadeStats, ZombiesStats, MiniWallsStats, Bedwars, BedwarsStats, Blitz, BlitzStats, Warlords, WarlordsStats, ArenaBrawl, ArenaBrawlStats, ArenaBrawlModeStats, Paintball, PaintballStats, VampireZ, VampireZStats, Quake, QuakeStats, TurboKartRacers, TurboKartRacersStats, Duels, DuelsStats, MegaWalls, MegaWallsStats, SkyWars, SkyWarsStats, TntGames, TntGamesStats, TntRunStats, PvpRunStats, TntTagStats, BowSpleefStats, WizardsStats, StatsSummary};
+pub use guild::{GuildReply, GuildData, GuildMember, GuildRank};
+pub use quests::{Quests, QuestProgress, QuestCompletion};
+pub use resources::{QuestsResourcesReply, QuestDefinition, QuestObjective, SkillsResourcesReply, SkillDefinition, ElectionResourcesReply, Mayor, Minister, Election, Candidate, Perk, GamesResourcesReply, GameDefinition, AchievementsResourcesReply, GameAchievements, OneTimeAchievement, TieredAchievement, AchievementTier, CollectionsResourcesReply, CollectionCategory, CollectionDefinition, CollectionTier, ItemsResourcesReply, ItemDefinition, VanityResourcesReply, VanityType, VanityRarity, BingoResourcesReply, BingoGoal};
+pub use counts::{CountsReply, GameCount};
+pub use leaderboards::{LeaderboardsReply, Leaderboard};
+pub use skyblock::{SkyblockBazaarReply, BazaarProduct, BazaarOrder, BazaarQuickStatus, SkyblockAuctionsReply, Auction, SkyblockProfilesReply, SkyblockProfile, ProfileMember, DungeonsData, DungeonType, SkyblockBingoReply, BingoEvent};
+#[cfg(feature = "legacy-key")]
 pub use key::{KeyReply, KeyData};
+#[cfg(feature = "test-util")]
+pub use player::PlayerDataBuilder;
+#[cfg(feature = "test-util")]
+pub use status::StatusDataBuilder;
+
+use crate::api::error::HypixelApiError;
+
+/// The common shape of every reply in this module, for generic code like caching, logging or
+/// bulk fetching that treats replies uniformly.
+///
+/// Replies that wrap their response fields in a data structure (e.g. [`StatusReply`] and
+/// [`StatusData`]) return it as their data, the player and guild data are optional. All other
+/// replies are their own data.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::{Reply, StatusReply};
+///
+/// fn successful_data<R: Reply>(replies: Vec<R>) -> Vec<R::Data> {
+///     replies.into_iter().filter(|reply| reply.success()).map(Reply::into_data).collect()
+/// }
+///
+/// let reply: StatusReply = serde_json::from_str(r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#).unwrap();
+/// assert!(successful_data(vec![reply])[0].online());
+/// ```
+pub trait Reply {
+    /// The payload of this reply.
+    type Data;
+
+    /// Returns whether the response was successful.
+    fn success(&self) -> bool;
+
+    /// Returns the reason the API gave for an unsuccessful response, if any.
+    fn cause(&self) -> Option<&str>;
+
+    /// Returns the payload of this reply.
+    fn data(&self) -> &Self::Data;
+
+    /// Consumes this reply and returns its payload.
+    fn into_data(self) -> Self::Data;
+
+    /// Returns this reply if it was successful, otherwise [`HypixelApiError::Unsuccessful`]
+    /// with the [cause](Reply::cause) given by the API.
+    ///
+    /// [`RequestHandler::request_typed`](crate::RequestHandler::request_typed) already checks
+    /// this. Use this for replies requested otherwise.
+    fn validate(self) -> Result<Self, HypixelApiError>
+    where
+        Self: Sized,
+    {
+        if self.success() {
+            Ok(self)
+        } else {
+            Err(HypixelApiError::Unsuccessful(self.cause().map(str::to_string)))
+        }
+    }
+}
\ No newline at end of file
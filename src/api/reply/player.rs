@@ -1,19 +1,24 @@
-use chrono::{DateTime, Local, TimeZone};
+use chrono::{DateTime, Local, Utc};
 use serde::Deserialize;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
 use serde::de::DeserializeOwned;
 use uuid::Uuid;
-use crate::api::{ColorCodes, MonthlyPackageRank, PackageRank, StaffLevel};
+use crate::api::{ColorCodes, GameType, MonthlyPackageRank, PackageRank, StaffLevel};
+use crate::api::reply::{GameStats, Quests, QuestsResourcesReply, VanityResourcesReply, VanityType};
+use crate::api::util::time::{from_millis, last_daily_reset};
 use crate::error::HypixelApiError;
 use crate::util::leveling;
 
 /// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/Player-Data).
 ///
 /// Response fields are captured in [`PlayerData`].
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct PlayerReply {
     success: bool,
+    cause: Option<String>,
+    #[cfg_attr(feature = "diagnostics", serde(default, deserialize_with = "deserialize_player"))]
     player: Option<PlayerData>,
 }
 
@@ -32,6 +37,20 @@ impl PlayerReply {
     pub fn player(&self) -> Option<&PlayerData> {
         self.player.as_ref()
     }
+
+    /// Consumes the reply and returns the player data, if present.
+    pub fn into_player(self) -> Option<PlayerData> {
+        self.player
+    }
+}
+
+impl_reply!(PlayerReply, player: Option<PlayerData>);
+
+/// Deserializes the player of a [`PlayerReply`], locating the fields captured by its catch-all
+/// map for strict mode.
+#[cfg(feature = "diagnostics")]
+fn deserialize_player<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<PlayerData>, D::Error> {
+    crate::api::diagnostics::within("player", || Option::deserialize(deserializer))
 }
 
 /// The response data corresponding to [`this endpoint`](https://api.hypixel.net/#tag/Player-Data).
@@ -53,16 +72,39 @@ impl PlayerReply {
 /// for more information.
 ///
 /// ### Game stats
-/// All game stats are captured generically. To get a specific one,
-/// use [`PlayerData::stat_value`] or define a corresponding struct
-/// and use [`PlayerData::stat_json`].
+/// All game stats are captured generically. Use [`PlayerData::stats`] for games
+/// that implement [`GameStats`]. To get any other one, use [`PlayerData::stat_value`]
+/// or define a corresponding struct and use [`PlayerData::stat_json`].
 ///
 /// ### Other properties
 /// You can get any property that the functions in this struct don't cover
 /// by using [`PlayerData::property_value`] or defining a corresponding struct
 /// and use [`PlayerData::property_json`].
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PlayerData {
+    fields: PlayerFields,
+    /// The fields of the response that are captured in `fields` explicitly, as they appeared.
+    raw: serde_json::Map<String, Value>,
+}
+
+impl<'de> Deserialize<'de> for PlayerData {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::Object(serde_json::Map::deserialize(deserializer)?);
+        #[cfg(feature = "diagnostics")]
+        let fields = crate::api::diagnostics::deserialize_part::<PlayerFields>(&value);
+        #[cfg(not(feature = "diagnostics"))]
+        let fields = PlayerFields::deserialize(&value);
+        let fields = fields.map_err(serde::de::Error::custom)?;
+        let Value::Object(mut raw) = value else { unreachable!() };
+        raw.retain(|name, _| name != "stats" && !fields.other.contains_key(name));
+        Ok(PlayerData { fields, raw })
+    }
+}
+
+/// The typed fields of [`PlayerData`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct PlayerFields {
+    #[serde(deserialize_with = "crate::api::util::uuid::deserialize")]
     uuid: Uuid,
     #[serde(rename = "displayname")]
     display_name: Option<String>,
@@ -80,35 +122,64 @@ pub struct PlayerData {
     new_package_rank: Option<PackageRank>,
     #[serde(rename = "monthlyPackageRank")]
     is_plus_plus: Option<MonthlyPackageRank>,
-    #[serde(rename = "rankPlusColor")]
+    #[serde(rename = "rankPlusColor", default, deserialize_with = "crate::api::util::lenient::deserialize_known")]
     rank_plus_color: Option<ColorCodes>,
-    #[serde(rename = "monthlyRankColor")]
+    #[serde(rename = "monthlyRankColor", default, deserialize_with = "crate::api::util::lenient::deserialize_known")]
     superstar_tag_color: Option<ColorCodes>,
-    #[serde(rename = "buildTeam", default)]
+    #[serde(rename = "buildTeam", default, deserialize_with = "crate::api::util::lenient::deserialize_bool")]
     build_team: bool,
-    #[serde(rename = "buildTeamAdmin", default)]
+    #[serde(rename = "buildTeamAdmin", default, deserialize_with = "crate::api::util::lenient::deserialize_bool")]
     build_team_admin: bool,
-    #[serde(rename = "firstLogin")]
+    #[serde(rename = "firstLogin", default, deserialize_with = "crate::api::util::lenient::deserialize_number_option")]
     first_login: Option<u64>,
-    #[serde(rename = "lastLogin")]
+    #[serde(rename = "lastLogin", default, deserialize_with = "crate::api::util::lenient::deserialize_number_option")]
     last_login: Option<u64>,
-    #[serde(rename = "lastLogout")]
+    #[serde(rename = "lastLogout", default, deserialize_with = "crate::api::util::lenient::deserialize_number_option")]
     last_logout: Option<u64>,
-    #[serde(rename = "networkExp", default)]
+    #[serde(rename = "networkExp", default, deserialize_with = "crate::api::util::lenient::deserialize_number")]
     network_exp: f64,
-    #[serde(rename = "networkLevel", default)]
+    #[serde(rename = "networkLevel", default, deserialize_with = "crate::api::util::lenient::deserialize_number")]
     network_lvl: f64,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "crate::api::util::lenient::deserialize_number")]
     karma: u64,
+    #[serde(rename = "rewardStreak", default, deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    reward_streak: u32,
+    #[serde(rename = "rewardHighScore", default, deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    reward_high_score: u32,
+    #[serde(rename = "rewardScore", default, deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    reward_score: u32,
+    #[serde(rename = "totalRewards", default, deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    total_rewards: u32,
+    #[serde(rename = "totalDailyRewards", default, deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    total_daily_rewards: u32,
+    #[serde(rename = "lastAdsenseGenerateTime", default, deserialize_with = "crate::api::util::lenient::deserialize_number_option")]
+    last_adsense_generate_time: Option<u64>,
+    #[serde(rename = "lastClaimedReward", default, deserialize_with = "crate::api::util::lenient::deserialize_number_option")]
+    last_claimed_reward: Option<u64>,
+    #[serde(rename = "mostRecentGameType")]
+    most_recent_game_type: Option<GameType>,
+    #[serde(rename = "giftingMeta", default)]
+    gifting_meta: GiftingMeta,
+    #[serde(default)]
+    quests: Quests,
+    #[serde(rename = "parkourCompletions", default)]
+    parkour_completions: ParkourCompletions,
+    #[serde(rename = "currentPet")]
+    current_pet: Option<String>,
+    #[serde(rename = "petConsumables", default)]
+    pet_consumables: HashMap<String, u64>,
+    #[serde(rename = "vanityMeta", default)]
+    vanity_meta: VanityMeta,
     stats: Option<HashMap<String, Value>>,
     #[serde(flatten)]
+    #[cfg_attr(feature = "diagnostics", serde(deserialize_with = "crate::api::diagnostics::capture"))]
     other: HashMap<String, Value>,
 }
 
 impl PlayerData {
     /// Returns the player's UUID.
     pub fn uuid(&self) -> Uuid {
-        self.uuid
+        self.fields.uuid
     }
 
     /// Returns the player's name.
@@ -123,19 +194,19 @@ impl PlayerData {
     /// 4. else if `"username"` is present, return it
     /// 5. else no username could be returned
     pub fn name(&self) -> Option<&str> {
-        if self.display_name.is_some() {
-            return self.display_name.as_deref();
+        if self.fields.display_name.is_some() {
+            return self.fields.display_name.as_deref();
         }
-        if let Some(aliases) = &self.known_aliases {
-            if aliases.len() > 0 {
-                return Some(&aliases[aliases.len() - 1]);
+        if let Some(aliases) = &self.fields.known_aliases {
+            if let Some(alias) = aliases.last() {
+                return Some(alias);
             }
         }
-        if self.player_name.is_some() {
-            return self.player_name.as_deref();
+        if self.fields.player_name.is_some() {
+            return self.fields.player_name.as_deref();
         }
-        if self.user_name.is_some() {
-            return self.user_name.as_deref();
+        if self.fields.user_name.is_some() {
+            return self.fields.user_name.as_deref();
         }
         None
     }
@@ -144,13 +215,13 @@ impl PlayerData {
     ///
     /// If no experience or level field was included, `0` will be used instead.
     pub fn network_xp(&self) -> u64 {
-        (self.network_exp + leveling::network::total_xp_to_full_level(self.network_lvl + 1.0)) as u64
+        (self.fields.network_exp + leveling::network::total_xp_to_full_level(self.fields.network_lvl + 1.0)) as u64
     }
 
     /// Returns the player's precise network level, including their progress to the next level.
     pub fn network_level(&self) -> f64 {
         // use direct values for precision
-        let xp = self.network_exp + leveling::network::total_xp_to_full_level(self.network_lvl + 1.0);
+        let xp = self.fields.network_exp + leveling::network::total_xp_to_full_level(self.fields.network_lvl + 1.0);
         leveling::network::exact_level(xp)
     }
 
@@ -158,56 +229,138 @@ impl PlayerData {
     ///
     /// If this field is not present, 0 is returned.
     pub fn karma(&self) -> u64 {
-        self.karma
+        self.fields.karma
+    }
+
+    /// Returns the date when the player first connected to Hypixel.
+    ///
+    /// Use [`DateTime::with_timezone`] to convert the result into any other time zone.
+    pub fn first_login_utc(&self) -> Option<DateTime<Utc>> {
+        self.fields.first_login.and_then(from_millis)
+    }
+
+    /// Returns the last known time when the player connected to the main Hypixel network.
+    ///
+    /// Use [`DateTime::with_timezone`] to convert the result into any other time zone.
+    pub fn last_login_utc(&self) -> Option<DateTime<Utc>> {
+        self.fields.last_login.and_then(from_millis)
+    }
+
+    /// Returns the last known time when the player disconnected from the main Hypixel network.
+    ///
+    /// Use [`DateTime::with_timezone`] to convert the result into any other time zone.
+    pub fn last_logout_utc(&self) -> Option<DateTime<Utc>> {
+        self.fields.last_logout.and_then(from_millis)
+    }
+
+    /// Returns `true` if the player logged in after they last logged out.
+    ///
+    /// This is a hint for players who hide their online status, as [`StatusData::online`](crate::StatusData::online)
+    /// is `false` for them. It can be wrong if the player was disconnected without a logout being
+    /// recorded. Players that hide their login times from the API are never probably online.
+    pub fn is_probably_online(&self) -> bool {
+        match (self.fields.last_login, self.fields.last_logout) {
+            (Some(login), Some(logout)) => login > logout,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
     }
 
     /// Returns the date when the player first connected to Hypixel.
+    #[deprecated(note = "depends on the time zone of the machine, use `first_login_utc` instead")]
     pub fn first_login(&self) -> Option<DateTime<Local>> {
-        self.first_login.map(|v| Local.timestamp_millis(v as i64))
+        self.first_login_utc().map(|v| v.with_timezone(&Local))
     }
 
     /// Returns the last known time when the player connected to the main Hypixel network.
+    #[deprecated(note = "depends on the time zone of the machine, use `last_login_utc` instead")]
     pub fn last_login(&self) -> Option<DateTime<Local>> {
-        self.last_login.map(|v| Local.timestamp_millis(v as i64))
+        self.last_login_utc().map(|v| v.with_timezone(&Local))
     }
 
     /// Returns the last known time when the player disconnected from the main Hypixel network.
+    #[deprecated(note = "depends on the time zone of the machine, use `last_logout_utc` instead")]
     pub fn last_logout(&self) -> Option<DateTime<Local>> {
-        self.last_logout.map(|v| Local.timestamp_millis(v as i64))
+        self.last_logout_utc().map(|v| v.with_timezone(&Local))
+    }
+
+    /// Returns the player's current daily reward streak.
+    ///
+    /// If this field is not present, 0 is returned.
+    pub fn reward_streak(&self) -> u32 {
+        self.fields.reward_streak
+    }
+
+    /// Returns the longest daily reward streak the player ever had.
+    ///
+    /// If this field is not present, 0 is returned.
+    pub fn reward_high_score(&self) -> u32 {
+        self.fields.reward_high_score
+    }
+
+    /// Returns the player's daily reward score.
+    ///
+    /// If this field is not present, 0 is returned.
+    pub fn reward_score(&self) -> u32 {
+        self.fields.reward_score
+    }
+
+    /// Returns the total amount of rewards the player has claimed.
+    ///
+    /// If this field is not present, 0 is returned.
+    pub fn total_rewards(&self) -> u32 {
+        self.fields.total_rewards
+    }
+
+    /// Returns the total amount of daily rewards the player has claimed.
+    ///
+    /// If this field is not present, 0 is returned.
+    pub fn total_daily_rewards(&self) -> u32 {
+        self.fields.total_daily_rewards
+    }
+
+    /// Returns the last time a daily reward link was generated for the player.
+    pub fn last_adsense_generate_time(&self) -> Option<DateTime<Utc>> {
+        self.fields.last_adsense_generate_time.and_then(from_millis)
+    }
+
+    /// Returns the last time the player claimed a daily reward.
+    pub fn last_claimed_reward(&self) -> Option<DateTime<Utc>> {
+        self.fields.last_claimed_reward.and_then(from_millis)
     }
 
     /// Returns the color of the player's `"+"`s if they have `MVP+` or `MVP++`.
     ///
     /// If they do not have either rank, or if they have not selected a color, `"RED"` is returned as the default.
     pub fn selected_plus_color(&self) -> ColorCodes {
-        self.rank_plus_color.unwrap_or(ColorCodes::Red)
+        self.fields.rank_plus_color.unwrap_or(ColorCodes::Red)
     }
 
     /// Returns the color of the player's name tag if they have `MVP++`.
     ///
     /// Defaults to [`ColorCodes::Gold`].
     pub fn superstar_tag_color(&self) -> ColorCodes {
-        self.superstar_tag_color.unwrap_or(ColorCodes::Gold)
+        self.fields.superstar_tag_color.unwrap_or(ColorCodes::Gold)
     }
 
     /// Returns the special rank of players if present.
     ///
     /// Defaults to [`StaffLevel::Normal`].
     pub fn staff_level(&self) -> &StaffLevel {
-        self.staff_level.as_ref().unwrap_or(&StaffLevel::Normal)
+        self.fields.staff_level.as_ref().unwrap_or(&StaffLevel::Normal)
     }
 
     /// Returns the highest in precedence rank that the player has.
     /// See [`this FAQ`](https://github.com/HypixelDev/PublicAPI/wiki/Common-Questions#how-do-i-get-a-players-rank-prefix).
     ///
-    /// This function only considers values in [`PackageRank`].
+    /// Ranks unknown to this crate are returned as [`PackageRank::Unknown`].
     pub fn package_rank(&self) -> PackageRank {
-        if self.is_plus_plus.filter(|v| *v != MonthlyPackageRank::None).is_some() {
+        if self.fields.is_plus_plus.as_ref().is_some_and(|v| *v == MonthlyPackageRank::Superstar) {
             PackageRank::MvpPlusPlus
-        } else if let Some(rank) = self.new_package_rank.filter(|v| *v != PackageRank::None) {
-            rank
-        } else if let Some(rank) = self.package_rank.filter(|v| *v != PackageRank::None) {
-            rank
+        } else if let Some(rank) = self.fields.new_package_rank.as_ref().filter(|v| **v != PackageRank::None) {
+            rank.clone()
+        } else if let Some(rank) = self.fields.package_rank.as_ref().filter(|v| **v != PackageRank::None) {
+            rank.clone()
         } else {
             PackageRank::None
         }
@@ -226,34 +379,157 @@ impl PlayerData {
     ///
     /// Defaults to false.
     pub fn on_build_team(&self) -> bool {
-        self.build_team || self.build_team_admin
+        self.fields.build_team || self.fields.build_team_admin
+    }
+
+    /// Returns the type of the last game the player joined, if present.
+    ///
+    /// Unlike the [`status`](crate::StatusReply) endpoint, this is also known while the player is offline.
+    pub fn most_recent_game_type(&self) -> Option<&GameType> {
+        self.fields.most_recent_game_type.as_ref()
+    }
+
+    /// Returns statistics about the gifts and ranks this player has given and received.
+    pub fn gifting(&self) -> &GiftingMeta {
+        &self.fields.gifting_meta
+    }
+
+    /// Returns the quests the player has started or completed.
+    pub fn quests(&self) -> &Quests {
+        &self.fields.quests
+    }
+
+    /// Returns the ids of the quests the player has completed since the last daily reset.
+    ///
+    /// Quests reset every day at midnight in Hypixel's time zone (`America/New_York`).
+    pub fn quests_completed_today(&self) -> impl Iterator<Item = &str> {
+        let reset = last_daily_reset(Utc::now());
+        self.fields.quests.iter()
+            .filter(move |(_, progress)| progress.last_completed().is_some_and(|t| t >= reset))
+            .map(|(id, _)| id)
+    }
+
+    /// Returns the total amount of times the player has completed a quest of `game`.
+    ///
+    /// `game` is the lowercase name used by [`QuestsResourcesReply`], e.g. `"bedwars"`.
+    pub fn quests_completed(&self, resources: &QuestsResourcesReply, game: &str) -> usize {
+        resources.quests_of(game).iter()
+            .filter_map(|q| self.fields.quests.quest(q.id()))
+            .map(|p| p.completions().len())
+            .sum()
+    }
+
+    /// Returns every parkour run the player has completed, grouped by lobby.
+    pub fn parkour_completions(&self) -> &ParkourCompletions {
+        &self.fields.parkour_completions
+    }
+
+    /// Returns the key of the pet the player has equipped (e.g. `"CAT_BLACK"`), if present.
+    pub fn current_pet(&self) -> Option<&str> {
+        self.fields.current_pet.as_deref()
+    }
+
+    /// Returns the pet the player has equipped, if present and listed in `pets`,
+    /// the reply of `/resources/vanity/pets`.
+    pub fn equipped_pet<'a>(&self, pets: &'a VanityResourcesReply) -> Option<&'a VanityType> {
+        self.fields.current_pet.as_deref().and_then(|key| pets.get(key))
+    }
+
+    /// Returns the amount of every pet consumable the player has, keyed by
+    /// their item name (e.g. `"CARROT_ITEM"`).
+    pub fn pet_consumables(&self) -> &HashMap<String, u64> {
+        &self.fields.pet_consumables
+    }
+
+    /// Returns the amount of the pet consumable `name` (e.g. `"CARROT_ITEM"`) the player has.
+    pub fn pet_consumable(&self, name: &str) -> u64 {
+        self.fields.pet_consumables.get(name).copied().unwrap_or_default()
+    }
+
+    /// Returns the names of every vanity package the player has unlocked,
+    /// e.g. `"pet_cat_black"`.
+    pub fn vanity_packages(&self) -> impl Iterator<Item = &str> {
+        self.fields.vanity_meta.packages.iter().map(String::as_str)
+    }
+
+    /// Returns every pet or companion of `resources` the player has unlocked, in the order of
+    /// `resources`.
+    ///
+    /// Pass the reply of `/resources/vanity/pets` for the player's pets and the reply of
+    /// `/resources/vanity/companions` for their companions.
+    pub fn owned_vanity<'a>(&self, resources: &'a VanityResourcesReply) -> Vec<&'a VanityType> {
+        resources.types().iter()
+            .filter(|vanity| vanity.package().is_some_and(|package| self.fields.vanity_meta.packages.iter().any(|p| p == package)))
+            .collect()
+    }
+
+    /// Returns the stats of the game `G`, if present,
+    /// automatically deserialized into [`GameStats::Stats`].
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use hypixel_api::{PlayerData, SkyWars};
+    ///
+    /// # fn print_kills(player: &PlayerData) {
+    /// if let Some(Ok(stats)) = player.stats::<SkyWars>() {
+    ///     println!("SkyWars kills: {}", stats.kills());
+    /// }
+    /// # }
+    /// ```
+    pub fn stats<G: GameStats>(&self) -> Option<Result<G::Stats, HypixelApiError>> {
+        self.stat_json(G::KEY)
+    }
+
+    /// Consumes the player data and returns the stats of the game `G`, if present,
+    /// automatically deserialized into [`GameStats::Stats`].
+    ///
+    /// Unlike [`PlayerData::stats`], this function does not clone the data.
+    pub fn into_stats<G: GameStats>(self) -> Option<Result<G::Stats, HypixelApiError>> {
+        self.into_stat_json(G::KEY)
     }
 
     /// Returns the json entry corresponding to `name`, if present.
     ///
     /// See [`PlayerData::stat_json`] for a possibly more convenient function.
     pub fn stat_value(&self, name: &str) -> Option<&Value> {
-        self.stats.as_ref().map(|m| m.get(name)).flatten()
+        self.fields.stats.as_ref().and_then(|m| m.get(name))
     }
 
     /// Returns the json entry corresponding to `name`, if present,
     /// and automatically deserialized into `T`.
     ///
     /// # Note
-    /// This function **clones** the data in order to deserialize it. In the future this
-    /// could be updated to automatically deserialize stable games.
+    /// This function **clones** the data in order to deserialize it. Use [`PlayerData::take_stat_json`]
+    /// or [`PlayerData::into_stat_json`] to avoid this for large stats.
     pub fn stat_json<T: DeserializeOwned>(&self, name: &str) -> Option<Result<T, HypixelApiError>> {
-        self.stats.as_ref().map(|m| m.get(name))
-            .flatten()
+        self.fields.stats.as_ref().and_then(|m| m.get(name))
             .map(|v| serde_json::from_value(v.clone()).map_err(|e| e.into()))
     }
 
+    /// Removes the json entry corresponding to `name`, if present,
+    /// and automatically deserializes it into `T`.
+    ///
+    /// Unlike [`PlayerData::stat_json`], this function does not clone the data.
+    /// Subsequent calls for the same `name` return [`Option::None`].
+    pub fn take_stat_json<T: DeserializeOwned>(&mut self, name: &str) -> Option<Result<T, HypixelApiError>> {
+        self.fields.stats.as_mut().and_then(|m| m.remove(name))
+            .map(|v| serde_json::from_value(v).map_err(|e| e.into()))
+    }
+
+    /// Consumes the player data and returns the json entry corresponding to `name`,
+    /// if present, automatically deserialized into `T`.
+    ///
+    /// Unlike [`PlayerData::stat_json`], this function does not clone the data.
+    pub fn into_stat_json<T: DeserializeOwned>(mut self, name: &str) -> Option<Result<T, HypixelApiError>> {
+        self.take_stat_json(name)
+    }
+
     /// Returns any other property this struct does not capture
     /// explicitly already, if present.
     ///
     /// See [`PlayerData::property_json`] for a possibly more convenient function.
     pub fn property_value(&self, name: &str) -> Option<&Value> {
-        self.other.get(name)
+        self.fields.other.get(name)
     }
 
     /// Returns any other property this struct does not capture
@@ -262,8 +538,323 @@ impl PlayerData {
     /// # Note
     /// This function **clones** the data in order to deserialize it. For maximum efficiency,
     /// always consider contributing stable fields to the repository, thank you!
+    /// Otherwise, use [`PlayerData::take_property_json`] or [`PlayerData::into_property_json`].
     pub fn property_json<T: DeserializeOwned>(&self, name: &str) -> Option<Result<T, HypixelApiError>> {
-        self.other.get(name)
+        self.fields.other.get(name)
             .map(|v| serde_json::from_value(v.clone()).map_err(|e| e.into()))
     }
-}
\ No newline at end of file
+
+    /// Removes any other property this struct does not capture
+    /// explicitly already, if present, and automatically deserializes
+    /// it into `T`.
+    ///
+    /// Unlike [`PlayerData::property_json`], this function does not clone the data.
+    /// Subsequent calls for the same `name` return [`Option::None`].
+    pub fn take_property_json<T: DeserializeOwned>(&mut self, name: &str) -> Option<Result<T, HypixelApiError>> {
+        self.fields.other.remove(name)
+            .map(|v| serde_json::from_value(v).map_err(|e| e.into()))
+    }
+
+    /// Consumes the player data and returns any other property this struct
+    /// does not capture explicitly already, if present, automatically
+    /// deserialized into `T`.
+    ///
+    /// Unlike [`PlayerData::property_json`], this function does not clone the data.
+    pub fn into_property_json<T: DeserializeOwned>(mut self, name: &str) -> Option<Result<T, HypixelApiError>> {
+        self.take_property_json(name)
+    }
+
+    /// Returns every scalar value of the player data keyed by its dotted path in the response,
+    /// e.g. `"stats.Bedwars.final_kills_bedwars"`, sorted by path.
+    ///
+    /// Array elements are keyed by their index, e.g. `"knownAliases.0"`, and `null`s are left out.
+    /// Only fields present in the response are included, with the values it contained.
+    /// This is meant to feed exporters (CSV, Parquet, ...) and diffing tools.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use hypixel_api::PlayerData;
+    ///
+    /// # fn export(player: &PlayerData) {
+    /// for (path, value) in player.flatten() {
+    ///     println!("{},{}", path, value);
+    /// }
+    /// # }
+    /// ```
+    pub fn flatten(&self) -> BTreeMap<String, Value> {
+        let mut flat = BTreeMap::new();
+        for (name, value) in &self.raw {
+            flatten_value(name.clone(), value, &mut flat);
+        }
+        for (name, value) in self.fields.stats.iter().flatten() {
+            flatten_value(format!("stats.{}", name), value, &mut flat);
+        }
+        for (name, value) in &self.fields.other {
+            flatten_value(name.clone(), value, &mut flat);
+        }
+        flat
+    }
+}
+
+/// Inserts every scalar in `value` into `flat`, keyed by its dotted path starting with `path`.
+fn flatten_value(path: String, value: &Value, flat: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Null => {}
+        Value::Object(fields) => for (name, value) in fields {
+            flatten_value(format!("{}.{}", path, name), value, flat);
+        },
+        Value::Array(values) => for (index, value) in values.iter().enumerate() {
+            flatten_value(format!("{}.{}", path, index), value, flat);
+        },
+        scalar => {
+            flat.insert(path, scalar.clone());
+        }
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl PlayerReply {
+    /// Creates a successful reply containing `player`, for use in tests.
+    pub fn new(player: Option<PlayerData>) -> Self {
+        PlayerReply {
+            success: true,
+            cause: None,
+            player,
+        }
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl PlayerData {
+    /// Returns a builder to create fake player data, for use in tests.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use hypixel_api::{PackageRank, PlayerData};
+    /// # use uuid::Uuid;
+    ///
+    /// let player = PlayerData::builder(Uuid::nil())
+    ///     .display_name("Notch")
+    ///     .package_rank(PackageRank::MvpPlus)
+    ///     .karma(1000)
+    ///     .build();
+    ///
+    /// assert_eq!(player.name(), Some("Notch"));
+    /// assert_eq!(player.package_rank(), PackageRank::MvpPlus);
+    /// ```
+    pub fn builder(uuid: Uuid) -> PlayerDataBuilder {
+        let mut fields = serde_json::Map::new();
+        fields.insert("uuid".to_string(), Value::String(uuid.simple().to_string()));
+        PlayerDataBuilder { fields }
+    }
+}
+
+/// A builder for fake [`PlayerData`], see [`PlayerData::builder`].
+///
+/// Fields are stored in the same json format the API uses, any field
+/// that doesn't have a dedicated setter can be set with [`PlayerDataBuilder::property`].
+#[cfg(feature = "test-util")]
+#[derive(Debug, Clone)]
+pub struct PlayerDataBuilder {
+    fields: serde_json::Map<String, Value>,
+}
+
+#[cfg(feature = "test-util")]
+impl PlayerDataBuilder {
+    /// Sets the player's display name.
+    pub fn display_name(self, name: &str) -> Self {
+        self.property("displayname", Value::from(name))
+    }
+
+    /// Sets the player's special rank.
+    pub fn staff_level(self, staff_level: StaffLevel) -> Self {
+        self.property("rank", Value::from(staff_level.to_string()))
+    }
+
+    /// Sets the player's package rank.
+    ///
+    /// [`PackageRank::MvpPlusPlus`] is stored as a monthly rank, like the API does.
+    pub fn package_rank(self, rank: PackageRank) -> Self {
+        match rank {
+            PackageRank::MvpPlusPlus => self.property("newPackageRank", Value::from(PackageRank::MvpPlus.to_string()))
+                .property("monthlyPackageRank", Value::from(MonthlyPackageRank::Superstar.to_string())),
+            rank => self.property("newPackageRank", Value::from(rank.to_string())),
+        }
+    }
+
+    /// Sets the color of the player's `"+"`s.
+    pub fn plus_color(self, color: ColorCodes) -> Self {
+        self.property("rankPlusColor", Value::from(color.to_string()))
+    }
+
+    /// Sets the color of the player's `MVP++` name tag.
+    pub fn superstar_tag_color(self, color: ColorCodes) -> Self {
+        self.property("monthlyRankColor", Value::from(color.to_string()))
+    }
+
+    /// Sets the time at which the player first connected to Hypixel.
+    pub fn first_login<Tz: chrono::TimeZone>(self, time: DateTime<Tz>) -> Self {
+        self.property("firstLogin", Value::from(time.timestamp_millis()))
+    }
+
+    /// Sets the last time the player connected to Hypixel.
+    pub fn last_login<Tz: chrono::TimeZone>(self, time: DateTime<Tz>) -> Self {
+        self.property("lastLogin", Value::from(time.timestamp_millis()))
+    }
+
+    /// Sets the last time the player disconnected from Hypixel.
+    pub fn last_logout<Tz: chrono::TimeZone>(self, time: DateTime<Tz>) -> Self {
+        self.property("lastLogout", Value::from(time.timestamp_millis()))
+    }
+
+    /// Sets the last time the player claimed a daily reward.
+    pub fn last_claimed_reward<Tz: chrono::TimeZone>(self, time: DateTime<Tz>) -> Self {
+        self.property("lastClaimedReward", Value::from(time.timestamp_millis()))
+    }
+
+    /// Sets the total amount of network experience the player has earned.
+    pub fn network_xp(self, xp: u64) -> Self {
+        self.property("networkExp", Value::from(xp))
+            .property("networkLevel", Value::from(0))
+    }
+
+    /// Sets the total amount of karma the player has earned.
+    pub fn karma(self, karma: u64) -> Self {
+        self.property("karma", Value::from(karma))
+    }
+
+    /// Sets the type of the last game the player joined.
+    pub fn most_recent_game_type(self, game_type: GameType) -> Self {
+        self.property("mostRecentGameType", Value::from(game_type.to_string()))
+    }
+
+    /// Sets the stats of the game `name`.
+    pub fn stat(mut self, name: &str, value: Value) -> Self {
+        self.fields.entry("stats")
+            .or_insert_with(|| Value::Object(serde_json::Map::new()))
+            .as_object_mut()
+            .expect("stats should always be an object")
+            .insert(name.to_string(), value);
+        self
+    }
+
+    /// Sets the property `name` to `value`, as it would appear in the API's response.
+    pub fn property(mut self, name: &str, value: Value) -> Self {
+        self.fields.insert(name.to_string(), value);
+        self
+    }
+
+    /// Creates the [`PlayerData`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value set with [`PlayerDataBuilder::property`] does not
+    /// match the format of the corresponding field.
+    pub fn build(self) -> PlayerData {
+        serde_json::from_value(Value::Object(self.fields))
+            .expect("properties should match the format of the api")
+    }
+}
+
+/// Statistics about the gifts a player has given and received.
+///
+/// Missing fields default to `0`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GiftingMeta {
+    ranks_given: u32,
+    gifts_given: u32,
+    bundles_given: u32,
+    bundles_received: u32,
+    real_bundles_given: u32,
+    real_bundles_received: u32,
+}
+
+impl GiftingMeta {
+    /// Returns the amount of ranks this player has gifted to others.
+    pub fn ranks_given(&self) -> u32 {
+        self.ranks_given
+    }
+
+    /// Returns the amount of gifts this player has given to others.
+    pub fn gifts_given(&self) -> u32 {
+        self.gifts_given
+    }
+
+    /// Returns the amount of gift bundles this player has given to others.
+    pub fn bundles_given(&self) -> u32 {
+        self.bundles_given
+    }
+
+    /// Returns the amount of gift bundles this player has received.
+    pub fn bundles_received(&self) -> u32 {
+        self.bundles_received
+    }
+
+    /// Returns the amount of bundles bought with real money this player has given to others.
+    pub fn real_bundles_given(&self) -> u32 {
+        self.real_bundles_given
+    }
+
+    /// Returns the amount of bundles bought with real money this player has received.
+    pub fn real_bundles_received(&self) -> u32 {
+        self.real_bundles_received
+    }
+}
+
+/// The vanity items a player has unlocked, see [`PlayerData::vanity_packages`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+struct VanityMeta {
+    packages: Vec<String>,
+}
+
+/// The parkour runs a player has completed, grouped by lobby name.
+///
+/// Lobby names are the ones used by the API, e.g. `"Bedwars"` or `"mainLobby2017"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct ParkourCompletions {
+    lobbies: HashMap<String, Vec<ParkourCompletion>>,
+}
+
+impl ParkourCompletions {
+    /// Returns the names of all lobbies in which the player completed a parkour.
+    pub fn lobbies(&self) -> impl Iterator<Item = &str> {
+        self.lobbies.keys().map(String::as_str)
+    }
+
+    /// Returns all completed runs in `lobby`.
+    ///
+    /// If the player never completed the parkour of this lobby, an empty slice is returned.
+    pub fn completions(&self, lobby: &str) -> &[ParkourCompletion] {
+        self.lobbies.get(lobby).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the fastest completed run in `lobby`, if present.
+    pub fn best_time(&self, lobby: &str) -> Option<Duration> {
+        self.completions(lobby).iter()
+            .map(ParkourCompletion::duration)
+            .min()
+    }
+}
+
+/// A single completed parkour run.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParkourCompletion {
+    time_start: u64,
+    time_took: u64,
+}
+
+impl ParkourCompletion {
+    /// Returns the time at which the player started this run.
+    pub fn time_started(&self) -> Option<DateTime<Utc>> {
+        from_millis(self.time_start)
+    }
+
+    /// Returns how long it took the player to finish this run.
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.time_took)
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::api::util::time::from_millis;
+
+/// The quests a player has started or completed, keyed by quest id.
+///
+/// Quest ids are the ones used by the API, e.g. `"bedwars_daily_win"`.
+/// Use [`QuestsResourcesReply`](crate::QuestsResourcesReply) to find their names.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Quests {
+    quests: HashMap<String, QuestProgress>,
+}
+
+impl Quests {
+    /// Returns the ids of all quests the player has started or completed.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.quests.keys().map(String::as_str)
+    }
+
+    /// Returns the progress of the quest `id`, if present.
+    pub fn quest(&self, id: &str) -> Option<&QuestProgress> {
+        self.quests.get(id)
+    }
+
+    /// Returns every quest id together with the player's progress.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &QuestProgress)> {
+        self.quests.iter().map(|(id, progress)| (id.as_str(), progress))
+    }
+}
+
+/// A player's progress of a single quest.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct QuestProgress {
+    #[serde(default)]
+    completions: Vec<QuestCompletion>,
+    active: Option<ActiveQuest>,
+}
+
+impl QuestProgress {
+    /// Returns every time the player completed this quest.
+    pub fn completions(&self) -> &[QuestCompletion] {
+        &self.completions
+    }
+
+    /// Returns `true` if the player has currently started this quest.
+    pub fn is_active(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Returns the time at which the player started the active attempt of this quest, if present.
+    pub fn started(&self) -> Option<DateTime<Utc>> {
+        self.active.as_ref().and_then(|a| a.started).and_then(from_millis)
+    }
+
+    /// Returns the last time the player completed this quest, if present.
+    pub fn last_completed(&self) -> Option<DateTime<Utc>> {
+        self.completions.iter().map(|c| c.time).max().and_then(from_millis)
+    }
+}
+
+/// A single completion of a quest.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct QuestCompletion {
+    time: u64,
+}
+
+impl QuestCompletion {
+    /// Returns the time at which the quest was completed.
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        from_millis(self.time)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+struct ActiveQuest {
+    started: Option<u64>,
+}
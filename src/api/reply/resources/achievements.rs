@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/Resources/paths/~1resources~1achievements/get).
+///
+/// Achievements are grouped by game, using the lowercase names of the API (e.g. `"bedwars"`).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementsResourcesReply {
+    success: bool,
+    cause: Option<String>,
+    last_updated: Option<u64>,
+    achievements: HashMap<String, GameAchievements>,
+}
+
+impl AchievementsResourcesReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the last time these resources were updated.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated.and_then(from_millis)
+    }
+
+    /// Returns the names of all games that have achievements.
+    pub fn games(&self) -> impl Iterator<Item = &str> {
+        self.achievements.keys().map(String::as_str)
+    }
+
+    /// Returns the achievements of `game`, if present.
+    pub fn game(&self, game: &str) -> Option<&GameAchievements> {
+        self.achievements.get(game)
+    }
+
+    /// Consumes the reply and returns the achievements of all games keyed by the name of the game.
+    pub fn into_achievements(self) -> HashMap<String, GameAchievements> {
+        self.achievements
+    }
+
+    /// Returns the points awarded for the one time achievement `name` in the format of the
+    /// player data, e.g. `"bedwars_bedwars_killer"`.
+    ///
+    /// Returns `0` if the achievement is unknown.
+    pub fn one_time_points(&self, name: &str) -> u32 {
+        name.split_once('_')
+            .and_then(|(game, key)| self.achievements.get(game)?.one_time(&key.to_uppercase()))
+            .map_or(0, OneTimeAchievement::points)
+    }
+
+    /// Returns the points awarded for all tiers of the tiered achievement `name` (e.g.
+    /// `"bedwars_beds"`) that are reached with `amount`.
+    ///
+    /// Returns `0` if the achievement is unknown.
+    pub fn tiered_points(&self, name: &str, amount: u64) -> u32 {
+        name.split_once('_')
+            .and_then(|(game, key)| self.achievements.get(game)?.tiered(&key.to_uppercase()))
+            .map_or(0, |achievement| achievement.points(amount))
+    }
+}
+
+impl_reply!(AchievementsResourcesReply);
+
+/// The achievements of a single game.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GameAchievements {
+    #[serde(default)]
+    one_time: HashMap<String, OneTimeAchievement>,
+    #[serde(default)]
+    tiered: HashMap<String, TieredAchievement>,
+    #[serde(default)]
+    total_points: u32,
+    #[serde(default)]
+    total_legacy_points: u32,
+}
+
+impl GameAchievements {
+    /// Returns the one time achievement `key` (e.g. `"BEDWARS_KILLER"`), if present.
+    pub fn one_time(&self, key: &str) -> Option<&OneTimeAchievement> {
+        self.one_time.get(key)
+    }
+
+    /// Returns all one time achievements, keyed in uppercase.
+    pub fn one_time_achievements(&self) -> &HashMap<String, OneTimeAchievement> {
+        &self.one_time
+    }
+
+    /// Returns the tiered achievement `key` (e.g. `"BEDS"`), if present.
+    pub fn tiered(&self, key: &str) -> Option<&TieredAchievement> {
+        self.tiered.get(key)
+    }
+
+    /// Returns all tiered achievements, keyed in uppercase.
+    pub fn tiered_achievements(&self) -> &HashMap<String, TieredAchievement> {
+        &self.tiered
+    }
+
+    /// Returns the total points of all achievements of this game that can still be earned.
+    pub fn total_points(&self) -> u32 {
+        self.total_points
+    }
+
+    /// Returns the total points of all legacy achievements of this game.
+    pub fn total_legacy_points(&self) -> u32 {
+        self.total_legacy_points
+    }
+}
+
+/// An achievement that is unlocked once.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OneTimeAchievement {
+    name: String,
+    #[serde(default)]
+    description: String,
+    points: u32,
+    #[serde(default)]
+    legacy: bool,
+    #[serde(default)]
+    secret: bool,
+    global_percent_unlocked: Option<f64>,
+    game_percent_unlocked: Option<f64>,
+}
+
+impl OneTimeAchievement {
+    /// Returns the name of this achievement.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of this achievement.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the points awarded for this achievement.
+    pub fn points(&self) -> u32 {
+        self.points
+    }
+
+    /// Returns `true` if this achievement can no longer be earned.
+    pub fn is_legacy(&self) -> bool {
+        self.legacy
+    }
+
+    /// Returns `true` if this achievement is hidden until unlocked.
+    pub fn is_secret(&self) -> bool {
+        self.secret
+    }
+
+    /// Returns the percentage of all players that unlocked this achievement, if present.
+    pub fn global_percent_unlocked(&self) -> Option<f64> {
+        self.global_percent_unlocked
+    }
+
+    /// Returns the percentage of the players of its game that unlocked this achievement, if present.
+    pub fn game_percent_unlocked(&self) -> Option<f64> {
+        self.game_percent_unlocked
+    }
+}
+
+/// An achievement with tiers that are reached by increasing an amount.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TieredAchievement {
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    legacy: bool,
+    #[serde(default)]
+    tiers: Vec<AchievementTier>,
+}
+
+impl TieredAchievement {
+    /// Returns the name of this achievement.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of this achievement, `%s` is replaced by the amount of a tier.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns `true` if this achievement can no longer be earned.
+    pub fn is_legacy(&self) -> bool {
+        self.legacy
+    }
+
+    /// Returns the tiers of this achievement.
+    pub fn tiers(&self) -> &[AchievementTier] {
+        &self.tiers
+    }
+
+    /// Returns the points awarded for all tiers that are reached with `amount`.
+    pub fn points(&self, amount: u64) -> u32 {
+        self.tiers.iter().filter(|tier| tier.amount <= amount).map(|tier| tier.points).sum()
+    }
+}
+
+/// A single tier of a [`TieredAchievement`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct AchievementTier {
+    tier: u32,
+    points: u32,
+    amount: u64,
+}
+
+impl AchievementTier {
+    /// Returns the number of this tier, starting at `1`.
+    pub fn tier(&self) -> u32 {
+        self.tier
+    }
+
+    /// Returns the points awarded for reaching this tier.
+    pub fn points(&self) -> u32 {
+        self.points
+    }
+
+    /// Returns the amount required to reach this tier.
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+}
@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/Resources/paths/~1resources~1skyblock~1bingo/get).
+///
+/// Contains the card of the current bingo event. Personal goals are completed by every player
+/// on their own, community goals by all players together and have tiers.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BingoResourcesReply {
+    success: bool,
+    cause: Option<String>,
+    last_updated: Option<u64>,
+    id: u32,
+    name: Option<String>,
+    start: Option<u64>,
+    end: Option<u64>,
+    modifier: Option<String>,
+    #[serde(default)]
+    goals: Vec<BingoGoal>,
+}
+
+impl BingoResourcesReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the last time these resources were updated.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated.and_then(from_millis)
+    }
+
+    /// Returns the id of the current bingo event, see [`BingoEvent::key`](crate::BingoEvent::key).
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the name of the current bingo event (e.g. `"March 2024"`), if present.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the date when the current bingo event started, if present.
+    pub fn start(&self) -> Option<DateTime<Utc>> {
+        self.start.and_then(from_millis)
+    }
+
+    /// Returns the date when the current bingo event ends, if present.
+    pub fn end(&self) -> Option<DateTime<Utc>> {
+        self.end.and_then(from_millis)
+    }
+
+    /// Returns the modifier of the current bingo event (e.g. `"NORMAL"`), if present.
+    pub fn modifier(&self) -> Option<&str> {
+        self.modifier.as_deref()
+    }
+
+    /// Returns every goal of the current bingo card, in the order of the response.
+    pub fn goals(&self) -> &[BingoGoal] {
+        &self.goals
+    }
+
+    /// Returns the goal `id`, if present.
+    pub fn goal(&self, id: &str) -> Option<&BingoGoal> {
+        self.goals.iter().find(|goal| goal.id == id)
+    }
+
+    /// Consumes the reply and returns every goal of the current bingo card.
+    pub fn into_goals(self) -> Vec<BingoGoal> {
+        self.goals
+    }
+}
+
+impl_reply!(BingoResourcesReply);
+
+/// A single goal of a bingo card, see [`BingoResourcesReply::goals`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BingoGoal {
+    id: String,
+    name: String,
+    lore: Option<String>,
+    #[serde(default)]
+    tiers: Vec<u64>,
+    #[serde(default, deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    progress: u64,
+    required_amount: Option<u64>,
+}
+
+impl BingoGoal {
+    /// Returns the id of this goal.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the name of this goal to display.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of this goal, if present.
+    pub fn lore(&self) -> Option<&str> {
+        self.lore.as_deref()
+    }
+
+    /// Returns `true` if this goal is completed by all players together.
+    pub fn is_community(&self) -> bool {
+        !self.tiers.is_empty()
+    }
+
+    /// Returns the amounts the community progress has to reach for every tier of this
+    /// community goal, empty for personal goals.
+    pub fn tiers(&self) -> &[u64] {
+        &self.tiers
+    }
+
+    /// Returns the progress of the community on this community goal, `0` for personal goals.
+    pub fn progress(&self) -> u64 {
+        self.progress
+    }
+
+    /// Returns the amount of tiers of this community goal the community has reached.
+    pub fn tier(&self) -> usize {
+        self.tiers.iter().filter(|&&amount| amount <= self.progress).count()
+    }
+
+    /// Returns `true` if the community has reached every tier of this community goal.
+    ///
+    /// Always `false` for personal goals.
+    pub fn is_community_complete(&self) -> bool {
+        self.is_community() && self.tier() == self.tiers.len()
+    }
+
+    /// Returns the amount a player has to reach to complete this personal goal, if present.
+    pub fn required_amount(&self) -> Option<u64> {
+        self.required_amount
+    }
+}
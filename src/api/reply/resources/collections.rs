@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/SkyBlock/paths/~1resources~1skyblock~1collections/get).
+///
+/// Collections are grouped by category in uppercase (e.g. `"FARMING"`) and keyed by the
+/// id of their item (e.g. `"WHEAT"`).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionsResourcesReply {
+    success: bool,
+    cause: Option<String>,
+    last_updated: Option<u64>,
+    version: Option<String>,
+    collections: HashMap<String, CollectionCategory>,
+}
+
+impl CollectionsResourcesReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the last time these resources were updated.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated.and_then(from_millis)
+    }
+
+    /// Returns the SkyBlock version of these resources, if present.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Returns the ids of all categories.
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        self.collections.keys().map(String::as_str)
+    }
+
+    /// Returns the category `id`, if present.
+    pub fn category(&self, id: &str) -> Option<&CollectionCategory> {
+        self.collections.get(id)
+    }
+
+    /// Consumes the reply and returns all categories keyed by their id.
+    pub fn into_categories(self) -> HashMap<String, CollectionCategory> {
+        self.collections
+    }
+
+    /// Returns the collection of the item `id` in any category, if present.
+    pub fn collection(&self, id: &str) -> Option<&CollectionDefinition> {
+        self.collections.values().find_map(|category| category.items.get(id))
+    }
+}
+
+impl_reply!(CollectionsResourcesReply);
+
+/// A category of SkyBlock collections, e.g. farming.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CollectionCategory {
+    name: String,
+    #[serde(default)]
+    items: HashMap<String, CollectionDefinition>,
+}
+
+impl CollectionCategory {
+    /// Returns the display name of this category.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the ids of the items of all collections in this category.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.items.keys().map(String::as_str)
+    }
+
+    /// Returns the collection of the item `id`, if present.
+    pub fn collection(&self, id: &str) -> Option<&CollectionDefinition> {
+        self.items.get(id)
+    }
+}
+
+/// The definition of a single SkyBlock collection.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionDefinition {
+    name: String,
+    max_tiers: u32,
+    #[serde(default)]
+    tiers: Vec<CollectionTier>,
+}
+
+impl CollectionDefinition {
+    /// Returns the display name of this collection.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the highest tier of this collection.
+    pub fn max_tiers(&self) -> u32 {
+        self.max_tiers
+    }
+
+    /// Returns the tiers of this collection.
+    pub fn tiers(&self) -> &[CollectionTier] {
+        &self.tiers
+    }
+}
+
+/// A single tier of a [`CollectionDefinition`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionTier {
+    tier: u32,
+    amount_required: u64,
+    #[serde(default)]
+    unlocks: Vec<String>,
+}
+
+impl CollectionTier {
+    /// Returns the number of this tier, starting at `1`.
+    pub fn tier(&self) -> u32 {
+        self.tier
+    }
+
+    /// Returns the amount of collected items required to reach this tier.
+    pub fn amount_required(&self) -> u64 {
+        self.amount_required
+    }
+
+    /// Returns the rewards unlocked by this tier with formatting codes.
+    pub fn unlocks(&self) -> &[String] {
+        &self.unlocks
+    }
+}
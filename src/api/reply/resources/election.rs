@@ -0,0 +1,199 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/SkyBlock/paths/~1resources~1skyblock~1election/get).
+///
+/// Contains the current mayor of SkyBlock and, while an election is open, its candidates.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ElectionResourcesReply {
+    success: bool,
+    cause: Option<String>,
+    last_updated: Option<u64>,
+    mayor: Mayor,
+    current: Option<Election>,
+}
+
+impl ElectionResourcesReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the last time these resources were updated.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated.and_then(from_millis)
+    }
+
+    /// Returns the mayor currently in office.
+    pub fn mayor(&self) -> &Mayor {
+        &self.mayor
+    }
+
+    /// Returns the election that is currently open, if any.
+    pub fn current(&self) -> Option<&Election> {
+        self.current.as_ref()
+    }
+
+    /// Consumes the reply and returns the current mayor and the current election, if any.
+    pub fn into_parts(self) -> (Mayor, Option<Election>) {
+        (self.mayor, self.current)
+    }
+}
+
+impl_reply!(ElectionResourcesReply);
+
+/// The mayor of SkyBlock, see [`ElectionResourcesReply::mayor`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Mayor {
+    key: String,
+    name: String,
+    #[serde(default)]
+    perks: Vec<Perk>,
+    minister: Option<Minister>,
+    election: Option<Election>,
+}
+
+impl Mayor {
+    /// Returns the key of this mayor, e.g. `"fishing"`.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the name of this mayor, e.g. `"Marina"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the perks that are active while this mayor is in office.
+    pub fn perks(&self) -> &[Perk] {
+        &self.perks
+    }
+
+    /// Returns the minister that serves alongside this mayor, if any.
+    pub fn minister(&self) -> Option<&Minister> {
+        self.minister.as_ref()
+    }
+
+    /// Returns the election this mayor won, if present.
+    pub fn election(&self) -> Option<&Election> {
+        self.election.as_ref()
+    }
+}
+
+/// The minister of SkyBlock, the runner-up of the last election.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Minister {
+    key: String,
+    name: String,
+    perk: Perk,
+}
+
+impl Minister {
+    /// Returns the key of this minister, e.g. `"mining"`.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the name of this minister, e.g. `"Cole"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the single perk that is active while this minister serves.
+    pub fn perk(&self) -> &Perk {
+        &self.perk
+    }
+}
+
+/// A single SkyBlock election.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Election {
+    year: u32,
+    #[serde(default)]
+    candidates: Vec<Candidate>,
+}
+
+impl Election {
+    /// Returns the SkyBlock year of this election.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// Returns the candidates of this election.
+    pub fn candidates(&self) -> &[Candidate] {
+        &self.candidates
+    }
+
+    /// Returns the candidate `key`, if present.
+    pub fn candidate(&self, key: &str) -> Option<&Candidate> {
+        self.candidates.iter().find(|candidate| candidate.key == key)
+    }
+
+    /// Returns the candidate with the most votes, if any.
+    pub fn leader(&self) -> Option<&Candidate> {
+        self.candidates.iter().filter(|candidate| candidate.votes.is_some()).max_by_key(|candidate| candidate.votes)
+    }
+}
+
+/// A candidate of an [`Election`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Candidate {
+    key: String,
+    name: String,
+    #[serde(default)]
+    perks: Vec<Perk>,
+    votes: Option<u64>,
+}
+
+impl Candidate {
+    /// Returns the key of this candidate, e.g. `"farming"`.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the name of this candidate, e.g. `"Finnegan"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the perks this candidate runs with.
+    pub fn perks(&self) -> &[Perk] {
+        &self.perks
+    }
+
+    /// Returns the votes this candidate received, if published.
+    pub fn votes(&self) -> Option<u64> {
+        self.votes
+    }
+}
+
+/// A perk of a mayor, minister or candidate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct Perk {
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    minister: bool,
+}
+
+impl Perk {
+    /// Returns the name of this perk.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of this perk with formatting codes.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns `true` if this perk is granted when its candidate becomes minister.
+    pub fn is_minister_perk(&self) -> bool {
+        self.minister
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/Resources/paths/~1resources~1games/get).
+///
+/// Games are keyed by their type name, e.g. `"SURVIVAL_GAMES"`. For the names of the games
+/// without requesting the resources, see [`util::games`](crate::util::games).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamesResourcesReply {
+    success: bool,
+    cause: Option<String>,
+    last_updated: Option<u64>,
+    games: HashMap<String, GameDefinition>,
+}
+
+impl GamesResourcesReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the last time these resources were updated.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated.and_then(from_millis)
+    }
+
+    /// Returns the type names of all games.
+    pub fn type_names(&self) -> impl Iterator<Item = &str> {
+        self.games.keys().map(String::as_str)
+    }
+
+    /// Returns the definition of the game with type name `type_name`, if present.
+    pub fn game(&self, type_name: &str) -> Option<&GameDefinition> {
+        self.games.get(type_name)
+    }
+
+    /// Consumes the reply and returns the definitions of all games keyed by their type name.
+    pub fn into_games(self) -> HashMap<String, GameDefinition> {
+        self.games
+    }
+
+    /// Returns the definition of the game with database name `database_name` (e.g. `"HungerGames"`), if present.
+    pub fn by_database_name(&self, database_name: &str) -> Option<&GameDefinition> {
+        self.games.values().find(|game| game.database_name == database_name)
+    }
+}
+
+impl_reply!(GamesResourcesReply);
+
+/// The definition of a single game.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameDefinition {
+    id: Option<u32>,
+    name: String,
+    database_name: String,
+    #[serde(default)]
+    mode_names: HashMap<String, String>,
+    #[serde(default)]
+    retired: bool,
+    #[serde(default)]
+    legacy: bool,
+}
+
+impl GameDefinition {
+    /// Returns the numeric id of this game, if present.
+    pub fn id(&self) -> Option<u32> {
+        self.id
+    }
+
+    /// Returns the name of this game to display, e.g. `"Blitz Survival Games"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the database name of this game, e.g. `"HungerGames"`.
+    pub fn database_name(&self) -> &str {
+        &self.database_name
+    }
+
+    /// Returns the name to display of the mode `mode` (e.g. `"teams_normal"`), if present.
+    pub fn mode_name(&self, mode: &str) -> Option<&str> {
+        self.mode_names.get(mode).map(String::as_str)
+    }
+
+    /// Returns `true` if this game can no longer be played.
+    pub fn is_retired(&self) -> bool {
+        self.retired
+    }
+
+    /// Returns `true` if this game is part of the classic games.
+    pub fn is_legacy(&self) -> bool {
+        self.legacy
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/SkyBlock/paths/~1resources~1skyblock~1items/get).
+///
+/// Items are looked up by their id, e.g. `"ASPECT_OF_THE_END"`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemsResourcesReply {
+    success: bool,
+    cause: Option<String>,
+    last_updated: Option<u64>,
+    #[serde(deserialize_with = "deserialize_items")]
+    items: HashMap<String, ItemDefinition>,
+}
+
+impl ItemsResourcesReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the last time these resources were updated.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated.and_then(from_millis)
+    }
+
+    /// Returns all items in no particular order.
+    pub fn items(&self) -> impl Iterator<Item = &ItemDefinition> {
+        self.items.values()
+    }
+
+    /// Returns the definition of the item `id`, if present.
+    pub fn item(&self, id: &str) -> Option<&ItemDefinition> {
+        self.items.get(id)
+    }
+
+    /// Consumes the reply and returns the definitions of all items keyed by their id.
+    pub fn into_items(self) -> HashMap<String, ItemDefinition> {
+        self.items
+    }
+
+    /// Returns the amount of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl_reply!(ItemsResourcesReply);
+
+fn deserialize_items<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<HashMap<String, ItemDefinition>, D::Error> {
+    let items: Vec<ItemDefinition> = Deserialize::deserialize(deserializer)?;
+    Ok(items.into_iter().map(|item| (item.id.clone(), item)).collect())
+}
+
+/// The definition of a single SkyBlock item.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ItemDefinition {
+    id: String,
+    name: String,
+    material: String,
+    tier: Option<String>,
+    category: Option<String>,
+    npc_sell_price: Option<f64>,
+    #[serde(default)]
+    museum: bool,
+}
+
+impl ItemDefinition {
+    /// Returns the id of this item, e.g. `"ASPECT_OF_THE_END"`.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the display name of this item.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the Minecraft material of this item, e.g. `"DIAMOND_SWORD"`.
+    pub fn material(&self) -> &str {
+        &self.material
+    }
+
+    /// Returns the rarity of this item (e.g. `"RARE"`), if present.
+    pub fn tier(&self) -> Option<&str> {
+        self.tier.as_deref()
+    }
+
+    /// Returns the category of this item (e.g. `"SWORD"`), if present.
+    pub fn category(&self) -> Option<&str> {
+        self.category.as_deref()
+    }
+
+    /// Returns the coins an NPC pays for this item, if it can be sold.
+    pub fn npc_sell_price(&self) -> Option<f64> {
+        self.npc_sell_price
+    }
+
+    /// Returns `true` if this item can be donated to the museum.
+    pub fn is_museum_item(&self) -> bool {
+        self.museum
+    }
+}
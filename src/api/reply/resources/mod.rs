@@ -0,0 +1,22 @@
+//! Data structures for the `/resources` endpoints, which contain
+//! static information about the Hypixel network.
+
+mod quests;
+mod skills;
+mod election;
+mod games;
+mod achievements;
+mod collections;
+mod items;
+mod vanity;
+mod bingo;
+
+pub use quests::{QuestsResourcesReply, QuestDefinition, QuestObjective};
+pub use skills::{SkillsResourcesReply, SkillDefinition};
+pub use election::{ElectionResourcesReply, Mayor, Minister, Election, Candidate, Perk};
+pub use games::{GamesResourcesReply, GameDefinition};
+pub use achievements::{AchievementsResourcesReply, GameAchievements, OneTimeAchievement, TieredAchievement, AchievementTier};
+pub use collections::{CollectionsResourcesReply, CollectionCategory, CollectionDefinition, CollectionTier};
+pub use items::{ItemsResourcesReply, ItemDefinition};
+pub use vanity::{VanityResourcesReply, VanityType, VanityRarity};
+pub use bingo::{BingoResourcesReply, BingoGoal};
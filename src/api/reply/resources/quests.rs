@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/Resources/paths/~1resources~1quests/get).
+///
+/// Quests are grouped by game, using the lowercase names of the API (e.g. `"bedwars"`).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestsResourcesReply {
+    success: bool,
+    cause: Option<String>,
+    last_updated: Option<u64>,
+    quests: HashMap<String, Vec<QuestDefinition>>,
+}
+
+impl QuestsResourcesReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the last time these resources were updated.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated.and_then(from_millis)
+    }
+
+    /// Returns the names of all games that have quests.
+    pub fn games(&self) -> impl Iterator<Item = &str> {
+        self.quests.keys().map(String::as_str)
+    }
+
+    /// Returns all quests of `game`.
+    ///
+    /// If the game has no quests, an empty slice is returned.
+    pub fn quests_of(&self, game: &str) -> &[QuestDefinition] {
+        self.quests.get(game).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the definition of the quest `id`, if present.
+    pub fn quest(&self, id: &str) -> Option<&QuestDefinition> {
+        self.quests.values().flatten().find(|q| q.id == id)
+    }
+
+    /// Consumes the reply and returns the quests of all games keyed by the name of the game.
+    pub fn into_quests(self) -> HashMap<String, Vec<QuestDefinition>> {
+        self.quests
+    }
+}
+
+impl_reply!(QuestsResourcesReply);
+
+/// The definition of a single quest.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct QuestDefinition {
+    id: String,
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    objectives: Vec<QuestObjective>,
+    #[serde(default)]
+    requirements: Vec<QuestRequirement>,
+}
+
+impl QuestDefinition {
+    /// Returns the id of this quest, as used in [`Quests`](crate::Quests).
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the display name of this quest.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of this quest, if present.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the objectives that need to be completed to finish this quest.
+    pub fn objectives(&self) -> &[QuestObjective] {
+        &self.objectives
+    }
+
+    /// Returns `true` if this quest can be completed once every day.
+    pub fn is_daily(&self) -> bool {
+        self.requirements.iter().any(|r| r.kind == "DailyResetQuestRequirement")
+    }
+
+    /// Returns `true` if this quest can be completed once every week.
+    pub fn is_weekly(&self) -> bool {
+        self.requirements.iter().any(|r| r.kind == "WeeklyResetQuestRequirement")
+    }
+}
+
+/// A single objective of a quest.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct QuestObjective {
+    id: String,
+    #[serde(rename = "type")]
+    kind: String,
+    integer: Option<u64>,
+}
+
+impl QuestObjective {
+    /// Returns the id of this objective.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the type of this objective, e.g. `"IntegerObjective"`.
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// Returns the amount that needs to be reached for integer objectives.
+    pub fn target(&self) -> Option<u64> {
+        self.integer
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct QuestRequirement {
+    #[serde(rename = "type")]
+    kind: String,
+}
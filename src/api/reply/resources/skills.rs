@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/SkyBlock/paths/~1resources~1skyblock~1skills/get).
+///
+/// Skills are keyed by their id in uppercase, e.g. `"FARMING"`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillsResourcesReply {
+    success: bool,
+    cause: Option<String>,
+    last_updated: Option<u64>,
+    version: Option<String>,
+    skills: HashMap<String, SkillDefinition>,
+}
+
+impl SkillsResourcesReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the last time these resources were updated.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated.and_then(from_millis)
+    }
+
+    /// Returns the SkyBlock version of these resources, if present.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Returns the ids of all skills.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.skills.keys().map(String::as_str)
+    }
+
+    /// Returns the definition of the skill `id`, if present.
+    pub fn skill(&self, id: &str) -> Option<&SkillDefinition> {
+        self.skills.get(id)
+    }
+
+    /// Consumes the reply and returns the definitions of all skills keyed by their id.
+    pub fn into_skills(self) -> HashMap<String, SkillDefinition> {
+        self.skills
+    }
+}
+
+impl_reply!(SkillsResourcesReply);
+
+/// The definition of a single SkyBlock skill.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillDefinition {
+    name: String,
+    description: Option<String>,
+    max_level: u32,
+    #[serde(default)]
+    levels: Vec<SkillLevel>,
+}
+
+impl SkillDefinition {
+    /// Returns the display name of this skill.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of this skill, if present.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the maximum level of this skill.
+    pub fn max_level(&self) -> u32 {
+        self.max_level
+    }
+
+    /// Returns the total experience required to reach every level of this skill,
+    /// in the format used by [`util::leveling::skyblock`](crate::util::leveling::skyblock).
+    pub fn xp_table(&self) -> Vec<f64> {
+        let mut levels: Vec<&SkillLevel> = self.levels.iter().collect();
+        levels.sort_by_key(|l| l.level);
+        levels.into_iter().map(|l| l.total_exp_required).collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SkillLevel {
+    level: u32,
+    total_exp_required: f64,
+}
@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::api::ColorCodes;
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/Resources/paths/~1resources~1vanity~1pets/get)
+/// and to [`this endpoint`](https://api.hypixel.net/#tag/Resources/paths/~1resources~1vanity~1companions/get),
+/// which share their format.
+///
+/// Use [`PlayerData::owned_vanity`](crate::PlayerData::owned_vanity) and
+/// [`PlayerData::equipped_pet`](crate::PlayerData::equipped_pet) to look up the pets
+/// and companions of a player.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VanityResourcesReply {
+    success: bool,
+    cause: Option<String>,
+    last_updated: Option<u64>,
+    #[serde(default)]
+    types: Vec<VanityType>,
+    #[serde(default)]
+    rarities: Vec<VanityRarity>,
+}
+
+impl VanityResourcesReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the last time these resources were updated.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated.and_then(from_millis)
+    }
+
+    /// Returns every pet or companion, in the order of the response.
+    pub fn types(&self) -> &[VanityType] {
+        &self.types
+    }
+
+    /// Returns the pet or companion with the key `key` (e.g. `"CAT_BLACK"`), if present.
+    pub fn get(&self, key: &str) -> Option<&VanityType> {
+        self.types.iter().find(|vanity| vanity.key == key)
+    }
+
+    /// Returns the pet or companion unlocked by the package `package` (e.g. `"pet_cat_black"`), if present.
+    pub fn by_package(&self, package: &str) -> Option<&VanityType> {
+        self.types.iter().find(|vanity| vanity.package.as_deref() == Some(package))
+    }
+
+    /// Returns every rarity, from the most common to the rarest.
+    pub fn rarities(&self) -> &[VanityRarity] {
+        &self.rarities
+    }
+
+    /// Returns the rarity named `name` (e.g. `"EPIC"`), if present.
+    pub fn rarity(&self, name: &str) -> Option<&VanityRarity> {
+        self.rarities.iter().find(|rarity| rarity.name == name)
+    }
+
+    /// Consumes the reply and returns every pet or companion.
+    pub fn into_types(self) -> Vec<VanityType> {
+        self.types
+    }
+}
+
+impl_reply!(VanityResourcesReply);
+
+/// A single pet or companion, see [`VanityResourcesReply`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct VanityType {
+    key: String,
+    name: String,
+    rarity: Option<String>,
+    package: Option<String>,
+}
+
+impl VanityType {
+    /// Returns the key of this pet or companion, e.g. `"CAT_BLACK"`.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the name of this pet or companion to display, e.g. `"Cat: Black"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the name of the rarity of this pet or companion, if present.
+    ///
+    /// Use [`VanityResourcesReply::rarity`] to look up its color.
+    pub fn rarity(&self) -> Option<&str> {
+        self.rarity.as_deref()
+    }
+
+    /// Returns the package that unlocks this pet or companion, if present.
+    pub fn package(&self) -> Option<&str> {
+        self.package.as_deref()
+    }
+}
+
+/// A rarity of pets and companions, see [`VanityResourcesReply::rarities`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct VanityRarity {
+    name: String,
+    #[serde(default, deserialize_with = "crate::api::util::lenient::deserialize_known")]
+    color: Option<ColorCodes>,
+}
+
+impl VanityRarity {
+    /// Returns the name of this rarity, e.g. `"EPIC"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the color this rarity is displayed in, if known.
+    pub fn color(&self) -> Option<ColorCodes> {
+        self.color
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/SkyBlock/paths/~1skyblock~1auctions/get).
+///
+/// Active auctions are split into pages, see [`total_pages`](SkyblockAuctionsReply::total_pages).
+/// See [`util::auctions`](crate::util::auctions) for common calculations on this data.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkyblockAuctionsReply {
+    success: bool,
+    cause: Option<String>,
+    #[serde(default)]
+    page: u32,
+    #[serde(default)]
+    total_pages: u32,
+    #[serde(default)]
+    total_auctions: u32,
+    last_updated: Option<u64>,
+    #[serde(default)]
+    #[cfg_attr(feature = "diagnostics", serde(deserialize_with = "deserialize_auctions"))]
+    auctions: Vec<Auction>,
+}
+
+impl SkyblockAuctionsReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the index of this page, starting from `0`.
+    pub fn page(&self) -> u32 {
+        self.page
+    }
+
+    pub fn total_pages(&self) -> u32 {
+        self.total_pages
+    }
+
+    /// Returns the amount of active auctions over all pages.
+    pub fn total_auctions(&self) -> u32 {
+        self.total_auctions
+    }
+
+    /// Returns the last time the auctions were updated.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated.and_then(from_millis)
+    }
+
+    /// Returns the auctions on this page.
+    pub fn auctions(&self) -> &[Auction] {
+        &self.auctions
+    }
+
+    /// Consumes the reply and returns the auctions on this page.
+    pub fn into_auctions(self) -> Vec<Auction> {
+        self.auctions
+    }
+}
+
+impl_reply!(SkyblockAuctionsReply);
+
+/// Deserializes the auctions of a [`SkyblockAuctionsReply`], locating the fields captured by
+/// their catch-all maps for strict mode.
+#[cfg(feature = "diagnostics")]
+fn deserialize_auctions<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<Auction>, D::Error> {
+    crate::api::diagnostics::within("auctions", || crate::api::diagnostics::elements(deserializer))
+}
+
+/// A single auction of the SkyBlock auction house.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Auction {
+    #[serde(deserialize_with = "crate::api::util::uuid::deserialize")]
+    uuid: Uuid,
+    #[serde(deserialize_with = "crate::api::util::uuid::deserialize")]
+    auctioneer: Uuid,
+    #[serde(default, deserialize_with = "crate::api::util::uuid::deserialize_option")]
+    profile_id: Option<Uuid>,
+    start: Option<u64>,
+    end: Option<u64>,
+    item_name: String,
+    #[serde(default)]
+    item_lore: String,
+    #[serde(default)]
+    category: String,
+    #[serde(default)]
+    tier: String,
+    #[serde(default)]
+    starting_bid: u64,
+    #[serde(default)]
+    highest_bid_amount: u64,
+    #[serde(default)]
+    bin: bool,
+    #[serde(default)]
+    claimed: bool,
+    item_bytes: Option<String>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "diagnostics", serde(deserialize_with = "crate::api::diagnostics::capture"))]
+    other: HashMap<String, Value>,
+}
+
+impl Auction {
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Returns the UUID of the player who created this auction.
+    pub fn auctioneer(&self) -> Uuid {
+        self.auctioneer
+    }
+
+    /// Returns the SkyBlock profile this auction was created from.
+    pub fn profile_id(&self) -> Option<Uuid> {
+        self.profile_id
+    }
+
+    pub fn start(&self) -> Option<DateTime<Utc>> {
+        self.start.and_then(from_millis)
+    }
+
+    pub fn end(&self) -> Option<DateTime<Utc>> {
+        self.end.and_then(from_millis)
+    }
+
+    /// Returns the name of the auctioned item without formatting codes.
+    pub fn item_name(&self) -> &str {
+        &self.item_name
+    }
+
+    /// Returns the lore of the auctioned item with formatting codes.
+    pub fn item_lore(&self) -> &str {
+        &self.item_lore
+    }
+
+    /// Returns the auction house category of the item (e.g. `"weapon"`).
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// Returns the rarity of the item (e.g. `"LEGENDARY"`).
+    pub fn tier(&self) -> &str {
+        &self.tier
+    }
+
+    pub fn starting_bid(&self) -> u64 {
+        self.starting_bid
+    }
+
+    /// Returns the highest bid on this auction, `0` if there are no bids.
+    pub fn highest_bid_amount(&self) -> u64 {
+        self.highest_bid_amount
+    }
+
+    /// Returns the price the item currently sells for: the highest bid if present,
+    /// otherwise the starting bid.
+    pub fn price(&self) -> u64 {
+        self.highest_bid_amount.max(self.starting_bid)
+    }
+
+    /// Returns `true` if this is a Buy It Now auction.
+    pub fn is_bin(&self) -> bool {
+        self.bin
+    }
+
+    pub fn is_claimed(&self) -> bool {
+        self.claimed
+    }
+
+    /// Returns the base64 encoded, gzipped NBT data of the item.
+    pub fn item_bytes(&self) -> Option<&str> {
+        self.item_bytes.as_deref()
+    }
+
+    /// Decodes the auctioned item from its NBT data.
+    #[cfg(feature = "nbt")]
+    pub fn item(&self) -> Result<Option<crate::api::util::nbt::SkyblockItem>, crate::api::error::HypixelApiError> {
+        match &self.item_bytes {
+            Some(bytes) => Ok(crate::api::util::nbt::decode_items(bytes)?.into_iter().next()),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns any other property this struct does not capture
+    /// explicitly already, if present.
+    pub fn property_value(&self, name: &str) -> Option<&Value> {
+        self.other.get(name)
+    }
+}
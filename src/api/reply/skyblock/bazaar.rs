@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::api::util::time::from_millis;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/SkyBlock/paths/~1skyblock~1bazaar/get).
+///
+/// Products are keyed by their product id, e.g. `"ENCHANTED_DIAMOND"`.
+/// See [`util::bazaar`](crate::util::bazaar) for common calculations on this data.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkyblockBazaarReply {
+    success: bool,
+    cause: Option<String>,
+    last_updated: Option<u64>,
+    #[serde(default)]
+    products: HashMap<String, BazaarProduct>,
+}
+
+impl SkyblockBazaarReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the last time the bazaar data was updated.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated.and_then(from_millis)
+    }
+
+    /// Returns the ids of all products.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.products.keys().map(String::as_str)
+    }
+
+    /// Returns all products keyed by their product id.
+    pub fn products(&self) -> &HashMap<String, BazaarProduct> {
+        &self.products
+    }
+
+    /// Consumes the reply and returns all products like [`SkyblockBazaarReply::products`].
+    pub fn into_products(self) -> HashMap<String, BazaarProduct> {
+        self.products
+    }
+
+    /// Returns the product `id`, if present.
+    pub fn product(&self, id: &str) -> Option<&BazaarProduct> {
+        self.products.get(id)
+    }
+}
+
+impl_reply!(SkyblockBazaarReply);
+
+/// The order book of a single bazaar product.
+///
+/// Note that the naming of the API is reversed from the point of view of a player:
+/// [`sell_summary`](BazaarProduct::sell_summary) contains the buy orders a player can instantly
+/// sell to, [`buy_summary`](BazaarProduct::buy_summary) contains the sell offers a player can
+/// instantly buy from.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BazaarProduct {
+    product_id: String,
+    #[serde(default)]
+    sell_summary: Vec<BazaarOrder>,
+    #[serde(default)]
+    buy_summary: Vec<BazaarOrder>,
+    quick_status: Option<BazaarQuickStatus>,
+}
+
+impl BazaarProduct {
+    pub fn product_id(&self) -> &str {
+        &self.product_id
+    }
+
+    /// Returns the buy orders of this product, sorted from the highest to the lowest price.
+    pub fn sell_summary(&self) -> &[BazaarOrder] {
+        &self.sell_summary
+    }
+
+    /// Returns the sell offers of this product, sorted from the lowest to the highest price.
+    pub fn buy_summary(&self) -> &[BazaarOrder] {
+        &self.buy_summary
+    }
+
+    /// Returns the price per unit to instantly buy this product, if anyone is selling it.
+    pub fn instant_buy_price(&self) -> Option<f64> {
+        self.buy_summary.first().map(|order| order.price_per_unit)
+    }
+
+    /// Returns the price per unit received when instantly selling this product, if anyone is buying it.
+    pub fn instant_sell_price(&self) -> Option<f64> {
+        self.sell_summary.first().map(|order| order.price_per_unit)
+    }
+
+    /// Returns the summarized statistics of this product, if present.
+    pub fn quick_status(&self) -> Option<&BazaarQuickStatus> {
+        self.quick_status.as_ref()
+    }
+}
+
+/// All orders of a bazaar product at the same price.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BazaarOrder {
+    amount: u64,
+    price_per_unit: f64,
+    orders: u32,
+}
+
+impl BazaarOrder {
+    /// Returns the total amount of items of these orders.
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    pub fn price_per_unit(&self) -> f64 {
+        self.price_per_unit
+    }
+
+    /// Returns the amount of orders at this price.
+    pub fn orders(&self) -> u32 {
+        self.orders
+    }
+}
+
+/// Summarized statistics of a bazaar product.
+///
+/// `sell_*` fields describe the buy orders and `buy_*` fields the sell offers,
+/// see [`BazaarProduct`].
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BazaarQuickStatus {
+    #[serde(default)]
+    sell_price: f64,
+    #[serde(default)]
+    sell_volume: u64,
+    #[serde(default)]
+    sell_moving_week: u64,
+    #[serde(default)]
+    sell_orders: u64,
+    #[serde(default)]
+    buy_price: f64,
+    #[serde(default)]
+    buy_volume: u64,
+    #[serde(default)]
+    buy_moving_week: u64,
+    #[serde(default)]
+    buy_orders: u64,
+}
+
+impl BazaarQuickStatus {
+    /// Returns the weighted average price of the top 2% of buy orders.
+    pub fn sell_price(&self) -> f64 {
+        self.sell_price
+    }
+
+    /// Returns the total amount of items in buy orders.
+    pub fn sell_volume(&self) -> u64 {
+        self.sell_volume
+    }
+
+    /// Returns the amount of items instantly sold in the past 7 days.
+    pub fn sell_moving_week(&self) -> u64 {
+        self.sell_moving_week
+    }
+
+    /// Returns the amount of active buy orders.
+    pub fn sell_orders(&self) -> u64 {
+        self.sell_orders
+    }
+
+    /// Returns the weighted average price of the top 2% of sell offers.
+    pub fn buy_price(&self) -> f64 {
+        self.buy_price
+    }
+
+    /// Returns the total amount of items in sell offers.
+    pub fn buy_volume(&self) -> u64 {
+        self.buy_volume
+    }
+
+    /// Returns the amount of items instantly bought in the past 7 days.
+    pub fn buy_moving_week(&self) -> u64 {
+        self.buy_moving_week
+    }
+
+    /// Returns the amount of active sell offers.
+    pub fn buy_orders(&self) -> u64 {
+        self.buy_orders
+    }
+}
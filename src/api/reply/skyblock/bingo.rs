@@ -0,0 +1,72 @@
+use serde::Deserialize;
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/SkyBlock/paths/~1skyblock~1bingo/get).
+///
+/// Contains the bingo events the player has taken part in. Use
+/// [`util::bingo::bingo_progress`](crate::util::bingo::bingo_progress) to match the goals of the
+/// current event against [`BingoResourcesReply`](crate::BingoResourcesReply).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SkyblockBingoReply {
+    success: bool,
+    cause: Option<String>,
+    #[serde(default)]
+    events: Vec<BingoEvent>,
+}
+
+impl SkyblockBingoReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns every bingo event the player has taken part in.
+    pub fn events(&self) -> &[BingoEvent] {
+        &self.events
+    }
+
+    /// Returns the event with the id `key`, if the player has taken part in it.
+    pub fn event(&self, key: u32) -> Option<&BingoEvent> {
+        self.events.iter().find(|event| event.key == key)
+    }
+
+    /// Consumes the reply and returns every bingo event the player has taken part in.
+    pub fn into_events(self) -> Vec<BingoEvent> {
+        self.events
+    }
+}
+
+impl_reply!(SkyblockBingoReply);
+
+/// The progress of a player in a single bingo event, see [`SkyblockBingoReply::events`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct BingoEvent {
+    key: u32,
+    #[serde(default, deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    points: u32,
+    #[serde(default)]
+    completed_goals: Vec<String>,
+}
+
+impl BingoEvent {
+    /// Returns the id of this event, see [`BingoResourcesReply::id`](crate::BingoResourcesReply::id).
+    pub fn key(&self) -> u32 {
+        self.key
+    }
+
+    /// Returns the amount of bingo points the player has earned in this event.
+    pub fn points(&self) -> u32 {
+        self.points
+    }
+
+    /// Returns the ids of the goals the player has completed in this event.
+    pub fn completed_goals(&self) -> &[String] {
+        &self.completed_goals
+    }
+
+    /// Returns `true` if the player has completed the goal `id` in this event.
+    pub fn has_completed(&self, id: &str) -> bool {
+        self.completed_goals.iter().any(|goal| goal == id)
+    }
+}
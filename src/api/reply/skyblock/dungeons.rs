@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use serde::Deserialize;
+use crate::util::leveling::dungeons;
+
+/// The dungeons data of a [`ProfileMember`](crate::ProfileMember).
+///
+/// The Catacombs and the classes share the leveling of
+/// [`util::leveling::dungeons`](crate::util::leveling::dungeons), the level getters of this
+/// struct and [`DungeonType`] use it. Missing values default to `0`.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+pub struct DungeonsData {
+    dungeon_types: HashMap<String, DungeonType>,
+    player_classes: HashMap<String, DungeonClass>,
+    selected_dungeon_class: Option<String>,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    secrets: u64,
+}
+
+impl DungeonsData {
+    /// Returns the dungeons the member has played, keyed by type like `"catacombs"`.
+    pub fn dungeon_types(&self) -> &HashMap<String, DungeonType> {
+        &self.dungeon_types
+    }
+
+    /// Returns the progress in the Catacombs, if the member has entered them.
+    pub fn catacombs(&self) -> Option<&DungeonType> {
+        self.dungeon_types.get("catacombs")
+    }
+
+    /// Returns the progress in the Master Mode Catacombs, if the member has entered them.
+    ///
+    /// Master Mode has no experience of its own, its runs count towards the Catacombs experience.
+    pub fn master_catacombs(&self) -> Option<&DungeonType> {
+        self.dungeon_types.get("master_catacombs")
+    }
+
+    /// Returns the Catacombs experience of the member.
+    pub fn catacombs_experience(&self) -> f64 {
+        self.catacombs().map_or(0.0, DungeonType::experience)
+    }
+
+    /// Returns the (floored) Catacombs level of the member.
+    pub fn catacombs_level(&self) -> f64 {
+        dungeons::calculate_level(self.catacombs_experience())
+    }
+
+    /// Returns the experience of the class `class` (e.g. `"mage"`), ignoring case.
+    pub fn class_experience(&self, class: &str) -> f64 {
+        self.player_classes.get(&class.to_lowercase()).map_or(0.0, |class| class.experience)
+    }
+
+    /// Returns the (floored) level of the class `class` (e.g. `"mage"`), ignoring case.
+    pub fn class_level(&self, class: &str) -> f64 {
+        dungeons::calculate_level(self.class_experience(class))
+    }
+
+    /// Returns the experience of every class the member has played, keyed by class name.
+    pub fn class_experiences(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.player_classes.iter().map(|(name, class)| (name.as_str(), class.experience))
+    }
+
+    /// Returns the average of the (floored) levels of the five classes, as shown in game.
+    pub fn class_average(&self) -> f64 {
+        const CLASSES: [&str; 5] = ["healer", "mage", "berserk", "archer", "tank"];
+        CLASSES.iter().map(|class| self.class_level(class)).sum::<f64>() / CLASSES.len() as f64
+    }
+
+    /// Returns the name of the selected class (e.g. `"mage"`), if present.
+    pub fn selected_class(&self) -> Option<&str> {
+        self.selected_dungeon_class.as_deref()
+    }
+
+    /// Returns the amount of secrets the member has found.
+    pub fn secrets(&self) -> u64 {
+        self.secrets
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+struct DungeonClass {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    experience: f64,
+}
+
+/// The progress of a member in a single dungeon type, see [`DungeonsData::dungeon_types`].
+///
+/// Floors are numbered as in game, the entrance is floor `0`.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+pub struct DungeonType {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    experience: f64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number_option")]
+    highest_tier_completed: Option<u32>,
+    tier_completions: HashMap<String, f64>,
+    best_score: HashMap<String, f64>,
+    fastest_time: HashMap<String, f64>,
+    fastest_time_s_plus: HashMap<String, f64>,
+}
+
+impl DungeonType {
+    /// Returns the experience the member has earned in this dungeon.
+    pub fn experience(&self) -> f64 {
+        self.experience
+    }
+
+    /// Returns the (floored) level of this dungeon.
+    pub fn level(&self) -> f64 {
+        dungeons::calculate_level(self.experience)
+    }
+
+    /// Returns the exact level of this dungeon, including the progress towards the next level.
+    pub fn exact_level(&self) -> f64 {
+        dungeons::exact_level(self.experience)
+    }
+
+    /// Returns the highest floor the member has completed, if any.
+    pub fn highest_floor_completed(&self) -> Option<u32> {
+        self.highest_tier_completed
+    }
+
+    /// Returns the amount of times the member has completed `floor`.
+    pub fn completions(&self, floor: u32) -> u64 {
+        floor_value(&self.tier_completions, floor).unwrap_or_default() as u64
+    }
+
+    /// Returns the amount of times the member has completed any floor.
+    pub fn total_completions(&self) -> u64 {
+        match self.tier_completions.get("total") {
+            Some(total) => *total as u64,
+            None => self.tier_completions.values().sum::<f64>() as u64,
+        }
+    }
+
+    /// Returns the completions of every floor the member has completed, ordered by floor.
+    pub fn floor_completions(&self) -> Vec<(u32, u64)> {
+        let mut floors: Vec<(u32, u64)> = self.tier_completions.iter()
+            .filter_map(|(floor, amount)| Some((floor.parse().ok()?, *amount as u64)))
+            .collect();
+        floors.sort_unstable();
+        floors
+    }
+
+    /// Returns the best score the member has achieved on `floor`, if they completed it.
+    pub fn best_score(&self, floor: u32) -> Option<u64> {
+        floor_value(&self.best_score, floor).map(|score| score as u64)
+    }
+
+    /// Returns the fastest completion of `floor`, if they completed it.
+    pub fn fastest_time(&self, floor: u32) -> Option<Duration> {
+        floor_value(&self.fastest_time, floor).map(|millis| Duration::from_millis(millis as u64))
+    }
+
+    /// Returns the fastest completion of `floor` with an S+ score, if they achieved one.
+    pub fn fastest_time_s_plus(&self, floor: u32) -> Option<Duration> {
+        floor_value(&self.fastest_time_s_plus, floor).map(|millis| Duration::from_millis(millis as u64))
+    }
+}
+
+fn floor_value(values: &HashMap<String, f64>, floor: u32) -> Option<f64> {
+    values.get(&floor.to_string()).copied()
+}
@@ -0,0 +1,13 @@
+//! Data structures for the `/skyblock` endpoints.
+
+mod bazaar;
+mod auctions;
+mod dungeons;
+mod profiles;
+mod bingo;
+
+pub use bazaar::{SkyblockBazaarReply, BazaarProduct, BazaarOrder, BazaarQuickStatus};
+pub use auctions::{SkyblockAuctionsReply, Auction};
+pub use profiles::{SkyblockProfilesReply, SkyblockProfile, ProfileMember};
+pub use dungeons::{DungeonsData, DungeonType};
+pub use bingo::{SkyblockBingoReply, BingoEvent};
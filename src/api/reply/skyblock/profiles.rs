@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::api::reply::skyblock::{DungeonsData, DungeonType};
+
+/// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/SkyBlock/paths/~1skyblock~1profiles/get).
+///
+/// Players without SkyBlock profiles have no profiles in this reply.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SkyblockProfilesReply {
+    success: bool,
+    cause: Option<String>,
+    #[serde(default)]
+    profiles: Option<Vec<SkyblockProfile>>,
+}
+
+impl SkyblockProfilesReply {
+    /// Returns whether the response was successful.
+    ///
+    /// This should always return true. (not guaranteed though)
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns all profiles of the player.
+    pub fn profiles(&self) -> &[SkyblockProfile] {
+        self.profiles.as_deref().unwrap_or_default()
+    }
+
+    /// Consumes the reply and returns all profiles of the player.
+    pub fn into_profiles(self) -> Vec<SkyblockProfile> {
+        self.profiles.unwrap_or_default()
+    }
+
+    /// Returns the profile the player has currently selected, if any.
+    pub fn selected_profile(&self) -> Option<&SkyblockProfile> {
+        self.profiles().iter().find(|profile| profile.selected)
+    }
+
+    /// Returns the profile with the id `profile_id`, if present.
+    pub fn profile(&self, profile_id: Uuid) -> Option<&SkyblockProfile> {
+        self.profiles().iter().find(|profile| profile.profile_id == profile_id)
+    }
+}
+
+impl_reply!(SkyblockProfilesReply, profiles: Option<Vec<SkyblockProfile>>);
+
+/// A SkyBlock profile shared by one or more members.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SkyblockProfile {
+    #[serde(deserialize_with = "crate::api::util::uuid::deserialize")]
+    profile_id: Uuid,
+    cute_name: Option<String>,
+    game_mode: Option<String>,
+    #[serde(default)]
+    selected: bool,
+    #[serde(default)]
+    members: HashMap<Uuid, ProfileMember>,
+    banking: Option<Banking>,
+}
+
+impl SkyblockProfile {
+    pub fn profile_id(&self) -> Uuid {
+        self.profile_id
+    }
+
+    /// Returns the name of this profile, e.g. `"Apple"`.
+    pub fn cute_name(&self) -> Option<&str> {
+        self.cute_name.as_deref()
+    }
+
+    /// Returns the game mode of this profile (e.g. `"ironman"`), or [`Option::None`] for a normal profile.
+    pub fn game_mode(&self) -> Option<&str> {
+        self.game_mode.as_deref()
+    }
+
+    /// Returns whether this is the profile the player has currently selected.
+    pub fn selected(&self) -> bool {
+        self.selected
+    }
+
+    /// Returns all members of this profile keyed by their uuid.
+    pub fn members(&self) -> &HashMap<Uuid, ProfileMember> {
+        &self.members
+    }
+
+    /// Consumes the profile and returns all members of this profile keyed by their uuid.
+    pub fn into_members(self) -> HashMap<Uuid, ProfileMember> {
+        self.members
+    }
+
+    /// Returns the member `uuid` of this profile, if present.
+    pub fn member(&self, uuid: Uuid) -> Option<&ProfileMember> {
+        self.members.get(&uuid)
+    }
+
+    /// Returns the amount of coins in the bank of this profile.
+    ///
+    /// Returns [`Option::None`] if the members disabled the banking API.
+    pub fn bank_balance(&self) -> Option<f64> {
+        self.banking.as_ref().map(|banking| banking.balance)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+struct Banking {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    balance: f64,
+}
+
+/// The data of a single member of a [`SkyblockProfile`].
+///
+/// Parts of the member data are only present if the member enabled the matching API setting,
+/// missing values default to `0` or an empty map.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+pub struct ProfileMember {
+    player_data: MemberPlayerData,
+    slayer: MemberSlayer,
+    dungeons: DungeonsData,
+    fairy_soul: MemberFairySoul,
+    currencies: MemberCurrencies,
+    collection: HashMap<String, u64>,
+}
+
+impl ProfileMember {
+    /// Returns the experience of every skill, keyed by skill id like `"SKILL_FARMING"`.
+    ///
+    /// Use [`util::skills::skill_average`](crate::util::skills::skill_average) to calculate the skill average.
+    pub fn skill_experience(&self) -> &HashMap<String, f64> {
+        &self.player_data.experience
+    }
+
+    /// Returns the slayer experience of the boss `boss` (e.g. `"zombie"`).
+    pub fn slayer_xp(&self, boss: &str) -> u64 {
+        self.slayer.slayer_bosses.get(boss).map_or(0, |boss| boss.xp)
+    }
+
+    /// Returns the slayer experience summed over all bosses.
+    pub fn total_slayer_xp(&self) -> u64 {
+        self.slayer.slayer_bosses.values().map(|boss| boss.xp).sum()
+    }
+
+    /// Returns the dungeons data of the member.
+    pub fn dungeons(&self) -> &DungeonsData {
+        &self.dungeons
+    }
+
+    /// Returns the dungeons the member has played, keyed by type like `"catacombs"`.
+    pub fn dungeon_types(&self) -> &HashMap<String, DungeonType> {
+        self.dungeons.dungeon_types()
+    }
+
+    /// Returns the amount of fairy souls the member has collected.
+    pub fn fairy_souls(&self) -> u64 {
+        self.fairy_soul.total_collected
+    }
+
+    /// Returns the amount of coins in the member's purse.
+    pub fn coin_purse(&self) -> f64 {
+        self.currencies.coin_purse
+    }
+
+    /// Returns the collected amount of every collection item, keyed by item id like `"WHEAT"`.
+    ///
+    /// Use [`util::collections::collection_progress`](crate::util::collections::collection_progress)
+    /// to calculate the unlocked tiers.
+    pub fn collection(&self) -> &HashMap<String, u64> {
+        &self.collection
+    }
+
+    /// Returns the collected amount of the collection item `id`, e.g. `"WHEAT"`.
+    pub fn collected(&self, id: &str) -> u64 {
+        self.collection.get(id).copied().unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+struct MemberPlayerData {
+    experience: HashMap<String, f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+struct MemberSlayer {
+    slayer_bosses: HashMap<String, SlayerBoss>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+struct SlayerBoss {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    xp: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+struct MemberFairySoul {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    total_collected: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+struct MemberCurrencies {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coin_purse: f64,
+}
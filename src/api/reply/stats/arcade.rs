@@ -0,0 +1,213 @@
+use serde::Deserialize;
+use crate::api::reply::stats::GameStats;
+
+/// Marker type for the stats of [Arcade Games](https://hypixel.net/arcade).
+#[derive(Debug, Copy, Clone)]
+pub struct Arcade;
+
+impl GameStats for Arcade {
+    const KEY: &'static str = "Arcade";
+    type Stats = ArcadeStats;
+}
+
+/// The Arcade stats of a player.
+///
+/// The Arcade minigames were added over many years and their fields follow no common naming
+/// scheme (Party Games alone is spread over three counters), the getters of this struct hide
+/// those differences. Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct ArcadeStats {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(rename = "wins_party", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_party_games_1: u64,
+    #[serde(rename = "wins_party_2", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_party_games_2: u64,
+    #[serde(rename = "wins_party_3", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_party_games_3: u64,
+    #[serde(rename = "wins_hole_in_the_wall", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_hole_in_the_wall: u64,
+    #[serde(rename = "wins_dayone", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_blocking_dead: u64,
+    #[serde(rename = "wins_oneinthequiver", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_bounty_hunters: u64,
+    #[serde(rename = "wins_dragonwars2", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_dragon_wars: u64,
+    #[serde(rename = "wins_ender", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_ender_spleef: u64,
+    #[serde(rename = "wins_farm_hunt", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_farm_hunt: u64,
+    #[serde(rename = "wins_soccer", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_football: u64,
+    #[serde(rename = "sw_game_wins", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_galaxy_wars: u64,
+    #[serde(rename = "seeker_wins_hide_and_seek", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_hide_and_seek_seeker: u64,
+    #[serde(rename = "hider_wins_hide_and_seek", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_hide_and_seek_hider: u64,
+    #[serde(rename = "wins_simon_says", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_hypixel_says: u64,
+    #[serde(rename = "wins_draw_their_thing", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_pixel_painters: u64,
+    #[serde(rename = "wins_throw_out", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins_throw_out: u64,
+    #[serde(flatten)]
+    zombies: ZombiesStats,
+    #[serde(flatten)]
+    mini_walls: MiniWallsStats,
+}
+
+impl ArcadeStats {
+    /// Returns the amount of Arcade coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of Party Games wins, summed over all versions of the game.
+    pub fn party_games_wins(&self) -> u64 {
+        self.wins_party_games_1 + self.wins_party_games_2 + self.wins_party_games_3
+    }
+
+    /// Returns the amount of Hole in the Wall wins.
+    pub fn hole_in_the_wall_wins(&self) -> u64 {
+        self.wins_hole_in_the_wall
+    }
+
+    /// Returns the amount of Blocking Dead wins.
+    pub fn blocking_dead_wins(&self) -> u64 {
+        self.wins_blocking_dead
+    }
+
+    /// Returns the amount of Bounty Hunters wins.
+    pub fn bounty_hunters_wins(&self) -> u64 {
+        self.wins_bounty_hunters
+    }
+
+    /// Returns the amount of Dragon Wars wins.
+    pub fn dragon_wars_wins(&self) -> u64 {
+        self.wins_dragon_wars
+    }
+
+    /// Returns the amount of Ender Spleef wins.
+    pub fn ender_spleef_wins(&self) -> u64 {
+        self.wins_ender_spleef
+    }
+
+    /// Returns the amount of Farm Hunt wins.
+    pub fn farm_hunt_wins(&self) -> u64 {
+        self.wins_farm_hunt
+    }
+
+    /// Returns the amount of Football wins.
+    pub fn football_wins(&self) -> u64 {
+        self.wins_football
+    }
+
+    /// Returns the amount of Galaxy Wars wins.
+    pub fn galaxy_wars_wins(&self) -> u64 {
+        self.wins_galaxy_wars
+    }
+
+    /// Returns the amount of Hide and Seek wins, both as seeker and as hider.
+    pub fn hide_and_seek_wins(&self) -> u64 {
+        self.wins_hide_and_seek_seeker + self.wins_hide_and_seek_hider
+    }
+
+    /// Returns the amount of Hypixel Says wins.
+    pub fn hypixel_says_wins(&self) -> u64 {
+        self.wins_hypixel_says
+    }
+
+    /// Returns the amount of Pixel Painters wins.
+    pub fn pixel_painters_wins(&self) -> u64 {
+        self.wins_pixel_painters
+    }
+
+    /// Returns the amount of Throw Out wins.
+    pub fn throw_out_wins(&self) -> u64 {
+        self.wins_throw_out
+    }
+
+    /// Returns the Zombies stats.
+    pub fn zombies(&self) -> &ZombiesStats {
+        &self.zombies
+    }
+
+    /// Returns the Mini Walls stats.
+    pub fn mini_walls(&self) -> &MiniWallsStats {
+        &self.mini_walls
+    }
+}
+
+/// The Zombies stats of a player over all maps, see [`ArcadeStats::zombies`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct ZombiesStats {
+    #[serde(rename = "wins_zombies", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(rename = "zombie_kills_zombies", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+    #[serde(rename = "deaths_zombies", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(rename = "best_round_zombies", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    best_round: u64,
+}
+
+impl ZombiesStats {
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of zombies killed.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the highest round the player has reached.
+    pub fn best_round(&self) -> u64 {
+        self.best_round
+    }
+}
+
+/// The Mini Walls stats of a player, see [`ArcadeStats::mini_walls`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct MiniWallsStats {
+    #[serde(rename = "wins_mini_walls", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(rename = "kills_mini_walls", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+    #[serde(rename = "final_kills_mini_walls", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    final_kills: u64,
+    #[serde(rename = "deaths_mini_walls", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+}
+
+impl MiniWallsStats {
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of kills.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of final kills.
+    pub fn final_kills(&self) -> u64 {
+        self.final_kills
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+}
@@ -0,0 +1,140 @@
+use serde::Deserialize;
+use crate::api::reply::stats::{GameStats, StatsSummary};
+use crate::api::util::ratios::{ratio, win_rate};
+
+/// Marker type for the stats of [Bed Wars](https://hypixel.net/bedwars).
+#[derive(Debug, Copy, Clone)]
+pub struct Bedwars;
+
+impl GameStats for Bedwars {
+    const KEY: &'static str = "Bedwars";
+    type Stats = BedwarsStats;
+}
+
+/// The overall Bed Wars stats of a player.
+///
+/// Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+pub struct BedwarsStats {
+    #[serde(rename = "Experience", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    experience: f64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    winstreak: u64,
+    #[serde(rename = "games_played_bedwars", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    games_played: u64,
+    #[serde(rename = "wins_bedwars", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(rename = "losses_bedwars", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    losses: u64,
+    #[serde(rename = "kills_bedwars", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+    #[serde(rename = "deaths_bedwars", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(rename = "final_kills_bedwars", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    final_kills: u64,
+    #[serde(rename = "final_deaths_bedwars", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    final_deaths: u64,
+    #[serde(rename = "beds_broken_bedwars", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    beds_broken: u64,
+    #[serde(rename = "beds_lost_bedwars", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    beds_lost: u64,
+}
+
+impl BedwarsStats {
+    /// Returns the total amount of Bed Wars experience the player has earned.
+    pub fn experience(&self) -> f64 {
+        self.experience
+    }
+
+    /// Returns the amount of Bed Wars coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the player's current winstreak.
+    ///
+    /// Players can hide their winstreak, in which case `0` is returned.
+    pub fn winstreak(&self) -> u64 {
+        self.winstreak
+    }
+
+    /// Returns the amount of games the player has played.
+    pub fn games_played(&self) -> u64 {
+        self.games_played
+    }
+
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of games the player has lost.
+    pub fn losses(&self) -> u64 {
+        self.losses
+    }
+
+    /// Returns the amount of regular kills.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of regular deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the amount of final kills.
+    pub fn final_kills(&self) -> u64 {
+        self.final_kills
+    }
+
+    /// Returns the amount of final deaths.
+    pub fn final_deaths(&self) -> u64 {
+        self.final_deaths
+    }
+
+    /// Returns the amount of beds the player has broken.
+    pub fn beds_broken(&self) -> u64 {
+        self.beds_broken
+    }
+
+    /// Returns the amount of times the player's bed was broken.
+    pub fn beds_lost(&self) -> u64 {
+        self.beds_lost
+    }
+
+    /// Returns the final kill/final death ratio, see [`ratio`](crate::util::ratios::ratio).
+    pub fn fkdr(&self) -> f64 {
+        ratio(self.final_kills, self.final_deaths)
+    }
+
+    /// Returns the kill/death ratio of regular kills, see [`ratio`](crate::util::ratios::ratio).
+    pub fn kdr(&self) -> f64 {
+        ratio(self.kills, self.deaths)
+    }
+
+    /// Returns the win/loss ratio, see [`ratio`](crate::util::ratios::ratio).
+    pub fn wlr(&self) -> f64 {
+        ratio(self.wins, self.losses)
+    }
+
+    /// Returns the beds broken/beds lost ratio, see [`ratio`](crate::util::ratios::ratio).
+    pub fn bblr(&self) -> f64 {
+        ratio(self.beds_broken, self.beds_lost)
+    }
+
+    /// Returns the share of games won, see [`win_rate`](crate::util::ratios::win_rate).
+    pub fn win_rate(&self) -> f64 {
+        win_rate(self.wins, self.losses)
+    }
+
+    /// Returns the wins, losses, final kills and final deaths of the player.
+    ///
+    /// Final kills are used rather than regular kills, as those are what Bed Wars overlays rank by.
+    pub fn summary(&self) -> StatsSummary {
+        StatsSummary::new(self.wins, self.losses, self.final_kills, self.final_deaths)
+    }
+}
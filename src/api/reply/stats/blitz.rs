@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::api::reply::stats::GameStats;
+
+/// Marker type for the stats of [Blitz Survival Games](https://hypixel.net/blitz).
+#[derive(Debug, Copy, Clone)]
+pub struct Blitz;
+
+impl GameStats for Blitz {
+    const KEY: &'static str = "HungerGames";
+    type Stats = BlitzStats;
+}
+
+/// The overall Blitz Survival Games stats of a player.
+///
+/// Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+pub struct BlitzStats {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(rename = "wins", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    solo_wins: u64,
+    #[serde(rename = "wins_teams", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    team_wins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(rename = "defaultkit")]
+    default_kit: Option<String>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "diagnostics", serde(deserialize_with = "crate::api::diagnostics::capture"))]
+    other: HashMap<String, Value>,
+}
+
+impl BlitzStats {
+    /// Returns the amount of Blitz coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of games the player has won, solo and in teams.
+    pub fn wins(&self) -> u64 {
+        self.solo_wins + self.team_wins
+    }
+
+    /// Returns the amount of solo games the player has won.
+    pub fn solo_wins(&self) -> u64 {
+        self.solo_wins
+    }
+
+    /// Returns the amount of team games the player has won.
+    pub fn team_wins(&self) -> u64 {
+        self.team_wins
+    }
+
+    /// Returns the amount of kills.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the name of the kit the player selected by default, if present.
+    pub fn default_kit(&self) -> Option<&str> {
+        self.default_kit.as_deref()
+    }
+
+    /// Returns the experience of the kit `kit` (e.g. `"archer"`), ignoring case.
+    pub fn kit_exp(&self, kit: &str) -> u64 {
+        self.other.get(&format!("exp_{}", kit.to_lowercase()))
+            .and_then(|value| crate::api::util::lenient::deserialize_number(value).ok())
+            .unwrap_or_default()
+    }
+
+    /// Returns the experience of every kit the player has earned experience with.
+    pub fn kits_exp(&self) -> HashMap<&str, u64> {
+        self.other.iter()
+            .filter_map(|(key, value)| Some((
+                key.strip_prefix("exp_")?,
+                crate::api::util::lenient::deserialize_number(value).ok()?,
+            )))
+            .collect()
+    }
+}
@@ -0,0 +1,528 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::api::reply::stats::GameStats;
+
+/// Marker type for the stats of [Warlords](https://hypixel.net/warlords).
+#[derive(Debug, Copy, Clone)]
+pub struct Warlords;
+
+impl GameStats for Warlords {
+    const KEY: &'static str = "Battleground";
+    type Stats = WarlordsStats;
+}
+
+/// The overall Warlords stats of a player.
+///
+/// Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct WarlordsStats {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    losses: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    assists: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    damage: u64,
+    #[serde(rename = "heal", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    healing: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    magic_dust: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    void_shards: u64,
+    chosen_class: Option<String>,
+}
+
+impl WarlordsStats {
+    /// Returns the amount of Warlords coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of games the player has won over all modes.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of games the player has lost over all modes.
+    pub fn losses(&self) -> u64 {
+        self.losses
+    }
+
+    /// Returns the amount of kills.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the amount of assists.
+    pub fn assists(&self) -> u64 {
+        self.assists
+    }
+
+    /// Returns the damage the player has dealt.
+    pub fn damage(&self) -> u64 {
+        self.damage
+    }
+
+    /// Returns the amount of health the player has healed.
+    pub fn healing(&self) -> u64 {
+        self.healing
+    }
+
+    /// Returns the amount of magic dust the player has.
+    pub fn magic_dust(&self) -> u64 {
+        self.magic_dust
+    }
+
+    /// Returns the amount of void shards the player has.
+    pub fn void_shards(&self) -> u64 {
+        self.void_shards
+    }
+
+    /// Returns the selected class (e.g. `"mage"`), if present.
+    pub fn chosen_class(&self) -> Option<&str> {
+        self.chosen_class.as_deref()
+    }
+}
+
+/// Marker type for the stats of [Arena Brawl](https://hypixel.net/arena).
+#[derive(Debug, Copy, Clone)]
+pub struct ArenaBrawl;
+
+impl GameStats for ArenaBrawl {
+    const KEY: &'static str = "Arena";
+    type Stats = ArenaBrawlStats;
+}
+
+/// The modes of Arena Brawl, the suffixes of their fields.
+const ARENA_MODES: [&str; 3] = ["1v1", "2v2", "4v4"];
+
+/// The overall Arena Brawl stats of a player.
+///
+/// Most stats are stored per mode, see [`ArenaBrawlStats::mode`].
+/// Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+pub struct ArenaBrawlStats {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    keys: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    rating: u64,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "diagnostics", serde(deserialize_with = "crate::api::diagnostics::capture"))]
+    other: HashMap<String, Value>,
+}
+
+impl ArenaBrawlStats {
+    /// Returns the amount of Arena Brawl coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of magical keys the player has.
+    pub fn keys(&self) -> u64 {
+        self.keys
+    }
+
+    /// Returns the player's rating.
+    pub fn rating(&self) -> u64 {
+        self.rating
+    }
+
+    /// Returns the stats of the mode `mode` (`"1v1"`, `"2v2"` or `"4v4"`).
+    pub fn mode(&self, mode: &str) -> ArenaBrawlModeStats {
+        let stat = |name: &str| self.other.get(&format!("{}_{}", name, mode))
+            .and_then(|value| crate::api::util::lenient::deserialize_number(value).ok())
+            .unwrap_or_default();
+        ArenaBrawlModeStats {
+            wins: stat("wins"),
+            losses: stat("losses"),
+            kills: stat("kills"),
+            deaths: stat("deaths"),
+            damage: stat("damage"),
+            healed: stat("healed"),
+        }
+    }
+
+    /// Returns the amount of games the player has won over all modes.
+    pub fn wins(&self) -> u64 {
+        ARENA_MODES.iter().map(|mode| self.mode(mode).wins).sum()
+    }
+
+    /// Returns the amount of games the player has lost over all modes.
+    pub fn losses(&self) -> u64 {
+        ARENA_MODES.iter().map(|mode| self.mode(mode).losses).sum()
+    }
+
+    /// Returns the amount of kills over all modes.
+    pub fn kills(&self) -> u64 {
+        ARENA_MODES.iter().map(|mode| self.mode(mode).kills).sum()
+    }
+
+    /// Returns the amount of deaths over all modes.
+    pub fn deaths(&self) -> u64 {
+        ARENA_MODES.iter().map(|mode| self.mode(mode).deaths).sum()
+    }
+}
+
+/// The Arena Brawl stats of a player in a single mode, see [`ArenaBrawlStats::mode`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ArenaBrawlModeStats {
+    wins: u64,
+    losses: u64,
+    kills: u64,
+    deaths: u64,
+    damage: u64,
+    healed: u64,
+}
+
+impl ArenaBrawlModeStats {
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of games the player has lost.
+    pub fn losses(&self) -> u64 {
+        self.losses
+    }
+
+    /// Returns the amount of kills.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the damage the player has dealt.
+    pub fn damage(&self) -> u64 {
+        self.damage
+    }
+
+    /// Returns the amount of health the player has healed.
+    pub fn healed(&self) -> u64 {
+        self.healed
+    }
+}
+
+/// Marker type for the stats of [Paintball](https://hypixel.net/paintball).
+#[derive(Debug, Copy, Clone)]
+pub struct Paintball;
+
+impl GameStats for Paintball {
+    const KEY: &'static str = "Paintball";
+    type Stats = PaintballStats;
+}
+
+/// The overall Paintball stats of a player.
+///
+/// Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct PaintballStats {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    killstreaks: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    shots_fired: u64,
+    #[serde(rename = "forcefieldTime", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    forcefield_time: u64,
+    hat: Option<String>,
+}
+
+impl PaintballStats {
+    /// Returns the amount of Paintball coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of kills.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the amount of killstreaks the player has earned.
+    pub fn killstreaks(&self) -> u64 {
+        self.killstreaks
+    }
+
+    /// Returns the amount of paintballs the player has shot.
+    pub fn shots_fired(&self) -> u64 {
+        self.shots_fired
+    }
+
+    /// Returns the total time the player has spent with an active force field.
+    pub fn forcefield_time(&self) -> Duration {
+        Duration::from_secs(self.forcefield_time)
+    }
+
+    /// Returns the selected hat (e.g. `"speed_hat"`), if present.
+    pub fn hat(&self) -> Option<&str> {
+        self.hat.as_deref()
+    }
+}
+
+/// Marker type for the stats of [VampireZ](https://hypixel.net/vampirez).
+#[derive(Debug, Copy, Clone)]
+pub struct VampireZ;
+
+impl GameStats for VampireZ {
+    const KEY: &'static str = "VampireZ";
+    type Stats = VampireZStats;
+}
+
+/// The overall VampireZ stats of a player, split by the side they played on.
+///
+/// Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct VampireZStats {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    human_wins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    human_kills: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    human_deaths: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    vampire_wins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    vampire_kills: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    vampire_deaths: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    zombie_kills: u64,
+}
+
+impl VampireZStats {
+    /// Returns the amount of VampireZ coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of games the player has won as a human.
+    pub fn human_wins(&self) -> u64 {
+        self.human_wins
+    }
+
+    /// Returns the amount of vampires the player has killed as a human.
+    pub fn human_kills(&self) -> u64 {
+        self.human_kills
+    }
+
+    /// Returns the amount of times the player died as a human.
+    pub fn human_deaths(&self) -> u64 {
+        self.human_deaths
+    }
+
+    /// Returns the amount of games the player has won as a vampire.
+    pub fn vampire_wins(&self) -> u64 {
+        self.vampire_wins
+    }
+
+    /// Returns the amount of humans the player has killed as a vampire.
+    pub fn vampire_kills(&self) -> u64 {
+        self.vampire_kills
+    }
+
+    /// Returns the amount of times the player died as a vampire.
+    pub fn vampire_deaths(&self) -> u64 {
+        self.vampire_deaths
+    }
+
+    /// Returns the amount of zombies the player has killed.
+    pub fn zombie_kills(&self) -> u64 {
+        self.zombie_kills
+    }
+}
+
+/// Marker type for the stats of [Quakecraft](https://hypixel.net/quakecraft).
+#[derive(Debug, Copy, Clone)]
+pub struct Quake;
+
+impl GameStats for Quake {
+    const KEY: &'static str = "Quake";
+    type Stats = QuakeStats;
+}
+
+/// The overall Quakecraft stats of a player, solo and in teams.
+///
+/// Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct QuakeStats {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(rename = "wins", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    solo_wins: u64,
+    #[serde(rename = "wins_teams", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    team_wins: u64,
+    #[serde(rename = "kills", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    solo_kills: u64,
+    #[serde(rename = "kills_teams", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    team_kills: u64,
+    #[serde(rename = "deaths", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    solo_deaths: u64,
+    #[serde(rename = "deaths_teams", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    team_deaths: u64,
+    #[serde(rename = "headshots", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    solo_headshots: u64,
+    #[serde(rename = "headshots_teams", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    team_headshots: u64,
+    #[serde(rename = "shots_fired", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    solo_shots_fired: u64,
+    #[serde(rename = "shots_fired_teams", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    team_shots_fired: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    highest_killstreak: u64,
+}
+
+impl QuakeStats {
+    /// Returns the amount of Quakecraft coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of games the player has won, solo and in teams.
+    pub fn wins(&self) -> u64 {
+        self.solo_wins + self.team_wins
+    }
+
+    /// Returns the amount of solo games the player has won.
+    pub fn solo_wins(&self) -> u64 {
+        self.solo_wins
+    }
+
+    /// Returns the amount of team games the player has won.
+    pub fn team_wins(&self) -> u64 {
+        self.team_wins
+    }
+
+    /// Returns the amount of kills, solo and in teams.
+    pub fn kills(&self) -> u64 {
+        self.solo_kills + self.team_kills
+    }
+
+    /// Returns the amount of deaths, solo and in teams.
+    pub fn deaths(&self) -> u64 {
+        self.solo_deaths + self.team_deaths
+    }
+
+    /// Returns the amount of headshots, solo and in teams.
+    pub fn headshots(&self) -> u64 {
+        self.solo_headshots + self.team_headshots
+    }
+
+    /// Returns the amount of shots fired, solo and in teams.
+    pub fn shots_fired(&self) -> u64 {
+        self.solo_shots_fired + self.team_shots_fired
+    }
+
+    /// Returns the player's longest killstreak.
+    pub fn highest_killstreak(&self) -> u64 {
+        self.highest_killstreak
+    }
+}
+
+/// Marker type for the stats of [Turbo Kart Racers](https://hypixel.net/turbo-kart-racers).
+#[derive(Debug, Copy, Clone)]
+pub struct TurboKartRacers;
+
+impl GameStats for TurboKartRacers {
+    const KEY: &'static str = "GingerBread";
+    type Stats = TurboKartRacersStats;
+}
+
+/// The overall Turbo Kart Racers stats of a player.
+///
+/// Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct TurboKartRacersStats {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(rename = "gold_trophy", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    gold_trophies: u64,
+    #[serde(rename = "silver_trophy", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    silver_trophies: u64,
+    #[serde(rename = "bronze_trophy", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    bronze_trophies: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    laps_completed: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    box_pickups: u64,
+}
+
+impl TurboKartRacersStats {
+    /// Returns the amount of Turbo Kart Racers coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of races the player has finished first.
+    pub fn gold_trophies(&self) -> u64 {
+        self.gold_trophies
+    }
+
+    /// Returns the amount of races the player has finished second.
+    pub fn silver_trophies(&self) -> u64 {
+        self.silver_trophies
+    }
+
+    /// Returns the amount of races the player has finished third.
+    pub fn bronze_trophies(&self) -> u64 {
+        self.bronze_trophies
+    }
+
+    /// Returns the amount of trophies the player has earned.
+    pub fn trophies(&self) -> u64 {
+        self.gold_trophies + self.silver_trophies + self.bronze_trophies
+    }
+
+    /// Returns the amount of laps the player has completed.
+    pub fn laps_completed(&self) -> u64 {
+        self.laps_completed
+    }
+
+    /// Returns the amount of item boxes the player has picked up.
+    pub fn box_pickups(&self) -> u64 {
+        self.box_pickups
+    }
+}
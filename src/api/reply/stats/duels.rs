@@ -0,0 +1,100 @@
+use serde::Deserialize;
+use crate::api::reply::stats::{GameStats, StatsSummary};
+use crate::api::util::ratios::{ratio, win_rate};
+
+/// Marker type for the stats of [Duels](https://hypixel.net/duels).
+#[derive(Debug, Copy, Clone)]
+pub struct Duels;
+
+impl GameStats for Duels {
+    const KEY: &'static str = "Duels";
+    type Stats = DuelsStats;
+}
+
+/// The overall Duels stats of a player, summed over all modes.
+///
+/// Missing fields default to `0`. See [`util::duels`](crate::util::duels) for the division titles.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct DuelsStats {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(rename = "games_played_duels", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    games_played: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    losses: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(rename = "current_winstreak", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    win_streak: u64,
+    #[serde(rename = "best_overall_winstreak", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    best_win_streak: u64,
+}
+
+impl DuelsStats {
+    /// Returns the amount of Duels coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of games the player has played.
+    pub fn games_played(&self) -> u64 {
+        self.games_played
+    }
+
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of games the player has lost.
+    pub fn losses(&self) -> u64 {
+        self.losses
+    }
+
+    /// Returns the amount of kills.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the player's current winstreak.
+    ///
+    /// Players can hide their winstreak, in which case `0` is returned.
+    pub fn win_streak(&self) -> u64 {
+        self.win_streak
+    }
+
+    /// Returns the player's best winstreak over all modes.
+    pub fn best_win_streak(&self) -> u64 {
+        self.best_win_streak
+    }
+
+    /// Returns the kill/death ratio, see [`ratio`](crate::util::ratios::ratio).
+    pub fn kdr(&self) -> f64 {
+        ratio(self.kills, self.deaths)
+    }
+
+    /// Returns the win/loss ratio, see [`ratio`](crate::util::ratios::ratio).
+    pub fn wlr(&self) -> f64 {
+        ratio(self.wins, self.losses)
+    }
+
+    /// Returns the share of games won, see [`win_rate`](crate::util::ratios::win_rate).
+    pub fn win_rate(&self) -> f64 {
+        win_rate(self.wins, self.losses)
+    }
+
+    /// Returns the wins, losses, kills and deaths of the player.
+    pub fn summary(&self) -> StatsSummary {
+        StatsSummary::new(self.wins, self.losses, self.kills, self.deaths)
+    }
+}
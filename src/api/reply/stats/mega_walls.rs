@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::api::reply::stats::GameStats;
+
+/// Marker type for the stats of [Mega Walls](https://hypixel.net/megawalls).
+#[derive(Debug, Copy, Clone)]
+pub struct MegaWalls;
+
+impl GameStats for MegaWalls {
+    const KEY: &'static str = "Walls3";
+    type Stats = MegaWallsStats;
+}
+
+/// The overall Mega Walls stats of a player.
+///
+/// Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+pub struct MegaWallsStats {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    class_points: u64,
+    chosen_class: Option<String>,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    losses: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    final_kills: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    final_deaths: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wither_damage: u64,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "diagnostics", serde(deserialize_with = "crate::api::diagnostics::capture"))]
+    other: HashMap<String, Value>,
+}
+
+impl MegaWallsStats {
+    /// Returns the amount of Mega Walls coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of class points the player has earned over all classes.
+    pub fn class_points(&self) -> u64 {
+        self.class_points
+    }
+
+    /// Returns the name of the selected class (e.g. `"Zombie"`), if present.
+    pub fn chosen_class(&self) -> Option<&str> {
+        self.chosen_class.as_deref()
+    }
+
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of games the player has lost.
+    pub fn losses(&self) -> u64 {
+        self.losses
+    }
+
+    /// Returns the amount of kills.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the amount of final kills.
+    pub fn final_kills(&self) -> u64 {
+        self.final_kills
+    }
+
+    /// Returns the amount of final deaths.
+    pub fn final_deaths(&self) -> u64 {
+        self.final_deaths
+    }
+
+    /// Returns the total damage the player has dealt to withers.
+    pub fn wither_damage(&self) -> u64 {
+        self.wither_damage
+    }
+
+    /// Returns the amount of final kills with the class `class`, ignoring case.
+    pub fn class_final_kills(&self, class: &str) -> u64 {
+        self.other.get(&format!("{}_final_kills", class.to_lowercase()))
+            .and_then(|value| crate::api::util::lenient::deserialize_number(value).ok())
+            .unwrap_or_default()
+    }
+}
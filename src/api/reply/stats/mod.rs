@@ -0,0 +1,46 @@
+//! Typed game stats found in the `stats` object of [`PlayerData`](crate::PlayerData).
+//!
+//! Every game is represented by a marker type that implements [`GameStats`],
+//! use [`PlayerData::stats`](crate::PlayerData::stats) to deserialize its stats.
+
+mod arcade;
+mod bedwars;
+mod blitz;
+mod classic;
+mod duels;
+mod mega_walls;
+mod skywars;
+mod summary;
+mod tnt_games;
+
+use serde::de::DeserializeOwned;
+
+pub use arcade::{Arcade, ArcadeStats, ZombiesStats, MiniWallsStats};
+pub use bedwars::{Bedwars, BedwarsStats};
+pub use blitz::{Blitz, BlitzStats};
+pub use classic::{Warlords, WarlordsStats, ArenaBrawl, ArenaBrawlStats, ArenaBrawlModeStats, Paintball, PaintballStats, VampireZ, VampireZStats, Quake, QuakeStats, TurboKartRacers, TurboKartRacersStats};
+pub use duels::{Duels, DuelsStats};
+pub use mega_walls::{MegaWalls, MegaWallsStats};
+pub use skywars::{SkyWars, SkyWarsStats};
+pub use summary::StatsSummary;
+pub use tnt_games::{TntGames, TntGamesStats, TntRunStats, PvpRunStats, TntTagStats, BowSpleefStats, WizardsStats};
+
+/// A game with stats stored in the `stats` object of [`PlayerData`](crate::PlayerData).
+///
+/// # Examples
+/// ```rust,no_run
+/// use hypixel_api::{Bedwars, BedwarsStats, PlayerData};
+///
+/// # fn print_wins(player: &PlayerData) {
+/// if let Some(Ok(stats)) = player.stats::<Bedwars>() {
+///     println!("Bedwars wins: {}", stats.wins());
+/// }
+/// # }
+/// ```
+pub trait GameStats {
+    /// The name of the entry in the `stats` object.
+    const KEY: &'static str;
+
+    /// The data structure the stats of this game are deserialized into.
+    type Stats: DeserializeOwned;
+}
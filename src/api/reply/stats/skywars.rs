@@ -0,0 +1,119 @@
+use serde::Deserialize;
+use crate::api::reply::stats::{GameStats, StatsSummary};
+use crate::api::util::ratios::{ratio, win_rate};
+
+/// Marker type for the stats of [SkyWars](https://hypixel.net/skywars).
+#[derive(Debug, Copy, Clone)]
+pub struct SkyWars;
+
+impl GameStats for SkyWars {
+    const KEY: &'static str = "SkyWars";
+    type Stats = SkyWarsStats;
+}
+
+/// The overall SkyWars stats of a player.
+///
+/// Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+pub struct SkyWarsStats {
+    #[serde(rename = "skywars_experience", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    experience: f64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    souls: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    heads: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    win_streak: u64,
+    #[serde(rename = "games_played_skywars", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    games_played: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    losses: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    assists: u64,
+}
+
+impl SkyWarsStats {
+    /// Returns the total amount of SkyWars experience the player has earned.
+    pub fn experience(&self) -> f64 {
+        self.experience
+    }
+
+    /// Returns the amount of SkyWars coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of souls the player has.
+    pub fn souls(&self) -> u64 {
+        self.souls
+    }
+
+    /// Returns the amount of heads the player has collected.
+    pub fn heads(&self) -> u64 {
+        self.heads
+    }
+
+    /// Returns the player's current winstreak.
+    pub fn win_streak(&self) -> u64 {
+        self.win_streak
+    }
+
+    /// Returns the amount of games the player has played.
+    pub fn games_played(&self) -> u64 {
+        self.games_played
+    }
+
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of games the player has lost.
+    pub fn losses(&self) -> u64 {
+        self.losses
+    }
+
+    /// Returns the amount of kills.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the amount of assists.
+    pub fn assists(&self) -> u64 {
+        self.assists
+    }
+
+    /// Returns the kill/death ratio, see [`ratio`](crate::util::ratios::ratio).
+    pub fn kdr(&self) -> f64 {
+        ratio(self.kills, self.deaths)
+    }
+
+    /// Returns the win/loss ratio, see [`ratio`](crate::util::ratios::ratio).
+    pub fn wlr(&self) -> f64 {
+        ratio(self.wins, self.losses)
+    }
+
+    /// Returns the share of games won, see [`win_rate`](crate::util::ratios::win_rate).
+    pub fn win_rate(&self) -> f64 {
+        win_rate(self.wins, self.losses)
+    }
+
+    /// Returns the wins, losses, kills and deaths of the player.
+    pub fn summary(&self) -> StatsSummary {
+        StatsSummary::new(self.wins, self.losses, self.kills, self.deaths)
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt::{Display, Formatter};
+use crate::api::util::ratios::{ratio, win_rate};
+
+/// The core numbers of a player's stats in a game, suitable for rendering a row of a
+/// stats overlay.
+///
+/// Created by the `summary` method of the typed stats structs, e.g. [`BedwarsStats::summary`](crate::BedwarsStats::summary).
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::StatsSummary;
+///
+/// let summary = StatsSummary::new(30, 10, 45, 15);
+/// assert_eq!(summary.kdr(), 3.0);
+/// assert_eq!(summary.win_rate(), 0.75);
+/// assert_eq!(summary.to_string(), "W 30 | L 10 | WLR 3.00 | K 45 | D 15 | KDR 3.00");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct StatsSummary {
+    wins: u64,
+    losses: u64,
+    kills: u64,
+    deaths: u64,
+}
+
+impl StatsSummary {
+    pub fn new(wins: u64, losses: u64, kills: u64, deaths: u64) -> Self {
+        StatsSummary { wins, losses, kills, deaths }
+    }
+
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    pub fn losses(&self) -> u64 {
+        self.losses
+    }
+
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the win/loss ratio, see [`ratio`](crate::util::ratios::ratio).
+    pub fn wlr(&self) -> f64 {
+        ratio(self.wins, self.losses)
+    }
+
+    /// Returns the kill/death ratio, see [`ratio`](crate::util::ratios::ratio).
+    pub fn kdr(&self) -> f64 {
+        ratio(self.kills, self.deaths)
+    }
+
+    /// Returns the share of games won, see [`win_rate`](crate::util::ratios::win_rate).
+    pub fn win_rate(&self) -> f64 {
+        win_rate(self.wins, self.losses)
+    }
+}
+
+impl Display for StatsSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "W {} | L {} | WLR {:.2} | K {} | D {} | KDR {:.2}", self.wins, self.losses, self.wlr(), self.kills, self.deaths, self.kdr())
+    }
+}
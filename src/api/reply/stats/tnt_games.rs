@@ -0,0 +1,244 @@
+use std::time::Duration;
+use serde::Deserialize;
+use crate::api::reply::stats::GameStats;
+
+/// Marker type for the stats of [TNT Games](https://hypixel.net/tnt-games).
+#[derive(Debug, Copy, Clone)]
+pub struct TntGames;
+
+impl GameStats for TntGames {
+    const KEY: &'static str = "TNTGames";
+    type Stats = TntGamesStats;
+}
+
+/// The TNT Games stats of a player, with the stats of every mode.
+///
+/// Missing fields default to `0`.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+pub struct TntGamesStats {
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    coins: u64,
+    #[serde(deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(rename = "winstreak", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    win_streak: u64,
+    #[serde(flatten)]
+    tnt_run: TntRunStats,
+    #[serde(flatten)]
+    pvp_run: PvpRunStats,
+    #[serde(flatten)]
+    tnt_tag: TntTagStats,
+    #[serde(flatten)]
+    bow_spleef: BowSpleefStats,
+    #[serde(flatten)]
+    wizards: WizardsStats,
+}
+
+impl TntGamesStats {
+    /// Returns the amount of TNT Games coins the player has.
+    pub fn coins(&self) -> u64 {
+        self.coins
+    }
+
+    /// Returns the amount of games the player has won over all modes.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the player's current winstreak.
+    pub fn win_streak(&self) -> u64 {
+        self.win_streak
+    }
+
+    /// Returns the TNT Run stats.
+    pub fn tnt_run(&self) -> &TntRunStats {
+        &self.tnt_run
+    }
+
+    /// Returns the PVP Run stats.
+    pub fn pvp_run(&self) -> &PvpRunStats {
+        &self.pvp_run
+    }
+
+    /// Returns the TNT Tag stats.
+    pub fn tnt_tag(&self) -> &TntTagStats {
+        &self.tnt_tag
+    }
+
+    /// Returns the Bow Spleef stats.
+    pub fn bow_spleef(&self) -> &BowSpleefStats {
+        &self.bow_spleef
+    }
+
+    /// Returns the Wizards stats.
+    pub fn wizards(&self) -> &WizardsStats {
+        &self.wizards
+    }
+}
+
+/// The TNT Run stats of a player, see [`TntGamesStats::tnt_run`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct TntRunStats {
+    #[serde(rename = "wins_tntrun", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(rename = "deaths_tntrun", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(rename = "record_tntrun", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    record: u64,
+}
+
+impl TntRunStats {
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the longest time the player survived, if they played at least once.
+    pub fn record(&self) -> Option<Duration> {
+        (self.record > 0).then(|| Duration::from_secs(self.record))
+    }
+}
+
+/// The PVP Run stats of a player, see [`TntGamesStats::pvp_run`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct PvpRunStats {
+    #[serde(rename = "wins_pvprun", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(rename = "kills_pvprun", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+    #[serde(rename = "deaths_pvprun", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(rename = "record_pvprun", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    record: u64,
+}
+
+impl PvpRunStats {
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of kills.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the longest time the player survived, if they played at least once.
+    pub fn record(&self) -> Option<Duration> {
+        (self.record > 0).then(|| Duration::from_secs(self.record))
+    }
+}
+
+/// The TNT Tag stats of a player, see [`TntGamesStats::tnt_tag`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct TntTagStats {
+    #[serde(rename = "wins_tntag", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(rename = "kills_tntag", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+}
+
+impl TntTagStats {
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of players the player has exploded while tagged.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+}
+
+/// The Bow Spleef stats of a player, see [`TntGamesStats::bow_spleef`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct BowSpleefStats {
+    #[serde(rename = "wins_bowspleef", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(rename = "deaths_bowspleef", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(rename = "tags_bowspleef", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    shots: u64,
+}
+
+impl BowSpleefStats {
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the amount of arrows the player has shot.
+    pub fn shots(&self) -> u64 {
+        self.shots
+    }
+}
+
+/// The Wizards stats of a player, see [`TntGamesStats::wizards`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(default)]
+pub struct WizardsStats {
+    #[serde(rename = "wins_capture", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    wins: u64,
+    #[serde(rename = "kills_capture", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    kills: u64,
+    #[serde(rename = "deaths_capture", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    deaths: u64,
+    #[serde(rename = "assists_capture", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    assists: u64,
+    #[serde(rename = "points_capture", deserialize_with = "crate::api::util::lenient::deserialize_number")]
+    points: u64,
+    #[serde(rename = "capture_class")]
+    class: Option<String>,
+}
+
+impl WizardsStats {
+    /// Returns the amount of games the player has won.
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    /// Returns the amount of kills.
+    pub fn kills(&self) -> u64 {
+        self.kills
+    }
+
+    /// Returns the amount of deaths.
+    pub fn deaths(&self) -> u64 {
+        self.deaths
+    }
+
+    /// Returns the amount of assists.
+    pub fn assists(&self) -> u64 {
+        self.assists
+    }
+
+    /// Returns the amount of capture points the player has earned.
+    pub fn points(&self) -> u64 {
+        self.points
+    }
+
+    /// Returns the selected class (e.g. `"fire_wizard"`), if present.
+    pub fn class(&self) -> Option<&str> {
+        self.class.as_deref()
+    }
+}
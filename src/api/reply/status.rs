@@ -1,17 +1,43 @@
 use std::ops::Deref;
 use serde::Deserialize;
 use uuid::Uuid;
+use crate::api::GameType;
 
 /// A data structure that maps to [`this endpoint`](https://api.hypixel.net/#tag/Player-Data/paths/~1status/get).
 ///
 /// Response fields are captured in [`StatusData`]
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(from = "StatusReplyFields")]
 pub struct StatusReply {
     success: bool,
-    #[serde(flatten)]
+    cause: Option<String>,
     data: StatusData,
 }
 
+/// The fields of a [`StatusReply`] as sent by the API. Unlike a flattened [`StatusData`],
+/// serde reports the fields of this struct that are ignored.
+#[derive(Deserialize)]
+struct StatusReplyFields {
+    success: bool,
+    cause: Option<String>,
+    #[serde(deserialize_with = "crate::api::util::uuid::deserialize")]
+    uuid: Uuid,
+    session: SessionData,
+}
+
+impl From<StatusReplyFields> for StatusReply {
+    fn from(fields: StatusReplyFields) -> Self {
+        StatusReply {
+            success: fields.success,
+            cause: fields.cause,
+            data: StatusData {
+                uuid: fields.uuid,
+                session: fields.session,
+            },
+        }
+    }
+}
+
 impl StatusReply {
     /// Returns whether the response was successful.
     ///
@@ -19,8 +45,15 @@ impl StatusReply {
     pub fn success(&self) -> bool {
         self.success
     }
+
+    /// Consumes the reply and returns the status data.
+    pub fn into_status(self) -> StatusData {
+        self.data
+    }
 }
 
+impl_reply!(StatusReply, data: StatusData);
+
 impl Deref for StatusReply {
     type Target = StatusData;
 
@@ -30,8 +63,9 @@ impl Deref for StatusReply {
 }
 
 /// The response data corresponding to [`this endpoint`](https://api.hypixel.net/#tag/Player-Data/paths/~1status/get).
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
 pub struct StatusData {
+    #[serde(deserialize_with = "crate::api::util::uuid::deserialize")]
     uuid: Uuid,
     session: SessionData,
 }
@@ -42,38 +76,153 @@ impl StatusData {
         self.uuid
     }
 
-    /// Returns `true` if the player is online.
-    ///
-    /// Players have the option to hide this value in their settings:
-    /// `false` means either offline or online but hidden.
+    /// Returns the session of the player.
+    pub fn session(&self) -> &SessionData {
+        &self.session
+    }
+
+    /// Consumes the status data and returns the session of the player.
+    pub fn into_session(self) -> SessionData {
+        self.session
+    }
+
+    /// Returns `true` if the player is online, see [`SessionData::online`].
     pub fn online(&self) -> bool {
-        self.session.online
+        self.session.online()
     }
 
     /// Returns the type of game the player is currently playing, if present.
     ///
-    /// TODO: This will be changed into an enum
+    /// See [`StatusData::game`] for the typed value.
     pub fn game_type(&self) -> Option<&str> {
-        self.session.game_type.as_deref()
+        self.session.game_type()
+    }
+
+    /// Returns the [`GameType`] the player is currently playing, if present.
+    pub fn game(&self) -> Option<GameType> {
+        self.session.game()
     }
 
     /// Returns the mode of the game the player is playing, if present.
     pub fn mode(&self) -> Option<&str> {
-        self.session.mode.as_deref()
+        self.session.mode()
     }
 
     /// Returns the map the player is playing on, if present.
     pub fn map(&self) -> Option<&str> {
-        self.session.map.as_deref()
+        self.session.map()
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl StatusReply {
+    /// Creates a successful reply containing `data`, for use in tests.
+    pub fn new(data: StatusData) -> Self {
+        StatusReply {
+            success: true,
+            cause: None,
+            data,
+        }
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl StatusData {
+    /// Returns a builder to create fake status data, for use in tests.
+    ///
+    /// The player is offline unless [`StatusDataBuilder::online`] is called.
+    pub fn builder(uuid: Uuid) -> StatusDataBuilder {
+        StatusDataBuilder {
+            data: StatusData {
+                uuid,
+                session: SessionData {
+                    online: false,
+                    game_type: None,
+                    mode: None,
+                    map: None,
+                },
+            },
+        }
+    }
+}
+
+/// A builder for fake [`StatusData`], see [`StatusData::builder`].
+#[cfg(feature = "test-util")]
+#[derive(Debug, Clone)]
+pub struct StatusDataBuilder {
+    data: StatusData,
+}
+
+#[cfg(feature = "test-util")]
+impl StatusDataBuilder {
+    /// Marks the player as online.
+    pub fn online(mut self) -> Self {
+        self.data.session.online = true;
+        self
+    }
+
+    /// Sets the type of game the player is playing.
+    pub fn game_type(mut self, game_type: &str) -> Self {
+        self.data.session.game_type = Some(game_type.to_string());
+        self
+    }
+
+    /// Sets the mode of the game the player is playing.
+    pub fn mode(mut self, mode: &str) -> Self {
+        self.data.session.mode = Some(mode.to_string());
+        self
+    }
+
+    /// Sets the map the player is playing on.
+    pub fn map(mut self, map: &str) -> Self {
+        self.data.session.map = Some(map.to_string());
+        self
+    }
+
+    /// Creates the [`StatusData`].
+    pub fn build(self) -> StatusData {
+        self.data
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
-struct SessionData {
+/// The session of a player, see [`StatusData::session`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct SessionData {
     online: bool,
-    /// TODO: chage into enum for easier game sorting
     #[serde(rename = "gameType")]
     game_type: Option<String>,
     mode: Option<String>,
     map: Option<String>,
+}
+
+impl SessionData {
+    /// Returns `true` if the player is online.
+    ///
+    /// Players have the option to hide this value in their settings:
+    /// `false` means either offline or online but hidden.
+    pub fn online(&self) -> bool {
+        self.online
+    }
+
+    /// Returns the type of game the player is currently playing (e.g. `"BEDWARS"`), if present.
+    pub fn game_type(&self) -> Option<&str> {
+        self.game_type.as_deref()
+    }
+
+    /// Returns the [`GameType`] the player is currently playing, if present.
+    ///
+    /// Game types introduced after this crate was published are returned as [`GameType::Unknown`].
+    pub fn game(&self) -> Option<GameType> {
+        self.game_type.clone().map(GameType::from)
+    }
+
+    /// Returns the mode of the game the player is playing, if present.
+    pub fn mode(&self) -> Option<&str> {
+        self.mode.as_deref()
+    }
+
+    /// Returns the map the player is playing on, if present.
+    pub fn map(&self) -> Option<&str> {
+        self.map.as_deref()
+    }
 }
\ No newline at end of file
@@ -1,32 +1,100 @@
-use std::error::Error;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt::Formatter;
-use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 use parking_lot::Mutex;
-use reqwest::{Client, Response};
-use reqwest::header::{AsHeaderName, HeaderMap};
-use serde::de::DeserializeOwned;
-use tokio::task::JoinHandle;
+use reqwest::{Client, Response, StatusCode};
+use reqwest::header::{HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH};
+use serde::Deserialize;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde_json::value::RawValue;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use tokio::sync::{broadcast, mpsc, watch};
 use uuid::Uuid;
+use crate::api::dispatch::{DEFAULT_QUEUE_CAPACITY, DEFAULT_WORKERS, Dispatcher, RequestFuture};
 use crate::api::error::HypixelApiError;
-use crate::api::throttler::RequestThrottler;
+use crate::api::events::{EVENT_CAPACITY, RequestEvent, RequestId};
+use crate::api::stats::{RequestStats, StatsRecorder, TagStats};
+use crate::api::stream::ItemScanner;
+use crate::api::cache::{CacheConfig, CachePolicy, ResponseCache, Validators};
+use crate::api::key::{KeyUsage, SecretKey};
+use crate::api::path::ApiPath;
+use crate::api::throttler::{RateLimitFallback, RateLimitStatus, RequestThrottler};
+use crate::api::retry::RetryPolicy;
+use crate::api::budget::DeserializationBudget;
+use crate::api::pagination::PageLimits;
+use crate::api::timed::{ResponseMeta, Timed};
+use crate::api::journal::Journal;
+use crate::api::vcr::{Cassette, Vcr};
+#[cfg(feature = "diagnostics")]
+use crate::api::diagnostics::{DiagnosticsCallback, DiagnosticsReport};
+#[cfg(feature = "body-capture")]
+use crate::api::capture::{BodyCapture, CapturedBody};
 use crate::error::ErrorReply;
 
+/// The base URL of the Hypixel API used by [`RequestHandler::new`].
+pub const DEFAULT_BASE_URL: &str = "https://api.hypixel.net/";
+
+/// The amount of streamed items that are buffered before the stream is read.
+const STREAM_BUFFER: usize = 64;
+
+/// The maximum size of the body of an unsuccessful response that is read for its cause.
+const MAX_ERROR_BODY: usize = 64 * 1024;
+
+/// A hook that may patch or reject the body of a response, see [`RequestHandlerBuilder::inspect_body`].
+type BodyInspector = Arc<dyn Fn(&str, &mut Vec<u8>) -> Result<(), String> + Send + Sync>;
+
+/// Queues requests to the Hypixel API within its rate limit, see [`RequestHandler::new`].
+///
+/// Cloning a RequestHandler is cheap, all clones share the same throttler, cache, workers and
+/// statistics. Store a clone in the state of your application or move one into a task instead
+/// of wrapping the handler in an [`Arc`].
+#[derive(Clone)]
 pub struct RequestHandler {
-    client: Client,
-    api_key: Uuid,
-    throttler: Arc<Mutex<RequestThrottler>>,
+    shared: Arc<SharedState>,
+    dispatcher: Dispatcher,
+    tag: Option<Cow<'static, str>>,
 }
 
 impl std::fmt::Debug for RequestHandler {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RequestHandler")
-            .field("client", &self.client)
-            .field("throttler", &self.throttler)
+            .field("api_key", &*self.shared.api_key.read())
+            .field("client", &self.shared.client)
+            .field("base_url", &self.shared.base_url)
+            .field("throttler", &self.shared.throttler)
+            .field("vcr", &self.shared.decoder.vcr)
+            .field("workers", &self.dispatcher.workers())
+            .field("tag", &self.tag)
             .finish()
     }
 }
 
+/// The state shared by a [`RequestHandler`], its clones and all of its requests.
+struct SharedState {
+    client: Client,
+    api_key: parking_lot::RwLock<SecretKey>,
+    /// The owner of the key, once verified with [`RequestHandler::verify_key`].
+    #[cfg(feature = "legacy-key")]
+    key_owner: parking_lot::RwLock<Option<Uuid>>,
+    /// Set once the API rejected the key, until it is replaced.
+    key_invalid: watch::Sender<bool>,
+    base_url: String,
+    default_query: Vec<(String, String)>,
+    throttler: Arc<Mutex<RequestThrottler>>,
+    decoder: ResponseDecoder,
+    events: broadcast::Sender<RequestEvent>,
+    stats: StatsRecorder,
+    cache: Option<ResponseCache>,
+    journal: Option<Journal>,
+    retry: RetryPolicy,
+    page_limits: PageLimits,
+    next_id: AtomicU64,
+}
+
 impl RequestHandler {
     /// Creates a new RequestHandler instance using an
     /// [api_key](https://api.hypixel.net/#section/Authentication)
@@ -50,15 +118,353 @@ impl RequestHandler {
     /// // Send requests ...
     /// # }
     /// ```
-    pub fn new(api_key: Uuid) -> Self {
+    pub fn new(api_key: impl Into<SecretKey>) -> Self {
+        RequestHandler::builder(api_key).build()
+    }
+
+    /// Creates a [`RequestHandlerBuilder`] to configure a new RequestHandler instance
+    /// using an [api_key](https://api.hypixel.net/#section/Authentication) obtained from Hypixel.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use hypixel_api::RequestHandler;
+    /// # use uuid::Uuid;
+    /// # use std::str::FromStr;
+    ///
+    /// # fn main() {
+    /// let api_key = Uuid::from_str(env!("HYPIXEL_API_KEY")).unwrap();
+    /// let request_handler = RequestHandler::builder(api_key)
+    ///     .base_url("http://localhost:8080")
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn builder(api_key: impl Into<SecretKey>) -> RequestHandlerBuilder {
+        RequestHandlerBuilder {
+            api_key: api_key.into(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            default_query: Vec::new(),
+            client: None,
+            vcr: None,
+            #[cfg(feature = "diagnostics")]
+            diagnostics: None,
+            #[cfg(feature = "diagnostics")]
+            strict: false,
+            #[cfg(feature = "body-capture")]
+            capture_length: None,
+            inspector: None,
+            cache: None,
+            journal: None,
+            workers: DEFAULT_WORKERS,
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
+            rate_limit_fallback: RateLimitFallback::default(),
+            retry: RetryPolicy::default(),
+            budget: DeserializationBudget::default(),
+            page_limits: PageLimits::default(),
+        }
+    }
+
+    /// Creates a new RequestHandler instance configured by environment variables,
+    /// see [`RequestHandler::builder_from_env`].
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use hypixel_api::RequestHandler;
+    ///
+    /// # fn main() {
+    /// // HYPIXEL_API_KEY=... HYPIXEL_TIMEOUT_SECS=10 cargo run
+    /// let request_handler = RequestHandler::from_env().expect("HYPIXEL_API_KEY is set");
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<RequestHandler, HypixelApiError> {
+        Ok(RequestHandler::builder_from_env()?.build())
+    }
+
+    /// Creates a [`RequestHandlerBuilder`] configured by environment variables, which can be
+    /// configured further.
+    ///
+    /// | Variable               | Setting                                                                  |
+    /// |------------------------|--------------------------------------------------------------------------|
+    /// | `HYPIXEL_API_KEY`      | the API key, required                                                    |
+    /// | `HYPIXEL_BASE_URL`     | [`RequestHandlerBuilder::base_url`]                                      |
+    /// | `HYPIXEL_TIMEOUT_SECS` | the timeout of every request in seconds                                  |
+    /// | `HYPIXEL_RATE_LIMIT`   | the requests per window of [`RequestHandlerBuilder::rate_limit_fallback`] |
+    /// | `HYPIXEL_WORKERS`      | [`RequestHandlerBuilder::workers`]                                       |
+    ///
+    /// Fails with [`HypixelApiError::MissingEnvVar`] if the API key is not set and with
+    /// [`HypixelApiError::InvalidEnvVar`] if any of the variables has a malformed value.
+    pub fn builder_from_env() -> Result<RequestHandlerBuilder, HypixelApiError> {
+        RequestHandler::builder_from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Creates a [`RequestHandlerBuilder`] from the variables returned by `var`, see [`RequestHandler::builder_from_env`].
+    pub(crate) fn builder_from_vars(var: impl Fn(&str) -> Option<String>) -> Result<RequestHandlerBuilder, HypixelApiError> {
+        fn parse<T: std::str::FromStr>(var: &impl Fn(&str) -> Option<String>, name: &str) -> Result<Option<T>, HypixelApiError>
+            where T::Err: std::fmt::Display
+        {
+            var(name).map(|value| value.trim().parse().map_err(|e: T::Err| {
+                HypixelApiError::InvalidEnvVar(name.to_string(), e.to_string())
+            })).transpose()
+        }
+
+        let api_key: Uuid = parse(&var, "HYPIXEL_API_KEY")?
+            .ok_or_else(|| HypixelApiError::MissingEnvVar("HYPIXEL_API_KEY".to_string()))?;
+        let mut builder = RequestHandler::builder(api_key);
+        if let Some(base_url) = var("HYPIXEL_BASE_URL") {
+            builder = builder.base_url(base_url);
+        }
+        if let Some(timeout) = parse::<u64>(&var, "HYPIXEL_TIMEOUT_SECS")? {
+            let client = Client::builder().timeout(std::time::Duration::from_secs(timeout)).build()?;
+            builder = builder.client(client);
+        }
+        if let Some(limit) = parse::<u32>(&var, "HYPIXEL_RATE_LIMIT")? {
+            let fallback = builder.rate_limit_fallback;
+            builder = builder.rate_limit_fallback(RateLimitFallback::new(fallback.reset(), limit).strict(fallback.is_strict()));
+        }
+        if let Some(workers) = parse(&var, "HYPIXEL_WORKERS")? {
+            builder = builder.workers(workers);
+        }
+        Ok(builder)
+    }
+
+    /// Returns the base URL all request paths are appended to.
+    pub fn base_url(&self) -> &str {
+        &self.shared.base_url
+    }
+
+    /// Returns the query parameters appended to every request, see [`RequestHandlerBuilder::query`].
+    pub fn default_query(&self) -> &[(String, String)] {
+        &self.shared.default_query
+    }
+
+    /// Returns the limits of the helpers that send many requests, see [`RequestHandlerBuilder::page_limits`].
+    pub fn page_limits(&self) -> PageLimits {
+        self.shared.page_limits
+    }
+
+    /// Returns the most recently captured bodies of failed responses, oldest first.
+    ///
+    /// This is empty unless capturing was enabled with [`RequestHandlerBuilder::capture_failed_bodies`].
+    /// See [`capture`](crate::capture).
+    #[cfg(feature = "body-capture")]
+    pub fn captured_bodies(&self) -> Vec<CapturedBody> {
+        self.shared.decoder.capture.as_ref().map(|capture| capture.captures()).unwrap_or_default()
+    }
+
+    /// Returns the response cache, if enabled with [`RequestHandlerBuilder::cache`].
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.shared.cache.as_ref()
+    }
+
+    /// Returns the request journal, if enabled with [`RequestHandlerBuilder::journal`].
+    pub fn journal(&self) -> Option<&Journal> {
+        self.shared.journal.as_ref()
+    }
+
+    /// Returns latency, throughput and error statistics of the requests handled so far.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use hypixel_api::RequestHandler;
+    ///
+    /// # fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// // send requests ...
+    /// let stats = request_handler.stats();
+    /// println!("p95: {:?}, {} requests/min, {:.1}% errors", stats.latency_p95(), stats.requests_per_minute(), stats.error_rate() * 100.0);
+    /// # }
+    /// ```
+    pub fn stats(&self) -> RequestStats {
+        self.shared.stats.snapshot()
+    }
+
+    /// Returns a clone of this handler that tags all of its requests with `tag`.
+    ///
+    /// Tagged requests share the rate limit, cache and workers of this handler, but are
+    /// additionally counted per tag in [`RequestHandler::stats_by_tag`] and, with the `metrics`
+    /// feature enabled, in the `TAGGED_REQUESTS_TOTAL` metrics. Use a tag per feature of your
+    /// application to find out which one uses up the rate limit of a shared key.
+    #[cfg_attr(feature = "metrics", doc = "See [`TAGGED_REQUESTS_TOTAL`](crate::metrics::TAGGED_REQUESTS_TOTAL).")]
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use hypixel_api::RequestHandler;
+    ///
+    /// # fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let leaderboard_bot = request_handler.tagged("leaderboards");
+    /// let tracker_bot = request_handler.tagged("tracker");
+    /// // send requests with either handler ...
+    /// for (tag, stats) in request_handler.stats_by_tag() {
+    ///     println!("{}: {} requests, {:?} on average", tag, stats.requests(), stats.average_latency());
+    /// }
+    /// # }
+    /// ```
+    pub fn tagged(&self, tag: impl Into<Cow<'static, str>>) -> RequestHandler {
         RequestHandler {
-            client: Client::new(),
-            api_key,
-            throttler: RequestThrottler::new(),
+            tag: Some(tag.into()),
+            ..self.clone()
         }
     }
 
-    /// Queues a new request for execution and returns a [`JoinHandle`] to it.
+    /// Returns the tag of the requests of this handler, see [`RequestHandler::tagged`].
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+
+    /// Returns the statistics of the requests of all [tagged](RequestHandler::tagged) handlers
+    /// sharing this handler's state, by tag.
+    pub fn stats_by_tag(&self) -> HashMap<String, TagStats> {
+        self.shared.stats.tags()
+    }
+
+    /// Subscribes to the [`RequestEvent`]s of all requests queued after this call.
+    ///
+    /// A subscriber that falls behind by more than 1024 events misses the oldest ones,
+    /// see [`broadcast::Receiver::recv`].
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// # use hypixel_api::StatusReply;
+    /// use hypixel_api::{RequestEvent, RequestHandler};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let mut events = request_handler.subscribe();
+    /// tokio::spawn(async move {
+    ///     while let Ok(event) = events.recv().await {
+    ///         if let RequestEvent::Completed { id, latency, .. } = event {
+    ///             println!("request {} took {:?}", id, latency);
+    ///         }
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn subscribe(&self) -> broadcast::Receiver<RequestEvent> {
+        self.shared.events.subscribe()
+    }
+
+    /// Returns the usage of the `ApiKey` as reported by the most recent authenticated response.
+    ///
+    /// Hypixel has removed the `/key` endpoint, this information is now derived
+    /// from the `ratelimit-*` headers sent along with every authenticated response.
+    /// If no such response has been received yet, [`Option::None`] is returned.
+    pub fn key_usage(&self) -> Option<KeyUsage> {
+        self.shared.throttler.lock().key_usage()
+    }
+
+    /// Returns a receiver of the [`RateLimitStatus`], which is updated after every response and
+    /// whenever a rate limit window resets.
+    ///
+    /// This allows to display the remaining budget of the key without polling
+    /// [`RequestHandler::key_usage`]. Use [`tokio_stream::wrappers::WatchStream`](https://docs.rs/tokio-stream)
+    /// to turn the receiver into a [`Stream`].
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use hypixel_api::RequestHandler;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let mut updates = request_handler.rate_limit_updates();
+    /// tokio::spawn(async move {
+    ///     while updates.changed().await.is_ok() {
+    ///         let status = *updates.borrow_and_update();
+    ///         if let (Some(remaining), Some(limit)) = (status.remaining(), status.limit()) {
+    ///             println!("{}/{} requests left", remaining, limit);
+    ///         }
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn rate_limit_updates(&self) -> watch::Receiver<RateLimitStatus> {
+        self.shared.throttler.lock().subscribe()
+    }
+
+    /// Returns the difference between the clock of the API and the local clock, as measured
+    /// from the `Date` and `Age` headers of the most recent response, if any was received.
+    ///
+    /// The difference is positive if the local clock is behind. Since the `Date` header has a
+    /// resolution of a second, skews of up to a second are to be expected. The rate limit does
+    /// not depend on the local clock, larger skews only hint at a drifting clock, e.g. to
+    /// explain timestamps that seem to lie in the future.
+    pub fn clock_skew(&self) -> Option<chrono::TimeDelta> {
+        self.shared.throttler.lock().clock_skew()
+    }
+
+    /// Returns `true` if the API rejected the `ApiKey` with `403 Forbidden`.
+    ///
+    /// While the key is invalid, all queued and future authenticated requests fail immediately
+    /// with [`HypixelApiError::InvalidApiKey`], instead of waiting for the rate limit only to be
+    /// rejected as well. Replace the key with [`RequestHandler::set_api_key`].
+    pub fn is_key_invalid(&self) -> bool {
+        *self.shared.key_invalid.borrow()
+    }
+
+    /// Replaces the `ApiKey` used by authenticated requests that are sent from now on,
+    /// this also resets [`RequestHandler::is_key_invalid`].
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use hypixel_api::{RequestHandler, StatusReply};
+    /// use hypixel_api::error::HypixelApiError;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let (old_key, new_key) = (Uuid::nil(), Uuid::nil());
+    /// let request_handler = RequestHandler::new(old_key);
+    /// let reply = request_handler.request::<StatusReply>("status?uuid=069a79f4-44e9-4726-a5be-fca90e38aaf5", true).await.unwrap();
+    /// if let Err(HypixelApiError::InvalidApiKey) = reply {
+    ///     request_handler.set_api_key(new_key);
+    /// }
+    /// # }
+    /// ```
+    pub fn set_api_key(&self, api_key: impl Into<SecretKey>) {
+        *self.shared.api_key.write() = api_key.into();
+        #[cfg(feature = "legacy-key")]
+        self.shared.key_owner.write().take();
+        self.shared.key_invalid.send_replace(false);
+    }
+
+    /// Requests the removed `/key` endpoint to verify the `ApiKey`, and returns the UUID of
+    /// the player that owns it.
+    ///
+    /// The owner is not revealed otherwise, until then [`RequestHandler::key_owner`] returns
+    /// [`Option::None`]. Hypixel has removed this endpoint, so this only works with proxies
+    /// and mock servers that still serve it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`HypixelApiError`] of the request if it failed.
+    #[cfg(feature = "legacy-key")]
+    pub async fn verify_key(&self) -> Result<Uuid, HypixelApiError> {
+        let reply = self.request::<crate::api::reply::KeyReply>("key", true).await??;
+        *self.shared.key_owner.write() = Some(reply.owner());
+        Ok(reply.owner())
+    }
+
+    /// Returns the UUID of the player that owns the `ApiKey`, if it was verified with
+    /// [`RequestHandler::verify_key`] since it was last replaced.
+    #[cfg(feature = "legacy-key")]
+    pub fn key_owner(&self) -> Option<Uuid> {
+        *self.shared.key_owner.read()
+    }
+
+    /// Queues a new request for execution and returns a [`RequestFuture`] to it.
+    ///
+    /// Queued requests are executed by a bounded set of workers, see
+    /// [`RequestHandlerBuilder::workers`]. The request is executed whether or not
+    /// the returned future is awaited.
     ///
     /// ## Arguments
     /// `path` should be a relative path to the API (without leading `/`), such as `"key"`
@@ -70,6 +476,10 @@ impl RequestHandler {
     ///
     /// If any part of the execution process fails, a [`HypixelApiError`] will be returned.
     ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime while no workers are running.
+    ///
     /// # Examples
     /// ```rust,no_run
     /// # use uuid::Uuid;
@@ -89,81 +499,1195 @@ impl RequestHandler {
     /// // use reply ...
     /// # }
     /// ```
-    #[cfg_attr(feature = "tracing", tracing::instrument(name = "queue_req", skip(self)))]
-    pub fn request<T: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool) -> JoinHandle<Result<T, HypixelApiError>> {
-        let url = format!("https://api.hypixel.net/{}", path);
-        let api_key = self.api_key.hyphenated().to_string();
-        let client = self.client.clone();
-        let throttler = Arc::clone(&self.throttler);
-        tokio::spawn(async move {
-            let client = client;
-            let url = url;
-            let api_key = api_key;
-            let throttler = throttler;
-            loop {
-                match RequestHandler::try_request(&client, &url, &api_key, &throttler, authenticated).await {
-                    Ok(Some(response)) => break response.json::<T>().await.map_err(|e| e.into()),
-                    Err(error) => break Err(error),
-                    _ => {}
+    ///
+    /// # Tracing
+    ///
+    /// With the `tracing` feature enabled, every request runs in a `request` span recording the
+    /// `endpoint`, the HTTP `status`, the `ratelimit_remaining` header, the `attempt` (a request is
+    /// retried after a `429 Too Many Requests` response), the `queue_wait_ms` spent waiting for
+    /// the rate limit and the `bytes` of the response body.
+    pub fn request<T: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool) -> RequestFuture<T> {
+        self.request_with_policy(path, authenticated, CachePolicy::Use)
+    }
+
+    /// Queues a new request to the typed endpoint `path` like [`RequestHandler::request`],
+    /// authenticated if the endpoint requires the API key.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// # use std::str::FromStr;
+    /// use hypixel_api::{ApiPath, PlayerReply, RequestHandler};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let uuid = Uuid::from_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap();
+    /// let reply = request_handler.request_typed::<PlayerReply>(ApiPath::Player { uuid }).await.unwrap().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Like [`RequestHandler::request`], and additionally
+    /// [`HypixelApiError::Unsuccessful`] with the cause given by the API if the response
+    /// reports `"success": false`, even with status code `200`.
+    pub fn request_typed<T: DeserializeOwned + Send + 'static>(&self, path: ApiPath) -> RequestFuture<T> {
+        let context = self.context(&path.to_path(), path.is_authenticated(), CachePolicy::Use);
+        let (sender, future) = RequestFuture::channel();
+        self.dispatch(context, |context, body| {
+            let result = body.and_then(|body| context.shared.decoder.decode_validated(&context.path, context.authenticated, &body));
+            context.finish(&result);
+            let _ = sender.send(result);
+        });
+        future
+    }
+
+    /// Waits until fewer than [`RequestHandlerBuilder::queue_capacity`] requests are queued,
+    /// then queues a new request like [`RequestHandler::request`].
+    ///
+    /// [`RequestHandler::request`] never waits, so a producer that queues requests faster than
+    /// they are executed grows the queue without bound. Awaiting this method instead slows the
+    /// producer down to the pace of the workers. Requests queued by other means still count
+    /// towards the queue length.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use hypixel_api::{PlayerReply, RequestHandler};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// # let uuids: Vec<Uuid> = Vec::new();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let mut requests = Vec::new();
+    /// for uuid in uuids {
+    ///     let path = format!("player?uuid={}", uuid.simple());
+    ///     requests.push(request_handler.request_when_ready::<PlayerReply>(&path, true).await);
+    /// }
+    /// # }
+    /// ```
+    pub async fn request_when_ready<T: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool) -> RequestFuture<T> {
+        self.dispatcher.ready().await;
+        self.request(path, authenticated)
+    }
+
+    /// Returns the amount of requests that are queued but not picked up by a worker yet.
+    pub fn queue_len(&self) -> usize {
+        self.dispatcher.queued()
+    }
+
+    /// Returns how many more requests can be queued before
+    /// [`RequestHandler::request_when_ready`] waits.
+    pub fn capacity(&self) -> usize {
+        self.dispatcher.capacity().saturating_sub(self.dispatcher.queued())
+    }
+
+    /// Queues a new request like [`RequestHandler::request`], using the [`cache`](crate::cache)
+    /// according to `policy`.
+    ///
+    /// Without a cache, the policy has no effect.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use hypixel_api::{RequestHandler, StatusReply};
+    /// use hypixel_api::cache::{CacheConfig, CachePolicy};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::builder(api_key).cache(CacheConfig::new()).build();
+    /// // the user clicked the refresh button
+    /// let reply = request_handler.request_with_policy::<StatusReply>("status?uuid=069a79f4-44e9-4726-a5be-fca90e38aaf5", true, CachePolicy::Refresh)
+    ///     .await.unwrap().unwrap();
+    /// # }
+    /// ```
+    pub fn request_with_policy<T: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool, policy: CachePolicy) -> RequestFuture<T> {
+        let context = self.context(path, authenticated, policy);
+        let (sender, future) = RequestFuture::channel();
+        self.dispatch(context, |context, body| {
+            let result = body.and_then(|body| context.shared.decoder.decode(&context.path, context.authenticated, &body));
+            context.finish(&result);
+            // an error only means the future was dropped
+            let _ = sender.send(result);
+        });
+        future
+    }
+
+    /// Queues a new request like [`RequestHandler::request`] and returns its data along with
+    /// how it was executed: the time it waited in the queue, the network time, the amount of
+    /// attempts and whether it was served from the cache.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use hypixel_api::{RequestHandler, StatusReply};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let reply = request_handler.request_timed::<StatusReply>("status?uuid=069a79f4-44e9-4726-a5be-fca90e38aaf5", true)
+    ///     .await.unwrap().unwrap();
+    /// if reply.meta().queue_wait() > reply.meta().network() {
+    ///     println!("the queue is backed up, {:?} spent waiting", reply.meta().queue_wait());
+    /// }
+    /// println!("online: {}", reply.online());
+    /// # }
+    /// ```
+    pub fn request_timed<T: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool) -> RequestFuture<Timed<T>> {
+        let context = self.context(path, authenticated, CachePolicy::Use);
+        let (sender, future) = RequestFuture::channel();
+        self.dispatch(context, |context, body| {
+            let result = body.and_then(|body| context.shared.decoder.decode(&context.path, context.authenticated, &body));
+            context.finish(&result);
+            let meta = context.meta();
+            let _ = sender.send(result.map(|data| Timed::new(data, meta)));
+        });
+        future
+    }
+
+    /// Queues a new request for execution and returns a [`RequestFuture`] to its raw body.
+    ///
+    /// The body is read into [`Bytes`] once, which can be shared cheaply, e.g. to archive
+    /// a response and deserialize it at the same time.
+    ///
+    /// # Errors
+    ///
+    /// Like [`RequestHandler::request`], except that the body is not deserialized.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use hypixel_api::{RequestHandler, SkyblockBazaarReply};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let body = request_handler.request_bytes("skyblock/bazaar", false).await.unwrap().unwrap();
+    /// let reply: SkyblockBazaarReply = serde_json::from_slice(&body).unwrap();
+    /// // store body, use reply ...
+    /// # }
+    /// ```
+    pub fn request_bytes(&self, path: &str, authenticated: bool) -> RequestFuture<Bytes> {
+        self.request_bytes_with_policy(path, authenticated, CachePolicy::Use)
+    }
+
+    /// Queues a new request like [`RequestHandler::request_bytes`], using the [`cache`](crate::cache)
+    /// according to `policy`.
+    pub fn request_bytes_with_policy(&self, path: &str, authenticated: bool, policy: CachePolicy) -> RequestFuture<Bytes> {
+        let context = self.context(path, authenticated, policy);
+        let (sender, future) = RequestFuture::channel();
+        self.dispatch(context, |context, body| {
+            context.finish(&body);
+            let _ = sender.send(body);
+        });
+        future
+    }
+
+    /// Queues a new request for execution and returns a [`Stream`] of the items in its response,
+    /// which are deserialized while the response is being received.
+    ///
+    /// The items are the elements of the first array, or the values of the first object, in the
+    /// root of the response, e.g. the `"auctions"` of `/skyblock/auctions` or the `"products"` of
+    /// `/skyblock/bazaar`. Other fields of the response are skipped. Only the item that is
+    /// currently received is kept in memory, which keeps memory usage low for large responses.
+    ///
+    /// An item that fails to deserialize is yielded as an error, the remaining items are still
+    /// yielded. If the request itself fails, its error is the last item. Dropping the stream cancels
+    /// the request. Responses of streamed requests are not recorded to a [`Cassette`].
+    ///
+    /// Streamed requests run on a task of their own rather than on a worker,
+    /// a slow reader of the stream does not hold up other requests.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use futures::StreamExt;
+    /// use hypixel_api::{Auction, RequestHandler};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let mut auctions = Box::pin(request_handler.request_stream_items::<Auction>("skyblock/auctions?page=0", false));
+    /// while let Some(auction) = auctions.next().await {
+    ///     let auction = auction.unwrap();
+    ///     // use auction ...
+    /// }
+    /// # }
+    /// ```
+    pub fn request_stream_items<T: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool) -> impl Stream<Item = Result<T, HypixelApiError>> + Send + 'static {
+        let context = self.context(path, authenticated, CachePolicy::Bypass);
+        #[cfg(feature = "tracing")]
+        let span = context.span();
+        let (item_tx, item_rx) = mpsc::channel(STREAM_BUFFER);
+        let task = async move {
+            let mut scanner = ItemScanner::new();
+            let result = async {
+                match context.shared.decoder.vcr.as_deref() {
+                    Some(Vcr::Replay(cassette)) => scanner.push(cassette.replay(&context.path)?.as_bytes()),
+                    _ => {
+                        let response = context.send(None).await?;
+                        let mut response = context.shared.decoder.check_status(&context.path, context.authenticated, response).await?;
+                        while let Some(chunk) = response.chunk().await? {
+                            scanner.push(&chunk);
+                            while let Some(item) = scanner.next_item() {
+                                if item_tx.send(item).await.is_err() {
+                                    return Ok(());
+                                }
+                            }
+                        }
+                    }
+                }
+                while let Some(item) = scanner.next_item() {
+                    if item_tx.send(item).await.is_err() {
+                        return Ok(());
+                    }
                 }
+                scanner.finish()
+            }.await;
+            context.finish(&result);
+            if let Err(error) = result {
+                let _ = item_tx.send(Err(error)).await;
             }
+        };
+        #[cfg(feature = "tracing")]
+        let task = tracing::Instrument::instrument(task, span);
+        tokio::spawn(task);
+        futures::stream::unfold(item_rx, |mut item_rx| async move {
+            item_rx.recv().await.map(|item| (item, item_rx))
         })
     }
 
-    #[cfg_attr(feature = "tracing", tracing::instrument(name = "try_send", level = "trace", skip_all))]
-    async fn try_request(client: &Client, url: &str, api_key: &str, throttler: &Arc<Mutex<RequestThrottler>>, authenticated: bool) -> Result<Option<Response>, HypixelApiError> {
-        let mut watcher = None;
-        loop {
-            let ticket = {
-                let mut throttler = throttler.lock();
-                let (ticket, wait_rx) = throttler.request_ticket();
-                if watcher.is_none() {
-                    watcher = Some(wait_rx);
+    /// Returns a [`Stream`] that requests every path of `paths` and yields each path along with
+    /// its result, in the order the requests finish.
+    ///
+    /// At most `concurrency` of these requests are queued at a time, the next path is taken from
+    /// `paths` as soon as one finished, and only while the stream is polled. Requests queued by
+    /// other tasks in the meantime are therefore served in between rather than after the whole
+    /// job, and a long or endless iterator does not flood the queue. All requests are throttled
+    /// like any other request of this handler.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use futures::StreamExt;
+    /// use hypixel_api::{ApiPath, RequestHandler, SkyblockAuctionsReply};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let pages = (0..50).map(|page| ApiPath::SkyblockAuctionsPage { page });
+    /// let mut replies = Box::pin(request_handler.stream_requests::<SkyblockAuctionsReply, _>(pages, 4));
+    /// while let Some((path, reply)) = replies.next().await {
+    ///     match reply {
+    ///         Ok(reply) => println!("{}: {} auctions", path, reply.auctions().len()),
+    ///         Err(error) => println!("{} failed: {}", path, error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn stream_requests<T, I>(&self, paths: I, concurrency: usize) -> impl Stream<Item = (ApiPath, Result<T, HypixelApiError>)> + Send + 'static
+    where
+        T: DeserializeOwned + Send + 'static,
+        I: IntoIterator<Item = ApiPath>,
+        I::IntoIter: Send + 'static,
+    {
+        let handler = self.clone();
+        futures::stream::iter(paths)
+            .map(move |path| {
+                let request = handler.request_typed::<T>(path.clone());
+                async move {
+                    let result = request.await.map_err(HypixelApiError::from).and_then(|result| result);
+                    (path, result)
                 }
-                ticket
-            };
-            if ticket {
-                break Ok(());
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+
+    /// Creates the context of a new request to `path` and emits [`RequestEvent::Queued`].
+    fn context(&self, path: &str, authenticated: bool, cache_policy: CachePolicy) -> RequestContext {
+        let context = RequestContext {
+            id: RequestId(self.shared.next_id.fetch_add(1, Ordering::Relaxed)),
+            path: path.to_string(),
+            authenticated,
+            cache_policy,
+            tag: self.tag.clone(),
+            queued_at: Instant::now(),
+            timing: Mutex::new(Timing::default()),
+            shared: Arc::clone(&self.shared),
+        };
+        context.emit(RequestEvent::Queued { id: context.id, path: context.path.clone() });
+        if let Some(journal) = &self.shared.journal {
+            journal.queued(path, authenticated, self.tag.as_deref());
+        }
+        context
+    }
+
+    /// Queues `context` for execution by a worker, which calls `complete` with the received body.
+    fn dispatch<F>(&self, context: RequestContext, complete: F)
+    where
+        F: FnOnce(&RequestContext, Result<Bytes, HypixelApiError>) + Send + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        let span = context.span();
+        let job = async move {
+            let body = context.body().await;
+            complete(&context, body);
+        };
+        #[cfg(feature = "tracing")]
+        let job = tracing::Instrument::instrument(job, span);
+        self.dispatcher.submit(job);
+    }
+}
+
+/// The state of a single request shared by its lifecycle events.
+struct RequestContext {
+    id: RequestId,
+    path: String,
+    authenticated: bool,
+    cache_policy: CachePolicy,
+    tag: Option<Cow<'static, str>>,
+    queued_at: Instant,
+    timing: Mutex<Timing>,
+    shared: Arc<SharedState>,
+}
+
+/// How a request was executed so far, see [`ResponseMeta`].
+#[derive(Debug, Default)]
+struct Timing {
+    first_sent: Option<Duration>,
+    network: Duration,
+    attempts: u32,
+    retries: u32,
+    from_cache: bool,
+}
+
+impl RequestContext {
+    #[cfg(feature = "tracing")]
+    fn span(&self) -> tracing::Span {
+        info_span!(
+            "request",
+            id = self.id.get(),
+            endpoint = %endpoint(&self.path),
+            authenticated = self.authenticated,
+            status = tracing::field::Empty,
+            ratelimit_remaining = tracing::field::Empty,
+            attempt = tracing::field::Empty,
+            queue_wait_ms = tracing::field::Empty,
+            bytes = tracing::field::Empty,
+        )
+    }
+
+    /// Returns the body of the response, replayed from a cassette, taken from the cache
+    /// or received from the API.
+    async fn body(&self) -> Result<Bytes, HypixelApiError> {
+        if let Some(Vcr::Replay(cassette)) = self.shared.decoder.vcr.as_deref() {
+            return self.shared.decoder.inspect(&self.path, Bytes::from(cassette.replay(&self.path)?));
+        }
+        let cache = self.shared.cache.as_ref().filter(|_| self.cache_policy != CachePolicy::Bypass);
+        let reusable = cache.filter(|_| self.cache_policy == CachePolicy::Use);
+        if let Some(body) = reusable.and_then(|cache| self.cached(cache)) {
+            return Ok(body);
+        }
+        // concurrent misses wait for the first fetch of this path and reuse its response if it was
+        // cached, i.e. successful, otherwise they send their own request
+        let _fetching = match reusable {
+            Some(cache) => {
+                let guard = cache.fetch_lock(&self.path).await;
+                if let Some(body) = guard.waited().then(|| self.cached(cache)).flatten() {
+                    return Ok(body);
+                }
+                Some(guard)
             }
-            if let Err(error) = watcher.as_mut().unwrap().changed().await {
-                break Err(error);
+            None => None,
+        };
+        let mut retry = 0;
+        loop {
+            let result = self.fetch(cache).await;
+            retry += 1;
+            match result.as_ref().err().and_then(|error| self.shared.retry.retry_delay(retry, error)) {
+                Some(delay) => {
+                    self.timing.lock().retries += 1;
+                    #[cfg(feature = "tracing")]
+                    warn!(retry, delay_ms = delay.as_millis() as u64, "Retrying transient failure");
+                    tokio::time::sleep(delay).await;
+                }
+                None => return result,
             }
-        }?;
+        }
+    }
 
-        let mut response = client.get(url);
-        if authenticated {
-            response = response.header("API-Key", api_key);
+    /// Returns the cached body of the response, refreshing it in the background if it is stale.
+    fn cached(&self, cache: &ResponseCache) -> Option<Bytes> {
+        let (body, revalidate) = cache.lookup(&self.path)?;
+        self.mark_from_cache();
+        #[cfg(feature = "tracing")]
+        debug!(revalidate, "Served from cache");
+        if revalidate {
+            self.revalidate();
         }
-        let response = response.send().await?;
+        Some(body)
+    }
 
-        let status_code = response.status();
-        let headers = response.headers();
-        let time_before_reset = get_from_headers(headers, "ratelimit-reset", 10)?.max(1);
-        let requests_remaining = get_from_headers(headers, "ratelimit-remaining", 110)?.max(1);
-        let result_check = {
-            let mut throttler = throttler.lock();
-            throttler.on_received(status_code, time_before_reset, requests_remaining)
+    /// Receives the body of the response from the API, revalidating and updating `cache`.
+    async fn fetch(&self, cache: Option<&ResponseCache>) -> Result<Bytes, HypixelApiError> {
+        let validators = cache.and_then(|cache| cache.validators(&self.path));
+        let mut response = self.send(validators.as_ref()).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            match cache.and_then(|cache| cache.revalidated(&self.path)) {
+                Some(body) => {
+                    self.mark_from_cache();
+                    #[cfg(feature = "tracing")]
+                    debug!("Revalidated cached response");
+                    return Ok(body);
+                }
+                // the cached response was evicted in the meantime
+                None => response = self.send(None).await?,
+            }
+        }
+        let headers = response.headers().clone();
+        let start = Instant::now();
+        let body = self.shared.decoder.read(&self.path, self.authenticated, response).await;
+        self.timing.lock().network += start.elapsed();
+        let body = body?;
+        match cache {
+            Some(cache) if ResponseDecoder::cacheable(&body) => {
+                cache.insert_validated(&self.path, body.clone(), Validators::new(&self.path, &headers, &body));
+            }
+            // a stale response is kept and refreshed again rather than replaced by an unsuccessful one
+            Some(cache) => cache.revalidation_failed(&self.path),
+            None => {}
+        }
+        Ok(body)
+    }
+
+    /// Refreshes the cached response to this request in the background, under the same id.
+    fn revalidate(&self) {
+        let context = RequestContext {
+            id: self.id,
+            path: self.path.clone(),
+            authenticated: self.authenticated,
+            cache_policy: CachePolicy::Refresh,
+            tag: self.tag.clone(),
+            queued_at: Instant::now(),
+            timing: Mutex::new(Timing::default()),
+            shared: Arc::clone(&self.shared),
         };
-        match result_check {
-            Ok(result) => {
-                if result {
-                    Ok(Some(response))
-                } else {
-                    Ok(None)
+        tokio::spawn(async move {
+            if context.body().await.is_err() {
+                if let Some(cache) = &context.shared.cache {
+                    cache.revalidation_failed(&context.path);
                 }
             }
-            Err(HypixelApiError::UnexpectedResponseCode(code, _)) => {
-                let cause = response.json::<ErrorReply>().await.ok();
-                Err(HypixelApiError::UnexpectedResponseCode(code, cause))
+        });
+    }
+
+    /// Sends the request once the rate limit allows it, retrying after `429 Too Many Requests`.
+    ///
+    /// With `validators`, the request is conditional and may be answered with `304 Not Modified`.
+    async fn send(&self, validators: Option<&Validators>) -> Result<Response, HypixelApiError> {
+        #[cfg(feature = "metrics")]
+        let endpoint = endpoint(&self.path);
+        let url = format!("{}{}", self.shared.base_url, self.path);
+        // parameters of the path take precedence over the defaults
+        let keys: Vec<_> = self.path.split_once('?').into_iter()
+            .flat_map(|(_, query)| query.split('&'))
+            .map(|pair| pair.split_once('=').map_or(pair, |(key, _)| key))
+            .collect();
+        let default_query: Vec<_> = self.shared.default_query.iter()
+            .filter(|(key, _)| !keys.contains(&key.as_str()))
+            .collect();
+        let api_key = *self.shared.api_key.read();
+        let mut key_header = HeaderValue::from_str(&api_key.expose().hyphenated().to_string())
+            .expect("a UUID is a valid header value");
+        key_header.set_sensitive(true);
+        let mut attempt = 0;
+        let throttled = RequestThrottler::send(&self.shared.throttler, self.authenticated, || {
+            attempt += 1;
+            self.emit(RequestEvent::Sent { id: self.id, attempt });
+            self.mark_sent();
+            let mut request = self.shared.client.get(&url).query(&default_query);
+            if self.authenticated {
+                request = request.header("API-Key", key_header.clone());
+            }
+            if let Some(validators) = validators {
+                if let Some(etag) = &validators.etag {
+                    request = request.header(IF_NONE_MATCH, etag);
+                }
+                if let Some(last_modified) = &validators.last_modified {
+                    request = request.header(IF_MODIFIED_SINCE, last_modified);
+                }
+            }
+            #[cfg(feature = "metrics")]
+            let endpoint = &endpoint;
+            async move {
+                let start = Instant::now();
+                let response = request.send().await;
+                self.timing.lock().network += start.elapsed();
+                let response = response?;
+                #[cfg(feature = "metrics")]
+                crate::api::metrics::record_response(endpoint, response.status(), start.elapsed());
+                Ok(response)
+            }
+        });
+        if !self.authenticated {
+            return throttled.await;
+        }
+        let mut key_invalid = self.shared.key_invalid.subscribe();
+        // cancelling a request that is in flight returns its ticket if it was the first of its window
+        let response = tokio::select! {
+            biased;
+            _ = key_invalid.wait_for(|invalid| *invalid) => return Err(HypixelApiError::InvalidApiKey),
+            response = throttled => response?,
+        };
+        #[cfg(feature = "tracing")]
+        debug!("Received response");
+        if response.status() == StatusCode::FORBIDDEN {
+            // a response to a key that was replaced in the meantime says nothing about the new key
+            if self.shared.api_key.read().expose() == api_key.expose() {
+                #[cfg(feature = "tracing")]
+                warn!("The API key was rejected, failing all authenticated requests until it is replaced");
+                self.shared.key_invalid.send_replace(true);
+            }
+            return Err(HypixelApiError::InvalidApiKey);
+        }
+        Ok(response)
+    }
+
+    fn mark_sent(&self) {
+        let mut timing = self.timing.lock();
+        timing.attempts += 1;
+        timing.first_sent.get_or_insert_with(|| self.queued_at.elapsed());
+    }
+
+    fn mark_from_cache(&self) {
+        let mut timing = self.timing.lock();
+        timing.from_cache = true;
+        timing.first_sent.get_or_insert_with(|| self.queued_at.elapsed());
+    }
+
+    /// Returns how this request was executed, for a request that finished.
+    fn meta(&self) -> ResponseMeta {
+        let timing = self.timing.lock();
+        let total = self.queued_at.elapsed();
+        ResponseMeta {
+            id: self.id,
+            total,
+            queue_wait: timing.first_sent.unwrap_or(total),
+            network: timing.network,
+            attempts: timing.attempts,
+            retries: timing.retries,
+            from_cache: timing.from_cache,
+        }
+    }
+
+    fn emit(&self, event: RequestEvent) {
+        // an error only means there are no subscribers
+        let _ = self.shared.events.send(event);
+    }
+
+    fn finish<T>(&self, result: &Result<T, HypixelApiError>) {
+        let latency = self.queued_at.elapsed();
+        self.shared.stats.record(latency, result.is_ok(), self.tag.as_deref());
+        if let Some(journal) = &self.shared.journal {
+            journal.finished(&self.path);
+        }
+        #[cfg(feature = "metrics")]
+        if let Some(tag) = &self.tag {
+            crate::api::metrics::record_tagged(tag, result.is_ok(), latency);
+        }
+        self.emit(match result {
+            Ok(_) => RequestEvent::Completed { id: self.id, latency, status: StatusCode::OK },
+            Err(error) => RequestEvent::Failed { id: self.id, latency, error: error.to_string() },
+        });
+    }
+}
+
+/// Returns the endpoint of `path`, the path without its query, e.g. `"skyblock/auctions"`.
+#[cfg_attr(not(any(feature = "metrics", feature = "tracing")), allow(dead_code))]
+pub(crate) fn endpoint(path: &str) -> &str {
+    path.split('?').next().unwrap_or_default().trim_matches('/')
+}
+
+/// A builder to configure a [`RequestHandler`], see [`RequestHandler::builder`].
+pub struct RequestHandlerBuilder {
+    api_key: SecretKey,
+    base_url: String,
+    default_query: Vec<(String, String)>,
+    client: Option<Client>,
+    vcr: Option<Vcr>,
+    #[cfg(feature = "diagnostics")]
+    diagnostics: Option<DiagnosticsCallback>,
+    #[cfg(feature = "diagnostics")]
+    strict: bool,
+    #[cfg(feature = "body-capture")]
+    capture_length: Option<usize>,
+    inspector: Option<BodyInspector>,
+    cache: Option<CacheConfig>,
+    journal: Option<Journal>,
+    workers: usize,
+    queue_capacity: usize,
+    rate_limit_fallback: RateLimitFallback,
+    retry: RetryPolicy,
+    budget: DeserializationBudget,
+    page_limits: PageLimits,
+}
+
+impl std::fmt::Debug for RequestHandlerBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestHandlerBuilder")
+            .field("api_key", &self.api_key)
+            .field("base_url", &self.base_url)
+            .field("default_query", &self.default_query)
+            .field("client", &self.client)
+            .field("vcr", &self.vcr)
+            .field("inspector", &self.inspector.is_some())
+            .field("cache", &self.cache)
+            .field("journal", &self.journal)
+            .field("workers", &self.workers)
+            .field("queue_capacity", &self.queue_capacity)
+            .field("rate_limit_fallback", &self.rate_limit_fallback)
+            .field("retry", &self.retry)
+            .field("budget", &self.budget)
+            .field("page_limits", &self.page_limits)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RequestHandlerBuilder {
+    /// Sets the base URL all request paths are appended to, [`DEFAULT_BASE_URL`] by default.
+    ///
+    /// This is useful to send requests to a proxy or a mock server.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        self.base_url = base_url;
+        self
+    }
+
+    /// Appends the query parameter `key` with `value` to every request, e.g. a token required
+    /// by a proxy in front of the API. Can be called multiple times for multiple parameters.
+    ///
+    /// A parameter with the same key in the path of a request takes precedence. The value is
+    /// percent-encoded, and the [`cache`](crate::cache) keys responses by their path without
+    /// these parameters.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use hypixel_api::RequestHandler;
+    /// # use uuid::Uuid;
+    ///
+    /// # fn main() {
+    /// let request_handler = RequestHandler::builder(Uuid::nil())
+    ///     .base_url("http://localhost:8080")
+    ///     .query("token", "secret")
+    ///     .build();
+    /// assert_eq!(request_handler.default_query(), [("token".to_string(), "secret".to_string())]);
+    /// # }
+    /// ```
+    pub fn query(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_query.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets the [`Client`] used to send requests.
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Records every successful response to a new [`Cassette`] at `path`,
+    /// overwriting any existing file.
+    pub fn record(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.vcr = Some(Vcr::Record(Cassette::new(path)));
+        self
+    }
+
+    /// Serves every request from `cassette` instead of the network.
+    ///
+    /// Requests to paths that were not recorded fail with [`HypixelApiError::CassetteMiss`].
+    pub fn replay(mut self, cassette: Cassette) -> Self {
+        self.vcr = Some(Vcr::Replay(cassette));
+        self
+    }
+
+    /// Checks every response against the data structure it is deserialized into and
+    /// calls `callback` with a report of the fields that were ignored or failed to deserialize.
+    ///
+    /// With the `tracing` feature enabled, these reports are logged as well.
+    /// See [`diagnostics`](crate::diagnostics).
+    #[cfg(feature = "diagnostics")]
+    pub fn diagnostics(mut self, callback: impl Fn(&DiagnosticsReport) + Send + Sync + 'static) -> Self {
+        self.diagnostics = Some(Arc::new(callback));
+        self
+    }
+
+    /// Fails responses with fields the data structure does not know with
+    /// [`HypixelApiError::UnknownFields`], instead of ignoring these fields.
+    ///
+    /// Meant for tests against recorded responses, see [strict mode](crate::diagnostics#strict-mode).
+    /// Reports are still passed to the callback of [`RequestHandlerBuilder::diagnostics`].
+    #[cfg(feature = "diagnostics")]
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Captures the raw bodies of failed responses, truncated to `max_length` bytes.
+    /// See [`capture`](crate::capture).
+    #[cfg(feature = "body-capture")]
+    pub fn capture_failed_bodies(mut self, max_length: usize) -> Self {
+        self.capture_length = Some(max_length);
+        self
+    }
+
+    /// Passes the body of every successful response along with its request path to `inspector`
+    /// before it is deserialized, cached or returned as bytes.
+    ///
+    /// `inspector` may patch the body in place, e.g. to replace values Hypixel occasionally
+    /// emits that do not fit the data structures, or reject it by returning a reason, which
+    /// fails the request with [`HypixelApiError::BodyRejected`]. Streamed requests, see
+    /// [`RequestHandler::request_stream_items`], are not inspected.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use hypixel_api::RequestHandler;
+    /// # use uuid::Uuid;
+    ///
+    /// # fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::builder(api_key)
+    ///     .inspect_body(|path, body| {
+    ///         if path.starts_with("skyblock/") {
+    ///             let patched = String::from_utf8_lossy(body).replace("\"Infinity\"", "0");
+    ///             *body = patched.into_bytes();
+    ///         }
+    ///         Ok(())
+    ///     })
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn inspect_body(mut self, inspector: impl Fn(&str, &mut Vec<u8>) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        self.inspector = Some(Arc::new(inspector));
+        self
+    }
+
+    /// Caches successful responses in memory, bounded as configured by `config`.
+    /// See [`cache`](crate::cache).
+    pub fn cache(mut self, config: CacheConfig) -> Self {
+        self.cache = Some(config);
+        self
+    }
+
+    /// Writes every queued request to `journal` until it finished, to resume after a crash.
+    /// See [`Journal`].
+    pub fn journal(mut self, journal: Journal) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// Sets the maximum amount of requests that are executed concurrently, 32 by default.
+    ///
+    /// Requests are queued and executed by this many workers, so queueing many requests
+    /// at once does not spawn a task per request. A request that is waiting for the rate
+    /// limit occupies its worker.
+    pub fn workers(mut self, workers: usize) -> Self {
+        self.workers = workers;
+        self
+    }
+
+    /// Sets the queue length below which [`RequestHandler::request_when_ready`] queues
+    /// a request, 1024 by default. A capacity of `0` is treated as `1`.
+    ///
+    /// Only requests that no worker has picked up yet count towards the queue length.
+    pub fn queue_capacity(mut self, queue_capacity: usize) -> Self {
+        self.queue_capacity = queue_capacity;
+        self
+    }
+
+    /// Sets the rate limit that is assumed for responses without `ratelimit-*` headers,
+    /// see [`RateLimitFallback`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::time::Duration;
+    /// use hypixel_api::{RateLimitFallback, RequestHandler};
+    /// # use uuid::Uuid;
+    ///
+    /// # fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::builder(api_key)
+    ///     .rate_limit_fallback(RateLimitFallback::new(Duration::from_secs(60), 1).strict(true))
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn rate_limit_fallback(mut self, fallback: RateLimitFallback) -> Self {
+        self.rate_limit_fallback = fallback;
+        self
+    }
+
+    /// Sets which transient failures are retried, see [`RetryPolicy`].
+    ///
+    /// Use [`RetryPolicy::disabled`] to fail on the first response.
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Limits the size of the responses that are deserialized, see [`DeserializationBudget`].
+    ///
+    /// Nothing is limited by default.
+    pub fn deserialization_budget(mut self, budget: DeserializationBudget) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Limits the pages, items and requests of the helpers that send many requests on their
+    /// own, see [`PageLimits`].
+    ///
+    /// Nothing is limited by default.
+    pub fn page_limits(mut self, limits: PageLimits) -> Self {
+        self.page_limits = limits;
+        self
+    }
+
+    /// Creates the configured [`RequestHandler`].
+    pub fn build(self) -> RequestHandler {
+        let shared = SharedState {
+            client: self.client.unwrap_or_default(),
+            api_key: parking_lot::RwLock::new(self.api_key),
+            #[cfg(feature = "legacy-key")]
+            key_owner: parking_lot::RwLock::new(None),
+            key_invalid: watch::channel(false).0,
+            base_url: self.base_url,
+            default_query: self.default_query,
+            throttler: RequestThrottler::new(self.rate_limit_fallback),
+            decoder: ResponseDecoder {
+                vcr: self.vcr.map(Arc::new),
+                #[cfg(feature = "diagnostics")]
+                diagnostics: self.diagnostics,
+                #[cfg(feature = "diagnostics")]
+                strict: self.strict,
+                #[cfg(feature = "body-capture")]
+                capture: self.capture_length.map(|length| Arc::new(BodyCapture::new(length))),
+                inspector: self.inspector,
+                budget: Arc::new(self.budget),
+            },
+            events: broadcast::channel(EVENT_CAPACITY).0,
+            stats: StatsRecorder::new(),
+            cache: self.cache.map(ResponseCache::new),
+            journal: self.journal,
+            retry: self.retry,
+            page_limits: self.page_limits,
+            next_id: AtomicU64::new(0),
+        };
+        RequestHandler {
+            shared: Arc::new(shared),
+            dispatcher: Dispatcher::new(self.workers, self.queue_capacity),
+            tag: None,
+        }
+    }
+}
+
+/// Turns successful responses into data structures.
+#[derive(Clone)]
+struct ResponseDecoder {
+    vcr: Option<Arc<Vcr>>,
+    #[cfg(feature = "diagnostics")]
+    diagnostics: Option<DiagnosticsCallback>,
+    #[cfg(feature = "diagnostics")]
+    strict: bool,
+    #[cfg(feature = "body-capture")]
+    capture: Option<Arc<BodyCapture>>,
+    inspector: Option<BodyInspector>,
+    budget: Arc<DeserializationBudget>,
+}
+
+impl ResponseDecoder {
+    /// Turns a response with an unexpected status code into an error.
+    async fn check_status(&self, _path: &str, _authenticated: bool, response: Response) -> Result<Response, HypixelApiError> {
+        let status_code = response.status();
+        if status_code == StatusCode::OK {
+            return Ok(response);
+        }
+        let body = ResponseDecoder::error_body(response).await;
+        let error = HypixelApiError::UnexpectedResponseCode(status_code, serde_json::from_slice::<ErrorReply>(&body).ok());
+        #[cfg(feature = "body-capture")]
+        if let Some(capture) = &self.capture {
+            capture.capture(_path, _authenticated, status_code, &body, &error);
+        }
+        Err(error)
+    }
+
+    /// Reads at most [`MAX_ERROR_BODY`] bytes of the body of an unsuccessful response, a larger
+    /// body is cut off and carries no cause.
+    async fn error_body(mut response: Response) -> Vec<u8> {
+        let mut body = Vec::new();
+        while let Ok(Some(chunk)) = response.chunk().await {
+            if body.len() + chunk.len() > MAX_ERROR_BODY {
+                body.extend_from_slice(&chunk[..MAX_ERROR_BODY - body.len()]);
+                break;
             }
-            Err(error) => Err(error)
+            body.extend_from_slice(&chunk);
+        }
+        body
+    }
+
+    /// Reads the body of a successful response, recording it if a cassette is recording.
+    async fn read(&self, path: &str, authenticated: bool, response: Response) -> Result<Bytes, HypixelApiError> {
+        let response = self.check_status(path, authenticated, response).await?;
+        let body = self.download(path, response).await?;
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("bytes", body.len());
+        if let Some(Vcr::Record(cassette)) = self.vcr.as_deref() {
+            cassette.record(path, &String::from_utf8_lossy(&body))?;
         }
+        self.inspect(path, body)
+    }
+
+    /// Reads the body of `response`, stopping as soon as it exceeds the byte limit of the budget.
+    async fn download(&self, path: &str, mut response: Response) -> Result<Bytes, HypixelApiError> {
+        if self.budget.limits(path).0.is_none() {
+            return Ok(response.bytes().await?);
+        }
+        if let Some(length) = response.content_length() {
+            self.budget.check_length(path, usize::try_from(length).unwrap_or(usize::MAX))?;
+        }
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            self.budget.check_length(path, body.len() + chunk.len())?;
+            body.extend_from_slice(&chunk);
+        }
+        Ok(Bytes::from(body))
+    }
+
+    /// Passes `body` to the inspector, if any, see [`RequestHandlerBuilder::inspect_body`].
+    fn inspect(&self, path: &str, body: Bytes) -> Result<Bytes, HypixelApiError> {
+        let Some(inspector) = &self.inspector else {
+            return Ok(body);
+        };
+        let mut body = Vec::from(body);
+        inspector(path, &mut body).map_err(|reason| HypixelApiError::BodyRejected(path.to_string(), reason))?;
+        Ok(Bytes::from(body))
+    }
+
+    fn decode<T: DeserializeOwned>(&self, path: &str, authenticated: bool, body: &[u8]) -> Result<T, HypixelApiError> {
+        self.budget.check(path, body)?;
+        self.decode_within_budget(path, authenticated, body)
+    }
+
+    /// Decodes `body` like [`ResponseDecoder::decode`], after turning `"success": false` into an error.
+    fn decode_validated<T: DeserializeOwned>(&self, path: &str, authenticated: bool, body: &[u8]) -> Result<T, HypixelApiError> {
+        self.budget.check(path, body)?;
+        ResponseDecoder::validate(body)?;
+        self.decode_within_budget(path, authenticated, body)
+    }
+
+    fn decode_within_budget<T: DeserializeOwned>(&self, path: &str, _authenticated: bool, body: &[u8]) -> Result<T, HypixelApiError> {
+        #[cfg(feature = "diagnostics")]
+        let result = match (&self.diagnostics, self.strict) {
+            (None, false) => ResponseDecoder::parse(body),
+            (callback, strict) => crate::api::diagnostics::deserialize_reported(path, body, callback.as_ref(), strict),
+        };
+        #[cfg(not(feature = "diagnostics"))]
+        let result = ResponseDecoder::parse(body);
+        #[cfg(feature = "body-capture")]
+        if let (Some(capture), Err(error)) = (&self.capture, &result) {
+            capture.capture(path, _authenticated, StatusCode::OK, body, error);
+        }
+        ResponseDecoder::observe(path, result)
+    }
+
+    /// Deserializes `body` with simd-json.
+    ///
+    /// simd-json parses in place, so `body` is copied first. Falls back to serde_json if
+    /// simd-json fails, e.g. for data structures that borrow a
+    /// [`RawValue`](serde_json::value::RawValue) from serde_json.
+    #[cfg(feature = "simd-json")]
+    fn parse<T: DeserializeOwned>(body: &[u8]) -> Result<T, HypixelApiError> {
+        let mut bytes = body.to_vec();
+        match simd_json::serde::from_slice(&mut bytes) {
+            Ok(value) => Ok(value),
+            Err(_) => serde_json::from_slice(body).map_err(|e| e.into()),
+        }
+    }
+
+    #[cfg(not(feature = "simd-json"))]
+    fn parse<T: DeserializeOwned>(body: &[u8]) -> Result<T, HypixelApiError> {
+        serde_json::from_slice(body).map_err(|e| e.into())
+    }
+
+    /// Turns a body that reports `"success": false` into an error with the cause given by the API.
+    ///
+    /// Runs before the body is decoded, so replies that require their payload fail the
+    /// same way as replies where it is optional.
+    fn validate(body: &[u8]) -> Result<(), HypixelApiError> {
+        #[derive(Deserialize)]
+        struct SuccessProbe {
+            success: Option<bool>,
+            cause: Option<String>,
+        }
+
+        match serde_json::from_slice::<SuccessProbe>(body) {
+            Ok(SuccessProbe { success: Some(false), cause }) => Err(HypixelApiError::Unsuccessful(cause)),
+            // malformed bodies are reported by the decoder
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns `true` if `body` may be cached, i.e. it is valid JSON and does not report
+    /// `"success": false`.
+    ///
+    /// Cached bodies are shared by requests of any type, so they are not decoded here.
+    fn cacheable(body: &[u8]) -> bool {
+        serde_json::from_slice::<IgnoredAny>(body).is_ok() && ResponseDecoder::validate(body).is_ok()
+    }
+
+    /// Records failures to deserialize `result`, the decoded response to `path`.
+    fn observe<T>(_path: &str, result: Result<T, HypixelApiError>) -> Result<T, HypixelApiError> {
+        #[cfg(feature = "metrics")]
+        if let Err(HypixelApiError::SerdeJsonError { .. }) = &result {
+            crate::api::metrics::record_deserialization_failure(endpoint(_path));
+        }
+        result
     }
 }
 
-fn get_from_headers<K: AsHeaderName, E: Error + Send + Sync + 'static, T: FromStr<Err=E> + Copy>(headers: &HeaderMap, name: K, default: T) -> Result<T, HypixelApiError> {
-    headers.get(name)
-        .map(|o| o.to_str())
-        .map(|o| o.map_or(Ok(default), |s| s.parse::<T>().map_err(|_| HypixelApiError::IntFromStrError(String::from(s)))))
-        .unwrap_or(Ok(default))
+/// The request surface of the Hypixel API, implemented by [`RequestHandler`].
+///
+/// Code that takes `impl HypixelApi` instead of a [`RequestHandler`] can be tested
+/// with a mock implementation, without network access or a real `ApiKey`.
+#[cfg_attr(feature = "test-util", doc = "See [`MockHypixelApi`](crate::MockHypixelApi) for a ready-to-use mock.")]
+///
+/// # Examples
+/// ```rust,no_run
+/// use hypixel_api::{HypixelApi, StatusReply};
+/// # use hypixel_api::error::HypixelApiError;
+///
+/// async fn is_online(api: &impl HypixelApi, uuid: &str) -> Result<bool, HypixelApiError> {
+///     let reply = api.request::<StatusReply>(&format!("status?uuid={}", uuid), true).await.unwrap()?;
+///     Ok(reply.online())
+/// }
+/// ```
+pub trait HypixelApi {
+    /// Queues a new request for execution and returns a [`RequestFuture`] to it.
+    ///
+    /// See [`RequestHandler::request`].
+    fn request<T: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool) -> RequestFuture<T>;
+
+    /// Queues a new request to the typed endpoint `path`.
+    ///
+    /// See [`RequestHandler::request_typed`]. Like it, the default fails with
+    /// [`HypixelApiError::Unsuccessful`] if the response reports `"success": false`: it requests
+    /// the raw body with [`HypixelApi::request`] and validates it in a task spawned on the
+    /// current tokio runtime before decoding `T`.
+    fn request_typed<T: DeserializeOwned + Send + 'static>(&self, path: ApiPath) -> RequestFuture<T> {
+        let body = self.request::<Box<RawValue>>(&path.to_path(), path.is_authenticated());
+        let (sender, future) = RequestFuture::channel();
+        tokio::spawn(async move {
+            // a dropped request drops the sender as well
+            let Ok(result) = body.await else { return };
+            let result = result.and_then(|body| {
+                ResponseDecoder::validate(body.get().as_bytes())?;
+                serde_json::from_str(body.get()).map_err(HypixelApiError::from)
+            });
+            let _ = sender.send(result);
+        });
+        future
+    }
+
+    /// Queues a new request for execution, using a cache according to `policy`.
+    ///
+    /// See [`RequestHandler::request_with_policy`]. Implementations without a cache
+    /// ignore the policy, which is the default.
+    fn request_with_policy<T: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool, _policy: CachePolicy) -> RequestFuture<T> {
+        self.request(path, authenticated)
+    }
+
+    /// Returns the usage of the `ApiKey` as reported by the most recent authenticated response.
+    ///
+    /// See [`RequestHandler::key_usage`].
+    fn key_usage(&self) -> Option<KeyUsage>;
+
+    /// Returns the limits of the helpers that send many requests.
+    ///
+    /// See [`RequestHandler::page_limits`]. Nothing is limited by default.
+    fn page_limits(&self) -> PageLimits {
+        PageLimits::new()
+    }
+}
+
+impl HypixelApi for RequestHandler {
+    fn request<T: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool) -> RequestFuture<T> {
+        RequestHandler::request(self, path, authenticated)
+    }
+
+    fn request_typed<T: DeserializeOwned + Send + 'static>(&self, path: ApiPath) -> RequestFuture<T> {
+        RequestHandler::request_typed(self, path)
+    }
+
+    fn request_with_policy<T: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool, policy: CachePolicy) -> RequestFuture<T> {
+        RequestHandler::request_with_policy(self, path, authenticated, policy)
+    }
+
+    fn key_usage(&self) -> Option<KeyUsage> {
+        RequestHandler::key_usage(self)
+    }
+
+    fn page_limits(&self) -> PageLimits {
+        RequestHandler::page_limits(self)
+    }
+}
+
+impl<T: HypixelApi + ?Sized> HypixelApi for &T {
+    fn request<R: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool) -> RequestFuture<R> {
+        (**self).request(path, authenticated)
+    }
+
+    fn request_typed<R: DeserializeOwned + Send + 'static>(&self, path: ApiPath) -> RequestFuture<R> {
+        (**self).request_typed(path)
+    }
+
+    fn request_with_policy<R: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool, policy: CachePolicy) -> RequestFuture<R> {
+        (**self).request_with_policy(path, authenticated, policy)
+    }
+
+    fn key_usage(&self) -> Option<KeyUsage> {
+        (**self).key_usage()
+    }
+
+    fn page_limits(&self) -> PageLimits {
+        (**self).page_limits()
+    }
+}
+
+impl<T: HypixelApi + ?Sized> HypixelApi for Arc<T> {
+    fn request<R: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool) -> RequestFuture<R> {
+        (**self).request(path, authenticated)
+    }
+
+    fn request_typed<R: DeserializeOwned + Send + 'static>(&self, path: ApiPath) -> RequestFuture<R> {
+        (**self).request_typed(path)
+    }
+
+    fn request_with_policy<R: DeserializeOwned + Send + 'static>(&self, path: &str, authenticated: bool, policy: CachePolicy) -> RequestFuture<R> {
+        (**self).request_with_policy(path, authenticated, policy)
+    }
+
+    fn key_usage(&self) -> Option<KeyUsage> {
+        (**self).key_usage()
+    }
+
+    fn page_limits(&self) -> PageLimits {
+        (**self).page_limits()
+    }
 }
@@ -0,0 +1,102 @@
+use std::time::Duration;
+use reqwest::StatusCode;
+use crate::api::error::HypixelApiError;
+
+/// Decides which failed responses are transient and retried by a
+/// [`RequestHandler`](crate::RequestHandler), see
+/// [`RequestHandlerBuilder::retry`](crate::RequestHandlerBuilder::retry).
+///
+/// Hypixel answers some requests with `503 Service Unavailable` during maintenance, or with a
+/// cause like `"Leaderboard data has not yet been populated"` that resolves within seconds.
+/// A response is retried if its status code or its cause (matched case-insensitively as a
+/// substring) is in the lists of this policy. The `n`-th retry waits `n` times the delay.
+///
+/// By default, responses with status `503` or a cause containing `"not yet been populated"`
+/// are retried up to 2 times with a delay of 1 second.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use hypixel_api::{RequestHandler, RetryPolicy};
+/// # use uuid::Uuid;
+///
+/// # fn main() {
+/// # let api_key = Uuid::nil();
+/// let request_handler = RequestHandler::builder(api_key)
+///     .retry(RetryPolicy::new().max_retries(5).delay(Duration::from_secs(2)).cause("try again later"))
+///     .build();
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    delay: Duration,
+    statuses: Vec<StatusCode>,
+    causes: Vec<String>,
+}
+
+impl RetryPolicy {
+    /// Creates the default policy.
+    pub fn new() -> Self {
+        RetryPolicy {
+            max_retries: 2,
+            delay: Duration::from_secs(1),
+            statuses: vec![StatusCode::SERVICE_UNAVAILABLE],
+            causes: vec!["not yet been populated".to_string()],
+        }
+    }
+
+    /// Creates a policy that never retries.
+    pub fn disabled() -> Self {
+        RetryPolicy::new().max_retries(0)
+    }
+
+    /// Sets the maximum amount of retries of a single request.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry, later retries wait longer.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Retries responses with status code `status` as well.
+    pub fn status(mut self, status: StatusCode) -> Self {
+        self.statuses.push(status);
+        self
+    }
+
+    /// Retries responses whose cause contains `cause` as well.
+    pub fn cause(mut self, cause: impl Into<String>) -> Self {
+        self.causes.push(cause.into().to_lowercase());
+        self
+    }
+
+    /// Returns `true` if `error` is a response that this policy retries.
+    pub fn is_retryable(&self, error: &HypixelApiError) -> bool {
+        match error {
+            HypixelApiError::UnexpectedResponseCode(status, cause) => {
+                self.statuses.contains(status) || cause.as_ref().is_some_and(|cause| {
+                    let cause = cause.cause().to_lowercase();
+                    self.causes.iter().any(|retryable| cause.contains(retryable.as_str()))
+                })
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the delay before retry number `retry` (starting at `1`) after `error`,
+    /// or [`Option::None`] if the request should not be retried.
+    pub(crate) fn retry_delay(&self, retry: u32, error: &HypixelApiError) -> Option<Duration> {
+        (retry <= self.max_retries && self.is_retryable(error)).then(|| self.delay * retry)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new()
+    }
+}
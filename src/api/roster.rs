@@ -0,0 +1,230 @@
+//! The player data of every member of a guild, see [`RequestHandler::get_guild_roster`].
+
+use std::collections::HashMap;
+use futures::StreamExt;
+use uuid::Uuid;
+use crate::api::error::HypixelApiError;
+use crate::api::path::ApiPath;
+use crate::api::reply::{GuildData, GuildMember, PlayerData, PlayerReply, Reply, StatusData, StatusReply};
+use crate::api::request::RequestHandler;
+
+/// Configures [`RequestHandler::get_guild_roster`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RosterOptions {
+    concurrency: usize,
+    statuses: bool,
+    ranks_first: bool,
+}
+
+impl RosterOptions {
+    /// Creates the default options: 8 members at a time, without statuses, in the order of the guild.
+    pub fn new() -> Self {
+        RosterOptions {
+            concurrency: 8,
+            statuses: false,
+            ranks_first: false,
+        }
+    }
+
+    /// Sets how many members are requested at a time, at least 1.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Requests the status of every member as well, one more request per member.
+    pub fn statuses(mut self, statuses: bool) -> Self {
+        self.statuses = statuses;
+        self
+    }
+
+    /// Requests the guild master first and the other members by the priority of their rank,
+    /// highest first, so that the staff is known first when the roster is cut short.
+    pub fn ranks_first(mut self, ranks_first: bool) -> Self {
+        self.ranks_first = ranks_first;
+        self
+    }
+}
+
+impl Default for RosterOptions {
+    fn default() -> Self {
+        RosterOptions::new()
+    }
+}
+
+/// A member of a guild together with their player data, see [`GuildRoster`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RosterMember {
+    member: GuildMember,
+    player: Option<PlayerData>,
+    status: Option<StatusData>,
+}
+
+impl RosterMember {
+    /// Returns the member's UUID.
+    pub fn uuid(&self) -> Uuid {
+        self.member.uuid()
+    }
+
+    /// Returns the guild membership of this member.
+    pub fn member(&self) -> &GuildMember {
+        &self.member
+    }
+
+    /// Returns the player data of this member.
+    ///
+    /// Returns [`Option::None`] if the request failed or the player has no data on hypixel.
+    pub fn player(&self) -> Option<&PlayerData> {
+        self.player.as_ref()
+    }
+
+    /// Returns the status of this member.
+    ///
+    /// Returns [`Option::None`] if statuses were not requested, see [`RosterOptions::statuses`],
+    /// or if the request failed.
+    pub fn status(&self) -> Option<&StatusData> {
+        self.status.as_ref()
+    }
+}
+
+/// The members of a guild joined with their player data, see [`RequestHandler::get_guild_roster`].
+#[derive(Debug)]
+pub struct GuildRoster {
+    guild_id: String,
+    members: Vec<RosterMember>,
+    errors: HashMap<Uuid, HypixelApiError>,
+}
+
+impl GuildRoster {
+    /// Returns the id of the guild.
+    pub fn guild_id(&self) -> &str {
+        &self.guild_id
+    }
+
+    /// Returns every member, in the order of the guild.
+    pub fn members(&self) -> &[RosterMember] {
+        &self.members
+    }
+
+    /// Returns the member `uuid`, if they are in the guild.
+    pub fn member(&self, uuid: &Uuid) -> Option<&RosterMember> {
+        self.members.iter().find(|member| member.uuid() == *uuid)
+    }
+
+    /// Returns the members that are online, if statuses were requested.
+    pub fn online(&self) -> impl Iterator<Item = &RosterMember> + '_ {
+        self.members.iter().filter(|member| member.status.as_ref().is_some_and(StatusData::online))
+    }
+
+    /// Returns the errors of the members whose requests failed.
+    ///
+    /// If both requests of a member failed, only the error of the player request is kept.
+    pub fn errors(&self) -> &HashMap<Uuid, HypixelApiError> {
+        &self.errors
+    }
+
+    /// Returns `true` if the requests of every member succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the members and the errors.
+    pub fn into_parts(self) -> (Vec<RosterMember>, HashMap<Uuid, HypixelApiError>) {
+        (self.members, self.errors)
+    }
+}
+
+impl RequestHandler {
+    /// Requests the player data (and optionally the status) of every member of `guild`.
+    ///
+    /// At most [`RosterOptions::concurrency`] members are requested at a time, see
+    /// [`RequestHandler::stream_requests`]. A failed request does not fail the roster, the
+    /// member is returned without the data and the error is returned in [`GuildRoster::errors`].
+    /// Use [`RequestHandler::tagged`] to track the requests of the roster separately.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use uuid::Uuid;
+    /// use hypixel_api::{ApiPath, GuildReply, RequestHandler, RosterOptions};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let api_key = Uuid::nil();
+    /// let request_handler = RequestHandler::new(api_key);
+    /// let reply = request_handler.request_typed::<GuildReply>(ApiPath::GuildByName { name: "Hypixel".to_string() })
+    ///     .await.unwrap().unwrap();
+    /// let guild = reply.guild().unwrap();
+    /// let roster = request_handler.get_guild_roster(guild, RosterOptions::new().statuses(true).ranks_first(true)).await;
+    /// for member in roster.online() {
+    ///     let name = member.player().and_then(|player| player.name()).unwrap_or("?");
+    ///     println!("{} ({}) is online", name, member.member().rank());
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_guild_roster(&self, guild: &GuildData, options: RosterOptions) -> GuildRoster {
+        let mut queue: Vec<&GuildMember> = guild.members().iter().collect();
+        if options.ranks_first {
+            queue.sort_by_key(|member| std::cmp::Reverse(rank_priority(guild, member)));
+        }
+        let mut replies = futures::stream::iter(queue)
+            .map(|member| async move {
+                let uuid = member.uuid();
+                let status = async {
+                    match options.statuses {
+                        true => Some(self.request_typed::<StatusReply>(ApiPath::Status { uuid }).await),
+                        false => None,
+                    }
+                };
+                let (player, status) = futures::join!(self.request_typed::<PlayerReply>(ApiPath::Player { uuid }), status);
+                (uuid, player, status)
+            })
+            .buffer_unordered(options.concurrency);
+
+        let mut players = HashMap::new();
+        let mut statuses = HashMap::new();
+        let mut errors = HashMap::new();
+        while let Some((uuid, player, status)) = replies.next().await {
+            if let Some(status) = status {
+                match status.map_err(HypixelApiError::from).and_then(|reply| reply) {
+                    Ok(reply) => {
+                        statuses.insert(uuid, reply.into_data());
+                    }
+                    Err(error) => {
+                        errors.insert(uuid, error);
+                    }
+                }
+            }
+            match player.map_err(HypixelApiError::from).and_then(|reply| reply) {
+                Ok(reply) => {
+                    if let Some(player) = reply.into_data() {
+                        players.insert(uuid, player);
+                    }
+                }
+                Err(error) => {
+                    errors.insert(uuid, error);
+                }
+            }
+        }
+
+        let members = guild.members().iter()
+            .map(|member| RosterMember {
+                member: member.clone(),
+                player: players.remove(&member.uuid()),
+                status: statuses.remove(&member.uuid()),
+            })
+            .collect();
+        GuildRoster {
+            guild_id: guild.id().to_string(),
+            members,
+            errors,
+        }
+    }
+}
+
+/// Returns the priority of the rank of `member`, the guild master ranks above every rank.
+fn rank_priority(guild: &GuildData, member: &GuildMember) -> u32 {
+    if member.is_guild_master() {
+        return u32::MAX;
+    }
+    guild.rank_of(member).map(|rank| rank.priority()).unwrap_or_default()
+}
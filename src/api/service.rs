@@ -0,0 +1,91 @@
+//! Integration with the [tower](https://docs.rs/tower) ecosystem.
+//!
+//! [`RequestHandler`] implements [`Service<HypixelRequest>`], responding with the raw body of the
+//! response. This allows standard middleware such as timeouts, buffers, load shedding and retries
+//! to be stacked on top of the throttling of this crate.
+//!
+//! The handler queues every request it is called with and is therefore always ready, requests
+//! wait for the rate limit after they were called, see [`RequestHandler::request`].
+//!
+//! # Examples
+//! ```rust,no_run
+//! use tower_service::Service;
+//! use hypixel_api::{RequestHandler, StatusReply};
+//! use hypixel_api::service::HypixelRequest;
+//! # use uuid::Uuid;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! # let api_key = Uuid::nil();
+//! let mut request_handler = RequestHandler::new(api_key);
+//! let body = request_handler.call(HypixelRequest::new("status?uuid=069a79f4-44e9-4726-a5be-fca90e38aaf5", true)).await.unwrap();
+//! let reply: StatusReply = serde_json::from_slice(&body).unwrap();
+//! # }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use bytes::Bytes;
+use tower_service::Service;
+use crate::api::dispatch::RequestFuture;
+use crate::api::error::HypixelApiError;
+use crate::api::request::RequestHandler;
+
+/// A request to the Hypixel API, see [`RequestHandler::request`] for the meaning of its parts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HypixelRequest {
+    path: String,
+    authenticated: bool,
+}
+
+impl HypixelRequest {
+    /// Creates a request to `path` (without leading `/`, including the query), sending the
+    /// API key along if `authenticated` is `true`.
+    pub fn new(path: impl Into<String>, authenticated: bool) -> Self {
+        HypixelRequest {
+            path: path.into(),
+            authenticated,
+        }
+    }
+
+    /// Returns the path of this request.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns whether the API key is sent along with this request.
+    pub fn authenticated(&self) -> bool {
+        self.authenticated
+    }
+}
+
+/// The future returned by calling a [`RequestHandler`] as a [`Service`], resolving to the raw body.
+#[derive(Debug)]
+pub struct ResponseFuture {
+    inner: RequestFuture<Bytes>,
+}
+
+impl Future for ResponseFuture {
+    type Output = Result<Bytes, HypixelApiError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.inner).poll(cx).map(|result| result?)
+    }
+}
+
+impl Service<HypixelRequest> for RequestHandler {
+    type Response = Bytes;
+    type Error = HypixelApiError;
+    type Future = ResponseFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: HypixelRequest) -> Self::Future {
+        ResponseFuture {
+            inner: self.request_bytes(&request.path, request.authenticated),
+        }
+    }
+}
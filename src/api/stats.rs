@@ -0,0 +1,198 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use parking_lot::Mutex;
+
+/// The amount of most recent latencies the percentiles are calculated from.
+const LATENCY_SAMPLES: usize = 1000;
+/// The window requests per minute are counted in.
+const THROUGHPUT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Statistics of the requests handled by a [`RequestHandler`](crate::RequestHandler),
+/// see [`RequestHandler::stats`](crate::RequestHandler::stats).
+///
+/// Latencies are measured from queueing a request until its response was deserialized,
+/// which includes the time spent waiting for the rate limit.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RequestStats {
+    uptime: Duration,
+    completed: u64,
+    failed: u64,
+    requests_per_minute: u32,
+    p50: Option<Duration>,
+    p95: Option<Duration>,
+    p99: Option<Duration>,
+}
+
+impl RequestStats {
+    /// Returns the time since the `RequestHandler` was created.
+    pub fn uptime(&self) -> Duration {
+        self.uptime
+    }
+
+    /// Returns the amount of requests that completed successfully since start.
+    pub fn completed(&self) -> u64 {
+        self.completed
+    }
+
+    /// Returns the amount of requests that failed since start.
+    pub fn failed(&self) -> u64 {
+        self.failed
+    }
+
+    /// Returns the ratio of failed requests to all finished requests since start,
+    /// between `0.0` and `1.0`.
+    pub fn error_rate(&self) -> f64 {
+        let total = self.completed + self.failed;
+        if total == 0 {
+            0.0
+        } else {
+            self.failed as f64 / total as f64
+        }
+    }
+
+    /// Returns the amount of requests that finished in the past minute.
+    pub fn requests_per_minute(&self) -> u32 {
+        self.requests_per_minute
+    }
+
+    /// Returns the median latency of the 1000 most recent requests.
+    pub fn latency_p50(&self) -> Option<Duration> {
+        self.p50
+    }
+
+    /// Returns the 95th percentile latency of the 1000 most recent requests.
+    pub fn latency_p95(&self) -> Option<Duration> {
+        self.p95
+    }
+
+    /// Returns the 99th percentile latency of the 1000 most recent requests.
+    pub fn latency_p99(&self) -> Option<Duration> {
+        self.p99
+    }
+}
+
+/// Statistics of the requests sent through a [tagged](crate::RequestHandler::tagged) handler,
+/// see [`RequestHandler::stats_by_tag`](crate::RequestHandler::stats_by_tag).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct TagStats {
+    completed: u64,
+    failed: u64,
+    total_latency: Duration,
+}
+
+impl TagStats {
+    /// Returns the amount of requests with this tag that completed successfully since start.
+    pub fn completed(&self) -> u64 {
+        self.completed
+    }
+
+    /// Returns the amount of requests with this tag that failed since start.
+    pub fn failed(&self) -> u64 {
+        self.failed
+    }
+
+    /// Returns the amount of requests with this tag that finished since start.
+    pub fn requests(&self) -> u64 {
+        self.completed + self.failed
+    }
+
+    /// Returns the sum of the latencies of all finished requests with this tag.
+    pub fn total_latency(&self) -> Duration {
+        self.total_latency
+    }
+
+    /// Returns the average latency of the finished requests with this tag.
+    pub fn average_latency(&self) -> Option<Duration> {
+        let requests = u32::try_from(self.requests()).ok().filter(|requests| *requests > 0)?;
+        Some(self.total_latency / requests)
+    }
+}
+
+/// Collects the data [`RequestStats`] are calculated from.
+#[derive(Debug)]
+pub(crate) struct StatsRecorder {
+    started: Instant,
+    inner: Mutex<StatsData>,
+}
+
+#[derive(Debug, Default)]
+struct StatsData {
+    completed: u64,
+    failed: u64,
+    latencies: VecDeque<Duration>,
+    finished_at: VecDeque<Instant>,
+    tags: HashMap<String, TagStats>,
+}
+
+impl StatsRecorder {
+    pub(crate) fn new() -> Self {
+        StatsRecorder {
+            started: Instant::now(),
+            inner: Mutex::new(StatsData::default()),
+        }
+    }
+
+    pub(crate) fn record(&self, latency: Duration, success: bool, tag: Option<&str>) {
+        let now = Instant::now();
+        let mut data = self.inner.lock();
+        if success {
+            data.completed += 1;
+        } else {
+            data.failed += 1;
+        }
+        if let Some(tag) = tag {
+            let stats = match data.tags.get_mut(tag) {
+                Some(stats) => stats,
+                None => data.tags.entry(tag.to_string()).or_default(),
+            };
+            if success {
+                stats.completed += 1;
+            } else {
+                stats.failed += 1;
+            }
+            stats.total_latency += latency;
+        }
+        if data.latencies.len() == LATENCY_SAMPLES {
+            data.latencies.pop_front();
+        }
+        data.latencies.push_back(latency);
+        data.finished_at.push_back(now);
+        StatsRecorder::expire(&mut data.finished_at, now);
+    }
+
+    pub(crate) fn snapshot(&self) -> RequestStats {
+        let now = Instant::now();
+        let mut data = self.inner.lock();
+        StatsRecorder::expire(&mut data.finished_at, now);
+        let mut latencies: Vec<Duration> = data.latencies.iter().copied().collect();
+        latencies.sort_unstable();
+        RequestStats {
+            uptime: now - self.started,
+            completed: data.completed,
+            failed: data.failed,
+            requests_per_minute: data.finished_at.len() as u32,
+            p50: percentile(&latencies, 0.50),
+            p95: percentile(&latencies, 0.95),
+            p99: percentile(&latencies, 0.99),
+        }
+    }
+
+    pub(crate) fn tags(&self) -> HashMap<String, TagStats> {
+        self.inner.lock().tags.clone()
+    }
+
+    fn expire(finished_at: &mut VecDeque<Instant>, now: Instant) {
+        while finished_at.front().is_some_and(|at| now.duration_since(*at) > THROUGHPUT_WINDOW) {
+            finished_at.pop_front();
+        }
+    }
+}
+
+/// Returns the nearest-rank percentile of the sorted `values`.
+fn percentile(values: &[Duration], percentile: f64) -> Option<Duration> {
+    if values.is_empty() {
+        return None;
+    }
+    let rank = (percentile * values.len() as f64).ceil() as usize;
+    Some(values[rank.clamp(1, values.len()) - 1])
+}
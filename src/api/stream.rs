@@ -0,0 +1,199 @@
+use std::io::ErrorKind;
+use serde::de::DeserializeOwned;
+use crate::api::error::HypixelApiError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Phase {
+    /// Looking for the container of the items.
+    Seek,
+    /// Inside the container, extracting items.
+    Items,
+    /// The container was closed, the remainder is ignored.
+    Done,
+}
+
+/// Extracts the items of the first array or object in a json document that is received in chunks.
+///
+/// The container is either the document itself or the first field of the root object whose
+/// value is an array or an object, e.g. `"auctions"` of `/skyblock/auctions` or `"products"`
+/// of `/skyblock/bazaar`. Items are the elements of an array or the values of an object.
+///
+/// Only the bytes of the item that is currently received are kept in memory.
+#[derive(Debug)]
+pub(crate) struct ItemScanner {
+    buffer: Vec<u8>,
+    position: usize,
+    depth: usize,
+    container_depth: usize,
+    container_is_object: bool,
+    in_string: bool,
+    escaped: bool,
+    expect_value: bool,
+    item_start: Option<usize>,
+    phase: Phase,
+}
+
+impl ItemScanner {
+    pub(crate) fn new() -> Self {
+        ItemScanner {
+            buffer: Vec::new(),
+            position: 0,
+            depth: 0,
+            container_depth: 0,
+            container_is_object: false,
+            in_string: false,
+            escaped: false,
+            expect_value: false,
+            item_start: None,
+            phase: Phase::Seek,
+        }
+    }
+
+    /// Appends a received chunk of the document.
+    pub(crate) fn push(&mut self, chunk: &[u8]) {
+        if self.phase != Phase::Done {
+            self.buffer.extend_from_slice(chunk);
+        }
+    }
+
+    /// Returns the next item if it has been received completely.
+    pub(crate) fn next_item<T: DeserializeOwned>(&mut self) -> Option<Result<T, HypixelApiError>> {
+        let item = self.scan();
+        let item = item.map(|(start, end)| {
+            let item = serde_json::from_slice(&self.buffer[start..end]).map_err(|e| e.into());
+            self.buffer.drain(..end);
+            self.position -= end;
+            item
+        });
+        if item.is_none() {
+            self.compact();
+        }
+        item
+    }
+
+    /// Checks that the whole document was received, call this once all chunks were pushed.
+    pub(crate) fn finish(&self) -> Result<(), HypixelApiError> {
+        match self.phase {
+            Phase::Done => Ok(()),
+            Phase::Seek if self.depth == 0 => Ok(()),
+            _ => Err(HypixelApiError::Io {
+                source: std::io::Error::new(ErrorKind::UnexpectedEof, "response ended in the middle of the json document"),
+            }),
+        }
+    }
+
+    /// Drops the bytes that were scanned and are not part of an item.
+    fn compact(&mut self) {
+        let keep_from = self.item_start.unwrap_or(self.position);
+        if keep_from > 0 {
+            self.buffer.drain(..keep_from);
+            self.position -= keep_from;
+            self.item_start = self.item_start.map(|_| 0);
+        }
+    }
+
+    /// Scans the buffer until an item is complete and returns its range.
+    fn scan(&mut self) -> Option<(usize, usize)> {
+        while self.position < self.buffer.len() && self.phase != Phase::Done {
+            let index = self.position;
+            let byte = self.buffer[index];
+            self.position += 1;
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                    if self.phase == Phase::Items && self.depth == self.container_depth {
+                        if let Some(start) = self.item_start.take() {
+                            return Some((start, index + 1));
+                        }
+                    }
+                }
+                continue;
+            }
+            if byte.is_ascii_whitespace() {
+                continue;
+            }
+            match self.phase {
+                Phase::Seek => self.seek(byte),
+                Phase::Items => if let Some(item) = self.items(index, byte) {
+                    return Some(item);
+                },
+                Phase::Done => {}
+            }
+        }
+        None
+    }
+
+    fn seek(&mut self, byte: u8) {
+        match byte {
+            b'"' => {
+                self.in_string = true;
+                self.expect_value = false;
+            }
+            b':' if self.depth == 1 => self.expect_value = true,
+            b'[' | b'{' => {
+                let container = self.depth == 0 && byte == b'[' || self.depth == 1 && self.expect_value;
+                self.depth += 1;
+                self.expect_value = false;
+                if container {
+                    self.phase = Phase::Items;
+                    self.container_depth = self.depth;
+                    self.container_is_object = byte == b'{';
+                }
+            }
+            b']' | b'}' => {
+                self.depth = self.depth.saturating_sub(1);
+                if self.depth == 0 {
+                    self.phase = Phase::Done;
+                }
+            }
+            _ => self.expect_value = false,
+        }
+    }
+
+    fn items(&mut self, index: usize, byte: u8) -> Option<(usize, usize)> {
+        let at_item_level = self.depth == self.container_depth;
+        match byte {
+            b'"' => {
+                self.in_string = true;
+                if at_item_level && (!self.container_is_object || self.expect_value) {
+                    self.item_start = Some(index);
+                    self.expect_value = false;
+                }
+            }
+            b':' if at_item_level => self.expect_value = true,
+            b',' if at_item_level => {
+                if let Some(start) = self.item_start.take() {
+                    return Some((start, index));
+                }
+            }
+            b'[' | b'{' => {
+                if at_item_level {
+                    self.item_start = Some(index);
+                    self.expect_value = false;
+                }
+                self.depth += 1;
+            }
+            b']' | b'}' => {
+                self.depth -= 1;
+                if self.depth == self.container_depth {
+                    return self.item_start.take().map(|start| (start, index + 1));
+                }
+                if self.depth < self.container_depth {
+                    self.phase = Phase::Done;
+                    return self.item_start.take().map(|start| (start, index));
+                }
+            }
+            _ => {
+                if at_item_level && self.item_start.is_none() {
+                    self.item_start = Some(index);
+                    self.expect_value = false;
+                }
+            }
+        }
+        None
+    }
+}
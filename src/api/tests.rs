@@ -2,12 +2,13 @@
 
 use std::error::Error;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 use futures::stream::FuturesUnordered;
 use futures::StreamExt;
 use uuid::Uuid;
-use crate::api::reply::{PlayerData, StatusData};
-use crate::{KeyReply, PlayerReply, RequestHandler};
+use crate::api::reply::{Bedwars, GuildData, PlayerData, QuestsResourcesReply, StatusData};
+use crate::{PlayerReply, RequestHandler, StatusReply, WithRaw};
 
 #[test]
 fn test_player() {
@@ -22,6 +23,7 @@ fn test_player() {
             "firstLogin": 0,
             "lastLogin": 0,
             "lastLogout": 0,
+            "mostRecentGameType": "BEDWARS",
             "stats": { }
         }
     "#;
@@ -30,6 +32,82 @@ fn test_player() {
     print!("Sample data:\n {:?}", data);
 }
 
+#[test]
+fn test_parkour() {
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "parkourCompletions": {
+                "Bedwars": [
+                    { "timeStart": 1585000000000, "timeTook": 41250 },
+                    { "timeStart": 1586000000000, "timeTook": 37800 }
+                ]
+            }
+        }
+    "#;
+
+    let data: PlayerData = serde_json::from_str(sample).unwrap();
+    let parkour = data.parkour_completions();
+    assert_eq!(parkour.completions("Bedwars").len(), 2);
+    assert_eq!(parkour.best_time("Bedwars"), Some(Duration::from_millis(37800)));
+    assert_eq!(parkour.best_time("SkyWars"), None);
+}
+
+#[test]
+fn test_game_stats() {
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "stats": {
+                "Bedwars": {
+                    "Experience": 500,
+                    "wins_bedwars": 12,
+                    "final_kills_bedwars": 34
+                }
+            }
+        }
+    "#;
+
+    let data: PlayerData = serde_json::from_str(sample).unwrap();
+    let stats = data.stats::<Bedwars>().unwrap().unwrap();
+    assert_eq!(stats.wins(), 12);
+    assert_eq!(stats.final_kills(), 34);
+    assert_eq!(stats.final_deaths(), 0);
+}
+
+#[test]
+fn test_quests() {
+    let now = chrono::Utc::now().timestamp_millis();
+    let sample = format!(r#"
+        {{
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "quests": {{
+                "bedwars_daily_win": {{ "completions": [{{ "time": 1600000000000 }}, {{ "time": {} }}] }},
+                "bedwars_weekly_bed_elims": {{ "completions": [{{ "time": 1600000000000 }}] }},
+                "skywars_solo_win": {{ "active": {{ "started": 1600000000000 }} }}
+            }}
+        }}
+    "#, now);
+    let resources = r#"
+        {
+            "success": true,
+            "quests": {
+                "bedwars": [
+                    { "id": "bedwars_daily_win", "name": "Daily Win", "requirements": [{ "type": "DailyResetQuestRequirement" }] },
+                    { "id": "bedwars_weekly_bed_elims", "name": "Bed Removal Co." }
+                ]
+            }
+        }
+    "#;
+
+    let data: PlayerData = serde_json::from_str(&sample).unwrap();
+    let resources: QuestsResourcesReply = serde_json::from_str(resources).unwrap();
+    assert_eq!(data.quests_completed_today().collect::<Vec<_>>(), vec!["bedwars_daily_win"]);
+    assert_eq!(data.quests_completed(&resources, "bedwars"), 3);
+    assert!(data.quests().quest("skywars_solo_win").unwrap().is_active());
+    assert!(resources.quest("bedwars_daily_win").unwrap().is_daily());
+}
+
 #[test]
 fn test_status() {
     let sample = r#"
@@ -46,29 +124,315 @@ fn test_status() {
 
     let data: StatusData = serde_json::from_str(sample).unwrap();
     print!("Sample data:\n {:?}", data);
+    assert_eq!(data.game(), Some(crate::GameType::Unknown("string".to_string())));
+
+    let session: crate::SessionData = serde_json::from_str(r#"{"online":true,"gameType":"BEDWARS","mode":"EIGHT_ONE"}"#).unwrap();
+    assert!(session.online());
+    assert_eq!(session.game(), Some(crate::GameType::Bedwars));
+    assert_eq!((session.game_type(), session.mode(), session.map()), (Some("BEDWARS"), Some("EIGHT_ONE"), None));
+    assert_eq!(data.into_session().mode(), Some("string"));
 }
 
 #[test]
-#[ignore]
-fn test_bulk() {
-    tokio::runtime::Runtime::new().unwrap()
-        .block_on(async move {
-            let request_handler = RequestHandler::new(Uuid::from_str(env!("HYPIXEL_KEY")).unwrap());
+fn test_guild() {
+    let sample = r#"
+        {
+            "_id": "553490650cf26f12ae5bac8f",
+            "name": "Mini Squid",
+            "exp": 1000,
+            "members": [
+                {
+                    "uuid": "f7c77d999f154a66a87dc4a51ef30d19",
+                    "rank": "Guild Master",
+                    "joined": 1429508197565,
+                    "expHistory": { "2022-02-20": 100, "2022-02-19": 50 }
+                },
+                {
+                    "uuid": "ad8fefaa8351454bb739a4eaa872173f",
+                    "rank": "Officer",
+                    "joined": 1429508197565,
+                    "expHistory": { "2022-02-20": 10 }
+                },
+                {
+                    "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+                    "rank": "Member",
+                    "joined": 1429508197565
+                }
+            ],
+            "ranks": [
+                { "name": "Officer", "default": false, "priority": 5 },
+                { "name": "Member", "default": true, "priority": 1 }
+            ],
+            "guildExpByGameType": { "BEDWARS": 800, "SKYWARS": 200 }
+        }
+    "#;
 
-            // status?uuid=ec174daf-b5a5-4ea1-adc6-35a7f9fc4a60
-            let mut future_pool = FuturesUnordered::new();
-            for i in 0..300 {
-                let future = request_handler.request::<KeyReply>("key", true);
-                future_pool.push(async move {
-                    (i, future.await)
-                });
-                tokio::time::sleep(Duration::from_millis(3)).await;
-            }
+    let data: GuildData = serde_json::from_str(sample).unwrap();
+    let officer = data.member(Uuid::from_str("ad8fefaa-8351-454b-b739-a4eaa872173f").unwrap()).unwrap();
+    assert_eq!(data.rank_of(officer).unwrap().priority(), 5);
+    assert_eq!(data.members_above_priority(1).count(), 2);
+    assert_eq!(data.members()[0].weekly_exp(), 150);
+    assert_eq!(data.exp_by_game_type(&crate::GameType::Bedwars), 800);
+
+    use crate::util::guild::{daily_exp, scaled_exp, weekly_exp, weekly_leaderboard};
+    let day = chrono::NaiveDate::from_ymd_opt(2022, 2, 20).unwrap();
+    assert_eq!(daily_exp(&data)[&day], 110);
+    assert_eq!(weekly_exp(&data), 160);
+    assert_eq!(weekly_leaderboard(&data)[0], (data.members()[0].uuid(), 150));
+    assert_eq!(scaled_exp(1_000_000), 259_000);
+}
+
+#[test]
+fn test_with_raw() {
+    let sample = r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":false}}"#;
+
+    let data: WithRaw<StatusReply> = serde_json::from_str(sample).unwrap();
+    assert!(!data.online());
+    assert_eq!(data.raw(), sample);
+}
+
+#[test]
+fn test_into_owned() {
+    use crate::GuildReply;
+
+    let reply: PlayerReply = serde_json::from_str(r#"{"success":true,"player":{"uuid":"ad8fefaa8351454bb739a4eaa872173f"}}"#).unwrap();
+    assert_eq!(reply.into_player().unwrap().uuid(), Uuid::from_str("ad8fefaa-8351-454b-b739-a4eaa872173f").unwrap());
+    let reply: PlayerReply = serde_json::from_str(r#"{"success":true,"player":null}"#).unwrap();
+    assert!(reply.into_player().is_none());
+
+    let reply: GuildReply = serde_json::from_str(r#"{"success":true,"guild":{"_id":"553490650cf26f12ae5bac8f","name":"Mini Squid","exp":0,"members":[{"uuid":"ad8fefaa8351454bb739a4eaa872173f","rank":"Guild Master","joined":1429508197565}],"ranks":[]}}"#).unwrap();
+    let members = reply.into_guild().unwrap().into_members();
+    assert_eq!(members[0].rank(), "Guild Master");
+
+    let reply: StatusReply = serde_json::from_str(r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#).unwrap();
+    assert!(reply.into_status().online());
+
+    let reply: crate::SkyblockProfilesReply = serde_json::from_str(r#"{"success":true,"profiles":null}"#).unwrap();
+    assert!(reply.into_profiles().is_empty());
+}
+
+/// A scripted Hypixel API that enforces a rate limit on simulated time.
+struct SimulatedApi {
+    state: parking_lot::Mutex<SimulatedWindow>,
+}
+
+struct SimulatedWindow {
+    limit: u32,
+    remaining: u32,
+    window: Duration,
+    reset_at: Option<tokio::time::Instant>,
+    ok: u32,
+    too_many_requests: u32,
+}
+
+struct SimulatedResponse {
+    status: reqwest::StatusCode,
+    headers: reqwest::header::HeaderMap,
+}
+
+impl crate::api::throttler::ThrottledResponse for SimulatedResponse {
+    fn status(&self) -> reqwest::StatusCode {
+        self.status
+    }
+
+    fn headers(&self) -> &reqwest::header::HeaderMap {
+        &self.headers
+    }
+}
+
+impl SimulatedApi {
+    fn new(limit: u32, window: Duration) -> Self {
+        SimulatedApi {
+            state: parking_lot::Mutex::new(SimulatedWindow { limit, remaining: limit, window, reset_at: None, ok: 0, too_many_requests: 0 }),
+        }
+    }
 
-            while let Some((i, reply)) = future_pool.next().await {
-                println!("Player data #{}: {:?}", i, reply);
+    /// Uses up the remaining requests of the current window, as another client of the same key would.
+    fn exhaust(&self) {
+        self.state.lock().remaining = 0;
+    }
+
+    /// Changes the limit, starting from the next window.
+    fn set_limit(&self, limit: u32) {
+        self.state.lock().limit = limit;
+    }
+
+    fn respond(&self) -> SimulatedResponse {
+        let mut state = self.state.lock();
+        let now = tokio::time::Instant::now();
+        let reset_at = match state.reset_at {
+            Some(reset_at) if reset_at > now => reset_at,
+            _ => {
+                let reset_at = now + state.window;
+                state.remaining = state.limit;
+                state.reset_at = Some(reset_at);
+                reset_at
             }
-        });
+        };
+        let status = if state.remaining > 0 {
+            state.remaining -= 1;
+            state.ok += 1;
+            reqwest::StatusCode::OK
+        } else {
+            state.too_many_requests += 1;
+            reqwest::StatusCode::TOO_MANY_REQUESTS
+        };
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("ratelimit-limit", state.limit.into());
+        headers.insert("ratelimit-remaining", state.remaining.into());
+        headers.insert("ratelimit-reset", (reset_at - now).as_secs_f64().ceil().max(1.0).to_string().parse().unwrap());
+        SimulatedResponse { status, headers }
+    }
+
+    /// Sends `amount` requests at once through `throttler` and returns their status codes.
+    async fn burst(self: &Arc<Self>, throttler: &Arc<parking_lot::Mutex<crate::api::throttler::RequestThrottler>>, amount: usize) -> Vec<reqwest::StatusCode> {
+        use crate::api::throttler::{RequestThrottler, ThrottledResponse};
+
+        let mut future_pool = FuturesUnordered::new();
+        for _ in 0..amount {
+            let api = Arc::clone(self);
+            let throttler = Arc::clone(throttler);
+            future_pool.push(tokio::spawn(async move {
+                RequestThrottler::send(&throttler, true, || {
+                    let response = api.respond();
+                    async move { Ok(response) }
+                }).await.map(|response| response.status())
+            }));
+        }
+        let mut statuses = Vec::new();
+        while let Some(status) = future_pool.next().await {
+            statuses.push(status.unwrap().unwrap());
+        }
+        statuses
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_throttle_burst() {
+    let api = Arc::new(SimulatedApi::new(120, Duration::from_secs(60)));
+    let throttler = crate::api::throttler::RequestThrottler::new_on_current_runtime(Default::default());
+    let start = tokio::time::Instant::now();
+
+    let statuses = api.burst(&throttler, 300).await;
+    assert!(statuses.iter().all(|status| *status == reqwest::StatusCode::OK));
+    let state = api.state.lock();
+    assert_eq!(state.ok, 300);
+    assert_eq!(state.too_many_requests, 0);
+    assert!(start.elapsed() >= Duration::from_secs(120));
+    assert!(start.elapsed() < Duration::from_secs(180));
+    drop(state);
+    assert_eq!(throttler.lock().key_usage().map(|usage| usage.limit()), Some(120));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_throttle_too_many_requests() {
+    let api = Arc::new(SimulatedApi::new(120, Duration::from_secs(60)));
+    let throttler = crate::api::throttler::RequestThrottler::new_on_current_runtime(Default::default());
+
+    let statuses = api.burst(&throttler, 50).await;
+    assert_eq!(statuses.len(), 50);
+    api.exhaust();
+    let start = tokio::time::Instant::now();
+    let statuses = api.burst(&throttler, 50).await;
+    assert!(statuses.iter().all(|status| *status == reqwest::StatusCode::OK));
+    let state = api.state.lock();
+    assert_eq!(state.ok, 100);
+    assert!(state.too_many_requests >= 1);
+    assert!(start.elapsed() >= Duration::from_secs(59));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_throttle_limit_change() {
+    let api = Arc::new(SimulatedApi::new(120, Duration::from_secs(60)));
+    let throttler = crate::api::throttler::RequestThrottler::new_on_current_runtime(Default::default());
+
+    api.burst(&throttler, 100).await;
+    api.set_limit(60);
+    let start = tokio::time::Instant::now();
+    let statuses = api.burst(&throttler, 100).await;
+    assert!(statuses.iter().all(|status| *status == reqwest::StatusCode::OK));
+    let state = api.state.lock();
+    assert_eq!(state.ok, 200);
+    assert_eq!(state.too_many_requests, 0);
+    assert!(start.elapsed() >= Duration::from_secs(60));
+    drop(state);
+    assert_eq!(throttler.lock().key_usage().map(|usage| usage.limit()), Some(60));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_throttle_clock_skew() {
+    use crate::api::throttler::RequestThrottler;
+
+    let throttler = RequestThrottler::new_on_current_runtime(Default::default());
+    assert!(throttler.lock().clock_skew().is_none());
+    // the local clock is 30 seconds ahead of the API
+    let date = (chrono::Utc::now() - chrono::TimeDelta::seconds(30)).format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+    let respond = || {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("ratelimit-remaining", 1.into());
+        headers.insert("ratelimit-reset", 5.into());
+        headers.insert(reqwest::header::DATE, date.parse().unwrap());
+        async { Ok(SimulatedResponse { status: reqwest::StatusCode::OK, headers }) }
+    };
+    let start = tokio::time::Instant::now();
+    for _ in 0..3 {
+        RequestThrottler::send(&throttler, true, respond).await.unwrap();
+    }
+    let skew = throttler.lock().clock_skew().unwrap();
+    assert!(skew <= chrono::TimeDelta::seconds(-30) && skew > chrono::TimeDelta::seconds(-32));
+    // the window reset is not affected by the skew
+    assert!(start.elapsed() >= Duration::from_secs(5 + 2) && start.elapsed() < Duration::from_secs(5 + 2 + 1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_throttle_cancelled_request() {
+    use crate::api::throttler::RequestThrottler;
+
+    let throttler = RequestThrottler::new_on_current_runtime(Default::default());
+    // the request holding the first ticket of the window is cancelled while it is in flight
+    let in_flight = RequestThrottler::send(&throttler, true, std::future::pending::<Result<SimulatedResponse, crate::error::HypixelApiError>>);
+    assert!(tokio::time::timeout(Duration::from_secs(1), in_flight).await.is_err());
+    let respond = || async { Ok(SimulatedResponse { status: reqwest::StatusCode::OK, headers: reqwest::header::HeaderMap::new() }) };
+    let response = tokio::time::timeout(Duration::from_secs(1), RequestThrottler::send(&throttler, true, respond)).await
+        .expect("request waited for the ticket of the cancelled request");
+    assert_eq!(response.unwrap().status, reqwest::StatusCode::OK);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limit_updates() {
+    use crate::api::throttler::RequestThrottler;
+
+    let throttler = RequestThrottler::new_on_current_runtime(Default::default());
+    let mut updates = throttler.lock().subscribe();
+    assert!(updates.borrow().key_usage().is_none());
+    let attempts = std::sync::atomic::AtomicU32::new(0);
+    let respond = || {
+        let status = match attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst) {
+            1 => reqwest::StatusCode::TOO_MANY_REQUESTS,
+            _ => reqwest::StatusCode::OK,
+        };
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("ratelimit-limit", 120.into());
+        headers.insert("ratelimit-remaining", 100.into());
+        headers.insert("ratelimit-reset", 5.into());
+        async move { Ok(SimulatedResponse { status, headers }) }
+    };
+
+    RequestThrottler::send(&throttler, true, respond).await.unwrap();
+    assert!(updates.has_changed().unwrap());
+    let status = *updates.borrow_and_update();
+    assert_eq!((status.limit(), status.remaining()), (Some(120), Some(100)));
+    assert!(!status.is_throttled());
+    assert_eq!(status.reset_in(), Some(Duration::from_secs(5)));
+    tokio::time::advance(Duration::from_secs(2)).await;
+    assert_eq!(updates.borrow().reset_in(), Some(Duration::from_secs(3)));
+
+    // the second attempt is rate limited, the third one succeeds after the window reset
+    let mut throttled = throttler.lock().subscribe();
+    let watcher = tokio::spawn(async move { throttled.wait_for(|status| status.is_throttled()).await.is_ok() });
+    RequestThrottler::send(&throttler, true, respond).await.unwrap();
+    assert!(watcher.await.unwrap());
+    assert!(!updates.borrow().is_throttled());
 }
 
 #[test]
@@ -86,3 +450,2712 @@ fn test_player_data() {
             }
         })
 }
+
+
+#[test]
+fn test_bedwars_level() {
+    use crate::util::leveling::bedwars;
+
+    assert_eq!(bedwars::calculate_level(0.0), 0.0);
+    assert_eq!(bedwars::calculate_level(7000.0), 4.0);
+    assert_eq!(bedwars::calculate_level(487000.0), 100.0);
+    assert_eq!(bedwars::exact_level(9500.0), 4.5);
+    assert_eq!(bedwars::total_xp_to_level(4.5), 9500.0);
+    assert_eq!(bedwars::total_xp_to_level(100.0), 487000.0);
+    assert_eq!(bedwars::xp_to_next_level(101.0), 1000.0);
+    assert_eq!(bedwars::prestige(bedwars::exact_level(1000000.0)), 2);
+}
+
+#[test]
+fn test_skywars_level() {
+    use crate::util::leveling::skywars;
+
+    assert_eq!(skywars::calculate_level(0.0), 1.0);
+    assert_eq!(skywars::exact_level(45.0), 2.5);
+    assert_eq!(skywars::exact_level(20000.0), 12.5);
+    assert_eq!(skywars::total_xp_to_level(12.5), 20000.0);
+    assert_eq!(skywars::total_xp_to_level(2.5), 45.0);
+    assert_eq!(skywars::xp_to_next_level(11.0), 5000.0);
+    assert_eq!(skywars::xp_to_next_level(12.0), 10000.0);
+}
+
+#[test]
+fn test_guild_level() {
+    use crate::util::leveling::guild;
+
+    assert_eq!(guild::calculate_level(99999.0), 0.0);
+    assert_eq!(guild::exact_level(375000.0), 2.5);
+    assert_eq!(guild::exact_level(51500000.0), 24.5);
+    assert_eq!(guild::total_xp_to_level(24.5), 51500000.0);
+    assert_eq!(guild::xp_to_next_level(20.0), 3000000.0);
+    assert_eq!(guild::exact_level(-1.0), 0.0);
+    assert_eq!(guild::exact_level(f64::NAN), 0.0);
+    assert_eq!(guild::exact_level(f64::INFINITY), f64::INFINITY);
+    assert_eq!(guild::calculate_level(guild::total_xp_to_level(1_000_000.0)), 1_000_000.0);
+}
+
+#[test]
+fn test_skyblock_skill_level() {
+    use crate::util::leveling::skyblock;
+
+    let capped = skyblock::skill_table(50);
+    let uncapped = skyblock::skill_table(60);
+    assert_eq!(skyblock::exact_level(275.0, capped), 2.5);
+    assert_eq!(skyblock::exact_level(60000000.0, capped), 50.0);
+    assert_eq!(skyblock::exact_level(57322425.0, uncapped), 50.5);
+    assert_eq!(skyblock::xp_to_next_level(50.0, capped), 0.0);
+    assert_eq!(skyblock::xp_to_next_level(50.0, uncapped), 4300000.0);
+    assert_eq!(skyblock::total_xp_to_level(2.5, capped), 275.0);
+}
+
+#[test]
+fn test_pet_level() {
+    use crate::util::leveling::pets::{self, PetRarity};
+
+    assert_eq!(pets::calculate_level(0.0, PetRarity::Legendary, pets::MAX_LEVEL), 1.0);
+    assert_eq!(pets::calculate_level(660.0, PetRarity::Legendary, pets::MAX_LEVEL), 2.0);
+    assert_eq!(pets::exact_level(25353230.0, PetRarity::Legendary, pets::MAX_LEVEL), 100.0);
+    assert_eq!(pets::exact_level(5624785.0, PetRarity::Common, pets::MAX_LEVEL), 100.0);
+    assert_eq!(pets::exact_level(210255385.0, PetRarity::Legendary, pets::max_level("GOLDEN_DRAGON")), 200.0);
+    assert_eq!(pets::total_xp_to_level(200.0, PetRarity::Legendary, pets::GOLDEN_DRAGON_MAX_LEVEL), 210255385.0);
+}
+
+#[test]
+fn test_dungeons_level() {
+    use crate::util::leveling::dungeons;
+
+    assert_eq!(dungeons::calculate_level(0.0), 0.0);
+    assert_eq!(dungeons::exact_level(25.0), 0.5);
+    assert_eq!(dungeons::calculate_level(49.0), 0.0);
+    assert_eq!(dungeons::calculate_level(125.0), 2.0);
+    assert_eq!(dungeons::calculate_level(569809639.0), 49.0);
+    assert_eq!(dungeons::calculate_level(569809640.0), 50.0);
+    assert_eq!(dungeons::exact_level(669809640.0), 50.5);
+    assert_eq!(dungeons::calculate_level(969809640.0), 52.0);
+    assert_eq!(dungeons::xp_to_next_level(0.0), 50.0);
+    assert_eq!(dungeons::xp_to_next_level(49.0), 116250000.0);
+    assert_eq!(dungeons::xp_to_next_level(50.0), 200000000.0);
+    assert_eq!(dungeons::total_xp_to_level(50.0), 569809640.0);
+    assert_eq!(dungeons::total_xp_to_level(50.5), 669809640.0);
+}
+
+#[test]
+fn test_slayer_level() {
+    use crate::util::leveling::slayer::{self, SlayerBoss};
+
+    assert_eq!(slayer::calculate_level(0.0, SlayerBoss::Zombie), 0.0);
+    assert_eq!(slayer::calculate_level(200.0, SlayerBoss::Zombie), 3.0);
+    assert_eq!(slayer::calculate_level(199.0, SlayerBoss::Zombie), 2.0);
+    assert_eq!(slayer::calculate_level(200.0, SlayerBoss::Wolf), 2.0);
+    assert_eq!(slayer::calculate_level(25.0, SlayerBoss::Spider), 2.0);
+    assert_eq!(slayer::calculate_level(15.0, SlayerBoss::Spider), 1.0);
+    assert_eq!(slayer::calculate_level(1000000.0, SlayerBoss::Blaze), 9.0);
+    assert_eq!(slayer::calculate_level(2000000.0, SlayerBoss::Zombie), 9.0);
+    assert_eq!(slayer::calculate_level(2400.0, SlayerBoss::Vampire), 5.0);
+    assert_eq!(slayer::exact_level(10.0, SlayerBoss::Zombie), 1.5);
+    assert_eq!(slayer::exact_level(5000.0, SlayerBoss::Vampire), 5.0);
+    assert_eq!(slayer::xp_to_next_level(2.0, SlayerBoss::Enderman), 220.0);
+    assert_eq!(slayer::xp_to_next_level(9.0, SlayerBoss::Zombie), 0.0);
+    assert_eq!(slayer::total_xp_to_level(4.0, SlayerBoss::Wolf), 1500.0);
+    assert_eq!(slayer::total_xp_to_level(12.0, SlayerBoss::Vampire), 2400.0);
+    assert_eq!(SlayerBoss::Vampire.max_level(), 5.0);
+    assert_eq!(SlayerBoss::from_id("enderman"), Some(SlayerBoss::Enderman));
+    assert_eq!(SlayerBoss::from_id(SlayerBoss::Blaze.id()), Some(SlayerBoss::Blaze));
+}
+
+#[test]
+fn test_color_codes() {
+    use crate::ColorCodes;
+
+    assert_eq!(ColorCodes::Red.code_char(), 'c');
+    assert_eq!(ColorCodes::from_code_char('c'), Some(ColorCodes::Red));
+    assert_eq!(ColorCodes::from_str("§c").unwrap(), ColorCodes::Red);
+    assert_eq!(ColorCodes::from_str("DARK_AQUA").unwrap(), ColorCodes::DarkAqua);
+    assert!(ColorCodes::from_str("PINK").is_err());
+    for code in "0123456789abcdef".chars() {
+        assert_eq!(ColorCodes::from_code_char(code).unwrap().code_char(), code);
+    }
+}
+
+#[test]
+fn test_display_name() {
+    use crate::util::formatting::{display_name, plain_display_name};
+
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "displayname": "Notch",
+            "newPackageRank": "MVP_PLUS",
+            "rankPlusColor": "DARK_GREEN"
+        }
+    "#;
+
+    let data: PlayerData = serde_json::from_str(sample).unwrap();
+    assert_eq!(display_name(&data).unwrap(), "§b[MVP§2+§b] §bNotch");
+    assert_eq!(plain_display_name(&data).unwrap(), "[MVP+] Notch");
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn test_nbt() {
+    use crate::util::nbt::{decode_item_bytes, Tag};
+
+    let sample = "H4sIAAAAAAACA+NiYOBkYMzkYmBgYGRiYMpMYRRhZGB1zi/NK2HkYmAuSUznYmBPySwuyEms5GBg8UvMTWUQObTczLG4IDW5RCE/TaEkI1XBNS+FgYuB37WipCjRsaSkKDOptCS1mANkHoOgY3CAq3NIvL9bfIiHa7yrnwvQLgYApBrYeXgAAAA=";
+
+    let root = decode_item_bytes(sample).unwrap();
+    let items = root.get("i").and_then(Tag::as_list).unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].get("Count").and_then(Tag::as_i64), Some(1));
+    assert_eq!(items[0].path(&["tag", "display", "Name"]).and_then(Tag::as_str), Some("§6Aspect of the End"));
+    assert_eq!(items[0].path(&["tag", "ExtraAttributes", "id"]).and_then(Tag::as_str), Some("ASPECT_OF_THE_END"));
+    assert!(decode_item_bytes("not nbt").is_err());
+}
+
+#[cfg(feature = "nbt")]
+#[test]
+fn test_skyblock_item() {
+    use crate::util::nbt::decode_items;
+
+    let sample = "H4sIAAAAAAACAy2PwU6DQBRFL0xbYaJpNO7csHdTN5q4Iy2Ni6aa2P1kyjxgEhjIzGDsF/EffJnQ+Hbv3dxz8jgQI9AcQBgi1Cp4DLDctr3xAQfzsuS4Udp1tbxEWBxlQ3gah9e9PNekktR1lPukLRJfUZIZFWNxaC1FEy/Awzi87WQjS3pPxiF/ftlswLHOfr2VqfdWn3tPLpq1uE+/v7LtSXzuxekjE9lxFyFqWqULTRar4upjWFtptb+IviutVORmD8Pd/ypq+qF6ujGOWzJ5JY1vyHjHELtK2s6QmytLNseKrHDTXxMfWOE6f3YiS78QAQAA";
+
+    let items = decode_items(sample).unwrap();
+    assert_eq!(items.len(), 1);
+    let item = &items[0];
+    assert_eq!(item.id(), "ASPECT_OF_THE_END");
+    assert_eq!(item.plain_name().unwrap(), "Fabled Aspect of the End");
+    assert_eq!(item.lore().len(), 1);
+    assert_eq!(item.enchantment("sharpness"), Some(5));
+    assert_eq!(item.enchantments().len(), 2);
+    assert_eq!(item.reforge(), Some("fabled"));
+    assert_eq!(item.stars(), 3);
+    assert!(item.is_recombobulated());
+    assert!(item.attributes().is_empty());
+}
+
+#[test]
+fn test_uuid() {
+    use crate::util::uuid::{dashed, parse, undashed};
+
+    let uuid = Uuid::parse_str("ad8fefaa8351454bb739a4eaa872173f").unwrap();
+    assert_eq!(parse("ad8fefaa-8351-454b-b739-a4eaa872173f"), Some(uuid));
+    assert_eq!(parse(" {AD8FEFAA8351454BB739A4EAA872173F} "), Some(uuid));
+    assert_eq!(parse("ad8fefaa8351454bb739a4eaa87217"), None);
+    assert_eq!(dashed("ad8fefaa8351454bb739a4eaa872173f").unwrap(), "ad8fefaa-8351-454b-b739-a4eaa872173f");
+    assert_eq!(undashed("ad8fefaa-8351-454b-b739-a4eaa872173f").unwrap(), "ad8fefaa8351454bb739a4eaa872173f");
+
+    let data: StatusData = serde_json::from_str(r#"{"uuid":"ad8fefaa-8351-454b-b739-a4eaa872173f","session":{"online":false}}"#).unwrap();
+    assert_eq!(data.uuid(), uuid);
+}
+
+#[test]
+fn test_leveling_trait() {
+    use crate::util::leveling::{BedwarsLeveling, GuildLeveling, Leveling, NetworkLeveling, SkillLeveling, SkyWarsLeveling};
+
+    fn round_trip(leveling: &dyn Leveling, exp: f64) {
+        let level = leveling.level_for_xp(exp);
+        assert!((leveling.xp_for_level(level) - exp).abs() < 1e-3, "{} xp -> {} lvl", exp, level);
+        assert!((leveling.progress(exp) - level.fract()).abs() < 1e-9);
+    }
+
+    for exp in [0.0, 1234.0, 55000.0, 800000.0] {
+        round_trip(&NetworkLeveling, exp);
+        round_trip(&BedwarsLeveling, exp);
+        round_trip(&SkyWarsLeveling, exp);
+        round_trip(&GuildLeveling, exp);
+        round_trip(&SkillLeveling::default(), exp);
+    }
+}
+
+#[test]
+fn test_bazaar() {
+    use crate::util::bazaar::{flip_margin, instant_buy_cost, spread, weighted_average};
+
+    let sample = r#"
+        {
+            "success": true,
+            "lastUpdated": 1650000000000,
+            "products": {
+                "ENCHANTED_DIAMOND": {
+                    "product_id": "ENCHANTED_DIAMOND",
+                    "sell_summary": [
+                        { "amount": 100, "pricePerUnit": 900.0, "orders": 2 },
+                        { "amount": 300, "pricePerUnit": 880.0, "orders": 5 }
+                    ],
+                    "buy_summary": [
+                        { "amount": 10, "pricePerUnit": 1000.0, "orders": 1 },
+                        { "amount": 50, "pricePerUnit": 1010.0, "orders": 3 }
+                    ],
+                    "quick_status": { "productId": "ENCHANTED_DIAMOND", "sellPrice": 895.0, "buyPrice": 1005.0 }
+                }
+            }
+        }
+    "#;
+
+    let reply: crate::SkyblockBazaarReply = serde_json::from_str(sample).unwrap();
+    let product = reply.product("ENCHANTED_DIAMOND").unwrap();
+    assert_eq!(spread(product), Some(100.0));
+    assert_eq!(flip_margin(product, 0.0), Some(100.0));
+    assert_eq!(weighted_average(product.sell_summary(), 2), Some(885.0));
+    assert_eq!(instant_buy_cost(product, 20), Some(20100.0));
+    assert_eq!(instant_buy_cost(product, 100), None);
+}
+
+#[test]
+fn test_auctions() {
+    use crate::util::auctions::{bin_only, ending_soon, group_by, lowest_bin_by};
+
+    let sample = r#"
+        {
+            "success": true,
+            "page": 0,
+            "totalPages": 1,
+            "totalAuctions": 3,
+            "lastUpdated": 1650000000000,
+            "auctions": [
+                { "uuid": "409a1e0f261a49849493278d6cd9305a", "auctioneer": "ad8fefaa8351454bb739a4eaa872173f", "end": 1650000060000, "item_name": "Hyperion", "starting_bid": 900, "bin": true },
+                { "uuid": "509a1e0f261a49849493278d6cd9305a", "auctioneer": "ad8fefaa8351454bb739a4eaa872173f", "end": 1650003600000, "item_name": "Hyperion", "starting_bid": 800, "bin": true },
+                { "uuid": "609a1e0f261a49849493278d6cd9305a", "auctioneer": "ad8fefaa8351454bb739a4eaa872173f", "end": 1650000030000, "item_name": "Hyperion", "starting_bid": 100, "highest_bid_amount": 500 }
+            ]
+        }
+    "#;
+
+    let reply: crate::SkyblockAuctionsReply = serde_json::from_str(sample).unwrap();
+    assert_eq!(bin_only(reply.auctions()).count(), 2);
+    assert_eq!(group_by(reply.auctions(), |a| Some(a.item_name().to_string()))["Hyperion"].len(), 3);
+    assert_eq!(lowest_bin_by(reply.auctions(), |a| a.item_name().to_string())["Hyperion"].starting_bid(), 800);
+    let soon = ending_soon(reply.auctions(), reply.last_updated().unwrap(), chrono::Duration::minutes(5));
+    assert_eq!(soon.iter().map(|a| a.price()).collect::<Vec<_>>(), vec![500, 900]);
+}
+
+#[test]
+fn test_games() {
+    use crate::util::games::{by_id, info, GAMES};
+    use crate::GameType;
+
+    for game in GAMES {
+        assert!(!matches!(game.game_type(), GameType::Unknown(_)), "{}", game.type_name());
+        assert_eq!(info(&game.game_type()), Some(game));
+        if let Some(id) = game.id() {
+            assert_eq!(by_id(id), Some(game));
+        }
+    }
+    assert_eq!(info(&GameType::Unknown("NEW_GAME".to_string())), None);
+
+    // the generated table matches the snapshot it is generated from
+    let snapshot: crate::GamesResourcesReply = serde_json::from_str(include_str!("fixtures/resources_games.json")).unwrap();
+    assert_eq!(snapshot.type_names().count(), GAMES.len());
+    for game in GAMES {
+        let definition = snapshot.game(game.type_name()).unwrap();
+        assert_eq!((definition.id(), definition.database_name(), definition.name()), (game.id(), game.database_name(), game.clean_name()));
+        assert_eq!(game.game_type().to_string(), game.type_name());
+    }
+}
+
+#[test]
+fn test_skill_average() {
+    use std::collections::HashMap;
+    use crate::util::skills::skill_average;
+
+    let resources = r#"
+        {
+            "success": true,
+            "skills": {
+                "FARMING": { "name": "Farming", "maxLevel": 2, "levels": [{ "level": 1, "totalExpRequired": 50 }, { "level": 2, "totalExpRequired": 175 }] },
+                "MINING": { "name": "Mining", "maxLevel": 2, "levels": [{ "level": 1, "totalExpRequired": 50 }, { "level": 2, "totalExpRequired": 175 }] },
+                "RUNECRAFTING": { "name": "Runecrafting", "maxLevel": 1, "levels": [{ "level": 1, "totalExpRequired": 50 }] }
+            }
+        }
+    "#;
+
+    let resources: crate::SkillsResourcesReply = serde_json::from_str(resources).unwrap();
+    let experience = HashMap::from([
+        ("SKILL_FARMING".to_string(), 300.0),
+        ("SKILL_MINING".to_string(), 112.5),
+        ("SKILL_RUNECRAFTING".to_string(), 1000.0),
+    ]);
+    let capped = skill_average(&experience, &resources, true);
+    assert_eq!(capped.average(), 1.5);
+    assert_eq!(capped.average_with_progress(), 1.75);
+    assert_eq!(skill_average(&experience, &resources, false).average(), 2.0);
+}
+
+#[test]
+fn test_bingo_progress() {
+    use crate::{BingoResourcesReply, SkyblockBingoReply};
+    use crate::util::bingo::bingo_progress;
+
+    let resources = r#"
+        {
+            "success": true,
+            "id": 30,
+            "name": "March 2024",
+            "start": 1709269200000,
+            "goals": [
+                {"id": "kill_zombies", "name": "Zombie Slayer", "lore": "Kill 100 zombies", "requiredAmount": 100},
+                {"id": "catch_fish", "name": "Fisherman", "lore": "Catch a fish"},
+                {"id": "reach_level", "name": "Leveling", "lore": "Reach level 10"},
+                {"id": "community_wheat", "name": "Harvest", "tiers": [1000, 5000, 10000], "progress": 6000},
+                {"id": "community_mining", "name": "Miners", "tiers": [100, 200], "progress": "250"}
+            ]
+        }
+    "#;
+    let player = r#"
+        {
+            "success": true,
+            "events": [
+                {"key": 29, "points": 120, "completed_goals": ["catch_fish", "reach_level"]},
+                {"key": 30, "points": 40, "completed_goals": ["kill_zombies", "community_mining"]}
+            ]
+        }
+    "#;
+
+    let resources: BingoResourcesReply = serde_json::from_str(resources).unwrap();
+    let harvest = resources.goal("community_wheat").unwrap();
+    assert!(harvest.is_community());
+    assert_eq!(harvest.tier(), 2);
+    assert!(resources.goal("community_mining").unwrap().is_community_complete());
+    assert!(!resources.goal("catch_fish").unwrap().is_community_complete());
+
+    let player: SkyblockBingoReply = serde_json::from_str(player).unwrap();
+    let progress = bingo_progress(&player, &resources);
+    assert_eq!((progress.event(), progress.points()), (30, 40));
+    assert_eq!(progress.completed_goals(), ["kill_zombies", "community_mining"]);
+    assert_eq!(progress.remaining_goals(), ["catch_fish", "reach_level"]);
+    assert_eq!(progress.remaining_community_goals(), ["community_wheat"]);
+    assert!(!progress.is_complete());
+
+    let newcomer: SkyblockBingoReply = serde_json::from_str(r#"{"success":true,"events":[]}"#).unwrap();
+    let progress = bingo_progress(&newcomer, &resources);
+    assert_eq!(progress.points(), 0);
+    assert_eq!(progress.remaining_goals().len(), 3);
+}
+
+#[test]
+fn test_collection_progress() {
+    use crate::util::collections::{collection_progress, completion};
+    use crate::ProfileMember;
+
+    let resources = r#"
+        {
+            "success": true,
+            "collections": {
+                "FARMING": {
+                    "name": "Farming",
+                    "items": {
+                        "WHEAT": { "name": "Wheat", "maxTiers": 3, "tiers": [{ "tier": 1, "amountRequired": 50 }, { "tier": 2, "amountRequired": 100 }, { "tier": 3, "amountRequired": 250 }] },
+                        "CARROT_ITEM": { "name": "Carrot", "maxTiers": 1, "tiers": [{ "tier": 1, "amountRequired": 100 }] }
+                    }
+                },
+                "MINING": {
+                    "name": "Mining",
+                    "items": {
+                        "COBBLESTONE": { "name": "Cobblestone", "maxTiers": 2, "tiers": [{ "tier": 1, "amountRequired": 50 }, { "tier": 2, "amountRequired": 100 }] }
+                    }
+                }
+            }
+        }
+    "#;
+
+    let resources: crate::CollectionsResourcesReply = serde_json::from_str(resources).unwrap();
+    let member: ProfileMember = serde_json::from_str(r#"{"collection":{"WHEAT":120,"COBBLESTONE":5000}}"#).unwrap();
+    assert_eq!(member.collected("WHEAT"), 120);
+    assert_eq!(member.collected("CARROT_ITEM"), 0);
+
+    let progress = collection_progress(member.collection(), &resources);
+    let ids: Vec<&str> = progress.iter().map(|collection| collection.id()).collect();
+    assert_eq!(ids, ["CARROT_ITEM", "WHEAT", "COBBLESTONE"]);
+    assert_eq!(progress[0].tier(), 0);
+    assert_eq!(progress[0].next_tier_requirement(), Some(100));
+    assert_eq!(progress[1].tier(), 2);
+    assert_eq!(progress[1].next_tier_requirement(), Some(250));
+    assert_eq!(progress[1].category(), "FARMING");
+    assert!(progress[2].is_maxed());
+    assert_eq!(progress[2].next_tier_requirement(), None);
+    assert_eq!(completion(&progress), 4.0 / 6.0 * 100.0);
+    assert_eq!(completion(&[]), 0.0);
+}
+
+#[test]
+fn test_duels_title() {
+    use crate::util::duels::{mode_title, overall_title, DuelsDivision};
+
+    assert_eq!(mode_title(50).unwrap().to_string(), "Rookie I");
+    assert_eq!(mode_title(99).unwrap().to_string(), "Rookie V");
+    assert_eq!(mode_title(100).unwrap().division(), DuelsDivision::Iron);
+    assert_eq!(mode_title(24999).unwrap().to_string(), "Godlike V");
+    assert_eq!(mode_title(10_000_000).unwrap().to_string(), "Ascended L");
+    assert_eq!(overall_title(99), None);
+    assert_eq!(overall_title(200).unwrap().to_string(), "Iron I");
+}
+
+#[test]
+fn test_pit_level() {
+    use crate::util::leveling::pit::{calculate_level, level_in_prestige, prestige, total_xp_to_level};
+
+    assert_eq!(calculate_level(150.0), 10.0);
+    assert_eq!(calculate_level(65950.0), 120.0);
+    assert_eq!(calculate_level(66115.0), 130.0);
+    assert_eq!(prestige(130.0), 1);
+    assert_eq!(level_in_prestige(130.0), 10.0);
+    assert_eq!(total_xp_to_level(240.0), 65950.0 + 72545.0);
+    assert_eq!(calculate_level(f64::MAX), 6120.0);
+}
+
+#[test]
+fn test_uhc_star() {
+    use crate::util::leveling::uhc::{calculate_level, exact_level, title, total_xp_to_level};
+
+    assert_eq!(calculate_level(0.0), 1.0);
+    assert_eq!(calculate_level(60.0), 3.0);
+    assert_eq!(calculate_level(5000.0), 8.0);
+    assert_eq!(calculate_level(30000.0), 15.0);
+    assert_eq!(exact_level(135.0), 3.5);
+    assert_eq!(total_xp_to_level(3.5), 135.0);
+    assert_eq!(title(5.0), "Knight");
+    assert_eq!(title(15.0), "Champion");
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_mock_server() {
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async move {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#)
+                .rate_limit(120, 100, 0)
+                .inject_too_many_requests(1);
+            let request_handler = server.request_handler(Uuid::nil());
+
+            let reply = request_handler.request::<StatusReply>("status?uuid=ad8fefaa8351454bb739a4eaa872173f", true).await.unwrap().unwrap();
+            assert!(reply.online());
+            assert_eq!(server.requests().len(), 2);
+            assert_eq!(request_handler.key_usage().unwrap().remaining(), 99);
+
+            let missing = request_handler.request::<StatusReply>("guild?id=1", true).await.unwrap();
+            assert!(matches!(missing, Err(crate::error::HypixelApiError::UnexpectedResponseCode(reqwest::StatusCode::NOT_FOUND, Some(_)))));
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_vcr() {
+    use crate::{Cassette, MockHypixelServer};
+
+    let path = std::env::temp_dir().join(format!("hypixel_api_cassette_{}.json", std::process::id()));
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).record(&path).build();
+            request_handler.request::<StatusReply>("status?uuid=ad8fefaa8351454bb739a4eaa872173f", true).await.unwrap().unwrap();
+
+            let cassette = Cassette::load(&path).unwrap();
+            assert_eq!(cassette.len(), 1);
+            assert!(!std::fs::read_to_string(&path).unwrap().contains("00000000"));
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url("http://invalid.localhost").replay(cassette).build();
+            let reply = request_handler.request::<StatusReply>("status?uuid=ad8fefaa8351454bb739a4eaa872173f", true).await.unwrap().unwrap();
+            assert!(reply.online());
+            let missing = request_handler.request::<StatusReply>("status", true).await.unwrap();
+            assert!(matches!(missing, Err(crate::error::HypixelApiError::CassetteMiss(_))));
+        });
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_journal() {
+    use crate::{Journal, MockHypixelServer};
+
+    let path = std::env::temp_dir().join(format!("hypixel_api_journal_{}.jsonl", std::process::id()));
+    // a previous run queued two pages and crashed after the first finished
+    std::fs::write(&path, concat!(
+        r#"{"op":"queued","path":"skyblock/auctions?page=0","authenticated":false}"#, "\n",
+        r#"{"op":"queued","path":"skyblock/auctions?page=1","authenticated":false,"tag":"scan"}"#, "\n",
+        r#"{"op":"finished","path":"skyblock/auctions?page=0"}"#, "\n",
+        r#"{"op":"queued","path":"skyblock/auc"#,
+    )).unwrap();
+    let journal = Journal::open(&path).unwrap();
+    let mut compacted = path.clone().into_os_string();
+    compacted.push(".tmp");
+    assert!(!std::path::Path::new(&compacted).exists());
+    let recovered = journal.recovered();
+    assert_eq!(recovered.len(), 1);
+    assert_eq!(recovered[0].path(), "skyblock/auctions?page=1");
+    assert_eq!(recovered[0].tag(), Some("scan"));
+    assert!(!recovered[0].authenticated());
+    // reopening before the request was queued again keeps it
+    drop(journal);
+    let journal = Journal::open(&path).unwrap();
+    assert_eq!(journal.recovered(), recovered);
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("skyblock/auctions", r#"{"success":true,"auctions":[]}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).journal(journal).build();
+            for entry in &recovered {
+                request_handler.tagged(entry.tag().unwrap().to_string())
+                    .request::<serde_json::Value>(entry.path(), entry.authenticated()).await.unwrap().unwrap();
+            }
+            assert!(request_handler.journal().unwrap().recovered().is_empty());
+            request_handler.request::<serde_json::Value>("skyblock/auctions?page=2", false).await.unwrap().unwrap();
+        });
+    assert!(Journal::open(&path).unwrap().recovered().is_empty());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "fixtures")]
+#[test]
+fn test_fixtures() {
+    use crate::{fixtures, GuildReply, SkillsResourcesReply, SkyblockAuctionsReply, SkyblockBazaarReply};
+
+    let player: PlayerReply = serde_json::from_str(fixtures::player_full()).unwrap();
+    let player = player.player().unwrap();
+    assert_eq!(player.name(), Some("ExamplePlayer"));
+    assert_eq!(player.package_rank(), crate::PackageRank::MvpPlusPlus);
+    assert_eq!(player.stats::<Bedwars>().unwrap().unwrap().wins(), 1523);
+    assert_eq!(player.parkour_completions().lobbies().count(), 2);
+
+    let online: StatusReply = serde_json::from_str(fixtures::status_online()).unwrap();
+    assert!(online.online());
+    let offline: StatusReply = serde_json::from_str(fixtures::status_offline()).unwrap();
+    assert!(!offline.online());
+
+    let guild: GuildReply = serde_json::from_str(fixtures::guild()).unwrap();
+    assert_eq!(guild.guild().unwrap().members().len(), 3);
+
+    let bazaar: SkyblockBazaarReply = serde_json::from_str(fixtures::bazaar()).unwrap();
+    assert_eq!(bazaar.product("BOOSTER_COOKIE").unwrap().instant_buy_price(), Some(2899999.9));
+
+    let auctions: SkyblockAuctionsReply = serde_json::from_str(fixtures::auctions()).unwrap();
+    assert_eq!(auctions.auctions().len(), 2);
+
+    let quests: QuestsResourcesReply = serde_json::from_str(fixtures::resources_quests()).unwrap();
+    assert!(quests.quest("bedwars_weekly_bed_elims").unwrap().is_weekly());
+
+    let skills: SkillsResourcesReply = serde_json::from_str(fixtures::resources_skills()).unwrap();
+    assert_eq!(skills.skill("FARMING").unwrap().xp_table().len(), 5);
+
+    let games: crate::GamesResourcesReply = serde_json::from_str(fixtures::resources_games()).unwrap();
+    assert_eq!(games.by_database_name("HungerGames").unwrap().mode_name("teams_normal"), Some("Teams"));
+
+    let profiles: crate::SkyblockProfilesReply = serde_json::from_str(fixtures::skyblock_profiles()).unwrap();
+    assert_eq!(profiles.profiles().len(), 2);
+}
+
+#[cfg(feature = "diagnostics")]
+#[test]
+fn test_diagnostics() {
+    use crate::diagnostics::deserialize_with_report;
+    use crate::SkillsResourcesReply;
+
+    let json = r#"{"success":true,"skills":{"FARMING":{"name":"Farming","maxLevel":60,"newField":1}}}"#;
+    let (reply, report) = deserialize_with_report::<SkillsResourcesReply>("resources/skyblock/skills", json);
+    assert_eq!(reply.unwrap().skill("FARMING").unwrap().max_level(), 60);
+    assert_eq!(report.path(), "resources/skyblock/skills");
+    assert_eq!(report.ignored_fields(), ["skills.FARMING.newField"]);
+    assert!(report.error().is_none());
+
+    let json = r#"{"success":true,"skills":{"FARMING":{"name":"Farming","maxLevel":"60"}}}"#;
+    let (reply, report) = deserialize_with_report::<SkillsResourcesReply>("resources/skyblock/skills", json);
+    assert!(reply.is_err());
+    assert_eq!(report.error().unwrap().field(), "skills.FARMING.maxLevel");
+
+    let json = r#"{"success":true,"player":{"uuid":"3fa85f6457174562b3fc2c963f66afa6","giftingMeta":{"newField":1}}}"#;
+    let (reply, report) = deserialize_with_report::<PlayerReply>("player", json);
+    assert!(reply.is_ok());
+    assert_eq!(report.ignored_fields(), ["player.giftingMeta.newField"]);
+
+    let json = r#"{"success":true,"player":{"uuid":"3fa85f6457174562b3fc2c963f66afa6","giftingMeta":{"giftsGiven":"many"}}}"#;
+    let (reply, report) = deserialize_with_report::<PlayerReply>("player", json);
+    assert!(reply.is_err());
+    assert_eq!(report.error().unwrap().field(), "player");
+    assert!(report.error().unwrap().message().starts_with("giftingMeta.giftsGiven: "));
+}
+
+#[cfg(all(feature = "diagnostics", feature = "test-util"))]
+#[test]
+fn test_diagnostics_callback() {
+    use std::sync::{Arc, Mutex};
+    use crate::{MockHypixelServer, SkillsResourcesReply};
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("resources/skyblock/skills", r#"{"success":true,"skills":{"FARMING":{"name":"Farming","maxLevel":60,"newField":1}}}"#);
+            let collected = reports.clone();
+            let request_handler = RequestHandler::builder(Uuid::nil())
+                .base_url(server.url())
+                .diagnostics(move |report| collected.lock().unwrap().push(report.to_string()))
+                .build();
+            let reply = request_handler.request::<SkillsResourcesReply>("resources/skyblock/skills", false).await.unwrap().unwrap();
+            assert!(reply.success());
+        });
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.len(), 1);
+    assert!(reports[0].contains("skills.FARMING.newField"));
+}
+
+#[cfg(all(feature = "diagnostics", feature = "test-util"))]
+#[test]
+fn test_strict() {
+    use crate::{MockHypixelServer, SkillsResourcesReply};
+    use crate::diagnostics::deserialize_strict;
+    use crate::error::HypixelApiError;
+
+    let known = r#"{"success":true,"skills":{"FARMING":{"name":"Farming","maxLevel":60}}}"#;
+    assert!(deserialize_strict::<SkillsResourcesReply>("resources/skyblock/skills", known).is_ok());
+    assert!(matches!(deserialize_strict::<SkillsResourcesReply>("resources/skyblock/skills", "{}"), Err(HypixelApiError::SerdeJsonError { .. })));
+    // fields captured by catch-all maps are unknown as well
+    let player = r#"{"success":true,"player":{"uuid":"ad8fefaa8351454bb739a4eaa872173f","displayname":"Player","newField":1}}"#;
+    assert!(serde_json::from_str::<PlayerReply>(player).is_ok());
+    match deserialize_strict::<PlayerReply>("player", player) {
+        Err(HypixelApiError::UnknownFields(_, fields)) => assert_eq!(fields, ["player.newField"]),
+        other => panic!("expected unknown fields, got {:?}", other),
+    }
+    assert!(deserialize_strict::<PlayerReply>("player", r#"{"success":true,"player":null}"#).is_ok());
+    // wherever the data structures with catch-all maps are nested
+    fn unknown_fields<T: std::fmt::Debug>(result: Result<T, HypixelApiError>) -> Vec<String> {
+        match result {
+            Err(HypixelApiError::UnknownFields(_, fields)) => fields,
+            other => panic!("expected unknown fields, got {:?}", other),
+        }
+    }
+    assert_eq!(unknown_fields(deserialize_strict::<crate::WithRaw<PlayerReply>>("player", player)), ["player.newField"]);
+    let data = r#"{"uuid":"ad8fefaa8351454bb739a4eaa872173f","displayname":"Player","newField":1,"otherField":2}"#;
+    assert_eq!(unknown_fields(deserialize_strict::<PlayerData>("player", data)), ["newField", "otherField"]);
+    assert_eq!(unknown_fields(deserialize_strict::<crate::BlitzStats>("player", r#"{"coins":1,"newField":1}"#)), ["newField"]);
+    let auctions = r#"{"success":true,"auctions":[
+        {"uuid":"409a1e0f261a49849493278d6cd9305a","auctioneer":"ad8fefaa8351454bb739a4eaa872173f","end":1650000060000,"item_name":"Hyperion"},
+        {"uuid":"509a1e0f261a49849493278d6cd9305a","auctioneer":"ad8fefaa8351454bb739a4eaa872173f","end":1650000060000,"item_name":"Hyperion","newField":1}
+    ]}"#;
+    assert_eq!(unknown_fields(deserialize_strict::<crate::SkyblockAuctionsReply>("skyblock/auctions", auctions)), ["auctions.1.newField"]);
+    // and so are the fields of the status data
+    let status = r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":false,"newField":1},"newField":1}"#;
+    match deserialize_strict::<StatusReply>("status", status) {
+        Err(HypixelApiError::UnknownFields(_, fields)) => assert_eq!(fields, ["session.newField", "newField"]),
+        other => panic!("expected unknown fields, got {:?}", other),
+    }
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("resources/skyblock/skills", r#"{"success":true,"skills":{"FARMING":{"name":"Farming","maxLevel":60,"newField":1}}}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).strict(true).build();
+            match request_handler.request::<SkillsResourcesReply>("resources/skyblock/skills", false).await.unwrap() {
+                Err(HypixelApiError::UnknownFields(path, fields)) => {
+                    assert_eq!(path, "resources/skyblock/skills");
+                    assert_eq!(fields, ["skills.FARMING.newField"]);
+                }
+                other => panic!("expected unknown fields, got {:?}", other),
+            }
+            let lenient = server.request_handler(Uuid::nil());
+            assert!(lenient.request::<SkillsResourcesReply>("resources/skyblock/skills", false).await.unwrap().is_ok());
+        });
+}
+
+#[cfg(all(feature = "metrics", feature = "test-util"))]
+#[test]
+fn test_metrics() {
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+    use crate::{metrics, MockHypixelServer};
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    assert_eq!(crate::api::request::endpoint("skyblock/auctions?page=2"), "skyblock/auctions");
+    ::metrics::with_local_recorder(&recorder, || {
+        tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap()
+            .block_on(async {
+                let server = MockHypixelServer::start().await;
+                server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+                server.reply("counts", r#"{"success":true,"playerCount":"many"}"#);
+                let request_handler = server.request_handler(Uuid::nil());
+                request_handler.request::<StatusReply>("status?uuid=ad8fefaa8351454bb739a4eaa872173f", true).await.unwrap().unwrap();
+                let invalid = request_handler.request::<StatusReply>("counts", true).await.unwrap();
+                assert!(invalid.is_err());
+            });
+    });
+
+    let snapshot = snapshotter.snapshot().into_vec();
+    let find = |name: &str, endpoint: &str| snapshot.iter()
+        .find(|(key, _, _, _)| key.key().name() == name && key.key().labels().any(|label| label.key() == "endpoint" && label.value() == endpoint))
+        .map(|(_, _, _, value)| value);
+    assert_eq!(find(metrics::REQUESTS_TOTAL, "status"), Some(&DebugValue::Counter(1)));
+    assert_eq!(find(metrics::REQUESTS_TOTAL, "counts"), Some(&DebugValue::Counter(1)));
+    assert_eq!(find(metrics::DESERIALIZATION_FAILURES_TOTAL, "counts"), Some(&DebugValue::Counter(1)));
+    assert_eq!(find(metrics::DESERIALIZATION_FAILURES_TOTAL, "status"), None);
+    assert!(matches!(find(metrics::REQUEST_DURATION_SECONDS, "status"), Some(DebugValue::Histogram(values)) if values.len() == 1));
+}
+
+#[cfg(all(feature = "tracing", feature = "test-util"))]
+#[tracing_test::traced_test]
+#[tokio::test]
+async fn test_tracing() {
+    use crate::MockHypixelServer;
+
+    let server = MockHypixelServer::start().await;
+    server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+    server.rate_limit(120, 100, 60);
+    let request_handler = server.request_handler(Uuid::nil());
+    let first = request_handler.request::<StatusReply>("status?uuid=ad8fefaa8351454bb739a4eaa872173f", true);
+    let second = request_handler.request::<StatusReply>("status?uuid=ad8fefaa8351454bb739a4eaa872173f", true);
+    first.await.unwrap().unwrap();
+    second.await.unwrap().unwrap();
+
+    assert!(logs_contain("endpoint=status"));
+    assert!(logs_contain("status=200"));
+    assert!(logs_contain("ratelimit_remaining=99"));
+    assert!(logs_contain("attempt=1"));
+    assert!(logs_contain("Waiting for the rate limit"));
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_request_events() {
+    use crate::{MockHypixelServer, RequestEvent};
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            let request_handler = server.request_handler(Uuid::nil());
+            let mut events = request_handler.subscribe();
+            request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap();
+            assert!(request_handler.request::<StatusReply>("missing", true).await.unwrap().is_err());
+
+            let mut received = Vec::new();
+            while let Ok(event) = events.try_recv() {
+                received.push(event);
+            }
+            assert_eq!(received.len(), 6);
+            assert!(matches!(&received[0], RequestEvent::Queued { id, path } if id.get() == 0 && path == "status"));
+            assert!(matches!(&received[1], RequestEvent::Sent { attempt: 1, .. }));
+            assert!(matches!(&received[2], RequestEvent::Completed { status, .. } if *status == reqwest::StatusCode::OK));
+            assert!(matches!(&received[3], RequestEvent::Queued { id, .. } if id.get() == 1));
+            assert!(matches!(&received[5], RequestEvent::Failed { id, .. } if id.get() == 1));
+
+            let stats = request_handler.stats();
+            assert_eq!(stats.completed(), 1);
+            assert_eq!(stats.failed(), 1);
+            assert_eq!(stats.error_rate(), 0.5);
+            assert_eq!(stats.requests_per_minute(), 2);
+            assert!(stats.latency_p50().unwrap() <= stats.latency_p99().unwrap());
+            assert!(request_handler.stats_by_tag().is_empty());
+
+            let tracker = request_handler.tagged("tracker");
+            let leaderboards = request_handler.tagged(String::from("leaderboards"));
+            assert_eq!(tracker.tag(), Some("tracker"));
+            assert_eq!(request_handler.tag(), None);
+            tracker.request::<StatusReply>("status", true).await.unwrap().unwrap();
+            tracker.request::<StatusReply>("status", true).await.unwrap().unwrap();
+            assert!(leaderboards.request::<StatusReply>("missing", true).await.unwrap().is_err());
+            let tags = request_handler.stats_by_tag();
+            assert_eq!(tags["tracker"].completed(), 2);
+            assert_eq!(tags["tracker"].requests(), 2);
+            assert!(tags["tracker"].average_latency().unwrap() <= tags["tracker"].total_latency());
+            assert_eq!(tags["leaderboards"].failed(), 1);
+            assert_eq!(request_handler.stats().completed(), 3);
+        });
+}
+
+#[cfg(all(feature = "body-capture", feature = "test-util"))]
+#[test]
+fn test_body_capture() {
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":"yes"}}"#);
+            server.reply_with_status("guild", 403, r#"{"success":false,"cause":"Invalid API key"}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).capture_failed_bodies(16).build();
+            assert!(request_handler.request::<StatusReply>("status", true).await.unwrap().is_err());
+            assert!(request_handler.request::<StatusReply>("guild", false).await.unwrap().is_err());
+
+            let captures = request_handler.captured_bodies();
+            assert_eq!(captures.len(), 2);
+            assert_eq!(captures[0].path(), "status");
+            assert_eq!(captures[0].status(), reqwest::StatusCode::OK);
+            assert_eq!(captures[0].body(), r#"{"success":true,"#);
+            assert!(captures[0].truncated());
+            assert_eq!(captures[0].request_headers(), [("API-Key".to_string(), "<redacted>".to_string())]);
+            assert!(!captures[0].to_string().contains("00000000-0000"));
+            assert_eq!(captures[1].status(), reqwest::StatusCode::FORBIDDEN);
+            assert!(captures[1].error().contains("Invalid API key"));
+            assert!(captures[1].request_headers().is_empty());
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_get_statuses() {
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            server.reply_with_status("status?uuid=00000000000000000000000000000002", 500, r#"{"success":false,"cause":"Internal error"}"#);
+            let request_handler = server.request_handler(Uuid::nil());
+            let uuids: Vec<Uuid> = (0..20).map(|i| Uuid::from_u128(i % 10)).collect();
+            let batch = request_handler.get_statuses(&uuids).await;
+            assert_eq!(server.requests().len(), 10);
+            assert_eq!(batch.statuses().len(), 9);
+            assert!(batch.status(&Uuid::from_u128(1)).unwrap().online());
+            assert!(batch.status(&Uuid::from_u128(2)).is_none());
+            assert!(batch.errors().contains_key(&Uuid::from_u128(2)));
+            assert!(!batch.is_complete());
+            let (statuses, errors) = batch.into_parts();
+            assert_eq!((statuses.len(), errors.len()), (9, 1));
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_stream_requests() {
+    use crate::{ApiPath, MockHypixelServer};
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            server.reply_with_status("status?uuid=00000000000000000000000000000003", 404, r#"{"success":false,"cause":"Not found"}"#);
+            let request_handler = server.request_handler(Uuid::nil());
+            let paths = (0..6).map(|i| ApiPath::Status { uuid: Uuid::from_u128(i) });
+            let replies = request_handler.stream_requests::<StatusReply, _>(paths, 2);
+            let results: Vec<(ApiPath, Result<StatusReply, _>)> = replies.collect().await;
+            assert_eq!(results.len(), 6);
+            assert_eq!(server.requests().len(), 6);
+            let failed: Vec<&ApiPath> = results.iter().filter(|(_, result)| result.is_err()).map(|(path, _)| path).collect();
+            assert_eq!(failed, [&ApiPath::Status { uuid: Uuid::from_u128(3) }]);
+
+            // paths are only taken while the stream is polled
+            let mut replies = Box::pin(request_handler.stream_requests::<StatusReply, _>((0..100).map(|i| ApiPath::Status { uuid: Uuid::from_u128(i) }), 2));
+            assert!(replies.next().await.unwrap().1.is_ok());
+            drop(replies);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            assert!(server.requests().len() <= 6 + 3);
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_guild_lookups() {
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("guild", r#"{"success":true,"guild":{"_id":"553490650cf26f12ae5bac8f","name":"Mini Squid","exp":0,"members":[],"ranks":[]}}"#);
+            server.reply("guild?name=Nobody", r#"{"success":true,"guild":null}"#);
+            let request_handler = server.request_handler(Uuid::nil());
+            let uuid = Uuid::from_str("ad8fefaa-8351-454b-b739-a4eaa872173f").unwrap();
+            let replies = [
+                request_handler.get_guild_by_id("553490650cf26f12ae5bac8f").await.unwrap().unwrap(),
+                request_handler.get_guild_by_name("Mini Squid").await.unwrap().unwrap(),
+                request_handler.get_guild_by_player(uuid).await.unwrap().unwrap(),
+            ];
+            assert!(replies.iter().all(|reply| reply.guild().unwrap().name() == "Mini Squid"));
+            assert!(request_handler.get_guild_by_name("Nobody").await.unwrap().unwrap().guild().is_none());
+            assert_eq!(server.requests(), [
+                "guild?id=553490650cf26f12ae5bac8f",
+                "guild?name=Mini%20Squid",
+                "guild?player=ad8fefaa8351454bb739a4eaa872173f",
+                "guild?name=Nobody",
+            ]);
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_inspect_body() {
+    use crate::error::HypixelApiError;
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("counts", r#"{"success":true,"playerCount":"Infinity"}"#);
+            server.reply("boosters", r#"{"success":true,"boosters":[]}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil())
+                .base_url(server.url())
+                .inspect_body(|path, body| {
+                    if path == "boosters" {
+                        return Err("boosters are disabled".to_string());
+                    }
+                    *body = String::from_utf8_lossy(body).replace(r#""Infinity""#, "0").into_bytes();
+                    Ok(())
+                })
+                .build();
+            let reply = request_handler.request::<crate::CountsReply>("counts", false).await.unwrap().unwrap();
+            assert_eq!(reply.player_count(), 0);
+            assert_eq!(&request_handler.request_bytes("counts", false).await.unwrap().unwrap()[..], br#"{"success":true,"playerCount":0}"#);
+            let error = request_handler.request::<serde_json::Value>("boosters", false).await.unwrap().unwrap_err();
+            assert!(matches!(&error, HypixelApiError::BodyRejected(path, reason) if path == "boosters" && reason == "boosters are disabled"));
+        });
+}
+
+#[cfg(all(feature = "simd-json", feature = "test-util"))]
+#[test]
+fn test_simd_json() {
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true,"gameType":"BEDWARS"}}"#);
+            server.reply("counts", r#"{"success":true,"playerCount":"many"}"#);
+            let request_handler = server.request_handler(Uuid::nil());
+            let reply = request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap();
+            assert!(reply.online());
+            // falls back to serde_json for RawValue
+            let reply = request_handler.request::<WithRaw<StatusReply>>("status", true).await.unwrap().unwrap();
+            assert!(reply.raw().contains("BEDWARS"));
+            let invalid = request_handler.request::<StatusReply>("counts", true).await.unwrap();
+            assert!(matches!(invalid, Err(crate::error::HypixelApiError::SerdeJsonError { .. })));
+        });
+}
+
+#[test]
+fn test_item_scanner() {
+    use crate::api::stream::ItemScanner;
+
+    fn scan(json: &str, chunk_size: usize) -> Vec<serde_json::Value> {
+        let mut scanner = ItemScanner::new();
+        let mut items = Vec::new();
+        for chunk in json.as_bytes().chunks(chunk_size) {
+            scanner.push(chunk);
+            while let Some(item) = scanner.next_item::<serde_json::Value>() {
+                items.push(item.unwrap());
+            }
+        }
+        scanner.finish().unwrap();
+        items
+    }
+
+    let auctions = r#"{"success":true,"page":0,"note":"a [tricky] {\"string\"}","auctions":[{"uuid":"a","lore":"]}"},{"uuid":"b","bids":[1,2]}],"after":[3]}"#;
+    let products = r#"{ "success" : true, "lastUpdated": 1, "products": { "A": {"id": "A"}, "B": {"id": "B,"} } }"#;
+    for chunk_size in 1..8 {
+        let items = scan(auctions, chunk_size);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["lore"], "]}");
+        assert_eq!(items[1]["bids"][1], 2);
+        let items = scan(products, chunk_size);
+        assert_eq!(items, vec![serde_json::json!({"id": "A"}), serde_json::json!({"id": "B,"})]);
+        assert_eq!(scan(r#"[1, "two", null, [3], -4.5e1]"#, chunk_size), vec![serde_json::json!(1), "two".into(), serde_json::Value::Null, serde_json::json!([3]), serde_json::json!(-45.0)]);
+    }
+    assert!(scan(r#"{"success":true,"empty":[]}"#, 3).is_empty());
+
+    let mut scanner = ItemScanner::new();
+    scanner.push(br#"{"auctions":[{"uuid":"#);
+    assert!(scanner.next_item::<serde_json::Value>().is_none());
+    assert!(scanner.finish().is_err());
+}
+
+#[cfg(all(feature = "test-util", feature = "fixtures"))]
+#[test]
+fn test_stream_items() {
+    use crate::{fixtures, Auction, MockHypixelServer, SkyblockAuctionsReply};
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("skyblock/auctions", fixtures::auctions());
+            let request_handler = server.request_handler(Uuid::nil());
+            let auctions: Vec<Auction> = request_handler.request_stream_items::<Auction>("skyblock/auctions?page=0", false)
+                .map(|auction| auction.unwrap())
+                .collect().await;
+            let reply: SkyblockAuctionsReply = serde_json::from_str(fixtures::auctions()).unwrap();
+            assert_eq!(auctions.len(), reply.auctions().len());
+            assert_eq!(auctions[0].uuid(), reply.auctions()[0].uuid());
+
+            let missing: Vec<_> = request_handler.request_stream_items::<Auction>("missing", false).collect().await;
+            assert_eq!(missing.len(), 1);
+            assert!(matches!(missing[0], Err(crate::error::HypixelApiError::UnexpectedResponseCode(..))));
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_request_bytes() {
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let json = r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#;
+            let server = MockHypixelServer::start().await;
+            server.reply("status", json);
+            let request_handler = server.request_handler(Uuid::nil());
+            let body = request_handler.request_bytes("status", true).await.unwrap().unwrap();
+            assert_eq!(&body[..], json.as_bytes());
+            let reply: StatusReply = serde_json::from_slice(&body).unwrap();
+            assert!(reply.online());
+            assert!(request_handler.request_bytes("missing", true).await.unwrap().is_err());
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_scan_all_auctions() {
+    use crate::MockHypixelApi;
+    use crate::util::auctions::scan_all_auctions;
+
+    let page = |page: u32, last_updated: u64| format!(
+        r#"{{"success":true,"page":{},"totalPages":4,"totalAuctions":3,"lastUpdated":{},"auctions":[{{"uuid":"409a1e0f261a49849493278d6cd9305a","auctioneer":"347ef6c1daac45ed9d1fa02818cf0fb6","item_name":"Item {}","starting_bid":{},"bin":true}}]}}"#,
+        page, last_updated, page, 100 + page,
+    );
+    let api = MockHypixelApi::new()
+        .with_reply("skyblock/auctions?page=0", page(0, 1700000000000))
+        .with_reply("skyblock/auctions?page=1", page(1, 1700000000000))
+        .with_reply("skyblock/auctions?page=2", page(2, 1700000060000));
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let scan = scan_all_auctions(&api, 2).await.unwrap();
+            assert_eq!(scan.len(), 3);
+            assert_eq!(scan.pages().iter().map(|page| page.page()).collect::<Vec<_>>(), [0, 1, 2]);
+            assert_eq!(scan.auctions().map(|auction| auction.starting_bid()).collect::<Vec<_>>(), [100, 101, 102]);
+            assert!(!scan.is_consistent());
+            assert_eq!(api.requests().len(), 4);
+
+            let empty = MockHypixelApi::new();
+            assert!(scan_all_auctions(&empty, 2).await.is_err());
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_snipe() {
+    use crate::MockHypixelApi;
+    use crate::util::auctions::{snipe, AuctionFilter};
+
+    let auction = |name: &str, tier: &str, lore: &str, bid: u64, bin: bool| format!(
+        r#"{{"uuid":"409a1e0f261a49849493278d6cd9305a","auctioneer":"347ef6c1daac45ed9d1fa02818cf0fb6","item_name":"{}","tier":"{}","item_lore":"{}","starting_bid":{},"bin":{}}}"#,
+        name, tier, lore, bid, bin,
+    );
+    let page = |page: u32, auctions: &[String]| format!(
+        r#"{{"success":true,"page":{},"totalPages":4,"totalAuctions":6,"lastUpdated":1700000000000,"auctions":[{}]}}"#,
+        page, auctions.join(","),
+    );
+    let api = MockHypixelApi::new()
+        .with_reply("skyblock/auctions?page=0", page(0, &[
+            auction("Hyperion", "LEGENDARY", "§9Ultimate Wise V", 900, true),
+            auction("Dirt", "COMMON", "", 1, true),
+        ]))
+        .with_reply("skyblock/auctions?page=1", page(1, &[
+            auction("Valkyrie", "MYTHIC", "§d§lUltimate WISE V", 800, true),
+            auction("Scylla", "LEGENDARY", "Ultimate Wise V", 700, false),
+        ]))
+        .with_reply("skyblock/auctions?page=2", page(2, &[
+            auction("Astraea", "LEGENDARY", "Ultimate Wise V", 2000, true),
+            auction("Mystery", "UNKNOWN", "Ultimate Wise V", 10, true),
+        ]));
+    let filter = AuctionFilter::new().bin_only().max_price(1000).tier_at_least("LEGENDARY").enchant_contains("ultimate wise");
+    assert!(!AuctionFilter::new().tier_at_least("NOT_A_TIER").matches(&serde_json::from_str(&auction("Dirt", "VERY_SPECIAL", "", 1, true)).unwrap()));
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let mut names: Vec<_> = snipe(&api, filter, 2)
+                .map(|auction| auction.unwrap().item_name().to_string())
+                .collect().await;
+            names.sort();
+            assert_eq!(names, ["Hyperion", "Valkyrie"]);
+            assert_eq!(api.requests().len(), 4);
+
+            let all: Vec<_> = snipe(&api, AuctionFilter::new(), 1).collect().await;
+            assert_eq!(all.len(), 6);
+            let failed: Vec<_> = snipe(&MockHypixelApi::new(), AuctionFilter::new(), 1).collect().await;
+            assert!(matches!(failed.as_slice(), [Err(_)]));
+        });
+}
+
+#[test]
+fn test_response_cache() {
+    use bytes::Bytes;
+    use crate::cache::{CacheConfig, ResponseCache};
+
+    let cache = ResponseCache::new(CacheConfig::new().max_entries(2).max_bytes(10));
+    cache.insert("a", Bytes::from_static(b"aaa"));
+    cache.insert("b", Bytes::from_static(b"bbb"));
+    assert_eq!(cache.get("a").as_deref(), Some(&b"aaa"[..]));
+    // "b" is the least recently used
+    cache.insert("c", Bytes::from_static(b"ccc"));
+    assert!(cache.get("b").is_none());
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.size_bytes(), 6);
+    // evicts "a" to stay within 10 bytes
+    cache.insert("d", Bytes::from_static(b"ddddddd"));
+    assert!(cache.get("a").is_none());
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.size_bytes(), 10);
+    cache.insert("e", Bytes::from_static(b"too large body"));
+    assert!(cache.get("e").is_none());
+    let stats = cache.stats();
+    assert_eq!((stats.hits(), stats.misses(), stats.evictions()), (1, 3, 2));
+    assert_eq!((stats.entries(), stats.bytes()), (2, 10));
+    assert_eq!(stats.hit_rate(), Some(0.25));
+
+    cache.insert("player?uuid=a", Bytes::from_static(b"a"));
+    cache.insert("player?uuid=b", Bytes::from_static(b"b"));
+    assert!(cache.invalidate("player?uuid=a"));
+    assert!(!cache.invalidate("player?uuid=a"));
+    cache.insert("d", Bytes::from_static(b"d"));
+    assert_eq!(cache.invalidate_prefix("player"), 1);
+    assert_eq!(cache.len(), 1);
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.size_bytes(), 0);
+    assert_eq!(cache.stats().hits(), 1);
+
+    let cache = ResponseCache::new(CacheConfig::new().ttl(Duration::ZERO));
+    cache.insert("a", Bytes::from_static(b"aaa"));
+    std::thread::sleep(Duration::from_millis(1));
+    assert!(cache.get("a").is_none());
+    assert!(cache.is_empty());
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_cached_requests() {
+    use crate::cache::{CacheConfig, CachePolicy};
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).cache(CacheConfig::new()).build();
+            for _ in 0..3 {
+                let reply = request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap();
+                assert!(reply.online());
+            }
+            assert!(request_handler.request::<StatusReply>("missing", true).await.unwrap().is_err());
+            assert_eq!(server.requests().len(), 2);
+            assert_eq!(request_handler.cache().unwrap().len(), 1);
+
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":false}}"#);
+            let reply = request_handler.request_with_policy::<StatusReply>("status", true, CachePolicy::Bypass).await.unwrap().unwrap();
+            assert!(!reply.online());
+            // bypassed responses are not cached
+            assert!(request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap().online());
+            let reply = request_handler.request_with_policy::<StatusReply>("status", true, CachePolicy::Refresh).await.unwrap().unwrap();
+            assert!(!reply.online());
+            assert!(!request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap().online());
+            assert_eq!(server.requests().len(), 4);
+
+            // unsuccessful and malformed replies are not cached
+            for body in [r#"{"success":false,"cause":"Try again"}"#, "{"] {
+                server.reply("counts", body);
+                for _ in 0..2 {
+                    let _ = request_handler.request::<serde_json::Value>("counts", true).await.unwrap();
+                }
+            }
+            assert_eq!(server.requests().len(), 8);
+            assert_eq!(request_handler.cache().unwrap().len(), 1);
+
+            // different reply types share one concurrent fetch of the same path
+            #[derive(serde::Deserialize)]
+            struct SlimStatus {
+                session: SlimSession,
+            }
+            #[derive(serde::Deserialize)]
+            struct SlimSession {
+                online: bool,
+            }
+            let path = "status?uuid=ad8fefaa8351454bb739a4eaa872173f";
+            let (slim, reply) = futures::join!(
+                request_handler.request::<SlimStatus>(path, true),
+                request_handler.request::<StatusReply>(path, true),
+            );
+            assert!(!slim.unwrap().unwrap().session.online);
+            assert!(!reply.unwrap().unwrap().online());
+            assert_eq!(server.requests().len(), 9);
+            assert_eq!(request_handler.cache().unwrap().len(), 2);
+
+            // an unsuccessful first reply is not shared, every waiting request sends its own
+            let path = "status?uuid=069a79f444e94726a5befca90e38aaf5";
+            server.reply(path, r#"{"success":false,"cause":"Try again"}"#);
+            let (slim, other) = futures::join!(
+                request_handler.request::<SlimStatus>(path, true),
+                request_handler.request::<SlimStatus>(path, true),
+            );
+            assert!(slim.unwrap().is_err() && other.unwrap().is_err());
+            assert_eq!(server.requests().len(), 11);
+            server.reply(path, r#"{"success":true,"uuid":"069a79f444e94726a5befca90e38aaf5","session":{"online":true}}"#);
+            let (slim, reply) = futures::join!(
+                request_handler.request::<SlimStatus>(path, true),
+                request_handler.request::<StatusReply>(path, true),
+            );
+            assert!(slim.unwrap().unwrap().session.online);
+            assert!(reply.unwrap().unwrap().online());
+            assert_eq!(server.requests().len(), 12);
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_retry_policy() {
+    use crate::error::{ErrorReply, HypixelApiError};
+    use crate::{MockHypixelServer, RetryPolicy};
+
+    let error = |status: u16, cause: &str| HypixelApiError::UnexpectedResponseCode(
+        reqwest::StatusCode::from_u16(status).unwrap(),
+        serde_json::from_str::<ErrorReply>(&format!(r#"{{"success":false,"cause":"{}"}}"#, cause)).ok(),
+    );
+    let policy = RetryPolicy::new().cause("Try Again");
+    assert!(policy.is_retryable(&error(503, "Maintenance")));
+    assert!(policy.is_retryable(&error(500, "Leaderboard data has not yet been populated")));
+    assert!(policy.is_retryable(&error(429, "please try again")));
+    assert!(!policy.is_retryable(&error(404, "Not found")));
+    assert!(!policy.is_retryable(&HypixelApiError::InvalidApiKey));
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply_with_status("leaderboards", 503, r#"{"success":false,"cause":"Maintenance"}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil())
+                .base_url(server.url())
+                .retry(RetryPolicy::new().max_retries(3).delay(Duration::from_millis(1)))
+                .build();
+            assert!(request_handler.request::<serde_json::Value>("leaderboards", true).await.unwrap().is_err());
+            assert_eq!(server.requests().len(), 4);
+            assert!(request_handler.request::<serde_json::Value>("missing", true).await.unwrap().is_err());
+            assert_eq!(server.requests().len(), 5);
+
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).retry(RetryPolicy::disabled()).build();
+            assert!(request_handler.request::<serde_json::Value>("leaderboards", true).await.unwrap().is_err());
+            assert_eq!(server.requests().len(), 6);
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_invalid_api_key() {
+    use crate::error::HypixelApiError;
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply_with_status("status", 403, r#"{"success":false,"cause":"Invalid API key"}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).workers(1).build();
+            let requests: Vec<_> = (0..10)
+                .map(|_| request_handler.request::<StatusReply>("status", true))
+                .collect();
+            for request in requests {
+                assert!(matches!(request.await.unwrap(), Err(HypixelApiError::InvalidApiKey)));
+            }
+            // the queued requests failed without being sent
+            assert_eq!(server.requests().len(), 1);
+            assert!(request_handler.is_key_invalid());
+            assert!(matches!(request_handler.request::<StatusReply>("status", true).await.unwrap(), Err(HypixelApiError::InvalidApiKey)));
+            assert_eq!(server.requests().len(), 1);
+
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            request_handler.set_api_key(Uuid::from_str("ad8fefaa8351454bb739a4eaa872173f").unwrap());
+            assert!(!request_handler.is_key_invalid());
+            assert!(request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap().online());
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_error_body_limit() {
+    use crate::error::HypixelApiError;
+    use crate::{MockHypixelServer, RetryPolicy};
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply_with_status("guild", 404, r#"{"success":false,"cause":"Not found"}"#);
+            server.reply_with_status("leaderboards", 502, format!(r#"{{"success":false,"cause":"{}"}}"#, "x".repeat(1024 * 1024)));
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).retry(RetryPolicy::disabled()).build();
+            let error = request_handler.request::<serde_json::Value>("guild", true).await.unwrap().unwrap_err();
+            assert!(matches!(error, HypixelApiError::UnexpectedResponseCode(_, Some(cause)) if cause.cause() == "Not found"));
+            // a huge error body is cut off instead of being read completely
+            let error = request_handler.request::<serde_json::Value>("leaderboards", true).await.unwrap().unwrap_err();
+            assert!(matches!(error, HypixelApiError::UnexpectedResponseCode(status, None) if status.as_u16() == 502));
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_failed_request_returns_ticket() {
+    use crate::{MockHypixelServer, RetryPolicy};
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            server.rate_limit(120, 100, 60);
+            server.inject_connection_failures(1);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).retry(RetryPolicy::disabled()).build();
+            // the first request of the window fails without a response and must not keep its ticket
+            assert!(request_handler.request::<StatusReply>("status", true).await.unwrap().is_err());
+            let reply = tokio::time::timeout(Duration::from_secs(5), request_handler.request::<StatusReply>("status", true)).await
+                .expect("request waited for a ticket that was never returned");
+            assert!(reply.unwrap().unwrap().online());
+            assert_eq!(server.requests().len(), 2);
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_rate_limit_fallback() {
+    use crate::error::HypixelApiError;
+    use crate::{MockHypixelServer, RateLimitFallback};
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil())
+                .base_url(server.url())
+                .rate_limit_fallback(RateLimitFallback::new(Duration::from_secs(60), 5).strict(true))
+                .build();
+            let error = request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap_err();
+            assert!(matches!(error, HypixelApiError::MissingRateLimitHeader(header) if header == "ratelimit-remaining"));
+            // unauthenticated responses do not carry rate limit headers
+            assert!(request_handler.request::<StatusReply>("status", false).await.unwrap().is_ok());
+
+            server.rate_limit(120, 100, 60);
+            assert!(request_handler.request::<StatusReply>("status", true).await.unwrap().is_ok());
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_conditional_requests() {
+    use crate::api::cache::Validators;
+    use crate::cache::CacheConfig;
+    use crate::MockHypixelServer;
+
+    let skills = r#"{"success":true,"lastUpdated":1700000000000,"skills":{"FARMING":{"name":"Farming","maxLevel":60}}}"#;
+    let validators = Validators::new("resources/skyblock/skills", &reqwest::header::HeaderMap::new(), skills.as_bytes());
+    assert_eq!(validators.last_modified.as_deref(), Some("Tue, 14 Nov 2023 22:13:20 GMT"));
+    assert!(Validators::new("skyblock/bazaar", &reqwest::header::HeaderMap::new(), skills.as_bytes()).last_modified.is_none());
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("resources/skyblock/skills", skills);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).cache(CacheConfig::new().ttl(Duration::ZERO)).build();
+            for _ in 0..3 {
+                let reply = request_handler.request::<crate::SkillsResourcesReply>("resources/skyblock/skills", false).await.unwrap().unwrap();
+                assert_eq!(reply.skill("FARMING").unwrap().max_level(), 60);
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            // the expired response was revalidated instead of downloaded again
+            assert_eq!(server.requests().len(), 3);
+            assert_eq!(request_handler.cache().unwrap().stats().misses(), 3);
+
+            server.reply("resources/skyblock/skills", skills.replace("60", "50"));
+            let reply = request_handler.request::<crate::SkillsResourcesReply>("resources/skyblock/skills", false).await.unwrap().unwrap();
+            assert_eq!(reply.skill("FARMING").unwrap().max_level(), 50);
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_stale_while_revalidate() {
+    use crate::cache::CacheConfig;
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            let cache = CacheConfig::new().ttl(Duration::ZERO).stale_while_revalidate(Duration::from_secs(3600));
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).cache(cache).build();
+            assert!(request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap().online());
+
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":false}}"#);
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            // the stale response is served while it is refreshed in the background
+            assert!(request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap().online());
+            let mut refreshed = false;
+            for _ in 0..1000 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                if !request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap().online() {
+                    refreshed = true;
+                    break;
+                }
+            }
+            assert!(refreshed);
+            let stats = request_handler.cache().unwrap().stats();
+            assert_eq!(stats.misses(), 1);
+            assert!(stats.stale_hits() >= 2);
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_dispatcher() {
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).workers(2).build();
+            let requests: Vec<_> = (0..20)
+                .map(|_| request_handler.request::<StatusReply>("status", true))
+                .collect();
+            // queued requests are completed after the handler was dropped
+            drop(request_handler);
+            for request in requests {
+                assert!(request.await.unwrap().unwrap().online());
+            }
+            assert_eq!(server.requests().len(), 20);
+        });
+}
+
+#[test]
+fn test_secret_key() {
+    use crate::SecretKey;
+
+    let key = Uuid::from_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap();
+    let secret = SecretKey::new(key);
+    assert_eq!(secret.expose(), key);
+    assert_eq!(secret.to_string(), "<redacted>");
+    assert_eq!(secret.reveal(2).to_string(), "…f5");
+    assert_eq!(secret.reveal(32).to_string(), "…aaf5");
+
+    let builder = RequestHandler::builder(key);
+    let request_handler = RequestHandler::builder(secret.reveal(4)).build();
+    for debug in [format!("{:?}", builder), format!("{:?}", builder.build()), format!("{:?}", request_handler)] {
+        assert!(!debug.contains("069a79f4"), "{}", debug);
+        assert!(!debug.contains("069a79f444e94726a5befca90e38aaf5"), "{}", debug);
+    }
+    assert!(format!("{:?}", request_handler).contains("SecretKey(…aaf5)"));
+}
+
+#[cfg(all(feature = "legacy-key", feature = "test-util"))]
+#[test]
+fn test_verify_key() {
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("key", r#"{"success":true,"record":{"queriesInPastMin":1,"owner":"069a79f444e94726a5befca90e38aaf5","limit":120,"totalQueries":9}}"#);
+            let request_handler = server.request_handler(Uuid::nil());
+            assert_eq!(request_handler.key_owner(), None);
+            let owner = request_handler.verify_key().await.unwrap();
+            assert_eq!(owner, Uuid::from_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap());
+            assert_eq!(request_handler.key_owner(), Some(owner));
+            request_handler.set_api_key(Uuid::nil());
+            assert_eq!(request_handler.key_owner(), None);
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_default_query() {
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil())
+                .base_url(server.url())
+                .query("token", "a b&c")
+                .query("source", "proxy")
+                .build();
+            request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap();
+            request_handler.request::<StatusReply>("status?uuid=ad8fefaa8351454bb739a4eaa872173f&token=own", true).await.unwrap().unwrap();
+            assert_eq!(server.requests(), [
+                "status?token=a+b%26c&source=proxy",
+                "status?uuid=ad8fefaa8351454bb739a4eaa872173f&token=own&source=proxy",
+            ]);
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_request_when_ready() {
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).workers(1).queue_capacity(3).build();
+            assert_eq!(request_handler.capacity(), 3);
+            // the workers do not run before this task yields
+            let mut requests: Vec<_> = (0..5)
+                .map(|_| request_handler.request::<StatusReply>("status", true))
+                .collect();
+            assert_eq!(request_handler.queue_len(), 5);
+            assert_eq!(request_handler.capacity(), 0);
+            requests.push(request_handler.request_when_ready::<StatusReply>("status", true).await);
+            assert!(request_handler.queue_len() <= 3);
+            for request in requests {
+                assert!(request.await.unwrap().unwrap().online());
+            }
+            assert_eq!(request_handler.queue_len(), 0);
+            assert_eq!(server.requests().len(), 6);
+        });
+}
+
+#[cfg(all(feature = "tower", feature = "test-util"))]
+#[test]
+fn test_tower_service() {
+    use tower_service::Service;
+    use crate::service::HypixelRequest;
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            let mut request_handler = server.request_handler(Uuid::nil());
+            std::future::poll_fn(|cx| request_handler.poll_ready(cx)).await.unwrap();
+            let body = request_handler.call(HypixelRequest::new("status", true)).await.unwrap();
+            let reply: StatusReply = serde_json::from_slice(&body).unwrap();
+            assert!(reply.online());
+            assert!(request_handler.call(HypixelRequest::new("missing", true)).await.is_err());
+        });
+}
+
+#[test]
+fn test_lenient_fields() {
+    use crate::util::lenient;
+
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "displayname": "string",
+            "firstLogin": "1500000000000",
+            "lastLogin": null,
+            "karma": "1200",
+            "networkExp": 1500.0,
+            "rewardStreak": 3.0,
+            "buildTeam": 1,
+            "stats": { "Bedwars": { "coins": "250", "wins_bedwars": 12.0 } }
+        }
+    "#;
+    let data: PlayerData = serde_json::from_str(sample).unwrap();
+    assert_eq!(data.karma(), 1200);
+    assert_eq!(data.first_login_utc().unwrap().timestamp(), 1_500_000_000);
+    assert!(data.last_login_utc().is_none());
+    let bedwars = data.stats::<Bedwars>().unwrap().unwrap();
+    assert_eq!(bedwars.coins(), 250);
+    assert_eq!(bedwars.wins(), 12);
+
+    let number = |json: &str| lenient::deserialize_number::<_, u64>(&mut serde_json::Deserializer::from_str(json));
+    assert_eq!(number("\" 7 \"").unwrap(), 7);
+    assert!(number("7.5").is_err());
+    assert!(number("\"seven\"").is_err());
+    let boolean = |json: &str| lenient::deserialize_bool(&mut serde_json::Deserializer::from_str(json)).unwrap();
+    assert!(boolean("true") && boolean("1") && boolean("\"1\""));
+    assert!(!boolean("0") && !boolean("\"false\""));
+    assert_eq!(lenient::deserialize_string(&mut serde_json::Deserializer::from_str("12")).unwrap(), "12");
+}
+
+#[test]
+fn test_player_flatten() {
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "displayname": "string",
+            "knownAliases": ["old", "string"],
+            "packageRank": "MVP_PLUS",
+            "networkExp": 1.0e3,
+            "giftingMeta": { "giftsGiven": 3, "giftedRanks": ["VIP"] },
+            "quests": { "bedwars_daily_win": { "completions": [{ "time": 1650000000000 }] } },
+            "parkourCompletions": { "mainLobby2022": [{ "timeStart": 1650000000000, "timeTook": 40000 }] },
+            "stats": { "Bedwars": { "final_kills_bedwars": 1234, "favourites": null, "shop": { "slots": [1, 2] } } },
+            "achievements": { "bedwars_level": 100 }
+        }
+    "#;
+    let mut player = serde_json::from_str::<PlayerData>(sample).unwrap();
+    let flat = player.flatten();
+    assert_eq!(flat["uuid"], "3fa85f6457174562b3fc2c963f66afa6");
+    assert_eq!(flat["knownAliases.1"], "string");
+    assert_eq!(flat["packageRank"], "MVP_PLUS");
+    assert_eq!(flat["networkExp"], 1000.0);
+    assert_eq!(flat["giftingMeta.giftsGiven"], 3);
+    assert_eq!(flat["giftingMeta.giftedRanks.0"], "VIP");
+    assert_eq!(flat["quests.bedwars_daily_win.completions.0.time"], 1650000000000u64);
+    assert_eq!(flat["parkourCompletions.mainLobby2022.0.timeTook"], 40000);
+    assert_eq!(flat["stats.Bedwars.final_kills_bedwars"], 1234);
+    assert_eq!(flat["stats.Bedwars.shop.slots.1"], 2);
+    assert_eq!(flat["achievements.bedwars_level"], 100);
+    assert!(!flat.contains_key("stats.Bedwars.favourites"));
+    assert!(!flat.contains_key("lastLogin"));
+    assert!(!flat.contains_key("karma"));
+    assert!(!flat.contains_key("giftingMeta.ranksGiven"));
+    assert_eq!(flat.len(), 15);
+
+    player.take_stat_json::<serde_json::Value>("Bedwars");
+    assert!(player.flatten().keys().all(|path| !path.starts_with("stats.")));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_offset_date_time() {
+    use chrono::{TimeZone, Utc};
+    use crate::util::time::{from_millis, IntoOffsetDateTime};
+
+    let time = from_millis(1_650_000_000_123).into_offset_date_time().unwrap();
+    assert_eq!(time.unix_timestamp(), 1_650_000_000);
+    assert_eq!(time.millisecond(), 123);
+    assert_eq!(time.offset(), time::UtcOffset::UTC);
+    assert!(None::<chrono::DateTime<Utc>>.into_offset_date_time().is_none());
+    assert!(Utc.with_ymd_and_hms(20000, 1, 1, 0, 0, 0).unwrap().into_offset_date_time().is_none());
+}
+
+/// A [`HypixelApi`](crate::HypixelApi) that answers every request with the next of a list of replies.
+struct ScriptedApi {
+    replies: parking_lot::Mutex<std::collections::VecDeque<String>>,
+}
+
+impl ScriptedApi {
+    fn new<'a>(replies: impl IntoIterator<Item = &'a str>) -> Self {
+        ScriptedApi {
+            replies: parking_lot::Mutex::new(replies.into_iter().map(str::to_string).collect()),
+        }
+    }
+}
+
+impl crate::HypixelApi for ScriptedApi {
+    fn request<T: serde::de::DeserializeOwned + Send + 'static>(&self, _path: &str, _authenticated: bool) -> crate::RequestFuture<T> {
+        crate::RequestFuture::ready(match self.replies.lock().pop_front() {
+            Some(json) => serde_json::from_str(&json).map_err(|e| e.into()),
+            None => Err(crate::error::HypixelApiError::UnexpectedResponseCode(reqwest::StatusCode::NOT_FOUND, None)),
+        })
+    }
+
+    fn key_usage(&self) -> Option<crate::KeyUsage> {
+        None
+    }
+}
+
+#[tokio::test]
+async fn test_request_typed_default() {
+    use crate::error::HypixelApiError;
+    use crate::{ApiPath, HypixelApi};
+
+    let uuid = Uuid::from_str("ad8fefaa8351454bb739a4eaa872173f").unwrap();
+    let api = ScriptedApi::new([
+        r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#,
+        r#"{"success":false,"cause":"Invalid API key"}"#,
+    ]);
+    assert!(api.request_typed::<StatusReply>(ApiPath::Status { uuid }).await.unwrap().unwrap().online());
+    let error = api.request_typed::<StatusReply>(ApiPath::Status { uuid }).await.unwrap().unwrap_err();
+    assert!(matches!(error, HypixelApiError::Unsuccessful(Some(cause)) if cause == "Invalid API key"));
+    assert!(api.request_typed::<StatusReply>(ApiPath::Status { uuid }).await.unwrap().is_err());
+}
+
+#[tokio::test]
+async fn test_status_watcher() {
+    use crate::GameType;
+    use crate::watch::{StatusEvent, StatusWatcher};
+
+    let uuid = Uuid::from_str("ad8fefaa8351454bb739a4eaa872173f").unwrap();
+    let api = ScriptedApi::new([
+        r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":false}}"#,
+        r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true,"gameType":"BEDWARS","mode":"LOBBY"}}"#,
+        r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true,"gameType":"BEDWARS","mode":"LOBBY"}}"#,
+        r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true,"gameType":"BEDWARS","mode":"EIGHT_ONE"}}"#,
+        r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":false}}"#,
+    ]);
+    let events: Vec<_> = StatusWatcher::new(&api, [uuid], Duration::from_millis(1)).into_stream().take(4).collect().await;
+    assert_eq!(events[0].as_ref().unwrap(), &StatusEvent::CameOnline { uuid, game_type: Some(GameType::Bedwars), mode: Some("LOBBY".to_string()) });
+    assert_eq!(events[1].as_ref().unwrap(), &StatusEvent::GameChanged { uuid, game_type: Some(GameType::Bedwars), mode: Some("EIGHT_ONE".to_string()) });
+    assert_eq!(events[2].as_ref().unwrap(), &StatusEvent::WentOffline { uuid });
+    assert!(events[3].is_err());
+}
+
+#[tokio::test]
+async fn test_guild_tracker() {
+    use crate::watch::{GuildEvent, GuildTracker};
+
+    let first = Uuid::from_str("ad8fefaa8351454bb739a4eaa872173f").unwrap();
+    let second = Uuid::from_str("069a79f444e94726a5befca90e38aaf5").unwrap();
+    let api = ScriptedApi::new([
+        r#"{"success":true,"guild":{"_id":"1","name":"Guild","members":[{"uuid":"ad8fefaa8351454bb739a4eaa872173f","rank":"Member","expHistory":{"2024-01-01":10}}]}}"#,
+        r#"{"success":true,"guild":{"_id":"1","name":"Guild","members":[{"uuid":"ad8fefaa8351454bb739a4eaa872173f","rank":"Officer","expHistory":{"2024-01-01":25}},{"uuid":"069a79f444e94726a5befca90e38aaf5","rank":"Member"}]}}"#,
+        r#"{"success":true,"guild":{"_id":"1","name":"Guild","members":[{"uuid":"069a79f444e94726a5befca90e38aaf5","rank":"Member"}]}}"#,
+    ]);
+    let events: Vec<_> = GuildTracker::new(&api, "1", Duration::from_millis(1)).into_stream().take(4).collect().await;
+    let events: Vec<_> = events.into_iter().map(Result::unwrap).collect();
+    assert_eq!(events[0], GuildEvent::RankChanged { uuid: first, from: "Member".to_string(), to: "Officer".to_string() });
+    assert_eq!(events[1], GuildEvent::GexpUpdated { uuid: first, previous: 10, current: 25 });
+    assert!(matches!(&events[2], GuildEvent::MemberJoined { member } if member.uuid() == second));
+    assert_eq!(events[3], GuildEvent::MemberLeft { uuid: first, rank: "Officer".to_string() });
+}
+
+#[tokio::test]
+async fn test_bazaar_tracker() {
+    use crate::watch::{BazaarEvent, BazaarTracker, PriceSide};
+
+    let bazaar = |buy: f64, sell: f64| format!(
+        r#"{{"success":true,"lastUpdated":1650000000000,"products":{{"DIAMOND":{{"product_id":"DIAMOND","quick_status":{{"buyPrice":{},"sellPrice":{}}}}},"COAL":{{"product_id":"COAL","quick_status":{{"buyPrice":0.0,"sellPrice":0.0}}}}}}}}"#,
+        buy, sell,
+    );
+    let replies = [bazaar(10.0, 8.0), bazaar(10.5, 8.2), bazaar(11.5, 8.2)];
+    let api = ScriptedApi::new(replies.iter().map(String::as_str));
+    let tracker = BazaarTracker::new(&api, Duration::from_millis(1)).threshold(0.1).products(["DIAMOND"]);
+    let prices = tracker.prices();
+    let events: Vec<_> = tracker.into_stream().take(2).collect().await;
+    assert_eq!(events[0].as_ref().unwrap(), &BazaarEvent::PriceMoved { product_id: "DIAMOND".to_string(), side: PriceSide::Buy, previous: 10.0, current: 11.5 });
+    assert!((events[0].as_ref().unwrap().change() - 0.15).abs() < 1e-9);
+    assert!(events[1].is_err());
+    assert_eq!(prices.get("DIAMOND").unwrap().buy_price(), 11.5);
+    assert!(prices.get("COAL").is_none());
+    assert_eq!(prices.last_updated().unwrap().timestamp(), 1_650_000_000);
+}
+
+#[tokio::test]
+async fn test_auction_watcher() {
+    use crate::watch::AuctionWatcher;
+
+    let auction = |uuid: &str, name: &str, tier: &str, bid: u64, bin: bool| format!(
+        r#"{{"uuid":"{}","auctioneer":"347ef6c1daac45ed9d1fa02818cf0fb6","item_name":"{}","tier":"{}","starting_bid":{},"bin":{}}}"#,
+        uuid, name, tier, bid, bin,
+    );
+    let page = |auctions: &[String]| format!(
+        r#"{{"success":true,"page":0,"totalPages":1,"totalAuctions":{},"lastUpdated":1700000000000,"auctions":[{}]}}"#,
+        auctions.len(), auctions.join(","),
+    );
+    let first = auction("409a1e0f261a49849493278d6cd9305a", "Hyperion", "LEGENDARY", 900, true);
+    let second = auction("509a1e0f261a49849493278d6cd9305a", "Hyperion", "LEGENDARY", 700, true);
+    let third = auction("609a1e0f261a49849493278d6cd9305a", "Hyperion", "LEGENDARY", 500, false);
+    let fourth = auction("709a1e0f261a49849493278d6cd9305a", "Dirt", "COMMON", 1, true);
+    let replies = [
+        page(&[first.clone(), second.clone(), third.clone(), second.clone()]),
+        page(&[first, third, fourth]),
+    ];
+    let api = ScriptedApi::new(replies.iter().map(String::as_str));
+    let updates: Vec<_> = AuctionWatcher::new(&api, Duration::from_millis(1)).into_stream().take(3).collect().await;
+
+    let update = updates[0].as_ref().unwrap();
+    let index = update.index();
+    assert!(update.diff().is_empty());
+    assert_eq!(index.len(), 3);
+    assert_eq!(index.by_item("Hyperion").count(), 3);
+    assert_eq!(index.bins().count(), 2);
+    assert_eq!(index.lowest_bin("Hyperion").unwrap().starting_bid(), 700);
+    assert!(index.get(Uuid::from_str("609a1e0f261a49849493278d6cd9305a").unwrap()).is_some());
+    assert_eq!(index.last_updated().unwrap().timestamp(), 1_700_000_000);
+
+    let update = updates[1].as_ref().unwrap();
+    assert_eq!(update.index().lowest_bin("Hyperion").unwrap().starting_bid(), 900);
+    assert_eq!(update.index().by_tier("COMMON").count(), 1);
+    assert_eq!(update.diff().new_auctions().len(), 1);
+    assert_eq!(update.diff().new_auctions()[0].item_name(), "Dirt");
+    assert_eq!(update.diff().ended_auctions().len(), 1);
+    assert_eq!(update.diff().ended_auctions()[0].starting_bid(), 700);
+    assert!(updates[2].is_err());
+}
+
+#[tokio::test]
+async fn test_election_watcher() {
+    use crate::watch::{ElectionEvent, ElectionWatcher};
+
+    let election = |mayor: &str, year: u32, perk: &str| format!(
+        r#"{{"success":true,"lastUpdated":1700000000000,"mayor":{{"key":"{mayor}","name":"{mayor}","perks":[{{"name":"Luck","description":"","minister":false}}],"minister":{{"key":"mining","name":"Cole","perk":{{"name":"Mining Fiesta","description":"","minister":true}}}},"election":{{"year":{year},"candidates":[]}}}},"current":{{"year":{next},"candidates":[{{"key":"farming","name":"Finnegan","perks":[{{"name":"{perk}","description":""}}],"votes":10}},{{"key":"fishing","name":"Marina","perks":[],"votes":20}}]}}}}"#,
+        mayor = mayor, year = year, next = year + 1, perk = perk,
+    );
+    let replies = [election("Diana", 300, "Pelt-pocalypse"), election("Diana", 300, "Pelt-pocalypse"), election("Diana", 300, "Blooming Business"), election("Paul", 301, "Blooming Business")];
+    let api = ScriptedApi::new(replies.iter().map(String::as_str));
+    let watcher = ElectionWatcher::new(&api, Duration::from_millis(1));
+    let state = watcher.state();
+    let events: Vec<_> = watcher.into_stream().take(4).collect().await;
+    match events[0].as_ref().unwrap() {
+        ElectionEvent::CandidatePerksChanged { year, key, previous, current } => {
+            assert_eq!((*year, key.as_str()), (301, "farming"));
+            assert_eq!(previous[0].name(), "Pelt-pocalypse");
+            assert_eq!(current[0].name(), "Blooming Business");
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+    match events[1].as_ref().unwrap() {
+        ElectionEvent::MayorChanged { previous, current } => {
+            assert_eq!(previous.name(), "Diana");
+            assert_eq!(current.name(), "Paul");
+            assert_eq!(current.minister().unwrap().perk().name(), "Mining Fiesta");
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+    // the election of year 302 opened, its candidates have no previous perks
+    assert!(matches!(events[2].as_ref().unwrap(), ElectionEvent::CandidatePerksChanged { year: 302, previous, .. } if previous.is_empty()));
+    assert!(events[3].is_err());
+    assert_eq!(state.mayor().unwrap().name(), "Paul");
+    assert_eq!(state.current().unwrap().leader().unwrap().name(), "Marina");
+}
+
+#[tokio::test]
+async fn test_counts_sampler() {
+    use crate::watch::CountsSampler;
+
+    let counts = |total: u64, bedwars: u64| format!(
+        r#"{{"success":true,"playerCount":{},"games":{{"BEDWARS":{{"players":{},"modes":{{"eight_one":5}}}},"LIMBO":{{"players":3}}}}}}"#,
+        total, bedwars,
+    );
+    let replies = [counts(100, 10), counts(120, 30), counts(90, 20), counts(110, 40)];
+    let reply: crate::CountsReply = serde_json::from_str(&replies[0]).unwrap();
+    assert_eq!(reply.game("BEDWARS").unwrap().modes()["eight_one"], 5);
+
+    let api = ScriptedApi::new(replies.iter().map(String::as_str));
+    let sampler = CountsSampler::new(&api, Duration::from_millis(1)).capacity(3);
+    let history = sampler.history();
+    let samples: Vec<_> = sampler.into_stream().take(5).collect().await;
+    assert_eq!(samples[0].as_ref().unwrap().total(), 100);
+    assert!(samples[4].is_err());
+
+    // the first sample was dropped to stay within the capacity
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.current_total(), Some(110));
+    assert_eq!(history.current("BEDWARS"), Some(40));
+    assert_eq!(history.current("SKYWARS"), None);
+    let stats = history.stats("BEDWARS", Duration::from_secs(3600)).unwrap();
+    assert_eq!((stats.min(), stats.max(), stats.samples()), (20, 40, 3));
+    assert_eq!(stats.average(), 30.0);
+    assert_eq!(history.total_stats(Duration::ZERO).unwrap().samples(), 1);
+    assert!(history.stats("SKYWARS", Duration::from_secs(3600)).is_none());
+}
+
+#[cfg(all(feature = "global", feature = "test-util"))]
+#[test]
+fn test_global_handler() {
+    use crate::MockHypixelServer;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            assert!(crate::init_with(server.request_handler(Uuid::nil())));
+            assert!(!crate::init(Uuid::nil()));
+
+            let uuid = Uuid::from_str("ad8fefaa8351454bb739a4eaa872173f").unwrap();
+            assert!(crate::get_status(uuid).await.unwrap().online());
+            assert!(crate::get_player(uuid).await.is_err());
+            assert_eq!(server.requests(), [
+                "status?uuid=ad8fefaa8351454bb739a4eaa872173f",
+                "player?uuid=ad8fefaa8351454bb739a4eaa872173f",
+            ]);
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_cloned_request_handler() {
+    use crate::MockHypixelServer;
+    use crate::cache::CacheConfig;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil())
+                .base_url(server.url())
+                .cache(CacheConfig::new())
+                .build();
+            let clone = request_handler.clone();
+            let task = tokio::spawn(async move {
+                clone.request::<StatusReply>("status", true).await.unwrap().unwrap()
+            });
+            let first = task.await.unwrap();
+            let second = request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap();
+            assert_eq!(first, second);
+
+            // the clone shares the cache and the statistics of the original handler
+            assert_eq!(server.requests().len(), 1);
+            assert_eq!(request_handler.stats().completed(), 2);
+            assert_eq!(request_handler.cache().unwrap().stats().hits(), 1);
+        });
+}
+
+#[tokio::test]
+async fn test_resources_cache() {
+    use crate::watch::ResourcesCache;
+
+    let games = r#"{"success":true,"lastUpdated":1700000000000,"games":{"SURVIVAL_GAMES":{"id":5,"name":"Blitz Survival Games","databaseName":"HungerGames","modeNames":{"teams_normal":"Teams"}},"QUAKECRAFT":{"id":2,"name":"Quakecraft","databaseName":"Quake","retired":true,"legacy":true}}}"#;
+    let achievements = r#"{"success":true,"lastUpdated":1700000000000,"achievements":{"bedwars":{"one_time":{"BEDWARS_KILLER":{"points":10,"name":"Killer","description":"Kill a player","gamePercentUnlocked":12.5,"globalPercentUnlocked":1.5}},"tiered":{"BEDS":{"name":"Bed Destroyer","description":"Destroy %s beds","tiers":[{"tier":1,"points":5,"amount":100},{"tier":2,"points":10,"amount":250},{"tier":3,"points":15,"amount":1000}]}},"total_points":40,"total_legacy_points":0}}}"#;
+    let skills = r#"{"success":true,"lastUpdated":1700000000000,"version":"0.20","skills":{"FARMING":{"name":"Farming","maxLevel":60,"levels":[{"level":1,"totalExpRequired":50.0}]}}}"#;
+    let collections = r#"{"success":true,"lastUpdated":1700000000000,"version":"0.20","collections":{"FARMING":{"name":"Farming","items":{"WHEAT":{"name":"Wheat","maxTiers":2,"tiers":[{"tier":1,"amountRequired":50,"unlocks":["Wheat Minion Recipes"]},{"tier":2,"amountRequired":100}]}}}}}"#;
+    let items = |id: &str| format!(r#"{{"success":true,"lastUpdated":1700000000000,"items":[{{"id":"{}","name":"Item","material":"STONE","tier":"RARE","npc_sell_price":2.5,"museum":true}}]}}"#, id);
+    let (first, second) = (items("ASPECT_OF_THE_END"), items("HYPERION"));
+    let api = Arc::new(ScriptedApi::new([games, achievements, skills, collections, &first, games, achievements, skills, collections, &second]));
+
+    let resources = ResourcesCache::start(Arc::clone(&api), Duration::from_millis(5)).await.unwrap();
+    let refreshed_at = resources.refreshed_at();
+    assert_eq!(resources.games().game("SURVIVAL_GAMES").unwrap().mode_name("teams_normal"), Some("Teams"));
+    assert!(resources.games().by_database_name("Quake").unwrap().is_retired());
+    assert_eq!(resources.achievements().one_time_points("bedwars_bedwars_killer"), 10);
+    assert_eq!(resources.achievements().tiered_points("bedwars_beds", 300), 15);
+    assert_eq!(resources.achievements().tiered_points("bedwars_unknown", 300), 0);
+    assert_eq!(resources.skills().skill("FARMING").unwrap().max_level(), 60);
+    assert_eq!(resources.collections().collection("WHEAT").unwrap().tiers()[0].unlocks().len(), 1);
+    assert_eq!(resources.items().item("ASPECT_OF_THE_END").unwrap().npc_sell_price(), Some(2.5));
+
+    // the second refresh fails, the catalogs of the first refresh are kept
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(resources.items().item("HYPERION").unwrap().is_museum_item());
+    assert!(resources.items().item("ASPECT_OF_THE_END").is_none());
+    assert!(resources.refreshed_at() > refreshed_at);
+    assert!(api.replies.lock().is_empty());
+}
+
+#[test]
+fn test_builder_from_env() {
+    use std::collections::HashMap;
+    use crate::error::HypixelApiError;
+
+    let from_vars = |vars: &[(&str, &str)]| {
+        let vars: HashMap<String, String> = vars.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect();
+        RequestHandler::builder_from_vars(|name| vars.get(name).cloned())
+    };
+    assert!(matches!(from_vars(&[]), Err(HypixelApiError::MissingEnvVar(name)) if name == "HYPIXEL_API_KEY"));
+    assert!(matches!(from_vars(&[("HYPIXEL_API_KEY", "not-a-key")]), Err(HypixelApiError::InvalidEnvVar(name, _)) if name == "HYPIXEL_API_KEY"));
+    assert!(matches!(
+        from_vars(&[("HYPIXEL_API_KEY", "ad8fefaa8351454bb739a4eaa872173f"), ("HYPIXEL_WORKERS", "many")]),
+        Err(HypixelApiError::InvalidEnvVar(name, _)) if name == "HYPIXEL_WORKERS"
+    ));
+
+    let builder = from_vars(&[
+        ("HYPIXEL_API_KEY", " ad8fefaa8351454bb739a4eaa872173f\n"),
+        ("HYPIXEL_BASE_URL", "http://localhost:8080/"),
+        ("HYPIXEL_TIMEOUT_SECS", "5"),
+        ("HYPIXEL_RATE_LIMIT", "60"),
+        ("HYPIXEL_WORKERS", "4"),
+    ]).unwrap();
+    let debug = format!("{:?}", builder);
+    assert!(debug.contains("remaining: 60"), "{}", debug);
+    let request_handler = builder.build();
+    assert_eq!(request_handler.base_url(), "http://localhost:8080/");
+    assert!(format!("{:?}", request_handler).contains("workers: 4"));
+}
+
+#[test]
+fn test_api_path() {
+    use crate::ApiPath;
+
+    let uuid = Uuid::from_str("ad8fefaa8351454bb739a4eaa872173f").unwrap();
+    assert_eq!(ApiPath::Status { uuid }.to_path(), "status?uuid=ad8fefaa8351454bb739a4eaa872173f");
+    assert_eq!(ApiPath::GuildByPlayer { uuid }.to_string(), "guild?player=ad8fefaa8351454bb739a4eaa872173f");
+    assert_eq!(ApiPath::GuildByName { name: "a&b=c ü".to_string() }.to_path(), "guild?name=a%26b%3Dc%20%C3%BC");
+    assert_eq!(ApiPath::SkyblockAuctionsPage { page: 2 }.to_path(), "skyblock/auctions?page=2");
+    assert!(ApiPath::Player { uuid }.is_authenticated());
+    assert!(ApiPath::SkyblockNews.is_authenticated());
+    assert!(!ApiPath::SkyblockItemsResources.is_authenticated());
+    assert!(!ApiPath::SkyblockBazaar.is_authenticated());
+}
+
+#[test]
+fn test_reply_trait() {
+    use crate::{CountsReply, GuildReply, Reply};
+
+    fn payloads<R: Reply + serde::de::DeserializeOwned>(json: &[&str]) -> Vec<R::Data> {
+        json.iter().map(|json| serde_json::from_str::<R>(json).unwrap()).filter(Reply::success).map(Reply::into_data).collect()
+    }
+
+    let status = r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#;
+    assert!(payloads::<StatusReply>(&[status])[0].online());
+    assert!(payloads::<WithRaw<StatusReply>>(&[status])[0].online());
+    assert_eq!(payloads::<PlayerReply>(&[r#"{"success":true,"player":null}"#]), vec![None]);
+    assert!(payloads::<GuildReply>(&[r#"{"success":false,"guild":null}"#]).is_empty());
+
+    let counts: CountsReply = serde_json::from_str(r#"{"success":true,"playerCount":5}"#).unwrap();
+    assert_eq!(Reply::data(&counts).player_count(), 5);
+}
+
+#[test]
+fn test_presence_hints() {
+    let player = |fields: &str| serde_json::from_str::<PlayerData>(&format!(r#"{{"uuid":"3fa85f6457174562b3fc2c963f66afa6"{}}}"#, fields)).unwrap();
+
+    assert!(player(r#","lastLogin":1600000002000,"lastLogout":1600000001000"#).is_probably_online());
+    assert!(!player(r#","lastLogin":1600000001000,"lastLogout":1600000002000"#).is_probably_online());
+    assert!(player(r#","lastLogin":1600000001000"#).is_probably_online());
+    assert!(!player("").is_probably_online());
+
+    let data = player(r#","lastClaimedReward":"1600000000000""#);
+    assert_eq!(data.last_claimed_reward().unwrap().timestamp(), 1_600_000_000);
+    assert_eq!(data.flatten()["lastClaimedReward"], "1600000000000");
+    assert!(player("").last_claimed_reward().is_none());
+}
+
+#[test]
+fn test_vanity() {
+    use crate::{ColorCodes, VanityResourcesReply};
+
+    let pets = r#"
+        {
+            "success": true,
+            "lastUpdated": 1600000000000,
+            "types": [
+                {"key": "CAT_BLACK", "name": "Cat: Black", "rarity": "COMMON", "package": "pet_cat_black"},
+                {"key": "WOLF", "name": "Wolf", "rarity": "EPIC", "package": "pet_wolf"},
+                {"key": "HORSE_BROWN", "name": "Horse: Brown", "rarity": "RARE", "package": "pet_horse_brown"}
+            ],
+            "rarities": [{"name": "COMMON", "color": "GREEN"}, {"name": "EPIC", "color": "NOT_A_COLOR"}]
+        }
+    "#;
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "currentPet": "WOLF",
+            "petConsumables": {"CARROT_ITEM": 12, "BONE": 3},
+            "vanityMeta": {"packages": ["pet_wolf", "suit_treasure_helmet", "pet_cat_black"]}
+        }
+    "#;
+
+    let pets: VanityResourcesReply = serde_json::from_str(pets).unwrap();
+    assert_eq!(pets.by_package("pet_horse_brown").unwrap().name(), "Horse: Brown");
+    assert_eq!(pets.rarity("COMMON").unwrap().color(), Some(ColorCodes::Green));
+    assert_eq!(pets.rarity("EPIC").unwrap().color(), None);
+
+    let data: PlayerData = serde_json::from_str(sample).unwrap();
+    assert_eq!(data.current_pet(), Some("WOLF"));
+    assert_eq!(data.equipped_pet(&pets).unwrap().name(), "Wolf");
+    assert_eq!((data.pet_consumable("CARROT_ITEM"), data.pet_consumable("CAKE")), (12, 0));
+    assert_eq!(data.vanity_packages().count(), 3);
+    let owned: Vec<_> = data.owned_vanity(&pets).iter().map(|pet| pet.key()).collect();
+    assert_eq!(owned, ["CAT_BLACK", "WOLF"]);
+    let flat = data.flatten();
+    assert_eq!(flat["petConsumables.BONE"], 3);
+    assert_eq!(flat["vanityMeta.packages.1"], "suit_treasure_helmet");
+
+    let data: PlayerData = serde_json::from_str(r#"{"uuid": "3fa85f6457174562b3fc2c963f66afa6"}"#).unwrap();
+    assert!(data.equipped_pet(&pets).is_none());
+    assert!(data.owned_vanity(&pets).is_empty());
+}
+
+#[test]
+fn test_tnt_games_stats() {
+    use crate::TntGames;
+
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "stats": {
+                "TNTGames": {
+                    "coins": 5000,
+                    "wins": 42,
+                    "winstreak": 3,
+                    "wins_tntrun": 20,
+                    "deaths_tntrun": 80,
+                    "record_tntrun": 312,
+                    "kills_pvprun": 7,
+                    "wins_tntag": "9",
+                    "kills_tntag": 30,
+                    "wins_bowspleef": 5,
+                    "tags_bowspleef": 1200,
+                    "wins_capture": 8,
+                    "kills_capture": 150,
+                    "points_capture": 400,
+                    "capture_class": "fire_wizard"
+                }
+            }
+        }
+    "#;
+
+    let data: PlayerData = serde_json::from_str(sample).unwrap();
+    let stats = data.stats::<TntGames>().unwrap().unwrap();
+    assert_eq!((stats.coins(), stats.wins(), stats.win_streak()), (5000, 42, 3));
+    assert_eq!(stats.tnt_run().record(), Some(Duration::from_secs(312)));
+    assert_eq!(stats.pvp_run().kills(), 7);
+    assert_eq!(stats.pvp_run().record(), None);
+    assert_eq!(stats.tnt_tag().wins(), 9);
+    assert_eq!(stats.bow_spleef().shots(), 1200);
+    assert_eq!(stats.wizards().class(), Some("fire_wizard"));
+    assert_eq!(stats.wizards().deaths(), 0);
+}
+
+#[test]
+fn test_classic_stats() {
+    use crate::{ArenaBrawl, Paintball, Quake, TurboKartRacers, VampireZ, Warlords};
+
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "stats": {
+                "Battleground": {"coins": 1000, "wins": 12, "losses": 8, "heal": 5400, "chosen_class": "mage"},
+                "Arena": {"coins": 300, "rating": 1050, "wins_1v1": 4, "wins_2v2": "6", "kills_4v4": 11, "losses_1v1": 2},
+                "Paintball": {"kills": 500, "deaths": 250, "forcefieldTime": 90, "hat": "speed_hat"},
+                "VampireZ": {"human_wins": 3, "vampire_kills": 17, "zombie_kills": 40},
+                "Quake": {"wins": 2, "wins_teams": 5, "kills": 100, "kills_teams": 50, "highest_killstreak": 9},
+                "GingerBread": {"gold_trophy": 7, "silver_trophy": 2, "bronze_trophy": 1, "laps_completed": 130}
+            }
+        }
+    "#;
+
+    let data: PlayerData = serde_json::from_str(sample).unwrap();
+    let warlords = data.stats::<Warlords>().unwrap().unwrap();
+    assert_eq!((warlords.wins(), warlords.losses(), warlords.healing()), (12, 8, 5400));
+    assert_eq!(warlords.chosen_class(), Some("mage"));
+    let arena = data.stats::<ArenaBrawl>().unwrap().unwrap();
+    assert_eq!((arena.wins(), arena.losses(), arena.kills(), arena.rating()), (10, 2, 11, 1050));
+    assert_eq!(arena.mode("2v2").wins(), 6);
+    assert_eq!(arena.mode("4v4").deaths(), 0);
+    let paintball = data.stats::<Paintball>().unwrap().unwrap();
+    assert_eq!(paintball.forcefield_time(), Duration::from_secs(90));
+    assert_eq!(paintball.hat(), Some("speed_hat"));
+    let vampirez = data.stats::<VampireZ>().unwrap().unwrap();
+    assert_eq!((vampirez.human_wins(), vampirez.vampire_kills(), vampirez.zombie_kills()), (3, 17, 40));
+    let quake = data.stats::<Quake>().unwrap().unwrap();
+    assert_eq!((quake.wins(), quake.kills(), quake.deaths()), (7, 150, 0));
+    let tkr = data.stats::<TurboKartRacers>().unwrap().unwrap();
+    assert_eq!((tkr.trophies(), tkr.gold_trophies(), tkr.laps_completed()), (10, 7, 130));
+}
+
+#[test]
+fn test_arcade_stats() {
+    use crate::Arcade;
+
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "stats": {
+                "Arcade": {
+                    "coins": 12000,
+                    "wins_party": 10,
+                    "wins_party_2": 4,
+                    "wins_party_3": "1",
+                    "seeker_wins_hide_and_seek": 2,
+                    "hider_wins_hide_and_seek": 3,
+                    "sw_game_wins": 6,
+                    "wins_zombies": 1,
+                    "best_round_zombies": 30,
+                    "final_kills_mini_walls": 12
+                }
+            }
+        }
+    "#;
+
+    let data: PlayerData = serde_json::from_str(sample).unwrap();
+    let stats = data.stats::<Arcade>().unwrap().unwrap();
+    assert_eq!(stats.coins(), 12000);
+    assert_eq!(stats.party_games_wins(), 15);
+    assert_eq!(stats.hide_and_seek_wins(), 5);
+    assert_eq!(stats.galaxy_wars_wins(), 6);
+    assert_eq!(stats.football_wins(), 0);
+    assert_eq!((stats.zombies().wins(), stats.zombies().best_round()), (1, 30));
+    assert_eq!(stats.mini_walls().final_kills(), 12);
+}
+
+#[test]
+fn test_mega_walls_and_blitz_stats() {
+    use crate::{Blitz, MegaWalls};
+
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "stats": {
+                "Walls3": {
+                    "coins": 700,
+                    "class_points": 1250,
+                    "chosen_class": "Zombie",
+                    "final_kills": 90,
+                    "wither_damage": "4500",
+                    "zombie_final_kills": 60,
+                    "herobrine_final_kills": 30
+                },
+                "HungerGames": {
+                    "kills": 25,
+                    "wins": 3,
+                    "wins_teams": 2,
+                    "defaultkit": "Archer",
+                    "exp_archer": 1500,
+                    "exp_knight": "200"
+                }
+            }
+        }
+    "#;
+
+    let data: PlayerData = serde_json::from_str(sample).unwrap();
+    let mega_walls = data.stats::<MegaWalls>().unwrap().unwrap();
+    assert_eq!((mega_walls.class_points(), mega_walls.final_kills(), mega_walls.wither_damage()), (1250, 90, 4500));
+    assert_eq!(mega_walls.chosen_class(), Some("Zombie"));
+    assert_eq!(mega_walls.class_final_kills("Zombie"), 60);
+    assert_eq!(mega_walls.class_final_kills("spider"), 0);
+
+    let blitz = data.stats::<Blitz>().unwrap().unwrap();
+    assert_eq!((blitz.kills(), blitz.wins()), (25, 5));
+    assert_eq!(blitz.kit_exp("Archer"), 1500);
+    assert_eq!(blitz.kits_exp().get("knight"), Some(&200));
+    assert_eq!(blitz.kits_exp().len(), 2);
+}
+
+#[test]
+fn test_quest_resets() {
+    use chrono::{TimeZone, Utc};
+    use crate::util::time::{completed_this_week, last_weekly_reset, next_daily_reset, next_weekly_reset};
+
+    // Friday the 8th of March 2024, the last weekly reset before daylight saving time starts
+    let now = Utc.with_ymd_and_hms(2024, 3, 13, 12, 0, 0).unwrap();
+    assert_eq!(last_weekly_reset(now), Utc.with_ymd_and_hms(2024, 3, 8, 5, 0, 0).unwrap());
+    assert_eq!(next_weekly_reset(now), Utc.with_ymd_and_hms(2024, 3, 15, 4, 0, 0).unwrap());
+
+    // on a Friday the reset of that same day counts
+    let friday = Utc.with_ymd_and_hms(2024, 3, 15, 4, 30, 0).unwrap();
+    assert_eq!(last_weekly_reset(friday), Utc.with_ymd_and_hms(2024, 3, 15, 4, 0, 0).unwrap());
+    assert!(completed_this_week(friday, friday));
+    assert!(!completed_this_week(Utc.with_ymd_and_hms(2024, 3, 15, 3, 59, 0).unwrap(), friday));
+
+    // daylight saving time ends on the 3rd of November 2024
+    let now = Utc.with_ymd_and_hms(2024, 11, 2, 12, 0, 0).unwrap();
+    assert_eq!(next_daily_reset(now), Utc.with_ymd_and_hms(2024, 11, 3, 4, 0, 0).unwrap());
+    assert_eq!(next_daily_reset(next_daily_reset(now)), Utc.with_ymd_and_hms(2024, 11, 4, 5, 0, 0).unwrap());
+}
+
+#[test]
+fn test_skyblock_profiles() {
+    use crate::SkyblockProfilesReply;
+
+    let reply: SkyblockProfilesReply = serde_json::from_str(include_str!("fixtures/skyblock_profiles.json")).unwrap();
+    let profile = reply.selected_profile().unwrap();
+    assert_eq!(profile.cute_name(), Some("Banana"));
+    assert_eq!(profile.game_mode(), Some("ironman"));
+    assert_eq!(profile.bank_balance(), Some(25000000.5));
+    assert_eq!(reply.profile(Uuid::from_str("5c1f2e0a-7b3d-4e8f-9a6b-1c2d3e4f5a6b").unwrap()).unwrap().bank_balance(), None);
+
+    let member = profile.member(Uuid::from_str("3fa85f64-5717-4562-b3fc-2c963f66afa6").unwrap()).unwrap();
+    assert_eq!(member.skill_experience().get("SKILL_COMBAT"), Some(&111672425.7));
+    assert_eq!((member.slayer_xp("zombie"), member.slayer_xp("wolf"), member.slayer_xp("enderman")), (1500000, 0, 0));
+    assert_eq!(member.total_slayer_xp(), 1750000);
+    assert_eq!(member.dungeon_types()["catacombs"].experience(), 25000000.5);
+    assert_eq!(member.fairy_souls(), 238);
+    assert_eq!(member.coin_purse(), 1523400.75);
+
+    let coop = profile.member(Uuid::from_str("9b2d6f4e-8a0c-4e1b-8d3f-5a7c9e1b3d5f").unwrap()).unwrap();
+    assert_eq!((coop.fairy_souls(), coop.coin_purse(), coop.total_slayer_xp()), (12, 0.0, 0));
+}
+
+#[test]
+fn test_dungeons_data() {
+    use crate::SkyblockProfilesReply;
+    use crate::util::leveling::dungeons;
+
+    let reply: SkyblockProfilesReply = serde_json::from_str(include_str!("fixtures/skyblock_profiles.json")).unwrap();
+    let member = reply.selected_profile().unwrap()
+        .member(Uuid::from_str("3fa85f64-5717-4562-b3fc-2c963f66afa6").unwrap()).unwrap();
+    let dungeons_data = member.dungeons();
+
+    assert_eq!(dungeons_data.catacombs_experience(), 25000000.5);
+    assert_eq!(dungeons_data.catacombs_level(), dungeons::calculate_level(25000000.5));
+    assert_eq!(dungeons_data.class_level("Mage"), dungeons::calculate_level(8500000.25));
+    assert_eq!(dungeons_data.class_experience("berserk"), 0.0);
+    let expected_average = ["healer", "mage", "archer"].iter()
+        .map(|class| dungeons::calculate_level(dungeons_data.class_experience(class)))
+        .sum::<f64>() / 5.0;
+    assert_eq!(dungeons_data.class_average(), expected_average);
+    assert_eq!(dungeons_data.selected_class(), Some("mage"));
+    assert_eq!(dungeons_data.secrets(), 4321);
+
+    let catacombs = dungeons_data.catacombs().unwrap();
+    assert_eq!(catacombs.highest_floor_completed(), Some(7));
+    assert_eq!((catacombs.completions(7), catacombs.completions(3), catacombs.total_completions()), (150, 0, 167));
+    assert_eq!(catacombs.floor_completions(), vec![(0, 5), (1, 12), (7, 150)]);
+    assert_eq!(catacombs.best_score(7), Some(317));
+    assert_eq!(catacombs.fastest_time_s_plus(7), Some(Duration::from_secs(295)));
+    assert_eq!(catacombs.fastest_time(7), None);
+
+    let master = dungeons_data.master_catacombs().unwrap();
+    assert_eq!((master.completions(3), master.best_score(3)), (20, Some(305)));
+
+    let empty: SkyblockProfilesReply = serde_json::from_str(r#"{"success":true,"profiles":null}"#).unwrap();
+    assert!(empty.profiles().is_empty());
+    assert!(empty.selected_profile().is_none());
+}
+
+#[tokio::test]
+async fn test_hydrate_leaders() {
+    use crate::LeaderboardsReply;
+
+    let reply: LeaderboardsReply = serde_json::from_str(r#"
+        {
+            "success": true,
+            "leaderboards": {
+                "BEDWARS": [
+                    {
+                        "path": "bedwars_level",
+                        "prefix": "Current",
+                        "title": "Level",
+                        "location": "-2,71,-70",
+                        "count": 10,
+                        "leaders": [
+                            "069a79f4-44e9-4726-a5be-fca90e38aaf5",
+                            "ad8fefaa-8351-454b-b739-a4eaa872173f",
+                            "3fa85f64-5717-4562-b3fc-2c963f66afa6"
+                        ]
+                    }
+                ]
+            }
+        }
+    "#).unwrap();
+    let leaderboard = reply.leaderboard("BEDWARS", "bedwars_level").unwrap();
+    assert_eq!((leaderboard.prefix(), leaderboard.title(), leaderboard.leaders().len()), ("Current", "Level", 3));
+    assert!(reply.game("SKYWARS").is_empty());
+
+    let api = ScriptedApi::new([
+        r#"{"success":true,"player":{"uuid":"069a79f444e94726a5befca90e38aaf5","displayname":"Notch"}}"#,
+        r#"{"success":true,"player":null}"#,
+    ]);
+    let leaders = reply.hydrate_leaders(&api, "BEDWARS", "bedwars_level", 2).await.unwrap();
+    assert_eq!(leaders.len(), 2);
+    assert_eq!(leaders[0].0, leaderboard.leaders()[0]);
+    assert_eq!(leaders[0].1.as_ref().unwrap().name(), Some("Notch"));
+    assert_eq!(leaders[1], (leaderboard.leaders()[1], None));
+    assert!(reply.hydrate_leaders(&api, "BEDWARS", "wins", 2).await.unwrap().is_empty());
+    // the script is exhausted, so the next request fails
+    assert!(reply.hydrate_leaders(&api, "BEDWARS", "bedwars_level", 1).await.is_err());
+}
+
+#[test]
+fn test_counts_by_game_type() {
+    use crate::{CountsReply, GameType};
+
+    let reply: CountsReply = serde_json::from_str(r#"
+        {
+            "success": true,
+            "playerCount": 95,
+            "games": {
+                "BEDWARS": { "players": 40, "modes": { "eight_one": 25, "four_four": 15 } },
+                "SKYWARS": { "players": 20 },
+                "DUELS": { "players": 20 },
+                "IDLE": { "players": 15 }
+            }
+        }
+    "#).unwrap();
+    assert_eq!(reply.players_in(&GameType::Bedwars), 40);
+    assert_eq!(reply.players_in(&GameType::Pit), 0);
+    assert_eq!(reply.modes(&GameType::Bedwars).unwrap().get("eight_one"), Some(&25));
+    assert!(reply.modes(&GameType::SkyWars).unwrap().is_empty());
+    assert!(reply.modes(&GameType::Pit).is_none());
+    assert_eq!(reply.by_population().collect::<Vec<_>>(), vec![
+        (GameType::Bedwars, 40),
+        (GameType::Duels, 20),
+        (GameType::SkyWars, 20),
+        (GameType::Unknown("IDLE".to_string()), 15),
+    ]);
+}
+
+#[test]
+fn test_stats_ratios() {
+    use crate::{Duels, SkyWars};
+
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "stats": {
+                "Bedwars": {
+                    "wins_bedwars": 30,
+                    "losses_bedwars": 10,
+                    "final_kills_bedwars": 90,
+                    "final_deaths_bedwars": 0,
+                    "beds_broken_bedwars": 50,
+                    "beds_lost_bedwars": 20
+                },
+                "SkyWars": {
+                    "kills": 100,
+                    "deaths": 40
+                },
+                "Duels": {
+                    "wins": 12,
+                    "losses": 4,
+                    "kills": 14,
+                    "deaths": 7
+                }
+            }
+        }
+    "#;
+
+    let data: PlayerData = serde_json::from_str(sample).unwrap();
+    let bedwars = data.stats::<Bedwars>().unwrap().unwrap();
+    assert_eq!((bedwars.fkdr(), bedwars.wlr(), bedwars.bblr(), bedwars.win_rate()), (90.0, 3.0, 2.5, 0.75));
+    assert_eq!(bedwars.summary().to_string(), "W 30 | L 10 | WLR 3.00 | K 90 | D 0 | KDR 90.00");
+
+    let skywars = data.stats::<SkyWars>().unwrap().unwrap();
+    assert_eq!((skywars.kdr(), skywars.wlr(), skywars.win_rate()), (2.5, 0.0, 0.0));
+
+    let duels = data.stats::<Duels>().unwrap().unwrap();
+    assert_eq!((duels.kdr(), duels.wlr(), duels.win_rate()), (2.0, 3.0, 0.75));
+    assert_eq!(duels.summary().kills(), 14);
+}
+
+#[test]
+fn test_unknown_enum_values() {
+    use crate::{ColorCodes, GuildReply, MonthlyPackageRank, PackageRank};
+
+    let sample = r#"
+        {
+            "uuid": "3fa85f6457174562b3fc2c963f66afa6",
+            "displayname": "Newcomer",
+            "newPackageRank": "MVP_PLUS_PLUS_PLUS",
+            "monthlyPackageRank": "HYPERSTAR",
+            "rankPlusColor": "ORANGE",
+            "monthlyRankColor": "AQUA"
+        }
+    "#;
+    let data: PlayerData = serde_json::from_str(sample).unwrap();
+    assert_eq!(data.package_rank(), PackageRank::Unknown("MVP_PLUS_PLUS_PLUS".to_string()));
+    assert!(data.has_rank());
+    assert_eq!(data.selected_plus_color(), ColorCodes::Red);
+    assert_eq!(data.superstar_tag_color(), ColorCodes::Aqua);
+    assert_eq!(PackageRank::from("MVP_PLUS".to_string()), PackageRank::MvpPlus);
+    assert_eq!(PackageRank::MvpPlus.to_string(), "MVP_PLUS");
+    assert_eq!(MonthlyPackageRank::from("HYPERSTAR".to_string()).to_string(), "HYPERSTAR");
+
+    let guild: GuildReply = serde_json::from_str(r#"{"success":true,"guild":{"_id":"1","name":"Test","tagColor":"ORANGE"}}"#).unwrap();
+    assert_eq!(guild.guild().unwrap().tag_color(), ColorCodes::Gray);
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test]
+async fn test_request_timed() {
+    use crate::cache::CacheConfig;
+    use crate::MockHypixelServer;
+
+    let server = MockHypixelServer::start().await;
+    server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#)
+        .rate_limit(120, 100, 0)
+        .inject_too_many_requests(1);
+    let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).cache(CacheConfig::new()).build();
+
+    let reply = request_handler.request_timed::<StatusReply>("status?uuid=ad8fefaa8351454bb739a4eaa872173f", true).await.unwrap().unwrap();
+    assert!(reply.online());
+    let meta = *reply.meta();
+    assert_eq!((meta.attempts(), meta.retries(), meta.from_cache()), (2, 0, false));
+    assert!(meta.network() > Duration::ZERO);
+    assert!(meta.queue_wait() + meta.network() <= meta.total());
+
+    let cached = request_handler.request_timed::<StatusReply>("status?uuid=ad8fefaa8351454bb739a4eaa872173f", true).await.unwrap().unwrap();
+    assert!(cached.meta().from_cache());
+    assert_eq!((cached.meta().attempts(), cached.meta().network()), (0, Duration::ZERO));
+    assert!(cached.meta().id() > meta.id());
+    assert_eq!(cached.into_data().online(), reply.into_data().online());
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_discord_formatting() {
+    use crate::{PackageRank, StaffLevel, StatsSummary};
+    use crate::util::discord::{code_block, display_name, rank_badge, rank_emoji_name, stats_table};
+
+    let player = PlayerData::builder(Uuid::nil()).display_name("Mr_Fish").package_rank(PackageRank::MvpPlus).build();
+    assert_eq!(rank_badge(&player).as_deref(), Some("**\\[MVP+\\]**"));
+    assert_eq!(display_name(&player).as_deref(), Some("**\\[MVP+\\]** Mr\\_Fish"));
+    assert_eq!(rank_emoji_name(&player).as_deref(), Some("mvp_plus"));
+
+    let admin = PlayerData::builder(Uuid::nil()).display_name("Boss").staff_level(StaffLevel::Admin).build();
+    assert_eq!(rank_emoji_name(&admin).as_deref(), Some("admin"));
+    let default = PlayerData::builder(Uuid::nil()).display_name("Steve").build();
+    assert_eq!((display_name(&default).as_deref(), rank_emoji_name(&default)), (Some("Steve"), None));
+
+    assert_eq!(code_block("a```b"), "```\na`\u{200B}``b\n```");
+    let table = stats_table(&[("SkyWars", StatsSummary::new(1, 0, 12, 3)), ("Duels", StatsSummary::new(120, 40, 150, 50))]);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[1], "Game    | W   | L  | WLR  | K   | D  | KDR");
+    assert_eq!(lines[3], "Duels   | 120 | 40 | 3.00 | 150 | 50 | 3.00");
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_validate() {
+    use crate::{ApiPath, MockHypixelServer, Reply};
+    use crate::error::HypixelApiError;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":false,"cause":"Malformed UUID"}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).build();
+            let uuid = Uuid::from_str("ad8fefaa8351454bb739a4eaa872173f").unwrap();
+            match request_handler.request_typed::<StatusReply>(ApiPath::Status { uuid }).await.unwrap() {
+                Err(HypixelApiError::Unsuccessful(cause)) => assert_eq!(cause.as_deref(), Some("Malformed UUID")),
+                other => panic!("expected an unsuccessful reply, got {:?}", other),
+            }
+
+            server.reply("player", r#"{"success":false,"player":null}"#);
+            let reply = request_handler.request::<PlayerReply>("player", true).await.unwrap().unwrap();
+            assert!(matches!(reply.validate(), Err(HypixelApiError::Unsuccessful(None))));
+
+            server.reply("guild", r#"{"success":false,"cause":"Invalid guild id","guild":null}"#);
+            let reply = request_handler.request::<crate::GuildReply>("guild", true).await.unwrap().unwrap();
+            assert_eq!(reply.cause(), Some("Invalid guild id"));
+            match reply.validate() {
+                Err(HypixelApiError::Unsuccessful(cause)) => assert_eq!(cause.as_deref(), Some("Invalid guild id")),
+                other => panic!("expected an unsuccessful reply, got {:?}", other),
+            }
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_deserialization_budget() {
+    use crate::{DeserializationBudget, MockHypixelServer};
+    use crate::error::HypixelApiError;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            // 9 nodes: the object, 4 keys, 3 values and the nested object
+            let status = r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#;
+            server.reply("status", status);
+            server.reply("counts", r#"{"success":true,"playerCount":1,"games":{"note":"{[\"x\"]}"}}"#);
+            let budget = |budget| RequestHandler::builder(Uuid::nil()).base_url(server.url()).deserialization_budget(budget).build();
+
+            let request_handler = budget(DeserializationBudget::new().max_nodes(9).max_bytes(status.len()));
+            assert!(request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap().online());
+            // strings containing brackets and escaped quotes count as a single node
+            request_handler.request::<serde_json::Value>("counts", false).await.unwrap().unwrap();
+
+            let request_handler = budget(DeserializationBudget::new().max_nodes(8));
+            match request_handler.request::<StatusReply>("status?uuid=ad8fefaa8351454bb739a4eaa872173f", true).await.unwrap() {
+                Err(HypixelApiError::BudgetExceeded(path, _)) => assert_eq!(path, "status?uuid=ad8fefaa8351454bb739a4eaa872173f"),
+                other => panic!("expected the budget to be exceeded, got {:?}", other),
+            }
+            let request_handler = budget(DeserializationBudget::new().max_bytes(10));
+            assert!(matches!(request_handler.request::<StatusReply>("status", true).await.unwrap(), Err(HypixelApiError::BudgetExceeded(..))));
+
+            let request_handler = budget(DeserializationBudget::new().max_bytes(10).endpoint("/status/", 1024, 100));
+            request_handler.request::<StatusReply>("status", true).await.unwrap().unwrap();
+            // raw bodies are limited in bytes while they are downloaded
+            assert!(matches!(request_handler.request_bytes("counts", false).await.unwrap(), Err(HypixelApiError::BudgetExceeded(..))));
+            assert!(matches!(request_handler.request::<serde_json::Value>("counts", false).await.unwrap(), Err(HypixelApiError::BudgetExceeded(..))));
+            let request_handler = budget(DeserializationBudget::new().max_nodes(1));
+            assert!(request_handler.request_bytes("counts", false).await.unwrap().is_ok());
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_guild_roster() {
+    use crate::{MockHypixelServer, RosterOptions};
+
+    let guild: GuildData = serde_json::from_str(r#"{
+        "_id": "553490650cf26f12ae5bac8f",
+        "name": "Roster",
+        "members": [
+            { "uuid": "00000000000000000000000000000001", "rank": "Member" },
+            { "uuid": "00000000000000000000000000000002", "rank": "Officer" },
+            { "uuid": "00000000000000000000000000000003", "rank": "Guild Master" }
+        ],
+        "ranks": [
+            { "name": "Officer", "default": false, "priority": 5 },
+            { "name": "Member", "default": true, "priority": 1 }
+        ]
+    }"#).unwrap();
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("player", r#"{"success":true,"player":{"uuid":"00000000000000000000000000000001","displayname":"Member"}}"#);
+            server.reply_with_status("player?uuid=00000000000000000000000000000002", 500, r#"{"success":false,"cause":"Internal error"}"#);
+            server.reply("status", r#"{"success":true,"uuid":"00000000000000000000000000000001","session":{"online":true}}"#);
+            let request_handler = server.request_handler(Uuid::nil());
+
+            let roster = request_handler.get_guild_roster(&guild, RosterOptions::new().concurrency(1).ranks_first(true)).await;
+            assert_eq!(server.requests(), [
+                "player?uuid=00000000000000000000000000000003",
+                "player?uuid=00000000000000000000000000000002",
+                "player?uuid=00000000000000000000000000000001",
+            ]);
+            assert_eq!(roster.guild_id(), "553490650cf26f12ae5bac8f");
+            let uuids: Vec<Uuid> = roster.members().iter().map(|member| member.uuid()).collect();
+            assert_eq!(uuids, [Uuid::from_u128(1), Uuid::from_u128(2), Uuid::from_u128(3)]);
+            assert_eq!(roster.member(&Uuid::from_u128(1)).unwrap().player().unwrap().name(), Some("Member"));
+            assert!(roster.member(&Uuid::from_u128(2)).unwrap().player().is_none());
+            assert!(roster.errors().contains_key(&Uuid::from_u128(2)));
+            assert!(!roster.is_complete());
+            assert_eq!(roster.online().count(), 0);
+
+            let roster = request_handler.get_guild_roster(&guild, RosterOptions::new().statuses(true)).await;
+            assert_eq!(server.requests().len(), 9);
+            assert_eq!(roster.online().count(), 3);
+            assert_eq!(roster.errors().len(), 1);
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_page_limits() {
+    use crate::{LimitReached, MockHypixelApi, MockHypixelServer, PageLimits};
+    use crate::error::HypixelApiError;
+    use crate::util::auctions::{scan_all_auctions, scan_auctions_with_limits, snipe_with_limits, AuctionFilter};
+
+    let page = |page: u32| format!(
+        r#"{{"success":true,"page":{},"totalPages":4,"totalAuctions":8,"lastUpdated":1700000000000,"auctions":[{}]}}"#,
+        page, [r#"{"uuid":"409a1e0f261a49849493278d6cd9305a","auctioneer":"347ef6c1daac45ed9d1fa02818cf0fb6","item_name":"Item","starting_bid":1,"bin":true}"#; 2].join(","),
+    );
+    let api = (0..4).fold(MockHypixelApi::new(), |api, number| api.with_reply(format!("skyblock/auctions?page={}", number), page(number)));
+    assert_eq!(PageLimits::new().max_pages(2).or(PageLimits::new().max_pages(5).max_items(3)), PageLimits::new().max_pages(2).max_items(3));
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let complete = scan_auctions_with_limits(&api, 2, PageLimits::new().max_pages(4)).await.unwrap();
+            assert!(complete.is_complete());
+            assert_eq!(complete.into_value().len(), 8);
+
+            let result = scan_auctions_with_limits(&api, 2, PageLimits::new().max_pages(3).max_requests(2)).await.unwrap();
+            assert_eq!(result.limit(), Some(LimitReached::Requests(2)));
+            assert_eq!(result.value().pages().len(), 2);
+            let result = scan_auctions_with_limits(&api, 1, PageLimits::new().max_items(4)).await.unwrap();
+            assert_eq!((result.limit(), result.value().len()), (Some(LimitReached::Items(4)), 4));
+            assert_eq!(api.requests().len(), 8);
+            let result = scan_auctions_with_limits(&api, 1, PageLimits::new().max_pages(0)).await.unwrap();
+            assert!(result.value().is_empty() && api.requests().len() == 8);
+
+            let auctions: Vec<_> = snipe_with_limits(&api, AuctionFilter::new(), 1, PageLimits::new().max_pages(3)).collect().await;
+            assert_eq!(auctions.len(), 7);
+            assert!(auctions[..6].iter().all(Result::is_ok));
+            assert!(matches!(auctions[6], Err(HypixelApiError::LimitReached(LimitReached::Pages(3)))));
+            let auctions: Vec<_> = snipe_with_limits(&api, AuctionFilter::new(), 1, PageLimits::new().max_items(2)).collect().await;
+            assert!(matches!(auctions.as_slice(), [Ok(_), Ok(_), Err(HypixelApiError::LimitReached(LimitReached::Items(2)))]));
+
+            let server = MockHypixelServer::start().await;
+            server.reply("skyblock/auctions", page(0));
+            server.reply("player", r#"{"success":true,"player":null}"#);
+            let request_handler = RequestHandler::builder(Uuid::nil()).base_url(server.url()).page_limits(PageLimits::new().max_requests(2)).build();
+            assert!(matches!(scan_all_auctions(&request_handler, 2).await, Err(HypixelApiError::LimitReached(LimitReached::Requests(2)))));
+            assert!(scan_auctions_with_limits(&request_handler, 2, PageLimits::new().max_requests(4)).await.unwrap().is_complete());
+
+            let leaderboard: crate::Leaderboard = serde_json::from_str(r#"{"path":"wins","prefix":"","title":"","location":"","count":3,"leaders":[
+                "069a79f4-44e9-4726-a5be-fca90e38aaf5","ad8fefaa-8351-454b-b739-a4eaa872173f","3fa85f64-5717-4562-b3fc-2c963f66afa6"]}"#).unwrap();
+            assert!(matches!(leaderboard.hydrate_leaders(&request_handler, 3).await, Err(HypixelApiError::LimitReached(_))));
+            let result = leaderboard.hydrate_leaders_with_limits(&request_handler, 3, PageLimits::new().max_items(1)).await.unwrap();
+            assert_eq!((result.value().len(), result.limit()), (1, Some(LimitReached::Items(1))));
+        });
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_client() {
+    use crate::{HypixelClient, MockHypixelServer};
+    use crate::util::auctions::AuctionFilter;
+
+    tokio::runtime::Runtime::new().unwrap()
+        .block_on(async {
+            let server = MockHypixelServer::start().await;
+            server.reply("status", r#"{"success":true,"uuid":"ad8fefaa8351454bb739a4eaa872173f","session":{"online":true}}"#);
+            server.reply("guild", r#"{"success":true,"guild":null}"#);
+            server.reply("skyblock/auctions", r#"{"success":true,"page":0,"totalPages":1,"totalAuctions":0,"auctions":[]}"#);
+            server.reply("resources/skyblock/skills", r#"{"success":true,"skills":{}}"#);
+            let client = HypixelClient::from(server.request_handler(Uuid::nil()));
+            let uuid = Uuid::from_str("ad8fefaa8351454bb739a4eaa872173f").unwrap();
+
+            assert!(client.player().status(uuid).await.unwrap().online());
+            assert!(client.guild().by_name("Mini Squid").await.unwrap().guild().is_none());
+            assert_eq!(client.skyblock().auctions().page(0).await.unwrap().total_pages(), 1);
+            assert_eq!(client.skyblock().auctions().snipe(AuctionFilter::new(), 1).count().await, 0);
+            assert!(client.resources().skyblock_skills().await.unwrap().success());
+            assert!(client.request::<serde_json::Value>("status", true).await.is_ok());
+            assert_eq!(server.requests(), [
+                "status?uuid=ad8fefaa8351454bb739a4eaa872173f",
+                "guild?name=Mini%20Squid",
+                "skyblock/auctions?page=0",
+                "skyblock/auctions?page=0",
+                "resources/skyblock/skills",
+                "status",
+            ]);
+            assert_eq!(client.handler().base_url(), server.url());
+        });
+}
@@ -1,81 +1,353 @@
+use std::error::Error;
+use std::future::Future;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
+use chrono::{DateTime, TimeDelta, Utc};
 use parking_lot::Mutex;
-use reqwest::StatusCode;
+use reqwest::header::{AsHeaderName, HeaderMap, AGE, DATE};
+use reqwest::{Response, StatusCode};
 use tokio::runtime;
-use tokio::sync::{mpsc, watch};
+use tokio::sync::{mpsc, watch, Semaphore};
 use tokio::time::{sleep, Instant};
 use crate::api::error::HypixelApiError;
+use crate::api::key::KeyUsage;
 
+/// The rate limit assumed for responses without `ratelimit-*` headers,
+/// see [`RequestHandlerBuilder::rate_limit_fallback`](crate::RequestHandlerBuilder::rate_limit_fallback).
+///
+/// Hypixel sends these headers along with every authenticated response, but proxies may strip
+/// them. By default, a missing header is replaced by a window of 10 seconds with 110 requests
+/// remaining. If this is too optimistic, e.g. because the key is shared with other applications,
+/// lower the fallback values or make missing headers an error with [`RateLimitFallback::strict`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RateLimitFallback {
+    reset: Duration,
+    remaining: u32,
+    strict: bool,
+}
+
+impl RateLimitFallback {
+    /// Creates a fallback that assumes `remaining` requests are left in a window that resets after `reset`.
+    pub fn new(reset: Duration, remaining: u32) -> Self {
+        RateLimitFallback {
+            reset,
+            remaining,
+            strict: false,
+        }
+    }
+
+    /// Sets whether an authenticated response without `ratelimit-remaining` or `ratelimit-reset`
+    /// header fails with [`HypixelApiError::MissingRateLimitHeader`].
+    ///
+    /// The fallback values are still used to throttle later requests.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Returns the assumed time until the window resets.
+    pub fn reset(&self) -> Duration {
+        self.reset
+    }
+
+    /// Returns the assumed amount of requests left in the window.
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// Returns whether missing headers of authenticated responses are an error.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+}
+
+impl Default for RateLimitFallback {
+    fn default() -> Self {
+        RateLimitFallback::new(Duration::from_secs(10), 110)
+    }
+}
+
+/// The state of the rate limit after the most recent response,
+/// see [`RequestHandler::rate_limit_updates`](crate::RequestHandler::rate_limit_updates).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RateLimitStatus {
+    key_usage: Option<KeyUsage>,
+    throttled: bool,
+    received_at: Option<Instant>,
+}
+
+impl RateLimitStatus {
+    /// Returns the usage of the `ApiKey` as reported by the most recent authenticated response,
+    /// see [`RequestHandler::key_usage`](crate::RequestHandler::key_usage).
+    pub fn key_usage(&self) -> Option<KeyUsage> {
+        self.key_usage
+    }
+
+    /// Returns the amount of requests the key is allowed to send per window, if known.
+    pub fn limit(&self) -> Option<u32> {
+        self.key_usage.map(|usage| usage.limit())
+    }
+
+    /// Returns the amount of requests that were left in the window, if known.
+    pub fn remaining(&self) -> Option<u32> {
+        self.key_usage.map(|usage| usage.remaining())
+    }
+
+    /// Returns the time that is left before the window resets, if known.
+    ///
+    /// Unlike [`KeyUsage::reset`], this accounts for the time since the response was received.
+    pub fn reset_in(&self) -> Option<Duration> {
+        let usage = self.key_usage?;
+        Some(usage.reset().saturating_sub(self.received_at?.elapsed()))
+    }
+
+    /// Returns `true` if a `429 Too Many Requests` response was received and requests wait for
+    /// the window to reset.
+    pub fn is_throttled(&self) -> bool {
+        self.throttled
+    }
+
+    /// Returns when the most recent response was received, if any.
+    pub fn received_at(&self) -> Option<Instant> {
+        self.received_at
+    }
+}
+
+/// Keeps track of the requests that may still be sent in the current window.
+///
+/// Every request consumes a ticket, a permit of `tickets` that is not returned once a response
+/// is received. A window starts with a single ticket, the first response of a window tells how
+/// many requests are left and releases that many tickets. Waiting requests are queued by the
+/// semaphore in order and never lock the throttler while waiting.
+///
+/// A request that fails or is cancelled before a response is received returns its ticket as long
+/// as its window has not been opened, see [`Ticket`].
+///
+/// A window is assumed to reset 2 seconds after the `ratelimit-reset` header says it does. The
+/// header is relative and the reset is timed with [`Instant`], so the wall clock, and its skew
+/// measured from the `Date` header of the API, do not affect when a window resets.
 #[derive(Debug)]
 pub struct RequestThrottler {
-    requests_left: u32,
+    tickets: Arc<Semaphore>,
+    window: u64,
     received_first: bool,
     overflow_flagged: bool,
-    notify_rx: watch::Receiver<()>,
-    time_tx: mpsc::Sender<Option<Duration>>,
+    key_usage: Option<KeyUsage>,
+    clock_skew: Option<TimeDelta>,
+    fallback: RateLimitFallback,
+    time_tx: mpsc::Sender<Duration>,
+    status: watch::Sender<RateLimitStatus>,
 }
 
 impl RequestThrottler {
-    /// Call this function from an async context
-    pub(crate) fn new() -> Arc<Mutex<Self>> {
-        let (notify_tx, notify_rx) = watch::channel(());
-        let (time_tx, time_rx) = mpsc::channel(5);
-        let handler = Arc::new(Mutex::new(RequestThrottler {
-            requests_left: 1,
-            received_first: false,
-            overflow_flagged: false,
-            notify_rx,
-            time_tx,
-        }));
-        let handler_cloned = Arc::clone(&handler);
+    /// Creates a throttler of which the timer resetting the window runs on a dedicated thread,
+    /// independent of the tokio runtime that created it.
+    pub(crate) fn new(fallback: RateLimitFallback) -> Arc<Mutex<Self>> {
+        let (handler, timer) = RequestThrottler::with_timer(fallback);
         std::thread::spawn(move || {
             runtime::Builder::new_current_thread()
                 .enable_time()
                 .build().unwrap()
-                .block_on(RequestThrottler::start_waiting(handler_cloned, notify_tx, time_rx))
+                .block_on(timer)
         });
         handler
     }
 
-    pub(crate) fn request_ticket(&mut self) -> (bool, watch::Receiver<()>) {
-        let allow_pass = if self.requests_left > 0 {
-            self.requests_left -= 1;
-            true
+    /// Creates a throttler of which the timer runs on the current tokio runtime, so that tests
+    /// can control time with [`tokio::time::pause`].
+    ///
+    /// The timer stops with the runtime, the throttler must not outlive it.
+    #[cfg(test)]
+    pub(crate) fn new_on_current_runtime(fallback: RateLimitFallback) -> Arc<Mutex<Self>> {
+        let (handler, timer) = RequestThrottler::with_timer(fallback);
+        tokio::spawn(timer);
+        handler
+    }
+
+    fn with_timer(fallback: RateLimitFallback) -> (Arc<Mutex<Self>>, impl Future<Output = ()>) {
+        let (time_tx, time_rx) = mpsc::channel(5);
+        let handler = Arc::new(Mutex::new(RequestThrottler {
+            tickets: Arc::new(Semaphore::new(1)),
+            window: 0,
+            received_first: false,
+            overflow_flagged: false,
+            key_usage: None,
+            clock_skew: None,
+            fallback,
+            time_tx,
+            status: watch::channel(RateLimitStatus::default()).0,
+        }));
+        let timer = RequestThrottler::start_waiting(Arc::clone(&handler), time_rx);
+        (handler, timer)
+    }
+
+    /// Waits for a ticket, sends the request using `send` and repeats this until
+    /// a response is received that is not rate limited.
+    ///
+    /// Responses with an unexpected status code are returned as well, the caller
+    /// is responsible for turning these into errors. `authenticated` tells whether the
+    /// responses are expected to carry rate limit headers, see [`RateLimitFallback::strict`].
+    ///
+    /// With the `tracing` feature enabled, the `attempt`, `queue_wait_ms`, `status` and
+    /// `ratelimit_remaining` fields of the current span are recorded.
+    pub(crate) async fn send<R, F, Fut>(throttler: &Arc<Mutex<RequestThrottler>>, authenticated: bool, mut send: F) -> Result<R, HypixelApiError>
+    where
+        R: ThrottledResponse,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<R, HypixelApiError>>,
+    {
+        let mut _attempt = 0u32;
+        loop {
+            _attempt += 1;
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("attempt", _attempt);
+            let ticket = RequestThrottler::acquire(throttler).await?;
+            let response = send().await?;
+            let result = throttler.lock().on_response(response.status(), response.headers(), authenticated);
+            ticket.answered();
+            match result {
+                Ok(true) | Err(HypixelApiError::UnexpectedResponseCode(..)) => break Ok(response),
+                Ok(false) => {}
+                Err(error) => break Err(error),
+            }
+        }
+    }
+
+    async fn acquire(throttler: &Arc<Mutex<RequestThrottler>>) -> Result<Ticket<'_>, HypixelApiError> {
+        let tickets = Arc::clone(&throttler.lock().tickets);
+        if let Ok(ticket) = tickets.try_acquire() {
+            ticket.forget();
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("queue_wait_ms", 0);
+            return Ok(Ticket::new(throttler));
+        }
+        #[cfg(feature = "tracing")]
+        let start = Instant::now();
+        #[cfg(feature = "tracing")]
+        debug!("Waiting for the rate limit");
+        #[cfg(feature = "metrics")]
+        let _queued = crate::api::metrics::throttle_wait();
+        // the semaphore is owned by the throttler and never closed
+        let ticket = tickets.acquire().await.expect("ticket semaphore closed");
+        ticket.forget();
+        #[cfg(feature = "tracing")]
+        {
+            let queue_wait_ms = start.elapsed().as_millis() as u64;
+            tracing::Span::current().record("queue_wait_ms", queue_wait_ms);
+            debug!(queue_wait_ms, "Rate limit allows the request to be sent");
+        }
+        Ok(Ticket::new(throttler))
+    }
+
+    /// Sets the amount of available tickets to `amount`.
+    fn set_tickets(&self, amount: usize) {
+        let available = self.tickets.available_permits();
+        if available > amount {
+            self.tickets.forget_permits(available - amount);
         } else {
-            false
-        };
-        (allow_pass, self.notify_rx.clone())
+            self.tickets.add_permits(amount - available);
+        }
+    }
+
+    fn on_response(&mut self, status_code: StatusCode, headers: &HeaderMap, authenticated: bool) -> Result<bool, HypixelApiError> {
+        let time_before_reset = get_from_headers(headers, "ratelimit-reset", self.fallback.reset.as_secs())?.max(1);
+        let requests_remaining = get_from_headers(headers, "ratelimit-remaining", self.fallback.remaining)?.max(1);
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("status", status_code.as_u16());
+            if headers.contains_key("ratelimit-remaining") {
+                span.record("ratelimit_remaining", get_from_headers(headers, "ratelimit-remaining", 0u32)?);
+            }
+        }
+        if let Some(clock_skew) = clock_skew(headers) {
+            self.clock_skew = Some(clock_skew);
+        }
+        if headers.contains_key("ratelimit-limit") {
+            self.update_key_usage(KeyUsage::new(
+                get_from_headers(headers, "ratelimit-limit", 0)?,
+                get_from_headers(headers, "ratelimit-remaining", 0)?,
+                Duration::from_secs(get_from_headers(headers, "ratelimit-reset", 0)?),
+            ));
+        }
+        let received = self.on_received(status_code, time_before_reset, requests_remaining);
+        self.publish(Some(Instant::now()));
+        if self.fallback.strict && authenticated && status_code == StatusCode::OK {
+            if let Some(missing) = ["ratelimit-remaining", "ratelimit-reset"].into_iter().find(|name| !headers.contains_key(*name)) {
+                #[cfg(feature = "tracing")]
+                warn!(header = missing, "Response is missing a rate limit header");
+                return Err(HypixelApiError::MissingRateLimitHeader(missing.to_string()));
+            }
+        }
+        received
+    }
+
+    pub(crate) fn key_usage(&self) -> Option<KeyUsage> {
+        self.key_usage
+    }
+
+    pub(crate) fn update_key_usage(&mut self, key_usage: KeyUsage) {
+        self.key_usage = Some(key_usage);
+    }
+
+    pub(crate) fn clock_skew(&self) -> Option<TimeDelta> {
+        self.clock_skew
+    }
+
+    pub(crate) fn subscribe(&self) -> watch::Receiver<RateLimitStatus> {
+        self.status.subscribe()
     }
 
-    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    /// Notifies subscribers of the current state, `received_at` replaces the time of the
+    /// most recent response if present.
+    fn publish(&self, received_at: Option<Instant>) {
+        let received_at = received_at.or(self.status.borrow().received_at);
+        self.status.send_replace(RateLimitStatus {
+            key_usage: self.key_usage,
+            throttled: self.overflow_flagged,
+            received_at,
+        });
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
     pub(crate) fn on_received(&mut self, status_code: StatusCode, time_before_reset: u64, requests_remaining: u32) -> Result<bool, HypixelApiError> {
         match status_code {
             StatusCode::TOO_MANY_REQUESTS => {
                 #[cfg(feature = "tracing")]
-                warn!("Too many requests response!");
+                warn!(time_before_reset, "Received 429 Too Many Requests, waiting for the rate limit to reset");
                 if !self.overflow_flagged {
                     self.overflow_flagged = true;
-                    self.requests_left = 0;
-                    self.time_tx.try_send(Some(Duration::from_secs(time_before_reset + 2)))?;
+                    self.set_tickets(0);
+                    self.time_tx.try_send(Duration::from_secs(time_before_reset + 2))?;
                 }
                 Ok(false)
             }
-            StatusCode::OK => {
-                if !self.received_first {
-                    self.received_first = true;
-                    self.requests_left = requests_remaining;
-                    self.time_tx.try_send(Some(Duration::from_secs(time_before_reset + 2)))?;
-                    self.time_tx.try_send(None)?;
-                }
+            // a conditional request that was answered with `304 Not Modified` succeeded as well
+            StatusCode::OK | StatusCode::NOT_MODIFIED => {
+                self.open_window(time_before_reset, requests_remaining)?;
                 Ok(true)
             }
-            code => return Err(HypixelApiError::UnexpectedResponseCode(code, None)),
+            code => {
+                // other errors are not rate limited, the window is opened all the same
+                self.open_window(time_before_reset, requests_remaining)?;
+                Err(HypixelApiError::UnexpectedResponseCode(code, None))
+            }
         }
     }
 
+    /// Releases the tickets of the current window once its first response was received.
+    fn open_window(&mut self, time_before_reset: u64, requests_remaining: u32) -> Result<(), HypixelApiError> {
+        if !self.received_first {
+            self.received_first = true;
+            self.set_tickets(requests_remaining as usize);
+            self.time_tx.try_send(Duration::from_secs(time_before_reset + 2))?;
+        }
+        Ok(())
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(name = "timer_thread", skip_all))]
-    async fn start_waiting(throttler: Arc<Mutex<RequestThrottler>>, wait_tx: watch::Sender<()>, mut time_rx: mpsc::Receiver<Option<Duration>>) {
+    async fn start_waiting(throttler: Arc<Mutex<RequestThrottler>>, mut time_rx: mpsc::Receiver<Duration>) {
         let sleeper = sleep(Duration::from_millis(10));
         tokio::pin!(sleeper);
         let mut duration_set = false;
@@ -83,32 +355,18 @@ impl RequestThrottler {
             tokio::select! {
                 () = &mut sleeper, if duration_set => {
                     duration_set = false;
-                    {
-                        let mut throttler = throttler.lock();
-                        throttler.received_first = false;
-                        throttler.overflow_flagged = false;
-                        throttler.requests_left = 1;
-                    }
-                    if let Err(_error) = wait_tx.send(()) {
-                        #[cfg(feature = "tracing")]
-                        error!(%_error, "Error while sending wake up!");
-                    }
+                    let mut throttler = throttler.lock();
+                    throttler.window += 1;
+                    throttler.received_first = false;
+                    throttler.overflow_flagged = false;
+                    throttler.set_tickets(1);
+                    throttler.publish(None);
                 }
                 duration = time_rx.recv() => {
                     match duration {
                         Some(duration) => {
-                            match duration {
-                                Some(duration) => {
-                                    sleeper.as_mut().reset(Instant::now() + duration);
-                                    duration_set = true;
-                                }
-                                None => {
-                                    if let Err(_error) = wait_tx.send(()) {
-                                        #[cfg(feature = "tracing")]
-                                        error!(%_error, "Error while sending wake up!");
-                                    }
-                                }
-                            }
+                            sleeper.as_mut().reset(Instant::now() + duration);
+                            duration_set = true;
                         }
                         None => break,
                     }
@@ -116,4 +374,71 @@ impl RequestThrottler {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// A ticket taken by a request that has not been answered yet.
+///
+/// Only the first response of a window opens it and arms the timer that resets it. If the request
+/// holding the only ticket of a window failed or was cancelled without returning its ticket, the
+/// window would never open and every later request would wait forever. Dropping the ticket
+/// therefore returns it, unless its window was opened or reset in the meantime.
+struct Ticket<'a> {
+    throttler: &'a Arc<Mutex<RequestThrottler>>,
+    window: u64,
+}
+
+impl<'a> Ticket<'a> {
+    fn new(throttler: &'a Arc<Mutex<RequestThrottler>>) -> Self {
+        let window = throttler.lock().window;
+        Ticket { throttler, window }
+    }
+
+    /// Consumes the ticket after a response was received.
+    fn answered(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Ticket<'_> {
+    fn drop(&mut self) {
+        let throttler = self.throttler.lock();
+        if throttler.window == self.window && !throttler.received_first && !throttler.overflow_flagged {
+            #[cfg(feature = "tracing")]
+            debug!("Request failed without a response, returning its ticket");
+            throttler.tickets.add_permits(1);
+        }
+    }
+}
+
+/// A response of which the rate limit headers are read by the [`RequestThrottler`].
+pub(crate) trait ThrottledResponse {
+    fn status(&self) -> StatusCode;
+
+    fn headers(&self) -> &HeaderMap;
+}
+
+impl ThrottledResponse for Response {
+    fn status(&self) -> StatusCode {
+        Response::status(self)
+    }
+
+    fn headers(&self) -> &HeaderMap {
+        Response::headers(self)
+    }
+}
+
+fn get_from_headers<K: AsHeaderName, E: Error + Send + Sync + 'static, T: FromStr<Err=E> + Copy>(headers: &HeaderMap, name: K, default: T) -> Result<T, HypixelApiError> {
+    headers.get(name)
+        .map(|o| o.to_str())
+        .map(|o| o.map_or(Ok(default), |s| s.parse::<T>().map_err(|_| HypixelApiError::IntFromStrError(String::from(s)))))
+        .unwrap_or(Ok(default))
+}
+
+/// Returns the difference between the time of the API and the local time, positive if the
+/// local clock is behind, from the `Date` and `Age` headers of a response that was just received.
+fn clock_skew(headers: &HeaderMap) -> Option<TimeDelta> {
+    let date = DateTime::parse_from_rfc2822(headers.get(DATE)?.to_str().ok()?).ok()?;
+    // a response served by a cache was generated `Age` seconds ago
+    let age = get_from_headers(headers, AGE, 0i64).unwrap_or(0);
+    Some(date.with_timezone(&Utc) + TimeDelta::seconds(age) - Utc::now())
+}
@@ -0,0 +1,106 @@
+use std::ops::Deref;
+use std::time::Duration;
+use crate::api::events::RequestId;
+
+/// How a single request was executed, see [`Timed`].
+///
+/// The queue wait and the network time tell a backed up queue apart from a slow API:
+/// the queue wait is the time before the request was first sent, spent waiting for a worker
+/// and for the rate limit, the network time is the time spent sending and receiving.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ResponseMeta {
+    pub(crate) id: RequestId,
+    pub(crate) total: Duration,
+    pub(crate) queue_wait: Duration,
+    pub(crate) network: Duration,
+    pub(crate) attempts: u32,
+    pub(crate) retries: u32,
+    pub(crate) from_cache: bool,
+}
+
+impl ResponseMeta {
+    /// Returns the id of the request, as used by its [`RequestEvent`](crate::RequestEvent)s.
+    pub fn id(&self) -> RequestId {
+        self.id
+    }
+
+    /// Returns the time from queueing the request until its response was received.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Returns the time the request waited for a worker and the rate limit before it was
+    /// first sent, or before it was served from the cache.
+    pub fn queue_wait(&self) -> Duration {
+        self.queue_wait
+    }
+
+    /// Returns the time spent sending the request and receiving its response, summed over
+    /// all attempts.
+    pub fn network(&self) -> Duration {
+        self.network
+    }
+
+    /// Returns the amount of times the request was sent, `0` if it was served from the cache.
+    ///
+    /// This includes requests sent again after `429 Too Many Requests` and after transient failures.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Returns the amount of times the request was retried after a transient failure,
+    /// see [`RetryPolicy`](crate::RetryPolicy).
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// Returns whether the response was served from the [`cache`](crate::cache), including
+    /// cached responses that were revalidated with the API.
+    pub fn from_cache(&self) -> bool {
+        self.from_cache
+    }
+}
+
+/// A wrapper around a response data structure with the [`ResponseMeta`] of its request,
+/// see [`RequestHandler::request_timed`](crate::RequestHandler::request_timed).
+///
+/// The data is accessible through [`Deref`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timed<T> {
+    data: T,
+    meta: ResponseMeta,
+}
+
+impl<T> Timed<T> {
+    pub(crate) fn new(data: T, meta: ResponseMeta) -> Self {
+        Timed { data, meta }
+    }
+
+    /// Returns the data.
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    /// Returns how the request was executed.
+    pub fn meta(&self) -> &ResponseMeta {
+        &self.meta
+    }
+
+    /// Consumes the wrapper and returns the data.
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    /// Consumes the wrapper and returns both the data and the meta data of its request.
+    pub fn into_parts(self) -> (T, ResponseMeta) {
+        (self.data, self.meta)
+    }
+}
+
+impl<T> Deref for Timed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
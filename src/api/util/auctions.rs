@@ -0,0 +1,669 @@
+//! Utilities to analyze the auctions of one or more [`SkyblockAuctionsReply`](crate::SkyblockAuctionsReply) pages.
+//!
+//! Most functions take a `key` function to identify items. [`Auction::item_name`] works
+//! without additional features, with the `nbt` feature enabled `item_id` identifies items
+//! by their SkyBlock id instead, which is not affected by reforges or stars.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use hypixel_api::util::auctions::lowest_bin_by;
+//! # use hypixel_api::SkyblockAuctionsReply;
+//!
+//! # fn print_lowest(reply: &SkyblockAuctionsReply) {
+//! let lowest = lowest_bin_by(reply.auctions(), |auction| auction.item_name().to_string());
+//! for (name, auction) in lowest {
+//!     println!("{}: {} coins", name, auction.starting_bid());
+//! }
+//! # }
+//! ```
+//!
+//! [`scan_all_auctions`] fetches every page of the auction house at once, [`AuctionIndex`]
+//! indexes the auctions of a scan for repeated queries. [`snipe`] yields the auctions that
+//! match an [`AuctionFilter`] as soon as their page is received. Both respect the
+//! [`PageLimits`] of the handler, [`scan_auctions_with_limits`] and [`snipe_with_limits`]
+//! take limits per call.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::{DateTime, Duration, Utc};
+use futures::future::{ready, Either};
+use futures::stream::{self, FuturesUnordered};
+use futures::{Stream, StreamExt};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::api::error::HypixelApiError;
+use crate::api::pagination::{PageLimits, PartialResult};
+use crate::api::reply::{Auction, SkyblockAuctionsReply};
+use crate::api::request::HypixelApi;
+use crate::api::util::formatting::strip_codes;
+
+/// The rarities of SkyBlock items as used by [`Auction::tier`], from the most common to the rarest.
+const TIERS: [&str; 9] = ["COMMON", "UNCOMMON", "RARE", "EPIC", "LEGENDARY", "MYTHIC", "DIVINE", "SPECIAL", "VERY_SPECIAL"];
+
+/// Returns only the Buy It Now auctions of `auctions`.
+pub fn bin_only<'a>(auctions: impl IntoIterator<Item = &'a Auction>) -> impl Iterator<Item = &'a Auction> {
+    auctions.into_iter().filter(|auction| auction.is_bin())
+}
+
+/// Groups `auctions` by the key returned by `key`, auctions without key are skipped.
+pub fn group_by<'a, K, F>(auctions: impl IntoIterator<Item = &'a Auction>, mut key: F) -> HashMap<K, Vec<&'a Auction>>
+where
+    K: Eq + Hash,
+    F: FnMut(&Auction) -> Option<K>,
+{
+    let mut groups: HashMap<K, Vec<&'a Auction>> = HashMap::new();
+    for auction in auctions {
+        if let Some(key) = key(auction) {
+            groups.entry(key).or_default().push(auction);
+        }
+    }
+    groups
+}
+
+/// Returns the cheapest Buy It Now auction for every item, identified by `key`.
+pub fn lowest_bin_by<'a, K, F>(auctions: impl IntoIterator<Item = &'a Auction>, mut key: F) -> HashMap<K, &'a Auction>
+where
+    K: Eq + Hash,
+    F: FnMut(&Auction) -> K,
+{
+    let mut lowest: HashMap<K, &'a Auction> = HashMap::new();
+    for auction in bin_only(auctions) {
+        lowest.entry(key(auction))
+            .and_modify(|current| if auction.starting_bid() < current.starting_bid() {
+                *current = auction;
+            })
+            .or_insert(auction);
+    }
+    lowest
+}
+
+/// Returns the auctions that end within `within` after `now`, sorted by their end time.
+///
+/// Auctions that have already ended are skipped.
+pub fn ending_soon<'a>(auctions: impl IntoIterator<Item = &'a Auction>, now: DateTime<Utc>, within: Duration) -> Vec<&'a Auction> {
+    let deadline = now + within;
+    let mut ending: Vec<_> = auctions.into_iter()
+        .filter(|auction| auction.end().is_some_and(|end| end > now && end <= deadline))
+        .collect();
+    ending.sort_by_key(|auction| auction.end());
+    ending
+}
+
+/// Returns the SkyBlock id of the auctioned item (e.g. `"ASPECT_OF_THE_END"`),
+/// for use as `key` in the functions of this module.
+///
+/// Returns [`Option::None`] if the item data could not be decoded.
+#[cfg(feature = "nbt")]
+pub fn item_id(auction: &Auction) -> Option<String> {
+    auction.item().ok().flatten().map(|item| item.id().to_string())
+}
+
+/// All pages of the auction house, fetched by [`scan_all_auctions`].
+#[derive(Debug, Clone)]
+pub struct AuctionScan {
+    pages: Vec<SkyblockAuctionsReply>,
+    started_at: DateTime<Utc>,
+    elapsed: std::time::Duration,
+}
+
+impl AuctionScan {
+    /// Returns the fetched pages, ordered by page number.
+    pub fn pages(&self) -> &[SkyblockAuctionsReply] {
+        &self.pages
+    }
+
+    /// Returns the auctions of all pages.
+    pub fn auctions(&self) -> impl Iterator<Item = &Auction> {
+        self.pages.iter().flat_map(|page| page.auctions())
+    }
+
+    /// Returns the amount of auctions of all pages.
+    pub fn len(&self) -> usize {
+        self.pages.iter().map(|page| page.auctions().len()).sum()
+    }
+
+    /// Returns `true` if no auctions were fetched.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the last time the first page was updated by Hypixel.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.pages.first().and_then(|page| page.last_updated())
+    }
+
+    /// Returns `true` if all pages were fetched from the same update of the auction house.
+    ///
+    /// Hypixel updates the auction house about every minute, a scan that overlaps with an
+    /// update may contain auctions twice or miss auctions.
+    pub fn is_consistent(&self) -> bool {
+        let last_updated = self.last_updated();
+        self.pages.iter().all(|page| page.last_updated() == last_updated)
+    }
+
+    /// Returns when the scan started.
+    pub fn started_at(&self) -> DateTime<Utc> {
+        self.started_at
+    }
+
+    /// Returns how long the scan took.
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.elapsed
+    }
+
+    /// Consumes the scan and returns the auctions of all pages.
+    pub fn into_auctions(self) -> impl Iterator<Item = Auction> {
+        self.pages.into_iter().flat_map(SkyblockAuctionsReply::into_auctions)
+    }
+}
+
+/// Identifies the item of an auction for an [`AuctionIndex`], see [`AuctionIndex::new`].
+pub type ItemKey = Arc<dyn Fn(&Auction) -> Option<String> + Send + Sync>;
+
+/// An in-memory index of auctions, queryable by item, tier and Buy It Now.
+///
+/// Items are identified by a key function, e.g. [`Auction::item_name`] or, with the `nbt`
+/// feature enabled, `item_id`. Auctions keep the order they were indexed in, duplicates
+/// (auctions that moved between pages during a scan) are only indexed once.
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use hypixel_api::RequestHandler;
+/// use hypixel_api::util::auctions::{scan_all_auctions, AuctionIndex};
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let request_handler = RequestHandler::new(Uuid::nil());
+/// let scan = scan_all_auctions(&request_handler, 8).await.unwrap();
+/// let index = AuctionIndex::from_scan(scan, Arc::new(|auction| Some(auction.item_name().to_string())));
+/// if let Some(auction) = index.lowest_bin("Aspect of the End") {
+///     println!("cheapest AOTE: {} coins", auction.starting_bid());
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AuctionIndex {
+    key: ItemKey,
+    auctions: Vec<Auction>,
+    positions: HashMap<Uuid, usize>,
+    by_item: HashMap<String, Vec<usize>>,
+    by_tier: HashMap<String, Vec<usize>>,
+    lowest_bin: HashMap<String, usize>,
+    last_updated: Option<DateTime<Utc>>,
+}
+
+impl std::fmt::Debug for AuctionIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuctionIndex")
+            .field("auctions", &self.auctions.len())
+            .field("items", &self.by_item.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl AuctionIndex {
+    /// Indexes `auctions`, identifying their items with `key`.
+    ///
+    /// Auctions for which `key` returns [`Option::None`] can only be found by uuid and tier.
+    pub fn new(auctions: impl IntoIterator<Item = Auction>, key: ItemKey) -> Self {
+        let mut index = AuctionIndex {
+            key,
+            auctions: Vec::new(),
+            positions: HashMap::new(),
+            by_item: HashMap::new(),
+            by_tier: HashMap::new(),
+            lowest_bin: HashMap::new(),
+            last_updated: None,
+        };
+        for auction in auctions {
+            if index.positions.contains_key(&auction.uuid()) {
+                continue;
+            }
+            let position = index.auctions.len();
+            index.positions.insert(auction.uuid(), position);
+            index.by_tier.entry(auction.tier().to_string()).or_default().push(position);
+            if let Some(item) = (index.key)(&auction) {
+                if auction.is_bin() {
+                    let auctions = &index.auctions;
+                    index.lowest_bin.entry(item.clone())
+                        .and_modify(|lowest| if auction.starting_bid() < auctions[*lowest].starting_bid() {
+                            *lowest = position;
+                        })
+                        .or_insert(position);
+                }
+                index.by_item.entry(item).or_default().push(position);
+            }
+            index.auctions.push(auction);
+        }
+        index
+    }
+
+    /// Indexes the auctions of `scan`, identifying their items with `key`.
+    pub fn from_scan(scan: AuctionScan, key: ItemKey) -> Self {
+        let last_updated = scan.last_updated();
+        let mut index = AuctionIndex::new(scan.into_auctions(), key);
+        index.last_updated = last_updated;
+        index
+    }
+
+    /// Returns the last time the auction house was updated by Hypixel before it was scanned,
+    /// only known for an index created by [`AuctionIndex::from_scan`].
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.last_updated
+    }
+
+    /// Returns the function that identifies the items of auctions.
+    pub fn key(&self) -> &ItemKey {
+        &self.key
+    }
+
+    /// Returns the amount of indexed auctions.
+    pub fn len(&self) -> usize {
+        self.auctions.len()
+    }
+
+    /// Returns `true` if no auctions are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.auctions.is_empty()
+    }
+
+    /// Returns all indexed auctions.
+    pub fn auctions(&self) -> &[Auction] {
+        &self.auctions
+    }
+
+    /// Returns the auction with the given `uuid`, if present.
+    pub fn get(&self, uuid: Uuid) -> Option<&Auction> {
+        self.positions.get(&uuid).map(|position| &self.auctions[*position])
+    }
+
+    /// Returns the keys of all indexed items.
+    pub fn items(&self) -> impl Iterator<Item = &str> {
+        self.by_item.keys().map(String::as_str)
+    }
+
+    /// Returns the auctions of `item`.
+    pub fn by_item<'a>(&'a self, item: &str) -> impl Iterator<Item = &'a Auction> {
+        self.select(self.by_item.get(item))
+    }
+
+    /// Returns the auctions of items with the rarity `tier`, e.g. `"LEGENDARY"`.
+    pub fn by_tier<'a>(&'a self, tier: &str) -> impl Iterator<Item = &'a Auction> {
+        self.select(self.by_tier.get(tier))
+    }
+
+    /// Returns all Buy It Now auctions.
+    pub fn bins(&self) -> impl Iterator<Item = &Auction> {
+        bin_only(&self.auctions)
+    }
+
+    /// Returns the cheapest Buy It Now auction of `item`, if present.
+    pub fn lowest_bin(&self, item: &str) -> Option<&Auction> {
+        self.lowest_bin.get(item).map(|position| &self.auctions[*position])
+    }
+
+    /// Compares this index to an index of an earlier scan.
+    pub fn diff(&self, previous: &AuctionIndex) -> AuctionDiff {
+        AuctionDiff {
+            new: self.auctions.iter()
+                .filter(|auction| !previous.positions.contains_key(&auction.uuid()))
+                .cloned()
+                .collect(),
+            ended: previous.auctions.iter()
+                .filter(|auction| !self.positions.contains_key(&auction.uuid()))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    fn select<'a>(&'a self, positions: Option<&'a Vec<usize>>) -> impl Iterator<Item = &'a Auction> {
+        positions.into_iter().flatten().map(|position| &self.auctions[*position])
+    }
+}
+
+/// The auctions that were created and ended between two scans, see [`AuctionIndex::diff`].
+///
+/// Ended auctions were either bought, expired or cancelled.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuctionDiff {
+    new: Vec<Auction>,
+    ended: Vec<Auction>,
+}
+
+impl AuctionDiff {
+    /// Returns the auctions that were not present in the earlier scan.
+    pub fn new_auctions(&self) -> &[Auction] {
+        &self.new
+    }
+
+    /// Returns the auctions of the earlier scan that are no longer present.
+    pub fn ended_auctions(&self) -> &[Auction] {
+        &self.ended
+    }
+
+    /// Returns `true` if no auctions were created or ended.
+    pub fn is_empty(&self) -> bool {
+        self.new.is_empty() && self.ended.is_empty()
+    }
+}
+
+/// Fetches all pages of the auction house.
+///
+/// The first page is fetched to learn the amount of pages, the remaining pages are fetched with at
+/// most `concurrency` requests in flight (at least one). Requests sent through a
+/// [`RequestHandler`](crate::RequestHandler) respect the rate limit as usual.
+///
+/// Pages that no longer exist when they are requested (`404 Not Found`, the auction house
+/// shrank during the scan) are skipped.
+///
+/// # Errors
+///
+/// Fails with [`HypixelApiError::LimitReached`] if the scan reached the
+/// [`PageLimits`] of `api`, see [`scan_auctions_with_limits`] to keep the pages fetched so far.
+///
+/// # Examples
+/// ```rust,no_run
+/// use hypixel_api::RequestHandler;
+/// use hypixel_api::util::auctions::{lowest_bin_by, scan_all_auctions};
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let request_handler = RequestHandler::new(Uuid::nil());
+/// let scan = scan_all_auctions(&request_handler, 8).await.unwrap();
+/// println!("{} auctions in {:?}", scan.len(), scan.elapsed());
+/// let lowest = lowest_bin_by(scan.auctions(), |auction| auction.item_name().to_string());
+/// # }
+/// ```
+pub async fn scan_all_auctions(api: &impl HypixelApi, concurrency: usize) -> Result<AuctionScan, HypixelApiError> {
+    scan_auctions_with_limits(api, concurrency, PageLimits::new()).await?
+        .into_complete()
+        .map_err(HypixelApiError::LimitReached)
+}
+
+/// Fetches pages of the auction house like [`scan_all_auctions`], until `limits` (or, where
+/// not set, the [`PageLimits`] of `api`) are reached.
+///
+/// A scan that reached a limit returns the pages fetched so far, along with the limit.
+///
+/// # Examples
+/// ```rust,no_run
+/// use hypixel_api::{PageLimits, RequestHandler};
+/// use hypixel_api::util::auctions::scan_auctions_with_limits;
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let request_handler = RequestHandler::new(Uuid::nil());
+/// let result = scan_auctions_with_limits(&request_handler, 8, PageLimits::new().max_pages(10)).await.unwrap();
+/// if let Some(limit) = result.limit() {
+///     println!("stopped after {}", limit);
+/// }
+/// println!("{} auctions", result.value().len());
+/// # }
+/// ```
+pub async fn scan_auctions_with_limits(api: &impl HypixelApi, concurrency: usize, limits: PageLimits) -> Result<PartialResult<AuctionScan>, HypixelApiError> {
+    let limits = limits.or(api.page_limits());
+    let started_at = Utc::now();
+    let start = Instant::now();
+    if let (0, limit) = limits.allowed_pages(1) {
+        let scan = AuctionScan { pages: Vec::new(), started_at, elapsed: start.elapsed() };
+        return Ok(PartialResult::new(scan, limit));
+    }
+    let first = api.request::<SkyblockAuctionsReply>("skyblock/auctions?page=0", false).await??;
+    let (allowed, mut limit) = limits.allowed_pages(first.total_pages() as usize);
+    let mut items = first.auctions().len();
+    let mut pages = vec![first];
+
+    let mut remaining = 1..allowed as u32;
+    let mut in_flight = FuturesUnordered::new();
+    loop {
+        while in_flight.len() < concurrency.max(1) {
+            if let (false, Some(reached)) = (remaining.is_empty(), limits.items_reached(items)) {
+                limit = Some(reached);
+                remaining = 0..0;
+            }
+            match remaining.next() {
+                Some(page) => in_flight.push(api.request::<SkyblockAuctionsReply>(&format!("skyblock/auctions?page={}", page), false)),
+                None => break,
+            }
+        }
+        match in_flight.next().await {
+            Some(reply) => match reply? {
+                Ok(page) => {
+                    items += page.auctions().len();
+                    pages.push(page);
+                }
+                Err(HypixelApiError::UnexpectedResponseCode(StatusCode::NOT_FOUND, _)) => {}
+                Err(error) => return Err(error),
+            },
+            None => break,
+        }
+    }
+    pages.sort_by_key(|page| page.page());
+
+    let scan = AuctionScan {
+        pages,
+        started_at,
+        elapsed: start.elapsed(),
+    };
+    Ok(PartialResult::new(scan, limit))
+}
+
+/// A filter for auctions, e.g. to find underpriced items as pages are fetched by [`snipe`].
+///
+/// An auction matches if it satisfies every condition that was set, a filter without
+/// conditions matches every auction. Conditions are prepared when they are set, and
+/// [`AuctionFilter::matches`] checks the cheap ones first, so the item data of an auction
+/// is only decoded if it passes every other condition.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::util::auctions::AuctionFilter;
+///
+/// let filter = AuctionFilter::new()
+///     .bin_only()
+///     .max_price(5_000_000)
+///     .tier_at_least("LEGENDARY")
+///     .enchant_contains("Ultimate Wise");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuctionFilter {
+    #[cfg(feature = "nbt")]
+    item_id: Option<String>,
+    max_price: Option<u64>,
+    bin_only: bool,
+    min_tier: Option<usize>,
+    enchant: Option<String>,
+}
+
+impl AuctionFilter {
+    /// Creates a filter that matches every auction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches auctions of the item with the SkyBlock id `id` (e.g. `"HYPERION"`), see [`item_id`].
+    #[cfg(feature = "nbt")]
+    pub fn item_id(mut self, id: impl Into<String>) -> Self {
+        self.item_id = Some(id.into());
+        self
+    }
+
+    /// Only matches auctions whose [price](Auction::price) is at most `price`.
+    pub fn max_price(mut self, price: u64) -> Self {
+        self.max_price = Some(price);
+        self
+    }
+
+    /// Only matches Buy It Now auctions.
+    pub fn bin_only(mut self) -> Self {
+        self.bin_only = true;
+        self
+    }
+
+    /// Only matches auctions of items with the rarity `tier` (e.g. `"EPIC"`) or rarer.
+    ///
+    /// If `tier` is not a known rarity, no auction matches.
+    pub fn tier_at_least(mut self, tier: &str) -> Self {
+        self.min_tier = Some(tier_rank(tier).unwrap_or(TIERS.len()));
+        self
+    }
+
+    /// Only matches auctions whose item lore contains `text` (e.g. `"Ultimate Wise V"`),
+    /// ignoring case and formatting codes.
+    pub fn enchant_contains(mut self, text: &str) -> Self {
+        self.enchant = Some(text.to_lowercase());
+        self
+    }
+
+    /// Returns `true` if `auction` satisfies every condition of this filter.
+    pub fn matches(&self, auction: &Auction) -> bool {
+        if self.bin_only && !auction.is_bin() {
+            return false;
+        }
+        if self.max_price.is_some_and(|price| auction.price() > price) {
+            return false;
+        }
+        if let Some(min_tier) = self.min_tier {
+            if !matches!(tier_rank(auction.tier()), Some(rank) if rank >= min_tier) {
+                return false;
+            }
+        }
+        if let Some(enchant) = &self.enchant {
+            if !strip_codes(auction.item_lore()).to_lowercase().contains(enchant.as_str()) {
+                return false;
+            }
+        }
+        #[cfg(feature = "nbt")]
+        if let Some(id) = &self.item_id {
+            if item_id(auction).as_ref() != Some(id) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Returns the position of `tier` in [`TIERS`], if known.
+fn tier_rank(tier: &str) -> Option<usize> {
+    TIERS.iter().position(|known| *known == tier)
+}
+
+/// Fetches every page of the auction house like [`scan_all_auctions`], and yields the auctions
+/// that match `filter` as soon as their page is received.
+///
+/// Pages are yielded in the order they are received, with at most `concurrency` requests in
+/// flight (at least one). A page that fails is yielded as an error, the remaining pages are
+/// still fetched, unless the first page failed. Pages that no longer exist are skipped. An
+/// auction that moved between pages during the scan may be yielded twice.
+///
+/// # Examples
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use hypixel_api::RequestHandler;
+/// use hypixel_api::util::auctions::{snipe, AuctionFilter};
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let request_handler = RequestHandler::new(Uuid::nil());
+/// let filter = AuctionFilter::new().bin_only().max_price(1_000_000).tier_at_least("EPIC");
+/// let mut matches = Box::pin(snipe(&request_handler, filter, 8));
+/// while let Some(auction) = matches.next().await {
+///     let auction = auction.unwrap();
+///     println!("{} for {} coins", auction.item_name(), auction.price());
+/// }
+/// # }
+/// ```
+pub fn snipe<'a>(api: &'a impl HypixelApi, filter: AuctionFilter, concurrency: usize) -> impl Stream<Item = Result<Auction, HypixelApiError>> + 'a {
+    snipe_with_limits(api, filter, concurrency, PageLimits::new())
+}
+
+/// Yields the auctions that match `filter` like [`snipe`], until `limits` (or, where not set,
+/// the [`PageLimits`] of `api`) are reached.
+///
+/// A scan that reached a limit ends with [`HypixelApiError::LimitReached`], after the auctions
+/// of the pages fetched so far. [`snipe`] applies the limits of `api` the same way.
+///
+/// # Examples
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use hypixel_api::{PageLimits, RequestHandler};
+/// use hypixel_api::error::HypixelApiError;
+/// use hypixel_api::util::auctions::{snipe_with_limits, AuctionFilter};
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let request_handler = RequestHandler::new(Uuid::nil());
+/// let filter = AuctionFilter::new().bin_only().max_price(1_000_000);
+/// let mut matches = Box::pin(snipe_with_limits(&request_handler, filter, 8, PageLimits::new().max_requests(20)));
+/// while let Some(auction) = matches.next().await {
+///     match auction {
+///         Ok(auction) => println!("{} for {} coins", auction.item_name(), auction.price()),
+///         Err(HypixelApiError::LimitReached(limit)) => println!("stopped after {}", limit),
+///         Err(error) => println!("a page failed: {}", error),
+///     }
+/// }
+/// # }
+/// ```
+pub fn snipe_with_limits<'a>(api: &'a impl HypixelApi, filter: AuctionFilter, concurrency: usize, limits: PageLimits) -> impl Stream<Item = Result<Auction, HypixelApiError>> + 'a {
+    let limits = limits.or(api.page_limits());
+    let pages = match limits.allowed_pages(1) {
+        (0, limit) => Either::Left(stream::iter(limit.map(|limit| Err(HypixelApiError::LimitReached(limit))))),
+        _ => Either::Right(fetch_pages(api, concurrency, limits)),
+    };
+    pages.flat_map(move |page| stream::iter(match page {
+        Ok(page) => page.into_auctions().into_iter()
+            .filter(|auction| filter.matches(auction))
+            .map(Ok)
+            .collect(),
+        Err(error) => vec![Err(error)],
+    }))
+}
+
+/// Yields the pages of the auction house in the order they are received, ending with
+/// [`HypixelApiError::LimitReached`] if `limits` stopped the scan early.
+fn fetch_pages(api: &impl HypixelApi, concurrency: usize, limits: PageLimits) -> impl Stream<Item = Result<SkyblockAuctionsReply, HypixelApiError>> + '_ {
+    let first = stream::once(async move {
+        api.request::<SkyblockAuctionsReply>("skyblock/auctions?page=0", false).await?
+    });
+    let pages = first.flat_map(move |first| match first {
+        Ok(first) => {
+            let (allowed, limit) = limits.allowed_pages(first.total_pages() as usize);
+            let rest = stream::iter(1..allowed as u32)
+                .map(move |page| api.request::<SkyblockAuctionsReply>(&format!("skyblock/auctions?page={}", page), false))
+                .buffer_unordered(concurrency.max(1))
+                .filter_map(|reply| ready(match reply {
+                    Ok(Err(HypixelApiError::UnexpectedResponseCode(StatusCode::NOT_FOUND, _))) => None,
+                    Ok(reply) => Some(reply),
+                    Err(error) => Some(Err(error.into())),
+                }));
+            Either::Left(stream::once(ready(Ok(first))).chain(rest).map(move |page| (page, limit)))
+        }
+        Err(error) => Either::Right(stream::once(ready((Err(error), None)))),
+    });
+    // stops at the item limit, and reports the limit once all allowed pages were yielded
+    stream::unfold((Box::pin(pages), 0, None, false), move |(mut pages, items, limit, done)| async move {
+        if done {
+            return None;
+        }
+        match pages.next().await {
+            Some((page, page_limit)) => {
+                if let Some(reached) = limits.items_reached(items) {
+                    return Some((Err(HypixelApiError::LimitReached(reached)), (pages, items, None, true)));
+                }
+                let items = items + page.as_ref().map_or(0, |page| page.auctions().len());
+                Some((page, (pages, items, page_limit, false)))
+            }
+            None => limit.map(|limit| (Err(HypixelApiError::LimitReached(limit)), (pages, items, None, true))),
+        }
+    })
+}
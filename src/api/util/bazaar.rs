@@ -0,0 +1,72 @@
+//! Utilities to analyze the bazaar data of a [`SkyblockBazaarReply`](crate::SkyblockBazaarReply).
+//!
+//! All prices are per unit unless stated otherwise. Keep in mind that the order book returned
+//! by the API only contains the best 30 prices on either side.
+
+use crate::api::reply::{BazaarOrder, BazaarProduct};
+
+/// The default tax on selling items through the bazaar (1.25%).
+///
+/// This is lowered by the Bazaar Flipper account upgrade.
+pub const DEFAULT_TAX: f64 = 0.0125;
+
+/// Returns the difference between the instant buy price and the instant sell price of `product`.
+///
+/// Returns [`Option::None`] if either side of the order book is empty.
+pub fn spread(product: &BazaarProduct) -> Option<f64> {
+    Some(product.instant_buy_price()? - product.instant_sell_price()?)
+}
+
+/// Returns the profit per unit of flipping `product`: filling a buy order just above the best
+/// buy order and selling with a sell offer just below the best sell offer, after paying `tax`
+/// (e.g. [`DEFAULT_TAX`]).
+///
+/// Returns [`Option::None`] if either side of the order book is empty.
+pub fn flip_margin(product: &BazaarProduct, tax: f64) -> Option<f64> {
+    let buy = product.instant_sell_price()?;
+    let sell = product.instant_buy_price()?;
+    Some(sell * (1.0 - tax) - buy)
+}
+
+/// Returns the average price of the first `n` entries of `orders`, weighted by their amount.
+///
+/// Use [`BazaarProduct::buy_summary`] or [`BazaarProduct::sell_summary`] as `orders`.
+/// Returns [`Option::None`] if there are no items in these orders.
+pub fn weighted_average(orders: &[BazaarOrder], n: usize) -> Option<f64> {
+    let (amount, total) = orders.iter()
+        .take(n)
+        .fold((0u64, 0.0), |(amount, total), order| {
+            (amount + order.amount(), total + order.amount() as f64 * order.price_per_unit())
+        });
+    (amount > 0).then(|| total / amount as f64)
+}
+
+/// Returns the total cost of instantly buying `quantity` items of `product`,
+/// walking up the sell offers.
+///
+/// Returns [`Option::None`] if the order book does not contain enough items.
+pub fn instant_buy_cost(product: &BazaarProduct, quantity: u64) -> Option<f64> {
+    walk(product.buy_summary(), quantity)
+}
+
+/// Returns the total coins received for instantly selling `quantity` items of `product`,
+/// walking down the buy orders, before tax.
+///
+/// Returns [`Option::None`] if the order book does not contain enough items.
+pub fn instant_sell_value(product: &BazaarProduct, quantity: u64) -> Option<f64> {
+    walk(product.sell_summary(), quantity)
+}
+
+fn walk(orders: &[BazaarOrder], quantity: u64) -> Option<f64> {
+    let mut remaining = quantity;
+    let mut total = 0.0;
+    for order in orders {
+        if remaining == 0 {
+            break;
+        }
+        let amount = remaining.min(order.amount());
+        total += amount as f64 * order.price_per_unit();
+        remaining -= amount;
+    }
+    (remaining == 0).then_some(total)
+}
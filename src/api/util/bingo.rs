@@ -0,0 +1,77 @@
+//! Utilities to calculate the progress of a player on the card of the current SkyBlock bingo event.
+//!
+//! The event of the player (see [`SkyblockBingoReply`]) is matched against the goals of the
+//! bingo resources ([`BingoResourcesReply`]) by the id of the event.
+
+use crate::api::reply::{BingoResourcesReply, SkyblockBingoReply};
+
+/// The progress of a player on the current bingo card, see [`bingo_progress`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BingoProgress {
+    event: u32,
+    points: u32,
+    completed: Vec<String>,
+    remaining: Vec<String>,
+    remaining_community: Vec<String>,
+}
+
+impl BingoProgress {
+    /// Returns the id of the bingo event.
+    pub fn event(&self) -> u32 {
+        self.event
+    }
+
+    /// Returns the amount of bingo points the player has earned in the event.
+    pub fn points(&self) -> u32 {
+        self.points
+    }
+
+    /// Returns the ids of the goals of the card the player has completed.
+    pub fn completed_goals(&self) -> &[String] {
+        &self.completed
+    }
+
+    /// Returns the ids of the personal goals of the card the player has not completed yet.
+    pub fn remaining_goals(&self) -> &[String] {
+        &self.remaining
+    }
+
+    /// Returns the ids of the community goals of the card that have not reached their last tier yet.
+    pub fn remaining_community_goals(&self) -> &[String] {
+        &self.remaining_community
+    }
+
+    /// Returns `true` if the player has completed every personal goal of the card.
+    pub fn is_complete(&self) -> bool {
+        self.remaining.is_empty()
+    }
+}
+
+/// Calculates the progress of the player of `player` on the card of `resources`.
+///
+/// A player without an entry for the current event has no points and no completed goals.
+/// Goals are returned in the order of the card.
+pub fn bingo_progress(player: &SkyblockBingoReply, resources: &BingoResourcesReply) -> BingoProgress {
+    let event = player.event(resources.id());
+    let has_completed = |id: &str| event.is_some_and(|event| event.has_completed(id));
+    let mut completed = Vec::new();
+    let mut remaining = Vec::new();
+    let mut remaining_community = Vec::new();
+    for goal in resources.goals() {
+        if has_completed(goal.id()) {
+            completed.push(goal.id().to_string());
+        } else if !goal.is_community() {
+            remaining.push(goal.id().to_string());
+        }
+        if goal.is_community() && !goal.is_community_complete() {
+            remaining_community.push(goal.id().to_string());
+        }
+    }
+    BingoProgress {
+        event: resources.id(),
+        points: event.map_or(0, |event| event.points()),
+        completed,
+        remaining,
+        remaining_community,
+    }
+}
@@ -0,0 +1,111 @@
+//! Utilities to calculate the SkyBlock collection progress of a profile member.
+//!
+//! The collected amounts of a member (see [`ProfileMember::collection`](crate::ProfileMember::collection))
+//! are matched against the tiers of the collections resources ([`CollectionsResourcesReply`]).
+//! Collections the member has never collected count as tier 0.
+
+use std::collections::HashMap;
+
+use crate::api::reply::CollectionsResourcesReply;
+
+/// The progress of a profile member in a single collection, see [`collection_progress`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CollectionProgress {
+    id: String,
+    category: String,
+    name: String,
+    collected: u64,
+    tier: u32,
+    max_tier: u32,
+    next_tier_requirement: Option<u64>,
+}
+
+impl CollectionProgress {
+    /// Returns the id of the item of this collection, e.g. `"WHEAT"`.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the id of the category of this collection, e.g. `"FARMING"`.
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// Returns the display name of this collection.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the amount of items the member has collected.
+    pub fn collected(&self) -> u64 {
+        self.collected
+    }
+
+    /// Returns the highest tier the member has unlocked, `0` if none.
+    pub fn tier(&self) -> u32 {
+        self.tier
+    }
+
+    /// Returns the highest tier of this collection.
+    pub fn max_tier(&self) -> u32 {
+        self.max_tier
+    }
+
+    /// Returns `true` if the member has unlocked every tier.
+    pub fn is_maxed(&self) -> bool {
+        self.tier >= self.max_tier
+    }
+
+    /// Returns the amount of collected items required for the next tier, if not maxed.
+    pub fn next_tier_requirement(&self) -> Option<u64> {
+        self.next_tier_requirement
+    }
+}
+
+/// Calculates the progress of every collection of the collections resources.
+///
+/// `collected` maps the ids of collection items (e.g. `"WHEAT"`) to the amount collected.
+/// The progress is returned ordered by category and id.
+pub fn collection_progress(collected: &HashMap<String, u64>, resources: &CollectionsResourcesReply) -> Vec<CollectionProgress> {
+    let mut progress = Vec::new();
+    for category_id in resources.categories() {
+        let Some(category) = resources.category(category_id) else { continue };
+        for id in category.ids() {
+            let Some(collection) = category.collection(id) else { continue };
+            let amount = collected.get(id).copied().unwrap_or(0);
+            let tier = collection.tiers().iter()
+                .filter(|tier| tier.amount_required() <= amount)
+                .map(|tier| tier.tier())
+                .max()
+                .unwrap_or(0);
+            let next_tier_requirement = collection.tiers().iter()
+                .filter(|tier| tier.amount_required() > amount)
+                .map(|tier| tier.amount_required())
+                .min();
+            progress.push(CollectionProgress {
+                id: id.to_string(),
+                category: category_id.to_string(),
+                name: collection.name().to_string(),
+                collected: amount,
+                tier,
+                max_tier: collection.max_tiers(),
+                next_tier_requirement,
+            });
+        }
+    }
+    progress.sort_by(|a, b| (&a.category, &a.id).cmp(&(&b.category, &b.id)));
+    progress
+}
+
+/// Returns the percentage of all collection tiers that are unlocked in `progress`,
+/// between `0.0` and `100.0`.
+///
+/// Returns `0.0` if `progress` has no tiers at all.
+pub fn completion(progress: &[CollectionProgress]) -> f64 {
+    let unlocked: u32 = progress.iter().map(|collection| collection.tier.min(collection.max_tier)).sum();
+    let total: u32 = progress.iter().map(|collection| collection.max_tier).sum();
+    if total == 0 {
+        return 0.0;
+    }
+    unlocked as f64 / total as f64 * 100.0
+}
@@ -0,0 +1,141 @@
+//! Utility functions to format player data for Discord messages.
+//!
+//! Discord renders a subset of markdown, so names and other text from the API are escaped
+//! before they are embedded in a message. Stats are rendered as tables in code blocks, which
+//! keep their columns aligned in the Discord client.
+//!
+//! # Examples
+//! ```rust
+//! use hypixel_api::StatsSummary;
+//! use hypixel_api::util::discord::{escape_markdown, stats_table};
+//!
+//! assert_eq!(escape_markdown("__Notch__"), "\\_\\_Notch\\_\\_");
+//!
+//! let table = stats_table(&[("Bed Wars", StatsSummary::new(30, 10, 90, 30))]);
+//! assert_eq!(table, "```\nGame     | W  | L  | WLR  | K  | D  | KDR\nBed Wars | 30 | 10 | 3.00 | 90 | 30 | 3.00\n```");
+//! ```
+
+#[cfg(feature = "reply")]
+use crate::api::{PackageRank, StaffLevel};
+#[cfg(feature = "reply")]
+use crate::api::reply::{PlayerData, StatsSummary};
+#[cfg(feature = "reply")]
+use crate::api::util::formatting::{rank_prefix, strip_codes};
+
+/// Characters that have a meaning in Discord markdown.
+const MARKDOWN_CHARS: [char; 13] = ['\\', '*', '_', '~', '`', '|', '>', '#', '-', '[', ']', '(', ')'];
+
+/// Escapes all characters of `text` that Discord would interpret as markdown.
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if MARKDOWN_CHARS.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Wraps `text` in a code block, breaking up any ``` in `text` that would end the block early.
+pub fn code_block(text: &str) -> String {
+    // a zero width space between the backticks keeps them from closing the block
+    format!("```\n{}\n```", text.replace("```", "`\u{200B}``"))
+}
+
+/// Returns the name of `player`, escaped for Discord markdown.
+///
+/// If the player has no name, [`Option::None`] is returned.
+#[cfg(feature = "reply")]
+pub fn player_name(player: &PlayerData) -> Option<String> {
+    player.name().map(escape_markdown)
+}
+
+/// Returns the rank of `player` as a bold text badge (e.g. `"**\[MVP+\]**"`).
+///
+/// Players without a rank have no badge. See [`rank_prefix`] for the order in which ranks are considered.
+#[cfg(feature = "reply")]
+pub fn rank_badge(player: &PlayerData) -> Option<String> {
+    rank_prefix(player).map(|prefix| format!("**{}**", escape_markdown(&strip_codes(&prefix))))
+}
+
+/// Returns a stable name for the rank of `player` to look up a custom emoji, e.g. `"mvp_plus"`
+/// or `"admin"`.
+///
+/// Bots typically upload an emoji per rank and render it as `<:name:id>`. Players without a
+/// rank have no emoji, ranks unknown to this crate use their lowercase API name.
+///
+/// # Examples
+/// ```rust,no_run
+/// use hypixel_api::util::discord::{player_name, rank_emoji_name};
+/// # use hypixel_api::PlayerData;
+/// # use std::collections::HashMap;
+///
+/// # fn format(player: &PlayerData, emoji_ids: &HashMap<String, u64>) -> Option<String> {
+/// let name = player_name(player)?;
+/// Some(match rank_emoji_name(player).and_then(|emoji| Some((emoji_ids.get(&emoji)?, emoji))) {
+///     Some((id, emoji)) => format!("<:{}:{}> {}", emoji, id, name),
+///     None => name,
+/// })
+/// # }
+/// ```
+#[cfg(feature = "reply")]
+pub fn rank_emoji_name(player: &PlayerData) -> Option<String> {
+    let staff = match player.staff_level() {
+        StaffLevel::Normal => None,
+        StaffLevel::Helper => Some("helper".to_string()),
+        StaffLevel::Moderator => Some("moderator".to_string()),
+        StaffLevel::Admin => Some("admin".to_string()),
+        StaffLevel::Unknown(rank) => Some(rank.to_lowercase()),
+    };
+    staff.or_else(|| match player.package_rank() {
+        PackageRank::None => None,
+        rank => Some(rank.to_string().to_lowercase()),
+    })
+}
+
+/// Returns the name of `player` prefixed with their [`rank_badge`], escaped for Discord markdown
+/// (e.g. `"**\[MVP+\]** Notch"`).
+///
+/// If the player has no name, [`Option::None`] is returned.
+#[cfg(feature = "reply")]
+pub fn display_name(player: &PlayerData) -> Option<String> {
+    let name = player_name(player)?;
+    Some(match rank_badge(player) {
+        Some(badge) => format!("{} {}", badge, name),
+        None => name,
+    })
+}
+
+/// Renders a table of stats summaries in a code block, one row per game.
+///
+/// Labels are placed in the first column as is, see [`code_block`].
+#[cfg(feature = "reply")]
+pub fn stats_table(rows: &[(&str, StatsSummary)]) -> String {
+    let header = ["Game", "W", "L", "WLR", "K", "D", "KDR"].map(str::to_string);
+    let cells: Vec<[String; 7]> = rows.iter().map(|(label, summary)| [
+        label.to_string(),
+        summary.wins().to_string(),
+        summary.losses().to_string(),
+        format!("{:.2}", summary.wlr()),
+        summary.kills().to_string(),
+        summary.deaths().to_string(),
+        format!("{:.2}", summary.kdr()),
+    ]).collect();
+
+    let mut widths = header.clone().map(|cell| cell.chars().count());
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let lines: Vec<String> = std::iter::once(&header).chain(&cells)
+        .map(|row| row.iter().zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string())
+        .collect();
+    code_block(&lines.join("\n"))
+}
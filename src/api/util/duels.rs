@@ -0,0 +1,129 @@
+//! Utilities to determine the Duels division title of a player from their wins.
+//!
+//! Every division consists of tiers (`I`, `II`, ...) which are reached after a fixed amount
+//! of additional wins. Titles for a single mode use the thresholds of [`DIVISIONS`], the
+//! overall title requires twice as many wins over all modes.
+//!
+//! # Examples
+//! ```rust
+//! use hypixel_api::util::duels::{mode_title, overall_title};
+//!
+//! assert_eq!(mode_title(49), None);
+//! assert_eq!(mode_title(720).unwrap().to_string(), "Diamond III");
+//! assert_eq!(overall_title(1440).unwrap().to_string(), "Diamond III");
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+/// A Duels division.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DuelsDivision {
+    Rookie,
+    Iron,
+    Gold,
+    Diamond,
+    Master,
+    Legend,
+    Grandmaster,
+    Godlike,
+    Celestial,
+    Divine,
+    Ascended,
+}
+
+impl DuelsDivision {
+    /// Returns the name of this division as shown in game.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DuelsDivision::Rookie => "Rookie",
+            DuelsDivision::Iron => "Iron",
+            DuelsDivision::Gold => "Gold",
+            DuelsDivision::Diamond => "Diamond",
+            DuelsDivision::Master => "Master",
+            DuelsDivision::Legend => "Legend",
+            DuelsDivision::Grandmaster => "Grandmaster",
+            DuelsDivision::Godlike => "Godlike",
+            DuelsDivision::Celestial => "Celestial",
+            DuelsDivision::Divine => "Divine",
+            DuelsDivision::Ascended => "Ascended",
+        }
+    }
+}
+
+/// The thresholds of every division for a single mode:
+/// `(division, wins for tier I, wins per tier, amount of tiers)`.
+pub const DIVISIONS: [(DuelsDivision, u32, u32, u32); 11] = [
+    (DuelsDivision::Rookie, 50, 10, 5),
+    (DuelsDivision::Iron, 100, 30, 5),
+    (DuelsDivision::Gold, 250, 50, 5),
+    (DuelsDivision::Diamond, 500, 100, 5),
+    (DuelsDivision::Master, 1000, 200, 5),
+    (DuelsDivision::Legend, 2000, 600, 5),
+    (DuelsDivision::Grandmaster, 5000, 1000, 5),
+    (DuelsDivision::Godlike, 10000, 3000, 5),
+    (DuelsDivision::Celestial, 25000, 5000, 5),
+    (DuelsDivision::Divine, 50000, 10000, 5),
+    (DuelsDivision::Ascended, 100000, 10000, 50),
+];
+
+/// A division together with its tier, displayed like `"Diamond III"`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DuelsTitle {
+    division: DuelsDivision,
+    tier: u32,
+}
+
+impl DuelsTitle {
+    pub fn division(&self) -> DuelsDivision {
+        self.division
+    }
+
+    /// Returns the tier within the division, starting from `1`.
+    pub fn tier(&self) -> u32 {
+        self.tier
+    }
+
+    /// Returns the tier within the division as roman numeral (e.g. `"III"`).
+    pub fn roman_tier(&self) -> String {
+        to_roman(self.tier)
+    }
+}
+
+impl Display for DuelsTitle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.division.name(), self.roman_tier())
+    }
+}
+
+/// Returns the title for `wins` in a single mode, [`Option::None`] below the first division.
+pub fn mode_title(wins: u32) -> Option<DuelsTitle> {
+    title(wins, 1)
+}
+
+/// Returns the overall title for `wins` over all modes, [`Option::None`] below the first division.
+pub fn overall_title(wins: u32) -> Option<DuelsTitle> {
+    title(wins, 2)
+}
+
+fn title(wins: u32, multiplier: u32) -> Option<DuelsTitle> {
+    let (division, start, step, tiers) = DIVISIONS.iter()
+        .rev()
+        .find(|(_, start, _, _)| wins >= start * multiplier)?;
+    let tier = ((wins - start * multiplier) / (step * multiplier) + 1).min(*tiers);
+    Some(DuelsTitle { division: *division, tier })
+}
+
+fn to_roman(mut number: u32) -> String {
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"),
+        (50, "L"), (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+    ];
+    let mut roman = String::new();
+    for (value, numeral) in NUMERALS {
+        while number >= value {
+            roman.push_str(numeral);
+            number -= value;
+        }
+    }
+    roman
+}
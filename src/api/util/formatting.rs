@@ -0,0 +1,235 @@
+//! Utility functions to work with strings containing Minecraft formatting codes, such as
+//! guild tags, rank prefixes or item names.
+//!
+//! A formatting code consists of [`FORMATTING_CHAR`] followed by a single character, see
+//! [this wiki](https://minecraft.fandom.com/wiki/Formatting_codes) for all codes.
+
+use std::fmt::Write;
+use crate::api::ColorCodes;
+#[cfg(feature = "reply")]
+use crate::api::{PackageRank, StaffLevel};
+#[cfg(feature = "reply")]
+use crate::api::reply::PlayerData;
+
+/// The character that starts every formatting code.
+pub const FORMATTING_CHAR: char = '§';
+
+/// (code, ansi style, html style) for every style code
+const STYLES: [(char, u8, &str); 4] = [
+    ('l', 1, "font-weight:bold"),
+    ('m', 9, "text-decoration:line-through"),
+    ('n', 4, "text-decoration:underline"),
+    ('o', 3, "font-style:italic"),
+];
+
+const RESET: char = 'r';
+
+/// Removes all formatting codes from `text`.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::util::formatting::strip_codes;
+///
+/// assert_eq!(strip_codes("§b[MVP§c+§b] Notch"), "[MVP+] Notch");
+/// ```
+pub fn strip_codes(text: &str) -> String {
+    tokens(text)
+        .filter_map(|token| match token {
+            Token::Char(c) => Some(c),
+            Token::Code(_) => None,
+        })
+        .collect()
+}
+
+/// Converts all formatting codes in `text` into ANSI escape sequences for terminals.
+///
+/// The obfuscated code (`§k`) is ignored. If any escape sequence was emitted,
+/// the result ends with a reset sequence.
+pub fn to_ansi(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut formatted = false;
+    for token in tokens(text) {
+        match token {
+            Token::Char(c) => result.push(c),
+            Token::Code(code) => {
+                let sgr = if let Some(color) = ColorCodes::from_code_char(code) {
+                    // like in game, a color resets all styles
+                    format!("0;{}", color.ansi())
+                } else if let Some((_, ansi, _)) = STYLES.iter().find(|(c, _, _)| *c == code) {
+                    ansi.to_string()
+                } else if code == RESET {
+                    "0".to_string()
+                } else {
+                    continue;
+                };
+                let _ = write!(result, "\x1b[{}m", sgr);
+                formatted = true;
+            }
+        }
+    }
+    if formatted {
+        result.push_str("\x1b[0m");
+    }
+    result
+}
+
+/// Converts all formatting codes in `text` into html `<span>` elements with inline styles.
+///
+/// All other text is html escaped. The obfuscated code (`§k`) is ignored.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::util::formatting::to_html;
+///
+/// assert_eq!(
+///     to_html("§c<3 §lHypixel"),
+///     r#"<span style="color:#FF5555">&lt;3 <span style="font-weight:bold">Hypixel</span></span>"#
+/// );
+/// ```
+pub fn to_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut open_spans = 0;
+    for token in tokens(text) {
+        match token {
+            Token::Char('<') => result.push_str("&lt;"),
+            Token::Char('>') => result.push_str("&gt;"),
+            Token::Char('&') => result.push_str("&amp;"),
+            Token::Char('"') => result.push_str("&quot;"),
+            Token::Char('\'') => result.push_str("&#39;"),
+            Token::Char(c) => result.push(c),
+            Token::Code(code) => {
+                if let Some(color) = ColorCodes::from_code_char(code) {
+                    close_spans(&mut result, &mut open_spans);
+                    let _ = write!(result, r#"<span style="color:#{:06X}">"#, color.hex());
+                    open_spans += 1;
+                } else if let Some((_, _, style)) = STYLES.iter().find(|(c, _, _)| *c == code) {
+                    let _ = write!(result, r#"<span style="{}">"#, style);
+                    open_spans += 1;
+                } else if code == RESET {
+                    close_spans(&mut result, &mut open_spans);
+                }
+            }
+        }
+    }
+    close_spans(&mut result, &mut open_spans);
+    result
+}
+
+/// Returns the rank prefix of `player` with formatting codes, like it is shown in game (e.g. `"§b[MVP§c+§b]"`).
+///
+/// A custom prefix set by Hypixel takes precedence, followed by the player's [`StaffLevel`]
+/// and finally their [`PackageRank`]. Players without a rank have no prefix.
+#[cfg(feature = "reply")]
+pub fn rank_prefix(player: &PlayerData) -> Option<String> {
+    rank_format(player).0
+}
+
+/// Returns the name of `player` with their rank prefix and formatting codes,
+/// like it is shown in game (e.g. `"§b[MVP§c+§b] Notch"`).
+///
+/// If the player has no name, [`Option::None`] is returned.
+///
+/// # Examples
+/// ```rust,no_run
+/// use hypixel_api::util::formatting::{display_name, to_ansi};
+/// # use hypixel_api::PlayerData;
+///
+/// # fn print_name(player: &PlayerData) {
+/// if let Some(name) = display_name(player) {
+///     println!("{}", to_ansi(&name));
+/// }
+/// # }
+/// ```
+#[cfg(feature = "reply")]
+pub fn display_name(player: &PlayerData) -> Option<String> {
+    let name = player.name()?;
+    Some(match rank_format(player) {
+        (Some(prefix), color) => format!("{} {}{}{}", prefix, FORMATTING_CHAR, color.code_char(), name),
+        (None, color) => format!("{}{}{}", FORMATTING_CHAR, color.code_char(), name),
+    })
+}
+
+/// Returns the name of `player` with their rank prefix, without formatting codes (e.g. `"[MVP+] Notch"`).
+///
+/// See [`display_name`].
+#[cfg(feature = "reply")]
+pub fn plain_display_name(player: &PlayerData) -> Option<String> {
+    display_name(player).map(|name| strip_codes(&name))
+}
+
+/// Returns the name of `player` with their rank prefix, colored with ANSI escape sequences for terminals.
+///
+/// See [`display_name`].
+#[cfg(feature = "reply")]
+pub fn ansi_display_name(player: &PlayerData) -> Option<String> {
+    display_name(player).map(|name| to_ansi(&name))
+}
+
+/// Returns the formatted prefix and name color of `player`.
+#[cfg(feature = "reply")]
+fn rank_format(player: &PlayerData) -> (Option<String>, ColorCodes) {
+    if let Some(Ok(prefix)) = player.property_json::<String>("prefix") {
+        let color = last_color(&prefix).unwrap_or(ColorCodes::Gray);
+        return (Some(prefix), color);
+    }
+    let staff = match player.staff_level() {
+        StaffLevel::Admin => Some(("§c[ADMIN]", ColorCodes::Red)),
+        StaffLevel::Moderator => Some(("§2[MOD]", ColorCodes::DarkGreen)),
+        StaffLevel::Helper => Some(("§9[HELPER]", ColorCodes::Blue)),
+        StaffLevel::Unknown(rank) if rank == "GAME_MASTER" => Some(("§2[GM]", ColorCodes::DarkGreen)),
+        StaffLevel::Unknown(rank) if rank == "YOUTUBER" => Some(("§c[§fYOUTUBE§c]", ColorCodes::Red)),
+        _ => None,
+    };
+    if let Some((prefix, color)) = staff {
+        return (Some(prefix.to_string()), color);
+    }
+    let plus = player.selected_plus_color().code_char();
+    match player.package_rank() {
+        PackageRank::Vip => (Some("§a[VIP]".to_string()), ColorCodes::Green),
+        PackageRank::VipPlus => (Some("§a[VIP§6+§a]".to_string()), ColorCodes::Green),
+        PackageRank::Mvp => (Some("§b[MVP]".to_string()), ColorCodes::Aqua),
+        PackageRank::MvpPlus => (Some(format!("§b[MVP§{}+§b]", plus)), ColorCodes::Aqua),
+        PackageRank::MvpPlusPlus => {
+            let tag = player.superstar_tag_color();
+            (Some(format!("§{0}[MVP§{1}++§{0}]", tag.code_char(), plus)), tag)
+        }
+        // ranks unknown to this crate are shown like players without a rank
+        PackageRank::None | PackageRank::Unknown(_) => (None, ColorCodes::Gray),
+    }
+}
+
+#[cfg(feature = "reply")]
+fn last_color(text: &str) -> Option<ColorCodes> {
+    tokens(text)
+        .filter_map(|token| match token {
+            Token::Code(code) => ColorCodes::from_code_char(code),
+            Token::Char(_) => None,
+        })
+        .last()
+}
+
+fn close_spans(result: &mut String, open_spans: &mut usize) {
+    for _ in 0..*open_spans {
+        result.push_str("</span>");
+    }
+    *open_spans = 0;
+}
+
+enum Token {
+    Char(char),
+    Code(char),
+}
+
+/// Splits `text` into characters and (lowercase) formatting codes.
+fn tokens(text: &str) -> impl Iterator<Item = Token> + '_ {
+    let mut chars = text.chars();
+    std::iter::from_fn(move || {
+        let c = chars.next()?;
+        if c == FORMATTING_CHAR {
+            // a trailing formatting char has no code and is dropped
+            chars.next().map(|code| Token::Code(code.to_ascii_lowercase()))
+        } else {
+            Some(Token::Char(c))
+        }
+    })
+}
@@ -0,0 +1,93 @@
+//! Static information about every [`GameType`], as listed by the
+//! [`/resources/games`](https://api.hypixel.net/#tag/Resources/paths/~1resources~1games/get) endpoint.
+//!
+//! Games are referenced in three ways throughout the API:
+//! - the type name (e.g. `"SURVIVAL_GAMES"`), used by [`GameType`] in `status`, `counts` and `boosters`
+//! - the database name (e.g. `"HungerGames"`), used as key in player stats
+//! - the numeric id (e.g. `5`), used in `boosters` and `recentgames`
+//!
+//! This module maps between them and to a clean name to display (e.g. `"Blitz Survival Games"`)
+//! without requesting the resources.
+//!
+//! # Examples
+//! ```rust
+//! use hypixel_api::GameType;
+//! use hypixel_api::util::games;
+//!
+//! assert_eq!(games::clean_name(&GameType::SurvivalGames), "Blitz Survival Games");
+//! assert_eq!(games::by_database_name("HungerGames").unwrap().game_type(), GameType::SurvivalGames);
+//! assert_eq!(games::by_id(58).unwrap().clean_name(), "Bed Wars");
+//! ```
+
+use crate::api::GameType;
+
+/// The names and id of a single game type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GameInfo {
+    id: Option<u32>,
+    type_name: &'static str,
+    database_name: &'static str,
+    clean_name: &'static str,
+}
+
+impl GameInfo {
+    const fn new(id: Option<u32>, type_name: &'static str, database_name: &'static str, clean_name: &'static str) -> Self {
+        GameInfo { id, type_name, database_name, clean_name }
+    }
+
+    /// Returns the numeric id of this game, lobbies have no id.
+    pub fn id(&self) -> Option<u32> {
+        self.id
+    }
+
+    /// Returns the type name of this game (e.g. `"SURVIVAL_GAMES"`).
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the database name of this game (e.g. `"HungerGames"`).
+    pub fn database_name(&self) -> &'static str {
+        self.database_name
+    }
+
+    /// Returns the name of this game to display (e.g. `"Blitz Survival Games"`).
+    pub fn clean_name(&self) -> &'static str {
+        self.clean_name
+    }
+
+    /// Returns the [`GameType`] of this game.
+    pub fn game_type(&self) -> GameType {
+        GameType::from(self.type_name.to_string())
+    }
+}
+
+// generated by build.rs from the `/resources/games` snapshot in fixtures/resources_games.json
+include!(concat!(env!("OUT_DIR"), "/games.rs"));
+
+/// Returns the game with numeric id `id`.
+pub fn by_id(id: u32) -> Option<&'static GameInfo> {
+    GAMES.iter().find(|game| game.id == Some(id))
+}
+
+/// Returns the game with type name `name` (e.g. `"SURVIVAL_GAMES"`).
+pub fn by_type_name(name: &str) -> Option<&'static GameInfo> {
+    GAMES.iter().find(|game| game.type_name == name)
+}
+
+/// Returns the game with database name `name` (e.g. `"HungerGames"`), ignoring case.
+pub fn by_database_name(name: &str) -> Option<&'static GameInfo> {
+    GAMES.iter().find(|game| game.database_name.eq_ignore_ascii_case(name))
+}
+
+/// Returns the information of `game_type`, [`Option::None`] for unknown game types.
+pub fn info(game_type: &GameType) -> Option<&'static GameInfo> {
+    by_type_name(&game_type.to_string())
+}
+
+/// Returns the name of `game_type` to display, falling back to its type name for unknown game types.
+pub fn clean_name(game_type: &GameType) -> String {
+    match info(game_type) {
+        Some(game) => game.clean_name.to_string(),
+        None => game_type.to_string(),
+    }
+}
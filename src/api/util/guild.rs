@@ -0,0 +1,75 @@
+//! Utilities to aggregate the guild experience (GEXP) of a [`GuildData`].
+//!
+//! The API reports the raw experience every member earned per day over the past 7 days.
+//! The experience that counts towards the guild level is scaled down once the guild as
+//! a whole earns more than [`FIRST_SCALING_THRESHOLD`] experience in a day, see [`scaled_exp`].
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::api::reply::GuildData;
+
+/// Daily guild experience above this amount only counts for [`FIRST_SCALING_FACTOR`].
+pub const FIRST_SCALING_THRESHOLD: u64 = 200_000;
+/// Daily guild experience above this amount only counts for [`SECOND_SCALING_FACTOR`].
+pub const SECOND_SCALING_THRESHOLD: u64 = 700_000;
+/// The fraction of experience that counts between the two thresholds.
+pub const FIRST_SCALING_FACTOR: f64 = 0.1;
+/// The fraction of experience that counts above the second threshold.
+pub const SECOND_SCALING_FACTOR: f64 = 0.03;
+
+/// Applies Hypixel's daily scaling to the raw experience a guild earned in a single day.
+///
+/// # Examples
+/// ```ignore
+///       150000 XP ->  150000 XP
+///       300000 XP ->  210000 XP
+///      1000000 XP ->  259000 XP
+/// ```
+pub fn scaled_exp(raw: u64) -> u64 {
+    let full = raw.min(FIRST_SCALING_THRESHOLD) as f64;
+    let first = raw.clamp(FIRST_SCALING_THRESHOLD, SECOND_SCALING_THRESHOLD) - FIRST_SCALING_THRESHOLD;
+    let second = raw.saturating_sub(SECOND_SCALING_THRESHOLD);
+    (full + first as f64 * FIRST_SCALING_FACTOR + second as f64 * SECOND_SCALING_FACTOR).round() as u64
+}
+
+/// Returns the raw experience the whole guild earned per day.
+pub fn daily_exp(guild: &GuildData) -> BTreeMap<NaiveDate, u64> {
+    let mut days = BTreeMap::new();
+    for member in guild.members() {
+        for (day, exp) in member.exp_history() {
+            *days.entry(*day).or_insert(0) += exp;
+        }
+    }
+    days
+}
+
+/// Returns the scaled experience the whole guild earned per day, see [`scaled_exp`].
+pub fn scaled_daily_exp(guild: &GuildData) -> BTreeMap<NaiveDate, u64> {
+    daily_exp(guild).into_iter().map(|(day, exp)| (day, scaled_exp(exp))).collect()
+}
+
+/// Returns the raw experience the whole guild earned in the past 7 days.
+pub fn weekly_exp(guild: &GuildData) -> u64 {
+    guild.members().iter().map(|m| m.weekly_exp()).sum()
+}
+
+/// Returns the scaled experience the whole guild earned in the past 7 days.
+pub fn scaled_weekly_exp(guild: &GuildData) -> u64 {
+    scaled_daily_exp(guild).values().sum()
+}
+
+/// Returns the experience every member earned on `day`.
+pub fn member_daily_exp(guild: &GuildData, day: NaiveDate) -> impl Iterator<Item = (Uuid, u64)> + '_ {
+    guild.members().iter().map(move |m| (m.uuid(), m.exp_history().get(&day).copied().unwrap_or(0)))
+}
+
+/// Returns every member with the experience they earned in the past 7 days,
+/// sorted from most to least experience.
+pub fn weekly_leaderboard(guild: &GuildData) -> Vec<(Uuid, u64)> {
+    let mut members: Vec<_> = guild.weekly_exp_per_member().collect();
+    members.sort_by_key(|(_, exp)| std::cmp::Reverse(*exp));
+    members
+}
@@ -0,0 +1,118 @@
+//! Permissive deserializers for fields whose encoding differs between players.
+//!
+//! Older player data sometimes encodes numbers as strings (`"12"`), booleans as numbers (`0`/`1`)
+//! or numbers as floats (`12.0`). The reply structs of this crate use these functions for such
+//! fields, use them in custom data structures with `deserialize_with`.
+//!
+//! # Examples
+//! ```rust
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct PitStats {
+//!     #[serde(default, deserialize_with = "hypixel_api::util::lenient::deserialize_number")]
+//!     kills: u64,
+//!     #[serde(default, deserialize_with = "hypixel_api::util::lenient::deserialize_bool")]
+//!     prestige_unlocked: bool,
+//! }
+//!
+//! let stats: PitStats = serde_json::from_str(r#"{"kills":"42","prestige_unlocked":1}"#).unwrap();
+//! assert_eq!(stats.kills, 42);
+//! assert!(stats.prestige_unlocked);
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+use serde::{Deserialize, Deserializer};
+use serde::de::{DeserializeOwned, Error};
+use serde_json::Value;
+
+/// Deserializes a number that may be encoded as a string or, for integers, as a float without
+/// fractional part.
+///
+/// Use with `#[serde(deserialize_with = "hypixel_api::util::lenient::deserialize_number")]`.
+pub fn deserialize_number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + FromStr,
+    T::Err: Display,
+{
+    number(Value::deserialize(deserializer)?)
+}
+
+/// Deserializes an optional number like [`deserialize_number`], `null` is deserialized as [`Option::None`].
+///
+/// Use with `#[serde(default, deserialize_with = "hypixel_api::util::lenient::deserialize_number_option")]`.
+pub fn deserialize_number_option<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + FromStr,
+    T::Err: Display,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(None),
+        value => number(value).map(Some),
+    }
+}
+
+/// Deserializes a boolean that may be encoded as a number (non-zero is `true`) or as a string.
+///
+/// Use with `#[serde(deserialize_with = "hypixel_api::util::lenient::deserialize_bool")]`.
+pub fn deserialize_bool<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::Bool(value) => Ok(value),
+        Value::Number(number) => Ok(number.as_f64().is_some_and(|number| number != 0.0)),
+        Value::String(text) => match text.trim() {
+            "true" | "1" => Ok(true),
+            "false" | "0" | "" => Ok(false),
+            _ => Err(D::Error::custom(format!("invalid boolean: {}", text))),
+        },
+        value => Err(D::Error::custom(format!("invalid boolean: {}", value))),
+    }
+}
+
+/// Deserializes a string that may be encoded as a number or a boolean.
+///
+/// Use with `#[serde(deserialize_with = "hypixel_api::util::lenient::deserialize_string")]`.
+pub fn deserialize_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::String(text) => Ok(text),
+        Value::Number(number) => Ok(number.to_string()),
+        Value::Bool(value) => Ok(value.to_string()),
+        value => Err(D::Error::custom(format!("invalid string: {}", value))),
+    }
+}
+
+/// Deserializes an optional value that is [`Option::None`] when it is `null` or does not
+/// deserialize into `T`, e.g. a value of an enum that was introduced after this crate was published.
+///
+/// Use with `#[serde(default, deserialize_with = "hypixel_api::util::lenient::deserialize_known")]`.
+pub fn deserialize_known<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    Ok(T::deserialize(Value::deserialize(deserializer)?).ok())
+}
+
+fn number<T, E>(value: Value) -> Result<T, E>
+where
+    T: DeserializeOwned + FromStr,
+    T::Err: Display,
+    E: Error,
+{
+    match value {
+        Value::String(text) => text.trim().parse().map_err(|e| E::custom(format!("invalid number {:?}: {}", text, e))),
+        Value::Number(number) => {
+            let integral = number.as_f64()
+                .filter(|float| number.is_f64() && float.fract() == 0.0)
+                .map(|float| if float < 0.0 { Value::from(float as i64) } else { Value::from(float as u64) });
+            match integral {
+                // floats like `12.0` are accepted for integers as well
+                Some(integral) => T::deserialize(integral).or_else(|_| T::deserialize(Value::Number(number))),
+                None => T::deserialize(Value::Number(number)),
+            }.map_err(E::custom)
+        }
+        value => T::deserialize(value).map_err(E::custom),
+    }
+}
@@ -0,0 +1,158 @@
+//! This module provides utility functions to work with Bed Wars stars and Bed Wars experience.
+//!
+//! Bed Wars levels (stars) are grouped into prestiges of [`LEVELS_PER_PRESTIGE`] levels each.
+//! Every prestige requires the same amount of experience: the first few levels of a prestige
+//! are "easy" levels that need less experience, every other level needs [`XP_PER_LEVEL`].
+//!
+//! | Level in prestige | Experience to next level |
+//! |-------------------|--------------------------|
+//! | 0                 | 500                      |
+//! | 1                 | 1000                     |
+//! | 2                 | 2000                     |
+//! | 3                 | 3500                     |
+//! | 4 - 99            | 5000                     |
+//!
+//! A player without experience is level `0`.
+
+use crate::api::util::leveling::Leveling;
+
+/// The amount of levels in a single prestige.
+pub const LEVELS_PER_PRESTIGE: f64 = 100.0;
+/// The amount of experience that is needed for a single prestige.
+pub const XP_PER_PRESTIGE: f64 = 487000.0;
+/// The amount of experience that is needed for a level that isn't an easy level.
+pub const XP_PER_LEVEL: f64 = 5000.0;
+
+const EASY_LEVELS_XP: [f64; 4] = [500.0, 1000.0, 2000.0, 3500.0];
+
+/// This function returns the level of a player calculated by the
+/// current experience gathered. Unlike [`exact_level`], this function returns
+/// the largest integer smaller than the exact level (= floored).
+///
+/// The result cannot be smaller than `0.0` and negative experience results in `0.0`.
+///
+/// # Examples
+/// ```ignore
+///            0 XP -> 0.0
+///          499 XP -> 0.0
+///         7000 XP -> 4.0
+///       487000 XP -> 100.0
+///      1000000 XP -> 207.0
+/// ```
+pub fn calculate_level(exp: f64) -> f64 {
+    exact_level(exp).floor()
+}
+
+/// This function returns the exact level of a player calculated by the
+/// current experience gathered. Unlike [`calculate_level`], this function does
+/// not floor its result and will return an accurate level.
+///
+/// The result cannot be smaller than `0.0` and negative experience results in `0.0`.
+///
+/// # Examples
+/// ```ignore
+///            0 XP -> 0.0
+///          250 XP -> 0.5
+///         9500 XP -> 4.5
+///       487000 XP -> 100.0
+///      1000000 XP -> 207.8
+/// ```
+pub fn exact_level(exp: f64) -> f64 {
+    if exp <= 0.0 {
+        return 0.0;
+    }
+    let prestiges = (exp / XP_PER_PRESTIGE).floor();
+    let mut level = prestiges * LEVELS_PER_PRESTIGE;
+    let mut exp = exp - prestiges * XP_PER_PRESTIGE;
+    for xp in EASY_LEVELS_XP {
+        if exp < xp {
+            return level + exp / xp;
+        }
+        level += 1.0;
+        exp -= xp;
+    }
+    level + exp / XP_PER_LEVEL
+}
+
+/// This function returns the prestige of a player at `level`, starting from `0`.
+///
+/// # Examples
+/// ```ignore
+///        0.0 -> 0
+///       99.9 -> 0
+///      100.0 -> 1
+///      512.3 -> 5
+/// ```
+pub fn prestige(level: f64) -> u32 {
+    (level.max(0.0) / LEVELS_PER_PRESTIGE).floor() as u32
+}
+
+/// This function returns the amount of experience that is needed to progress from `level` to `level + 1`. (e.g. 5 to 6)
+/// Smaller values than `0` always return the experience of the first easy level.
+///
+/// # Examples
+/// ```ignore
+///       0 (to 1)   =  500.0 XP
+///       3 (to 4)   = 3500.0 XP
+///       4 (to 5)   = 5000.0 XP
+///     101 (to 102) = 1000.0 XP
+/// ```
+pub fn xp_to_next_level(level: f64) -> f64 {
+    let level_in_prestige = (level.max(0.0).floor() % LEVELS_PER_PRESTIGE) as usize;
+    EASY_LEVELS_XP.get(level_in_prestige).copied().unwrap_or(XP_PER_LEVEL)
+}
+
+/// This method returns the experience required to reach that level. This method is precise, that means
+/// you can pass any progress of a level to receive the experience to reach that progress. (e.g. 4.5 returns
+/// the experience required to reach level 4 and 50% of the experience required to go from level 4 to level 5).
+///
+/// # Examples
+/// ```ignore
+///        0.0 =       0.0 XP
+///        1.0 =     500.0 XP
+///        4.5 =    9500.0 XP
+///      100.0 =  487000.0 XP
+///      207.8 = 1000000.0 XP
+/// ```
+pub fn total_xp_to_level(level: f64) -> f64 {
+    if level <= 0.0 {
+        return 0.0;
+    }
+    let lvl = level.floor();
+    let prestiges = (lvl / LEVELS_PER_PRESTIGE).floor();
+    let level_in_prestige = lvl - prestiges * LEVELS_PER_PRESTIGE;
+    let easy_levels = (level_in_prestige as usize).min(EASY_LEVELS_XP.len());
+    let easy_xp: f64 = EASY_LEVELS_XP[..easy_levels].iter().sum();
+    let other_xp = (level_in_prestige - easy_levels as f64) * XP_PER_LEVEL;
+    prestiges * XP_PER_PRESTIGE + easy_xp + other_xp + xp_to_next_level(lvl) * (level % 1.0)
+}
+
+/// This method returns the current progress of this level to reach the next level.
+///
+/// # Examples
+/// ```ignore
+///          250.0 XP   (Lv. 0) = 0.5   (50 %)
+///         9500.0 XP   (Lv. 4) = 0.5   (50 %)
+///      1000000.0 XP (Lv. 207) = 0.8   (80 %)
+/// ```
+pub fn percentage_to_next_level(exp: f64) -> f64 {
+    exact_level(exp) % 1.0
+}
+
+/// BedWars leveling, see the [module documentation](self).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BedwarsLeveling;
+
+impl Leveling for BedwarsLeveling {
+    fn level_for_xp(&self, exp: f64) -> f64 {
+        exact_level(exp)
+    }
+
+    fn xp_for_level(&self, level: f64) -> f64 {
+        total_xp_to_level(level)
+    }
+
+    fn progress(&self, exp: f64) -> f64 {
+        percentage_to_next_level(exp)
+    }
+}
@@ -0,0 +1,120 @@
+//! This module provides utility functions to work with SkyBlock dungeon levels and dungeon experience.
+//!
+//! The Catacombs and every dungeon class (Healer, Mage, Berserk, Archer and Tank) share
+//! the same leveling: the first [`TABLE_LEVELS`] levels follow [`DUNGEON_XP_TABLE`], in the
+//! format of [`skyblock`](super::skyblock). Every level after that needs [`XP_PER_LEVEL_AFTER_TABLE`]
+//! more experience.
+//!
+//! A player without experience is level `0`.
+
+use crate::api::util::leveling::{skyblock, Leveling};
+
+/// The amount of levels that follow the experience table.
+pub const TABLE_LEVELS: f64 = 50.0;
+/// The amount of experience that is needed for every level after [`TABLE_LEVELS`].
+pub const XP_PER_LEVEL_AFTER_TABLE: f64 = 200000000.0;
+
+/// The total experience required to reach every dungeon level, up to level 50.
+pub const DUNGEON_XP_TABLE: [f64; 50] = [
+    50.0, 125.0, 235.0, 395.0, 625.0, 955.0, 1425.0, 2095.0, 3045.0, 4385.0,
+    6275.0, 8940.0, 12700.0, 17960.0, 25340.0, 35640.0, 50040.0, 70040.0, 97640.0, 135640.0,
+    188140.0, 259640.0, 356640.0, 488640.0, 668640.0, 911640.0, 1239640.0, 1684640.0, 2284640.0, 3084640.0,
+    4149640.0, 5559640.0, 7459640.0, 9959640.0, 13259640.0, 17559640.0, 23159640.0, 30359640.0, 39559640.0, 51559640.0,
+    66559640.0, 85559640.0, 109559640.0, 139559640.0, 177559640.0, 225559640.0, 285559640.0, 360559640.0, 453559640.0, 569809640.0,
+];
+
+const TABLE_XP: f64 = DUNGEON_XP_TABLE[DUNGEON_XP_TABLE.len() - 1];
+
+/// This function returns the level of a player calculated by the
+/// current experience gathered. Unlike [`exact_level`], this function returns
+/// the largest integer smaller than the exact level (= floored).
+///
+/// The result cannot be smaller than `0.0` and negative experience results in `0.0`.
+///
+/// # Examples
+/// ```ignore
+///             0 XP -> 0.0
+///           125 XP -> 2.0
+///     569809640 XP -> 50.0
+///     969809640 XP -> 52.0
+/// ```
+pub fn calculate_level(exp: f64) -> f64 {
+    exact_level(exp).floor()
+}
+
+/// This function returns the exact level of a player calculated by the
+/// current experience gathered. Unlike [`calculate_level`], this function does
+/// not floor its result and will return an accurate level.
+///
+/// The result cannot be smaller than `0.0` and negative experience results in `0.0`.
+///
+/// # Examples
+/// ```ignore
+///             0 XP -> 0.0
+///            25 XP -> 0.5
+///     669809640 XP -> 50.5
+/// ```
+pub fn exact_level(exp: f64) -> f64 {
+    if exp < TABLE_XP {
+        skyblock::exact_level(exp, &DUNGEON_XP_TABLE)
+    } else {
+        TABLE_LEVELS + (exp - TABLE_XP) / XP_PER_LEVEL_AFTER_TABLE
+    }
+}
+
+/// This function returns the amount of experience that is needed to progress from `level` to `level + 1`. (e.g. 5 to 6)
+///
+/// # Examples
+/// ```ignore
+///       0 (to 1)   =        50.0 XP
+///      49 (to 50)  = 116250000.0 XP
+///      50 (to 51)  = 200000000.0 XP
+/// ```
+pub fn xp_to_next_level(level: f64) -> f64 {
+    if level >= TABLE_LEVELS {
+        XP_PER_LEVEL_AFTER_TABLE
+    } else {
+        skyblock::xp_to_next_level(level, &DUNGEON_XP_TABLE)
+    }
+}
+
+/// This method returns the experience required to reach that level. This method is precise, that means
+/// you can pass any progress of a level to receive the experience to reach that progress. (e.g. 50.5 returns
+/// the experience required to reach level 50 and 50% of the experience required to go from level 50 to level 51).
+///
+/// # Examples
+/// ```ignore
+///        1.0 =        50.0 XP
+///       50.0 = 569809640.0 XP
+///       50.5 = 669809640.0 XP
+/// ```
+pub fn total_xp_to_level(level: f64) -> f64 {
+    if level >= TABLE_LEVELS {
+        TABLE_XP + (level - TABLE_LEVELS) * XP_PER_LEVEL_AFTER_TABLE
+    } else {
+        skyblock::total_xp_to_level(level, &DUNGEON_XP_TABLE)
+    }
+}
+
+/// This method returns the current progress of this level to reach the next level.
+pub fn percentage_to_next_level(exp: f64) -> f64 {
+    exact_level(exp) % 1.0
+}
+
+/// Catacombs and dungeon class leveling, see the [module documentation](self).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DungeonsLeveling;
+
+impl Leveling for DungeonsLeveling {
+    fn level_for_xp(&self, exp: f64) -> f64 {
+        exact_level(exp)
+    }
+
+    fn xp_for_level(&self, level: f64) -> f64 {
+        total_xp_to_level(level)
+    }
+
+    fn progress(&self, exp: f64) -> f64 {
+        percentage_to_next_level(exp)
+    }
+}
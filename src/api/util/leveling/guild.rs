@@ -0,0 +1,134 @@
+//! This module provides utility functions to work with guild levels and guild experience.
+//!
+//! This module follows the java implementation of the Hypixel API.\
+//! See [here](https://github.com/HypixelDev/PublicAPI/blob/master/hypixel-api-core/src/main/java/net/hypixel/api/util/GuildLeveling.java).
+//!
+//! The experience needed for the first levels increases following a fixed table,
+//! every level after that needs [`MAX_XP_TO_NEXT_LEVEL`] experience.
+//! A guild without experience is level `0`.
+
+use crate::api::util::leveling::Leveling;
+
+/// The amount of experience that is needed for every level after the increasing thresholds.
+pub const MAX_XP_TO_NEXT_LEVEL: f64 = 3000000.0;
+
+const XP_TO_NEXT_LEVEL: [f64; 15] = [
+    100000.0, 150000.0, 250000.0, 500000.0, 750000.0,
+    1000000.0, 1250000.0, 1500000.0, 2000000.0, 2500000.0,
+    2500000.0, 2500000.0, 2500000.0, 2500000.0, 3000000.0,
+];
+
+/// This function returns the level of a guild calculated by the
+/// current experience gathered. Unlike [`exact_level`], this function returns
+/// the largest integer smaller than the exact level (= floored).
+///
+/// The result cannot be smaller than `0.0` and negative experience results in `0.0`.
+///
+/// # Examples
+/// ```ignore
+///             0 XP -> 0.0
+///         99999 XP -> 0.0
+///        250000 XP -> 2.0
+///      50000000 XP -> 24.0
+/// ```
+pub fn calculate_level(exp: f64) -> f64 {
+    exact_level(exp).floor()
+}
+
+/// This function returns the exact level of a guild calculated by the
+/// current experience gathered. Unlike [`calculate_level`], this function does
+/// not floor its result and will return an accurate level.
+///
+/// The result cannot be smaller than `0.0`, negative experience and `NaN` result in `0.0`
+/// and infinite experience results in an infinite level.
+///
+/// # Examples
+/// ```ignore
+///             0 XP -> 0.0
+///         50000 XP -> 0.5
+///        375000 XP -> 2.5
+///      51500000 XP -> 24.5
+/// ```
+pub fn exact_level(exp: f64) -> f64 {
+    if exp.is_nan() || exp <= 0.0 {
+        return 0.0;
+    }
+    if exp.is_infinite() {
+        return f64::INFINITY;
+    }
+    let mut exp = exp;
+    for (level, needed) in XP_TO_NEXT_LEVEL.iter().enumerate() {
+        if exp < *needed {
+            return level as f64 + exp / needed;
+        }
+        exp -= needed;
+    }
+    XP_TO_NEXT_LEVEL.len() as f64 + exp / MAX_XP_TO_NEXT_LEVEL
+}
+
+/// This function returns the amount of experience that is needed to progress from `level` to `level + 1`. (e.g. 5 to 6)
+/// Smaller values than `0` always return the experience needed to reach level 1.
+///
+/// # Examples
+/// ```ignore
+///       0 (to 1)   =  100000.0 XP
+///       3 (to 4)   =  500000.0 XP
+///      10 (to 11)  = 2500000.0 XP
+///      20 (to 21)  = 3000000.0 XP
+/// ```
+pub fn xp_to_next_level(level: f64) -> f64 {
+    XP_TO_NEXT_LEVEL.get(level.max(0.0).floor() as usize).copied().unwrap_or(MAX_XP_TO_NEXT_LEVEL)
+}
+
+/// This method returns the experience required to reach that level. This method is precise, that means
+/// you can pass any progress of a level to receive the experience to reach that progress. (e.g. 2.5 returns
+/// the experience required to reach level 2 and 50% of the experience required to go from level 2 to level 3).
+///
+/// # Examples
+/// ```ignore
+///        0.0 =        0.0 XP
+///        2.0 =   250000.0 XP
+///        2.5 =   375000.0 XP
+///       15.0 = 23000000.0 XP
+///       24.5 = 51500000.0 XP
+/// ```
+pub fn total_xp_to_level(level: f64) -> f64 {
+    if level <= 0.0 {
+        return 0.0;
+    }
+    let lvl = level.floor();
+    let table_levels = (lvl as usize).min(XP_TO_NEXT_LEVEL.len());
+    let table_xp: f64 = XP_TO_NEXT_LEVEL[..table_levels].iter().sum();
+    let other_xp = (lvl - table_levels as f64) * MAX_XP_TO_NEXT_LEVEL;
+    table_xp + other_xp + xp_to_next_level(lvl) * (level % 1.0)
+}
+
+/// This method returns the current progress of this level to reach the next level.
+///
+/// # Examples
+/// ```ignore
+///         50000.0 XP  (Lv. 0) = 0.5 (50 %)
+///        375000.0 XP  (Lv. 2) = 0.5 (50 %)
+///      51500000.0 XP (Lv. 24) = 0.5 (50 %)
+/// ```
+pub fn percentage_to_next_level(exp: f64) -> f64 {
+    exact_level(exp) % 1.0
+}
+
+/// Guild leveling, see the [module documentation](self).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GuildLeveling;
+
+impl Leveling for GuildLeveling {
+    fn level_for_xp(&self, exp: f64) -> f64 {
+        exact_level(exp)
+    }
+
+    fn xp_for_level(&self, level: f64) -> f64 {
+        total_xp_to_level(level)
+    }
+
+    fn progress(&self, exp: f64) -> f64 {
+        percentage_to_next_level(exp)
+    }
+}
@@ -1,3 +1,50 @@
 //! Different utility functions to work with experience and levels
+//!
+//! Every module provides the same set of functions for its own leveling, the [`Leveling`]
+//! trait allows code to be generic over them.
+//!
+//! # Examples
+//! ```rust
+//! use hypixel_api::util::leveling::{BedwarsLeveling, Leveling, NetworkLeveling};
+//!
+//! fn describe(leveling: &impl Leveling, exp: f64) -> String {
+//!     format!("level {:.0} ({:.0}%)", leveling.level_for_xp(exp).floor(), leveling.progress(exp) * 100.0)
+//! }
+//!
+//! assert_eq!(describe(&NetworkLeveling, 5000.0), "level 1 (50%)");
+//! assert_eq!(describe(&BedwarsLeveling, 500.0), "level 1 (0%)");
+//! ```
 
-pub mod network;
\ No newline at end of file
+pub mod network;
+pub mod bedwars;
+pub mod skywars;
+pub mod guild;
+pub mod skyblock;
+pub mod dungeons;
+pub mod slayer;
+pub mod pets;
+pub mod pit;
+pub mod uhc;
+
+pub use network::NetworkLeveling;
+pub use bedwars::BedwarsLeveling;
+pub use skywars::SkyWarsLeveling;
+pub use guild::GuildLeveling;
+pub use skyblock::SkillLeveling;
+pub use dungeons::DungeonsLeveling;
+pub use pit::PitLeveling;
+pub use uhc::UhcLeveling;
+
+/// A leveling system that maps experience to levels and back.
+///
+/// Levels are exact, the fractional part is the progress towards the next level.
+pub trait Leveling {
+    /// Returns the exact level corresponding to `exp`.
+    fn level_for_xp(&self, exp: f64) -> f64;
+
+    /// Returns the total experience required to reach `level`, including progress.
+    fn xp_for_level(&self, level: f64) -> f64;
+
+    /// Returns the progress from the current level towards the next level, between `0.0` and `1.0`.
+    fn progress(&self, exp: f64) -> f64;
+}
@@ -20,6 +20,8 @@
 //! experience that is is linearly interpolated between two experience amounts corresponding to nearest surrounding
 //! integer network levels.
 
+use crate::api::util::leveling::Leveling;
+
 pub const BASE: f64 = 10000.0;
 pub const GROWTH: f64 = 2500.0;
 
@@ -135,4 +137,22 @@ pub fn percentage_to_next_level(exp: f64) -> f64 {
     let lvl = calculate_level(exp);
     let x0 = total_xp_to_level(lvl);
     (exp - x0) / (total_xp_to_level(lvl + 1.0) - x0)
-}
\ No newline at end of file
+}
+
+/// Hypixel's network leveling, see the [module documentation](self).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NetworkLeveling;
+
+impl Leveling for NetworkLeveling {
+    fn level_for_xp(&self, exp: f64) -> f64 {
+        exact_level(exp)
+    }
+
+    fn xp_for_level(&self, level: f64) -> f64 {
+        total_xp_to_level(level)
+    }
+
+    fn progress(&self, exp: f64) -> f64 {
+        percentage_to_next_level(exp)
+    }
+}
@@ -0,0 +1,143 @@
+//! This module provides utility functions to work with SkyBlock pet levels and pet experience.
+//!
+//! All pets share the same experience table, [`PET_XP_TABLE`], but pets of a higher [`PetRarity`]
+//! skip the first entries of this table, see [`PetRarity::offset`]. Pets start at level `1` and most
+//! pets are capped at level [`MAX_LEVEL`]. The Golden Dragon is capped at [`GOLDEN_DRAGON_MAX_LEVEL`],
+//! use [`max_level`] to get the cap of a pet type.
+
+use crate::api::util::leveling::skyblock;
+
+/// The maximum level of most pets.
+pub const MAX_LEVEL: u32 = 100;
+/// The maximum level of the Golden Dragon pet.
+pub const GOLDEN_DRAGON_MAX_LEVEL: u32 = 200;
+
+/// The experience needed to progress from one pet level to the next, starting at
+/// level 1 for a common pet.
+pub const PET_XP_TABLE: [f64; 119] = [
+    100.0, 110.0, 120.0, 130.0, 145.0, 160.0, 175.0, 190.0, 210.0, 230.0,
+    250.0, 275.0, 300.0, 330.0, 360.0, 400.0, 440.0, 490.0, 540.0, 600.0,
+    660.0, 730.0, 800.0, 880.0, 960.0, 1050.0, 1150.0, 1260.0, 1380.0, 1510.0,
+    1650.0, 1800.0, 1960.0, 2130.0, 2310.0, 2500.0, 2700.0, 2920.0, 3160.0, 3420.0,
+    3700.0, 4000.0, 4350.0, 4750.0, 5200.0, 5700.0, 6300.0, 7000.0, 7800.0, 8700.0,
+    9700.0, 10800.0, 12000.0, 13300.0, 14700.0, 16200.0, 17800.0, 19500.0, 21300.0, 23200.0,
+    25200.0, 27400.0, 29800.0, 32400.0, 35200.0, 38200.0, 41400.0, 44800.0, 48400.0, 52200.0,
+    56200.0, 60400.0, 64800.0, 69400.0, 74200.0, 79200.0, 84700.0, 90700.0, 97200.0, 104200.0,
+    111700.0, 119700.0, 128200.0, 137200.0, 146700.0, 156700.0, 167700.0, 179700.0, 192700.0, 206700.0,
+    221700.0, 237700.0, 254700.0, 272700.0, 291700.0, 311700.0, 333700.0, 357700.0, 383700.0, 411700.0,
+    441700.0, 476700.0, 516700.0, 561700.0, 611700.0, 666700.0, 726700.0, 791700.0, 861700.0, 936700.0,
+    1016700.0, 1101700.0, 1191700.0, 1286700.0, 1386700.0, 1496700.0, 1616700.0, 1746700.0, 1886700.0,
+];
+
+// level 100 to 101 is free, level 101 to 102 costs this amount,
+// every level after that costs the last entry of the table
+const GOLDEN_DRAGON_LEVEL_102_XP: f64 = 5555.0;
+
+/// The rarity (tier) of a pet.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PetRarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+    Mythic,
+}
+
+impl PetRarity {
+    /// Returns the rarity corresponding to the tier used by the API (e.g. `"LEGENDARY"`), if present.
+    pub fn from_tier(tier: &str) -> Option<PetRarity> {
+        match tier {
+            "COMMON" => Some(PetRarity::Common),
+            "UNCOMMON" => Some(PetRarity::Uncommon),
+            "RARE" => Some(PetRarity::Rare),
+            "EPIC" => Some(PetRarity::Epic),
+            "LEGENDARY" => Some(PetRarity::Legendary),
+            "MYTHIC" => Some(PetRarity::Mythic),
+            _ => None,
+        }
+    }
+
+    /// Returns the amount of entries of [`PET_XP_TABLE`] a pet of this rarity skips.
+    pub fn offset(&self) -> usize {
+        match self {
+            PetRarity::Common => 0,
+            PetRarity::Uncommon => 6,
+            PetRarity::Rare => 11,
+            PetRarity::Epic => 16,
+            PetRarity::Legendary | PetRarity::Mythic => 20,
+        }
+    }
+}
+
+/// Returns the maximum level of the pet type used by the API (e.g. `"GOLDEN_DRAGON"`).
+pub fn max_level(pet_type: &str) -> u32 {
+    match pet_type {
+        "GOLDEN_DRAGON" => GOLDEN_DRAGON_MAX_LEVEL,
+        _ => MAX_LEVEL,
+    }
+}
+
+/// Returns the total experience required to reach every level after level 1, for a pet
+/// of `rarity` capped at `max_level`, in the format of [`skyblock`](super::skyblock).
+///
+/// Levels after [`MAX_LEVEL`] follow the leveling of the Golden Dragon.
+pub fn xp_table(rarity: PetRarity, max_level: u32) -> Vec<f64> {
+    let offset = rarity.offset();
+    let regular_levels = max_level.min(MAX_LEVEL).saturating_sub(1) as usize;
+    let mut per_level: Vec<f64> = PET_XP_TABLE[offset..offset + regular_levels].to_vec();
+    if max_level > MAX_LEVEL {
+        let extra_levels = (max_level - MAX_LEVEL) as usize;
+        per_level.extend([0.0, GOLDEN_DRAGON_LEVEL_102_XP].iter().copied()
+            .chain(std::iter::repeat(PET_XP_TABLE[PET_XP_TABLE.len() - 1]))
+            .take(extra_levels));
+    }
+    per_level.iter()
+        .scan(0.0, |total, xp| {
+            *total += xp;
+            Some(*total)
+        })
+        .collect()
+}
+
+/// This function returns the level of a pet calculated by the
+/// current experience gathered. Unlike [`exact_level`], this function returns
+/// the largest integer smaller than the exact level (= floored).
+///
+/// # Examples
+/// ```ignore
+///             0 XP -> 1.0   (any rarity)
+///           660 XP -> 2.0   (legendary)
+///      25353230 XP -> 100.0 (legendary)
+///     210255385 XP -> 200.0 (legendary, max level 200)
+/// ```
+pub fn calculate_level(exp: f64, rarity: PetRarity, max_level: u32) -> f64 {
+    exact_level(exp, rarity, max_level).floor()
+}
+
+/// This function returns the exact level of a pet calculated by the
+/// current experience gathered, including the progress to the next level.
+///
+/// The result cannot be smaller than `1.0` or larger than `max_level`.
+pub fn exact_level(exp: f64, rarity: PetRarity, max_level: u32) -> f64 {
+    1.0 + skyblock::exact_level(exp, &xp_table(rarity, max_level))
+}
+
+/// This function returns the amount of experience that is needed to progress from `level` to `level + 1`.
+///
+/// If `level` is `max_level` or higher, `0.0` is returned.
+pub fn xp_to_next_level(level: f64, rarity: PetRarity, max_level: u32) -> f64 {
+    skyblock::xp_to_next_level(level - 1.0, &xp_table(rarity, max_level))
+}
+
+/// This method returns the experience required to reach `level`, including progress.
+///
+/// Levels higher than `max_level` return the experience of `max_level`.
+pub fn total_xp_to_level(level: f64, rarity: PetRarity, max_level: u32) -> f64 {
+    skyblock::total_xp_to_level(level - 1.0, &xp_table(rarity, max_level))
+}
+
+/// This method returns the current progress of this level to reach the next level.
+pub fn percentage_to_next_level(exp: f64, rarity: PetRarity, max_level: u32) -> f64 {
+    exact_level(exp, rarity, max_level) % 1.0
+}
@@ -0,0 +1,146 @@
+//! This module provides utility functions to work with The Pit prestiges, levels and Pit experience.
+//!
+//! Pit levels are grouped into prestiges of [`LEVELS_PER_PRESTIGE`] levels each. The functions
+//! in this module work with absolute levels: `prestige * 120 + level in prestige`, use [`prestige`]
+//! and [`level_in_prestige`] to split them.
+//!
+//! The experience to go from one level to the next is the base experience of its bracket of
+//! 10 levels ([`LEVEL_XP`]) multiplied by the multiplier of the prestige ([`PRESTIGE_MULTIPLIERS`]):
+//!
+//! | Level in prestige | Base experience to next level |
+//! |-------------------|-------------------------------|
+//! | 0 - 9             | 15                            |
+//! | 10 - 19           | 30                            |
+//! | ...               | ...                           |
+//! | 110 - 119         | 1500                          |
+//!
+//! A player without experience is level `0` of prestige `0`. The highest prestige is [`MAX_PRESTIGE`].
+
+use crate::api::util::leveling::Leveling;
+
+/// The amount of levels in a single prestige.
+pub const LEVELS_PER_PRESTIGE: f64 = 120.0;
+/// The highest prestige a player can reach.
+pub const MAX_PRESTIGE: u32 = 50;
+/// The base experience to progress a level, per bracket of 10 levels.
+pub const LEVEL_XP: [f64; 12] = [15.0, 30.0, 50.0, 75.0, 125.0, 300.0, 600.0, 800.0, 900.0, 1000.0, 1200.0, 1500.0];
+/// The experience multiplier of every prestige.
+pub const PRESTIGE_MULTIPLIERS: [f64; 51] = [
+    1.0, 1.1, 1.2, 1.3, 1.4, 1.5, 1.75, 2.0, 2.5, 3.0,
+    4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 12.0, 14.0, 16.0,
+    18.0, 20.0, 24.0, 28.0, 32.0, 36.0, 40.0, 45.0, 50.0, 75.0,
+    100.0, 101.0, 101.0, 101.0, 101.0, 101.0, 200.0, 300.0, 400.0, 500.0,
+    750.0, 1000.0, 1250.0, 1500.0, 1750.0, 2000.0, 3000.0, 5000.0, 10000.0, 50000.0,
+    100000.0,
+];
+
+/// The experience required for a full prestige without multiplier.
+const BASE_PRESTIGE_XP: f64 = 65950.0;
+const MAX_LEVEL: f64 = (MAX_PRESTIGE + 1) as f64 * LEVELS_PER_PRESTIGE;
+
+/// This function returns the absolute level of a player calculated by the
+/// current experience gathered. Unlike [`exact_level`], this function returns
+/// the largest integer smaller than the exact level (= floored).
+///
+/// # Examples
+/// ```ignore
+///          0 XP -> 0.0   (prestige 0, level 0)
+///        150 XP -> 10.0  (prestige 0, level 10)
+///      65950 XP -> 120.0 (prestige 1, level 0)
+///      66115 XP -> 130.0 (prestige 1, level 10)
+/// ```
+pub fn calculate_level(exp: f64) -> f64 {
+    exact_level(exp).floor()
+}
+
+/// This function returns the exact absolute level of a player calculated by the
+/// current experience gathered. Unlike [`calculate_level`], this function does
+/// not floor its result and will return an accurate level.
+///
+/// The result cannot be larger than the last level of [`MAX_PRESTIGE`].
+pub fn exact_level(exp: f64) -> f64 {
+    let mut remaining = exp.max(0.0);
+    let mut level = 0.0;
+    while level < MAX_LEVEL {
+        let xp = xp_to_next_level(level);
+        if remaining < xp {
+            return level + remaining / xp;
+        }
+        remaining -= xp;
+        level += 1.0;
+    }
+    MAX_LEVEL
+}
+
+/// This function returns the prestige of a player at absolute `level`, starting from `0`.
+pub fn prestige(level: f64) -> u32 {
+    ((level.max(0.0) / LEVELS_PER_PRESTIGE).floor() as u32).min(MAX_PRESTIGE)
+}
+
+/// This function returns the level within the prestige of a player at absolute `level`.
+pub fn level_in_prestige(level: f64) -> f64 {
+    level.max(0.0) - prestige(level) as f64 * LEVELS_PER_PRESTIGE
+}
+
+/// This function returns the amount of experience that is needed to progress from `level` to `level + 1`. (e.g. 5 to 6)
+///
+/// # Examples
+/// ```ignore
+///       0 (to 1)   =   15.0 XP
+///     119 (to 120) = 1500.0 XP
+///     120 (to 121) =   16.5 XP
+/// ```
+pub fn xp_to_next_level(level: f64) -> f64 {
+    let bracket = (level_in_prestige(level) / 10.0).floor() as usize;
+    LEVEL_XP[bracket.min(LEVEL_XP.len() - 1)] * PRESTIGE_MULTIPLIERS[prestige(level) as usize]
+}
+
+/// This function returns the experience required to complete `prestige` (level 0 to 120 of that prestige).
+///
+/// # Examples
+/// ```ignore
+///     0 = 65950.0 XP
+///     1 = 72545.0 XP
+/// ```
+pub fn prestige_xp(prestige: u32) -> f64 {
+    BASE_PRESTIGE_XP * PRESTIGE_MULTIPLIERS[prestige.min(MAX_PRESTIGE) as usize]
+}
+
+/// This method returns the experience required to reach absolute `level`, including progress.
+pub fn total_xp_to_level(level: f64) -> f64 {
+    let level = level.clamp(0.0, MAX_LEVEL);
+    let prestige = prestige(level);
+    let mut total: f64 = (0..prestige).map(prestige_xp).sum();
+    let mut lvl = prestige as f64 * LEVELS_PER_PRESTIGE;
+    while lvl + 1.0 <= level {
+        total += xp_to_next_level(lvl);
+        lvl += 1.0;
+    }
+    if lvl < MAX_LEVEL {
+        total += xp_to_next_level(lvl) * (level - lvl);
+    }
+    total
+}
+
+/// This method returns the current progress of this level to reach the next level.
+pub fn percentage_to_next_level(exp: f64) -> f64 {
+    exact_level(exp) % 1.0
+}
+
+/// The Pit leveling, see the [module documentation](self).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PitLeveling;
+
+impl Leveling for PitLeveling {
+    fn level_for_xp(&self, exp: f64) -> f64 {
+        exact_level(exp)
+    }
+
+    fn xp_for_level(&self, level: f64) -> f64 {
+        total_xp_to_level(level)
+    }
+
+    fn progress(&self, exp: f64) -> f64 {
+        percentage_to_next_level(exp)
+    }
+}
@@ -0,0 +1,147 @@
+//! This module provides utility functions to work with SkyBlock skill levels and skill experience.
+//!
+//! Skill levels are defined by a table of total experience amounts: the value at index `i`
+//! is the experience required to reach level `i + 1`. The length of the table is the maximum
+//! level of the skill. The up-to-date tables can be found in the skills resources
+//! ([`SkillDefinition::xp_table`](crate::SkillDefinition::xp_table)), a copy is bundled
+//! with this crate as [`SKILL_XP_TABLE`] for when these resources are unavailable.
+//!
+//! Most skills are capped at level 50, some (e.g. Farming, Mining and Enchanting) at level 60.
+//! Use [`skill_table`] to get the bundled table for either cap.
+//!
+//! A player without experience is level `0`.
+
+use crate::api::util::leveling::Leveling;
+
+/// The total experience required to reach every skill level, up to level 60.
+pub const SKILL_XP_TABLE: [f64; 60] = [
+    50.0, 175.0, 375.0, 675.0, 1175.0, 1925.0, 2925.0, 4425.0, 6425.0, 9925.0,
+    14925.0, 22425.0, 32425.0, 47425.0, 67425.0, 97425.0, 147425.0, 222425.0, 322425.0, 522425.0,
+    822425.0, 1222425.0, 1722425.0, 2322425.0, 3022425.0, 3822425.0, 4722425.0, 5722425.0, 6822425.0, 8022425.0,
+    9322425.0, 10722425.0, 12222425.0, 13822425.0, 15522425.0, 17322425.0, 19222425.0, 21222425.0, 23322425.0, 25522425.0,
+    27822425.0, 30222425.0, 32722425.0, 35322425.0, 38072425.0, 40972425.0, 44072425.0, 47472425.0, 51172425.0, 55172425.0,
+    59472425.0, 64072425.0, 68972425.0, 74172425.0, 79672425.0, 85472425.0, 91572425.0, 97972425.0, 104672425.0, 111672425.0,
+];
+
+/// Returns the bundled experience table of a skill capped at `max_level`.
+///
+/// `max_level` is clamped to the length of [`SKILL_XP_TABLE`].
+pub fn skill_table(max_level: usize) -> &'static [f64] {
+    &SKILL_XP_TABLE[..max_level.min(SKILL_XP_TABLE.len())]
+}
+
+/// This function returns the level of a player calculated by the
+/// current experience gathered. Unlike [`exact_level`], this function returns
+/// the largest integer smaller than the exact level (= floored).
+///
+/// The result cannot be smaller than `0.0` and negative experience results in `0.0`.
+///
+/// # Examples
+/// ```ignore
+///            0 XP -> 0.0  (any table)
+///          175 XP -> 2.0  (any table)
+///     60000000 XP -> 50.0 (50 levels)
+///     60000000 XP -> 51.0 (60 levels)
+/// ```
+pub fn calculate_level(exp: f64, table: &[f64]) -> f64 {
+    exact_level(exp, table).floor()
+}
+
+/// This function returns the exact level of a player calculated by the
+/// current experience gathered. Unlike [`calculate_level`], this function does
+/// not floor its result and will return an accurate level.
+///
+/// The result cannot be larger than the maximum level of `table`, which has no progress.
+///
+/// # Examples
+/// ```ignore
+///           25 XP -> 0.5  (any table)
+///          275 XP -> 2.5  (any table)
+///     60000000 XP -> 50.0 (50 levels)
+///     57322425 XP -> 50.5 (60 levels)
+/// ```
+pub fn exact_level(exp: f64, table: &[f64]) -> f64 {
+    if exp <= 0.0 {
+        return 0.0;
+    }
+    match table.iter().position(|xp| exp < *xp) {
+        Some(next) => {
+            let x0 = if next == 0 { 0.0 } else { table[next - 1] };
+            next as f64 + (exp - x0) / (table[next] - x0)
+        }
+        None => table.len() as f64,
+    }
+}
+
+/// This function returns the amount of experience that is needed to progress from `level` to `level + 1`. (e.g. 5 to 6)
+///
+/// If `level` is the maximum level of `table` or higher, `0.0` is returned.
+///
+/// # Examples
+/// ```ignore
+///       0 (to 1)   =      50.0 XP
+///       5 (to 6)   =     750.0 XP
+///      49 (to 50)  = 4000000.0 XP
+///      50 (to 51)  =       0.0 XP (50 levels)
+///      50 (to 51)  = 4300000.0 XP (60 levels)
+/// ```
+pub fn xp_to_next_level(level: f64, table: &[f64]) -> f64 {
+    let lvl = level.max(0.0).floor();
+    total_xp_to_level(lvl + 1.0, table) - total_xp_to_level(lvl, table)
+}
+
+/// This method returns the experience required to reach that level. This method is precise, that means
+/// you can pass any progress of a level to receive the experience to reach that progress. (e.g. 2.5 returns
+/// the experience required to reach level 2 and 50% of the experience required to go from level 2 to level 3).
+///
+/// Levels higher than the maximum level of `table` return the experience of the maximum level.
+///
+/// # Examples
+/// ```ignore
+///        1.0 =       50.0 XP
+///        2.5 =      275.0 XP
+///       50.0 = 55172425.0 XP
+/// ```
+pub fn total_xp_to_level(level: f64, table: &[f64]) -> f64 {
+    if level <= 0.0 || table.is_empty() {
+        return 0.0;
+    }
+    let lvl = level.floor() as usize;
+    if lvl >= table.len() {
+        return table[table.len() - 1];
+    }
+    let x0 = if lvl == 0 { 0.0 } else { table[lvl - 1] };
+    x0 + (table[lvl] - x0) * (level % 1.0)
+}
+
+/// This method returns the current progress of this level to reach the next level.
+///
+/// At the maximum level of `table`, `0.0` is returned.
+pub fn percentage_to_next_level(exp: f64, table: &[f64]) -> f64 {
+    exact_level(exp, table) % 1.0
+}
+
+/// The leveling of a SkyBlock skill with experience table `table`, see the [module documentation](self).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SkillLeveling<'a>(pub &'a [f64]);
+
+impl Default for SkillLeveling<'static> {
+    /// Returns the leveling of a skill capped at level 50.
+    fn default() -> Self {
+        SkillLeveling(skill_table(50))
+    }
+}
+
+impl Leveling for SkillLeveling<'_> {
+    fn level_for_xp(&self, exp: f64) -> f64 {
+        exact_level(exp, self.0)
+    }
+
+    fn xp_for_level(&self, level: f64) -> f64 {
+        total_xp_to_level(level, self.0)
+    }
+
+    fn progress(&self, exp: f64) -> f64 {
+        percentage_to_next_level(exp, self.0)
+    }
+}
@@ -0,0 +1,143 @@
+//! This module provides utility functions to work with SkyWars levels and SkyWars experience.
+//!
+//! The first [`TABLE_LEVELS`] levels follow a fixed table of total experience amounts,
+//! every level after that needs [`XP_PER_LEVEL`] more experience.
+//!
+//! | Level | Total experience |
+//! |-------|------------------|
+//! | 1     | 0                |
+//! | 2     | 20               |
+//! | 3     | 70               |
+//! | 4     | 150              |
+//! | 5     | 250              |
+//! | 6     | 500              |
+//! | 7     | 1000             |
+//! | 8     | 2000             |
+//! | 9     | 3500             |
+//! | 10    | 6000             |
+//! | 11    | 10000            |
+//! | 12    | 15000            |
+//!
+//! A player without experience is level `1`.
+
+use crate::api::util::leveling::Leveling;
+
+/// The amount of levels that follow the experience table.
+pub const TABLE_LEVELS: f64 = 12.0;
+/// The amount of experience that is needed for every level after [`TABLE_LEVELS`].
+pub const XP_PER_LEVEL: f64 = 10000.0;
+
+const TOTAL_XP_TABLE: [f64; 12] = [0.0, 20.0, 70.0, 150.0, 250.0, 500.0, 1000.0, 2000.0, 3500.0, 6000.0, 10000.0, 15000.0];
+
+/// This function returns the level of a player calculated by the
+/// current experience gathered. Unlike [`exact_level`], this function returns
+/// the largest integer smaller than the exact level (= floored).
+///
+/// The result cannot be smaller than `1.0` and negative experience results in `1.0`.
+///
+/// # Examples
+/// ```ignore
+///            0 XP -> 1.0
+///           50 XP -> 2.0
+///        15000 XP -> 12.0
+///       100000 XP -> 20.0
+/// ```
+pub fn calculate_level(exp: f64) -> f64 {
+    exact_level(exp).floor()
+}
+
+/// This function returns the exact level of a player calculated by the
+/// current experience gathered. Unlike [`calculate_level`], this function does
+/// not floor its result and will return an accurate level.
+///
+/// The result cannot be smaller than `1.0` and negative experience results in `1.0`.
+///
+/// # Examples
+/// ```ignore
+///            0 XP -> 1.0
+///           45 XP -> 2.5
+///        20000 XP -> 12.5
+///       100000 XP -> 20.5
+/// ```
+pub fn exact_level(exp: f64) -> f64 {
+    if exp <= 0.0 {
+        return 1.0;
+    }
+    match TOTAL_XP_TABLE.iter().position(|xp| exp < *xp) {
+        Some(next) => {
+            let x0 = TOTAL_XP_TABLE[next - 1];
+            next as f64 + (exp - x0) / (TOTAL_XP_TABLE[next] - x0)
+        }
+        None => TABLE_LEVELS + (exp - TOTAL_XP_TABLE[TOTAL_XP_TABLE.len() - 1]) / XP_PER_LEVEL,
+    }
+}
+
+/// This function returns the amount of experience that is needed to progress from `level` to `level + 1`. (e.g. 5 to 6)
+/// The levels passed *must* be absolute levels with the smallest level being 1.
+/// Smaller values always return the experience needed to reach level 2.
+///
+/// # Examples
+/// ```ignore
+///       1 (to 2)   =    20.0 XP
+///       5 (to 6)   =   250.0 XP
+///      11 (to 12)  =  5000.0 XP
+///      12 (to 13)  = 10000.0 XP
+/// ```
+pub fn xp_to_next_level(level: f64) -> f64 {
+    let lvl = level.max(1.0).floor();
+    total_xp_to_level(lvl + 1.0) - total_xp_to_level(lvl)
+}
+
+/// This method returns the experience required to reach that level. This method is precise, that means
+/// you can pass any progress of a level to receive the experience to reach that progress. (e.g. 12.5 returns
+/// the experience required to reach level 12 and 50% of the experience required to go from level 12 to level 13).
+///
+/// # Examples
+/// ```ignore
+///        1.0 =      0.0 XP
+///        2.5 =     45.0 XP
+///       12.0 =  15000.0 XP
+///       12.5 =  20000.0 XP
+///       20.5 = 100000.0 XP
+/// ```
+pub fn total_xp_to_level(level: f64) -> f64 {
+    if level <= 1.0 {
+        return 0.0;
+    }
+    let lvl = level.floor();
+    if lvl >= TABLE_LEVELS {
+        return TOTAL_XP_TABLE[TOTAL_XP_TABLE.len() - 1] + (level - TABLE_LEVELS) * XP_PER_LEVEL;
+    }
+    let x0 = TOTAL_XP_TABLE[lvl as usize - 1];
+    x0 + (TOTAL_XP_TABLE[lvl as usize] - x0) * (level % 1.0)
+}
+
+/// This method returns the current progress of this level to reach the next level.
+///
+/// # Examples
+/// ```ignore
+///         45.0 XP  (Lv. 2) = 0.5  (50 %)
+///      20000.0 XP (Lv. 12) = 0.5  (50 %)
+///     102500.0 XP (Lv. 20) = 0.75 (75 %)
+/// ```
+pub fn percentage_to_next_level(exp: f64) -> f64 {
+    exact_level(exp) % 1.0
+}
+
+/// SkyWars leveling, see the [module documentation](self).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SkyWarsLeveling;
+
+impl Leveling for SkyWarsLeveling {
+    fn level_for_xp(&self, exp: f64) -> f64 {
+        exact_level(exp)
+    }
+
+    fn xp_for_level(&self, level: f64) -> f64 {
+        total_xp_to_level(level)
+    }
+
+    fn progress(&self, exp: f64) -> f64 {
+        percentage_to_next_level(exp)
+    }
+}
@@ -0,0 +1,130 @@
+//! This module provides utility functions to work with SkyBlock slayer levels and slayer experience.
+//!
+//! Every [`SlayerBoss`] has its own table of total experience amounts, in the format of
+//! [`skyblock`](super::skyblock). Slayer levels are only awarded as whole levels in game,
+//! the fractional progress returned by [`exact_level`] is provided for display purposes.
+//!
+//! A player without experience is level `0`.
+
+use crate::api::util::leveling::{skyblock, Leveling};
+
+const ZOMBIE_XP_TABLE: [f64; 9] = [5.0, 15.0, 200.0, 1000.0, 5000.0, 20000.0, 100000.0, 400000.0, 1000000.0];
+const SPIDER_XP_TABLE: [f64; 9] = [5.0, 25.0, 200.0, 1000.0, 5000.0, 20000.0, 100000.0, 400000.0, 1000000.0];
+const DEFAULT_XP_TABLE: [f64; 9] = [10.0, 30.0, 250.0, 1500.0, 5000.0, 20000.0, 100000.0, 400000.0, 1000000.0];
+const VAMPIRE_XP_TABLE: [f64; 5] = [20.0, 75.0, 240.0, 840.0, 2400.0];
+
+/// A slayer boss type, each with their own leveling.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SlayerBoss {
+    /// Revenant Horror
+    Zombie,
+    /// Tarantula Broodfather
+    Spider,
+    /// Sven Packmaster
+    Wolf,
+    /// Voidgloom Seraph
+    Enderman,
+    /// Inferno Demonlord
+    Blaze,
+    /// Riftstalker Bloodfiend
+    Vampire,
+}
+
+impl SlayerBoss {
+    /// Returns the boss corresponding to the id used by the API (e.g. `"zombie"`), if present.
+    pub fn from_id(id: &str) -> Option<SlayerBoss> {
+        match id {
+            "zombie" => Some(SlayerBoss::Zombie),
+            "spider" => Some(SlayerBoss::Spider),
+            "wolf" => Some(SlayerBoss::Wolf),
+            "enderman" => Some(SlayerBoss::Enderman),
+            "blaze" => Some(SlayerBoss::Blaze),
+            "vampire" => Some(SlayerBoss::Vampire),
+            _ => None,
+        }
+    }
+
+    /// Returns the id of this boss as used by the API.
+    pub fn id(&self) -> &'static str {
+        match self {
+            SlayerBoss::Zombie => "zombie",
+            SlayerBoss::Spider => "spider",
+            SlayerBoss::Wolf => "wolf",
+            SlayerBoss::Enderman => "enderman",
+            SlayerBoss::Blaze => "blaze",
+            SlayerBoss::Vampire => "vampire",
+        }
+    }
+
+    /// Returns the total experience required to reach every level of this boss.
+    pub fn xp_table(&self) -> &'static [f64] {
+        match self {
+            SlayerBoss::Zombie => &ZOMBIE_XP_TABLE,
+            SlayerBoss::Spider => &SPIDER_XP_TABLE,
+            SlayerBoss::Wolf | SlayerBoss::Enderman | SlayerBoss::Blaze => &DEFAULT_XP_TABLE,
+            SlayerBoss::Vampire => &VAMPIRE_XP_TABLE,
+        }
+    }
+
+    /// Returns the maximum level of this boss.
+    pub fn max_level(&self) -> f64 {
+        self.xp_table().len() as f64
+    }
+}
+
+/// This function returns the level of a player calculated by the
+/// current experience gathered for `boss`. This is the level shown in game.
+///
+/// # Examples
+/// ```ignore
+///           0 XP -> 0.0 (any boss)
+///         200 XP -> 3.0 (zombie)
+///         200 XP -> 2.0 (wolf)
+///     2000000 XP -> 9.0 (zombie)
+///        2400 XP -> 5.0 (vampire)
+/// ```
+pub fn calculate_level(exp: f64, boss: SlayerBoss) -> f64 {
+    skyblock::calculate_level(exp, boss.xp_table())
+}
+
+/// This function returns the exact level of a player calculated by the
+/// current experience gathered for `boss`, including the progress to the next level.
+///
+/// The result cannot be larger than the maximum level of `boss`.
+pub fn exact_level(exp: f64, boss: SlayerBoss) -> f64 {
+    skyblock::exact_level(exp, boss.xp_table())
+}
+
+/// This function returns the amount of experience that is needed to progress from `level` to `level + 1`
+/// for `boss`.
+///
+/// If `level` is the maximum level of `boss` or higher, `0.0` is returned.
+pub fn xp_to_next_level(level: f64, boss: SlayerBoss) -> f64 {
+    skyblock::xp_to_next_level(level, boss.xp_table())
+}
+
+/// This method returns the experience required to reach `level` for `boss`.
+///
+/// Levels higher than the maximum level of `boss` return the experience of the maximum level.
+pub fn total_xp_to_level(level: f64, boss: SlayerBoss) -> f64 {
+    skyblock::total_xp_to_level(level, boss.xp_table())
+}
+
+/// This method returns the current progress of this level to reach the next level for `boss`.
+pub fn percentage_to_next_level(exp: f64, boss: SlayerBoss) -> f64 {
+    exact_level(exp, boss) % 1.0
+}
+
+impl Leveling for SlayerBoss {
+    fn level_for_xp(&self, exp: f64) -> f64 {
+        exact_level(exp, *self)
+    }
+
+    fn xp_for_level(&self, level: f64) -> f64 {
+        total_xp_to_level(level, *self)
+    }
+
+    fn progress(&self, exp: f64) -> f64 {
+        percentage_to_next_level(exp, *self)
+    }
+}
@@ -0,0 +1,132 @@
+//! This module provides utility functions to work with UHC Champions stars and UHC score.
+//!
+//! UHC stars follow a fixed table of total score amounts, up to [`MAX_STAR`]:
+//!
+//! | Star | Total score | Title      |
+//! |------|-------------|------------|
+//! | 1    | 0           | Recruit    |
+//! | 2    | 10          | Initiate   |
+//! | 3    | 60          | Soldier    |
+//! | 4    | 210         | Sergeant   |
+//! | 5    | 460         | Knight     |
+//! | 6    | 960         | Captain    |
+//! | 7    | 1710        | Centurion  |
+//! | 8    | 2710        | Gladiator  |
+//! | 9    | 5210        | Warlord    |
+//! | 10   | 10210       | Champion   |
+//! | 11   | 13210       | Champion   |
+//! | 12   | 16210       | Champion   |
+//! | 13   | 19210       | Champion   |
+//! | 14   | 22210       | Champion   |
+//! | 15   | 25210       | Champion   |
+//!
+//! A player without score is star `1`.
+
+use crate::api::util::leveling::Leveling;
+
+/// The highest star a player can reach.
+pub const MAX_STAR: f64 = 15.0;
+
+const TOTAL_SCORE_TABLE: [f64; 15] = [
+    0.0, 10.0, 60.0, 210.0, 460.0, 960.0, 1710.0, 2710.0, 5210.0, 10210.0,
+    13210.0, 16210.0, 19210.0, 22210.0, 25210.0,
+];
+const TITLES: [&str; 10] = [
+    "Recruit", "Initiate", "Soldier", "Sergeant", "Knight",
+    "Captain", "Centurion", "Gladiator", "Warlord", "Champion",
+];
+
+/// This function returns the star of a player calculated by the
+/// current score. Unlike [`exact_level`], this function returns
+/// the largest integer smaller than the exact star (= floored).
+///
+/// The result cannot be smaller than `1.0` or larger than [`MAX_STAR`].
+///
+/// # Examples
+/// ```ignore
+///         0 score -> 1.0
+///        60 score -> 3.0
+///      5000 score -> 8.0
+///     30000 score -> 15.0
+/// ```
+pub fn calculate_level(score: f64) -> f64 {
+    exact_level(score).floor()
+}
+
+/// This function returns the exact star of a player calculated by the
+/// current score, including the progress to the next star.
+///
+/// The result cannot be smaller than `1.0` or larger than [`MAX_STAR`].
+///
+/// # Examples
+/// ```ignore
+///         5 score -> 1.5
+///       135 score -> 3.5
+///     30000 score -> 15.0
+/// ```
+pub fn exact_level(score: f64) -> f64 {
+    match TOTAL_SCORE_TABLE.iter().position(|total| score < *total) {
+        Some(0) => 1.0,
+        Some(next) => {
+            let x0 = TOTAL_SCORE_TABLE[next - 1];
+            next as f64 + (score - x0) / (TOTAL_SCORE_TABLE[next] - x0)
+        }
+        None => MAX_STAR,
+    }
+}
+
+/// This function returns the amount of score that is needed to progress from `star` to `star + 1`. (e.g. 5 to 6)
+///
+/// At [`MAX_STAR`] or higher, `0.0` is returned.
+pub fn xp_to_next_level(star: f64) -> f64 {
+    let star = star.max(1.0).floor();
+    total_xp_to_level(star + 1.0) - total_xp_to_level(star)
+}
+
+/// This method returns the score required to reach `star`, including progress.
+///
+/// Stars higher than [`MAX_STAR`] return the score of [`MAX_STAR`].
+///
+/// # Examples
+/// ```ignore
+///      1.0 =     0.0 score
+///      3.5 =   135.0 score
+///     15.0 = 25210.0 score
+/// ```
+pub fn total_xp_to_level(star: f64) -> f64 {
+    let star = star.clamp(1.0, MAX_STAR);
+    let index = star.floor() as usize - 1;
+    match TOTAL_SCORE_TABLE.get(index + 1) {
+        Some(next) => TOTAL_SCORE_TABLE[index] + (next - TOTAL_SCORE_TABLE[index]) * (star % 1.0),
+        None => TOTAL_SCORE_TABLE[index],
+    }
+}
+
+/// This method returns the current progress of this star to reach the next star.
+pub fn percentage_to_next_level(score: f64) -> f64 {
+    exact_level(score) % 1.0
+}
+
+/// This function returns the title belonging to `star` (e.g. `"Knight"`).
+pub fn title(star: f64) -> &'static str {
+    let index = (star.max(1.0).floor() as usize - 1).min(TITLES.len() - 1);
+    TITLES[index]
+}
+
+/// UHC Champions leveling, see the [module documentation](self).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct UhcLeveling;
+
+impl Leveling for UhcLeveling {
+    fn level_for_xp(&self, exp: f64) -> f64 {
+        exact_level(exp)
+    }
+
+    fn xp_for_level(&self, level: f64) -> f64 {
+        total_xp_to_level(level)
+    }
+
+    fn progress(&self, exp: f64) -> f64 {
+        percentage_to_next_level(exp)
+    }
+}
@@ -1,3 +1,25 @@
 //! Utilities to work with data returned by the Hypixel API.
 
-pub mod leveling;
\ No newline at end of file
+pub mod leveling;
+pub mod formatting;
+pub mod discord;
+pub mod time;
+pub mod games;
+pub mod duels;
+pub mod ratios;
+#[cfg(feature = "reply")]
+pub mod guild;
+#[cfg(feature = "reply")]
+pub mod bazaar;
+#[cfg(feature = "reply")]
+pub mod auctions;
+#[cfg(feature = "reply")]
+pub mod skills;
+#[cfg(feature = "reply")]
+pub mod collections;
+#[cfg(feature = "reply")]
+pub mod bingo;
+pub mod uuid;
+pub mod lenient;
+#[cfg(feature = "nbt")]
+pub mod nbt;
\ No newline at end of file
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use crate::api::util::formatting::strip_codes;
+use crate::api::util::nbt::Tag;
+
+/// A SkyBlock item parsed from its NBT data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkyblockItem {
+    id: String,
+    uuid: Option<String>,
+    count: u8,
+    display_name: Option<String>,
+    lore: Vec<String>,
+    enchantments: HashMap<String, u32>,
+    reforge: Option<String>,
+    stars: u32,
+    recombobulated: bool,
+    attributes: HashMap<String, u32>,
+    extra: Tag,
+}
+
+impl SkyblockItem {
+    /// Parses a single item tag (an element of the `i` list in an inventory).
+    ///
+    /// Returns [`Option::None`] for empty slots and non-SkyBlock items.
+    pub fn from_tag(tag: &Tag) -> Option<Self> {
+        let extra = tag.path(&["tag", "ExtraAttributes"])?;
+        let display = tag.path(&["tag", "display"]);
+        Some(SkyblockItem {
+            id: extra.get("id")?.as_str()?.to_string(),
+            uuid: extra.get("uuid").and_then(Tag::as_str).map(str::to_string),
+            count: tag.get("Count").and_then(Tag::as_i64).unwrap_or(1) as u8,
+            display_name: display.and_then(|display| display.get("Name")).and_then(Tag::as_str).map(str::to_string),
+            lore: display.and_then(|display| display.get("Lore"))
+                .and_then(Tag::as_list)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|line| line.as_str().map(str::to_string))
+                .collect(),
+            enchantments: levels(extra.get("enchantments")),
+            reforge: extra.get("modifier").and_then(Tag::as_str).map(str::to_string),
+            stars: extra.get("upgrade_level")
+                .or_else(|| extra.get("dungeon_item_level"))
+                .and_then(Tag::as_i64)
+                .unwrap_or(0) as u32,
+            recombobulated: extra.get("rarity_upgrades").and_then(Tag::as_i64).unwrap_or(0) > 0,
+            attributes: levels(extra.get("attributes")),
+            extra: extra.clone(),
+        })
+    }
+
+    /// Returns the SkyBlock id of this item (e.g. `"ASPECT_OF_THE_END"`).
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the unique id of this item, only present on unstackable items.
+    pub fn uuid(&self) -> Option<&str> {
+        self.uuid.as_deref()
+    }
+
+    pub fn count(&self) -> u8 {
+        self.count
+    }
+
+    /// Returns the display name of this item with formatting codes.
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    /// Returns the display name of this item without formatting codes.
+    pub fn plain_name(&self) -> Option<String> {
+        self.display_name.as_deref().map(strip_codes)
+    }
+
+    /// Returns the lore lines of this item with formatting codes.
+    pub fn lore(&self) -> &[String] {
+        &self.lore
+    }
+
+    /// Returns the enchantments on this item mapped to their level (e.g. `"sharpness" -> 5`).
+    pub fn enchantments(&self) -> &HashMap<String, u32> {
+        &self.enchantments
+    }
+
+    /// Returns the level of enchantment `name` on this item, if present.
+    pub fn enchantment(&self, name: &str) -> Option<u32> {
+        self.enchantments.get(name).copied()
+    }
+
+    /// Returns the reforge applied to this item (e.g. `"fabled"`).
+    pub fn reforge(&self) -> Option<&str> {
+        self.reforge.as_deref()
+    }
+
+    /// Returns the amount of (dungeon) stars on this item.
+    pub fn stars(&self) -> u32 {
+        self.stars
+    }
+
+    /// Returns whether this item has been upgraded with a Recombobulator 3000.
+    pub fn is_recombobulated(&self) -> bool {
+        self.recombobulated
+    }
+
+    /// Returns the Crimson Isle attributes on this item mapped to their level.
+    pub fn attributes(&self) -> &HashMap<String, u32> {
+        &self.attributes
+    }
+
+    /// Returns the complete `ExtraAttributes` tag of this item, for fields not captured
+    /// by this struct.
+    pub fn extra_attributes(&self) -> &Tag {
+        &self.extra
+    }
+}
+
+fn levels(tag: Option<&Tag>) -> HashMap<String, u32> {
+    tag.and_then(Tag::as_compound)
+        .map(|levels| levels.iter()
+            .filter_map(|(name, level)| Some((name.clone(), level.as_i64()? as u32)))
+            .collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,248 @@
+//! Decoding of the NBT data found in SkyBlock items.
+//!
+//! The Hypixel API returns inventories and items (e.g. `item_bytes` in auctions, museum
+//! and profile inventories) as base64 encoded, gzipped
+//! [NBT](https://minecraft.wiki/w/NBT_format) data. [`decode_item_bytes`] turns these blobs
+//! into a navigable [`Tag`], [`decode_items`] parses them into typed [`SkyblockItem`]s.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use hypixel_api::util::nbt::decode_item_bytes;
+//!
+//! # fn print_items(item_bytes: &str) -> Result<(), hypixel_api::error::HypixelApiError> {
+//! let root = decode_item_bytes(item_bytes)?;
+//! for item in root.get("i").and_then(|items| items.as_list()).unwrap_or_default() {
+//!     let name = item.path(&["tag", "display", "Name"]).and_then(|name| name.as_str());
+//!     println!("{:?}", name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use flate2::read::GzDecoder;
+
+use crate::api::error::HypixelApiError;
+
+mod item;
+
+pub use item::SkyblockItem;
+
+/// A single NBT tag.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tag {
+    /// A signed 8-bit integer, also used for booleans.
+    Byte(i8),
+    /// A signed 16-bit integer.
+    Short(i16),
+    /// A signed 32-bit integer.
+    Int(i32),
+    /// A signed 64-bit integer.
+    Long(i64),
+    /// A 32-bit floating point number.
+    Float(f32),
+    /// A 64-bit floating point number.
+    Double(f64),
+    /// An array of signed 8-bit integers.
+    ByteArray(Vec<i8>),
+    /// A string, e.g. an item name with color codes.
+    String(String),
+    /// A list of unnamed tags of the same type.
+    List(Vec<Tag>),
+    /// A set of named tags, e.g. the `ExtraAttributes` of an item.
+    Compound(HashMap<String, Tag>),
+    /// An array of signed 32-bit integers.
+    IntArray(Vec<i32>),
+    /// An array of signed 64-bit integers.
+    LongArray(Vec<i64>),
+}
+
+impl Tag {
+    /// Returns the child tag with name `key` if this tag is a compound.
+    pub fn get(&self, key: &str) -> Option<&Tag> {
+        self.as_compound()?.get(key)
+    }
+
+    /// Follows `path` through nested compounds, returning the tag at the end.
+    pub fn path(&self, path: &[&str]) -> Option<&Tag> {
+        path.iter().try_fold(self, |tag, key| tag.get(key))
+    }
+
+    /// Returns any integer tag as an `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Tag::Byte(value) => Some(*value as i64),
+            Tag::Short(value) => Some(*value as i64),
+            Tag::Int(value) => Some(*value as i64),
+            Tag::Long(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns any numeric tag as an `f64`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Tag::Float(value) => Some(*value as f64),
+            Tag::Double(value) => Some(*value),
+            _ => self.as_i64().map(|value| value as f64),
+        }
+    }
+
+    /// Returns the value of this tag if it is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Tag::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the tags of this tag if it is a list.
+    pub fn as_list(&self) -> Option<&[Tag]> {
+        match self {
+            Tag::List(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the named child tags of this tag if it is a compound.
+    pub fn as_compound(&self) -> Option<&HashMap<String, Tag>> {
+        match self {
+            Tag::Compound(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Decodes base64 encoded, gzipped NBT data (like `item_bytes`) into its root compound tag.
+pub fn decode_item_bytes(data: &str) -> Result<Tag, HypixelApiError> {
+    let compressed = STANDARD.decode(data.trim())
+        .map_err(|err| HypixelApiError::NbtDecodeError(err.to_string()))?;
+    decode_gzipped(&compressed)
+}
+
+/// Decodes base64 encoded, gzipped NBT data (like `item_bytes`) into the SkyBlock items it contains.
+///
+/// Empty inventory slots are skipped.
+pub fn decode_items(data: &str) -> Result<Vec<SkyblockItem>, HypixelApiError> {
+    let root = decode_item_bytes(data)?;
+    Ok(root.get("i")
+        .and_then(Tag::as_list)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(SkyblockItem::from_tag)
+        .collect())
+}
+
+/// Decodes gzipped NBT data into its root compound tag.
+pub fn decode_gzipped(data: &[u8]) -> Result<Tag, HypixelApiError> {
+    let mut bytes = Vec::new();
+    GzDecoder::new(data).read_to_end(&mut bytes)
+        .map_err(|err| HypixelApiError::NbtDecodeError(err.to_string()))?;
+    decode(&bytes)
+}
+
+/// Decodes uncompressed NBT data into its root compound tag.
+pub fn decode(data: &[u8]) -> Result<Tag, HypixelApiError> {
+    let mut reader = Reader { data, position: 0 };
+    match reader.u8()? {
+        COMPOUND => {
+            reader.string()?;
+            reader.payload(COMPOUND, 0)
+        }
+        id => Err(HypixelApiError::NbtDecodeError(format!("root tag is not a compound (id {})", id))),
+    }
+}
+
+const END: u8 = 0;
+const COMPOUND: u8 = 10;
+/// Protects against stack overflows on malicious input.
+const MAX_DEPTH: usize = 512;
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], HypixelApiError> {
+        let bytes = self.position.checked_add(len)
+            .and_then(|end| self.data.get(self.position..end))
+            .ok_or_else(|| HypixelApiError::NbtDecodeError("unexpected end of data".to_string()))?;
+        self.position += len;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], HypixelApiError> {
+        Ok(self.bytes(N)?.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, HypixelApiError> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn len(&mut self) -> Result<usize, HypixelApiError> {
+        let len = i32::from_be_bytes(self.array()?);
+        Ok(len.max(0) as usize)
+    }
+
+    fn string(&mut self) -> Result<String, HypixelApiError> {
+        let len = u16::from_be_bytes(self.array()?) as usize;
+        // Java's modified UTF-8 is identical to UTF-8 for all characters used in item data
+        Ok(String::from_utf8_lossy(self.bytes(len)?).into_owned())
+    }
+
+    fn payload(&mut self, id: u8, depth: usize) -> Result<Tag, HypixelApiError> {
+        if depth > MAX_DEPTH {
+            return Err(HypixelApiError::NbtDecodeError("tags are nested too deeply".to_string()));
+        }
+        Ok(match id {
+            1 => Tag::Byte(i8::from_be_bytes(self.array()?)),
+            2 => Tag::Short(i16::from_be_bytes(self.array()?)),
+            3 => Tag::Int(i32::from_be_bytes(self.array()?)),
+            4 => Tag::Long(i64::from_be_bytes(self.array()?)),
+            5 => Tag::Float(f32::from_be_bytes(self.array()?)),
+            6 => Tag::Double(f64::from_be_bytes(self.array()?)),
+            7 => {
+                let len = self.len()?;
+                Tag::ByteArray(self.bytes(len)?.iter().map(|byte| *byte as i8).collect())
+            }
+            8 => Tag::String(self.string()?),
+            9 => {
+                let element = self.u8()?;
+                let len = self.len()?;
+                let mut list = Vec::with_capacity(len.min(self.data.len()));
+                for _ in 0..len {
+                    list.push(self.payload(element, depth + 1)?);
+                }
+                Tag::List(list)
+            }
+            COMPOUND => {
+                let mut compound = HashMap::new();
+                loop {
+                    let child = self.u8()?;
+                    if child == END {
+                        break;
+                    }
+                    let name = self.string()?;
+                    compound.insert(name, self.payload(child, depth + 1)?);
+                }
+                Tag::Compound(compound)
+            }
+            11 => {
+                let len = self.len()?;
+                let bytes = self.bytes(len.saturating_mul(4))?;
+                Tag::IntArray(bytes.chunks_exact(4).map(|chunk| i32::from_be_bytes(chunk.try_into().unwrap())).collect())
+            }
+            12 => {
+                let len = self.len()?;
+                let bytes = self.bytes(len.saturating_mul(8))?;
+                Tag::LongArray(bytes.chunks_exact(8).map(|chunk| i64::from_be_bytes(chunk.try_into().unwrap())).collect())
+            }
+            id => return Err(HypixelApiError::NbtDecodeError(format!("unknown tag id {}", id))),
+        })
+    }
+}
@@ -0,0 +1,27 @@
+//! Ratios of game stats like the kill/death ratio, as shown by Hypixel and stats sites.
+//!
+//! # Examples
+//! ```rust
+//! use hypixel_api::util::ratios::{ratio, win_rate};
+//!
+//! assert_eq!(ratio(45, 15), 3.0);
+//! assert_eq!(ratio(45, 0), 45.0);
+//! assert_eq!(win_rate(3, 1), 0.75);
+//! assert_eq!(win_rate(0, 0), 0.0);
+//! ```
+
+/// Returns `numerator / denominator`, or `numerator` if `denominator` is `0`.
+///
+/// This follows the convention of Hypixel and most stats sites: a player without deaths
+/// has their kills as kill/death ratio.
+pub fn ratio(numerator: u64, denominator: u64) -> f64 {
+    numerator as f64 / denominator.max(1) as f64
+}
+
+/// Returns the share of games won as a value from `0.0` to `1.0`, or `0.0` if no games were played.
+pub fn win_rate(wins: u64, losses: u64) -> f64 {
+    match wins + losses {
+        0 => 0.0,
+        games => wins as f64 / games as f64,
+    }
+}
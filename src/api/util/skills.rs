@@ -0,0 +1,78 @@
+//! Utilities to calculate the SkyBlock skill average of a profile member.
+//!
+//! The skill average is the mean level of all skills except the cosmetic ones
+//! ([`COSMETIC_SKILLS`]), using the leveling tables of the skills resources
+//! ([`SkillsResourcesReply`]).
+
+use std::collections::HashMap;
+
+use crate::api::reply::SkillsResourcesReply;
+use crate::api::util::leveling::skyblock;
+
+/// Skills that do not count towards the skill average.
+pub const COSMETIC_SKILLS: [&str; 2] = ["RUNECRAFTING", "SOCIAL"];
+
+/// The skill average of a profile member, see [`skill_average`].
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct SkillAverage {
+    average: f64,
+    average_with_progress: f64,
+}
+
+impl SkillAverage {
+    /// Returns the average of the (floored) skill levels, as shown in game.
+    pub fn average(&self) -> f64 {
+        self.average
+    }
+
+    /// Returns the average of the exact skill levels, including the progress towards the next level.
+    pub fn average_with_progress(&self) -> f64 {
+        self.average_with_progress
+    }
+}
+
+/// Calculates the skill average from the skill experience of a profile member.
+///
+/// `experience` maps skills to their experience, keyed by skill id in any of the formats used
+/// by the API: `"SKILL_FARMING"`, `"experience_skill_farming"` or `"FARMING"`. Missing skills count as level 0.
+///
+/// If `capped` is `false`, experience beyond the maximum level of a skill keeps adding levels,
+/// each requiring as much experience as the last level of the skill.
+pub fn skill_average(experience: &HashMap<String, f64>, resources: &SkillsResourcesReply, capped: bool) -> SkillAverage {
+    let experience: HashMap<String, f64> = experience.iter()
+        .map(|(key, exp)| (normalize(key), *exp))
+        .collect();
+
+    let mut count = 0;
+    let mut levels = 0.0;
+    let mut exact_levels = 0.0;
+    for id in resources.ids().filter(|id| !COSMETIC_SKILLS.contains(id)) {
+        let table = resources.skill(id).map(|skill| skill.xp_table()).unwrap_or_default();
+        let exp = experience.get(id).copied().unwrap_or(0.0);
+        let level = if capped { skyblock::exact_level(exp, &table) } else { uncapped_level(exp, &table) };
+        count += 1;
+        levels += level.floor();
+        exact_levels += level;
+    }
+    if count == 0 {
+        return SkillAverage::default();
+    }
+    SkillAverage {
+        average: levels / count as f64,
+        average_with_progress: exact_levels / count as f64,
+    }
+}
+
+fn uncapped_level(exp: f64, table: &[f64]) -> f64 {
+    let level = skyblock::exact_level(exp, table);
+    match table {
+        [.., before_last, last] if exp > *last => level + (exp - last) / (last - before_last),
+        _ => level,
+    }
+}
+
+fn normalize(key: &str) -> String {
+    let key = key.to_ascii_uppercase();
+    let key = key.strip_prefix("EXPERIENCE_").unwrap_or(&key);
+    key.strip_prefix("SKILL_").unwrap_or(key).to_string()
+}
@@ -0,0 +1,193 @@
+//! Utilities to work with the timestamps returned by the Hypixel API.
+//!
+//! Almost all timestamps are sent as milliseconds since the unix epoch, although some
+//! (mostly SkyBlock) fields use seconds or send their value as a string.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::America::New_York;
+use serde_json::Value;
+
+/// Timestamps below this value are considered to be in seconds rather than milliseconds.
+///
+/// In milliseconds this is early 1973, in seconds this is the year 5138.
+const SECONDS_THRESHOLD: i64 = 100_000_000_000;
+
+/// Converts milliseconds since the unix epoch to a [`DateTime<Utc>`].
+///
+/// Returns [`Option::None`] if the value is out of range.
+pub fn from_millis(millis: u64) -> Option<DateTime<Utc>> {
+    Utc.timestamp_millis_opt(i64::try_from(millis).ok()?).single()
+}
+
+/// Parses a timestamp field in any of the formats used by the Hypixel API.
+///
+/// This accepts integers, floats and numeric strings, in either seconds or milliseconds
+/// since the unix epoch (like SkyBlock's `last_save`).
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::util::time::parse_timestamp;
+/// use serde_json::json;
+///
+/// let millis = parse_timestamp(&json!(1650000000000u64)).unwrap();
+/// let seconds = parse_timestamp(&json!("1650000000")).unwrap();
+/// assert_eq!(millis, seconds);
+/// ```
+pub fn parse_timestamp(value: &Value) -> Option<DateTime<Utc>> {
+    let number = match value {
+        Value::Number(number) => number.as_i64().or_else(|| number.as_f64().map(|n| n as i64))?,
+        Value::String(string) => {
+            let string = string.trim();
+            string.parse::<i64>().ok().or_else(|| string.parse::<f64>().ok().map(|n| n as i64))?
+        }
+        _ => return None,
+    };
+    if number < 0 {
+        return None;
+    }
+    if number < SECONDS_THRESHOLD {
+        Utc.timestamp_opt(number, 0).single()
+    } else {
+        Utc.timestamp_millis_opt(number).single()
+    }
+}
+
+/// Formats `time` relative to the current time (e.g. `"3h ago"`), see [`format_relative_to`].
+pub fn format_relative<Tz: TimeZone>(time: &DateTime<Tz>) -> String {
+    format_relative_to(time, &Utc::now())
+}
+
+/// Formats `time` relative to `now` using the largest fitting unit.
+///
+/// # Examples
+/// ```rust
+/// use chrono::{Duration, Utc};
+/// use hypixel_api::util::time::format_relative_to;
+///
+/// let now = Utc::now();
+/// assert_eq!(format_relative_to(&(now - Duration::hours(3)), &now), "3h ago");
+/// assert_eq!(format_relative_to(&(now + Duration::days(2)), &now), "in 2d");
+/// assert_eq!(format_relative_to(&now, &now), "just now");
+/// ```
+pub fn format_relative_to<Tz: TimeZone, Tz2: TimeZone>(time: &DateTime<Tz>, now: &DateTime<Tz2>) -> String {
+    let difference = time.clone().with_timezone(&Utc) - now.clone().with_timezone(&Utc);
+    let future = difference > Duration::zero();
+    let seconds = difference.num_seconds().unsigned_abs();
+
+    let (amount, unit) = match seconds {
+        0..=9 => return "just now".to_string(),
+        10..=59 => (seconds, "s"),
+        60..=3_599 => (seconds / 60, "m"),
+        3_600..=86_399 => (seconds / 3_600, "h"),
+        86_400..=2_591_999 => (seconds / 86_400, "d"),
+        2_592_000..=31_535_999 => (seconds / 2_592_000, "mo"),
+        _ => (seconds / 31_536_000, "y"),
+    };
+    if future {
+        format!("in {}{}", amount, unit)
+    } else {
+        format!("{}{} ago", amount, unit)
+    }
+}
+
+/// Returns the last daily quest reset at or before `now`.
+///
+/// Daily quests reset at midnight in Hypixel's time zone (`America/New_York`), so the reset
+/// moves by an hour in UTC whenever New York switches to or from daylight saving time.
+///
+/// # Examples
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use hypixel_api::util::time::{last_daily_reset, next_daily_reset};
+///
+/// let now = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+/// // midnight in New York is 04:00 UTC during daylight saving time
+/// assert_eq!(last_daily_reset(now), Utc.with_ymd_and_hms(2024, 7, 1, 4, 0, 0).unwrap());
+/// assert_eq!(next_daily_reset(now), Utc.with_ymd_and_hms(2024, 7, 2, 4, 0, 0).unwrap());
+/// ```
+pub fn last_daily_reset(now: DateTime<Utc>) -> DateTime<Utc> {
+    new_york_midnight(now.with_timezone(&New_York).date_naive())
+}
+
+/// Returns the first daily quest reset after `now`, see [`last_daily_reset`].
+pub fn next_daily_reset(now: DateTime<Utc>) -> DateTime<Utc> {
+    new_york_midnight(now.with_timezone(&New_York).date_naive() + Duration::days(1))
+}
+
+/// Returns the last weekly quest reset at or before `now`.
+///
+/// Weekly quests reset every Friday at midnight in Hypixel's time zone (`America/New_York`).
+pub fn last_weekly_reset(now: DateTime<Utc>) -> DateTime<Utc> {
+    let today = now.with_timezone(&New_York).date_naive();
+    let days_since_friday = (today.weekday().num_days_from_monday() + 7 - Weekday::Fri.num_days_from_monday()) % 7;
+    new_york_midnight(today - Duration::days(i64::from(days_since_friday)))
+}
+
+/// Returns the first weekly quest reset after `now`, see [`last_weekly_reset`].
+pub fn next_weekly_reset(now: DateTime<Utc>) -> DateTime<Utc> {
+    let last = last_weekly_reset(now).with_timezone(&New_York).date_naive();
+    new_york_midnight(last + Duration::days(7))
+}
+
+/// Returns `true` if a quest completed at `completed` counts as completed today, i.e. it was
+/// completed after the last daily reset at `now`.
+///
+/// # Examples
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use hypixel_api::util::time::completed_today;
+///
+/// // 03:00 UTC on the 1st of March is still the 29th of February in New York
+/// let completed = Utc.with_ymd_and_hms(2024, 3, 1, 3, 0, 0).unwrap();
+/// assert!(completed_today(completed, Utc.with_ymd_and_hms(2024, 3, 1, 4, 0, 0).unwrap()));
+/// assert!(!completed_today(completed, Utc.with_ymd_and_hms(2024, 3, 1, 6, 0, 0).unwrap()));
+/// ```
+pub fn completed_today(completed: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    completed >= last_daily_reset(now) && completed <= now
+}
+
+/// Returns `true` if a quest completed at `completed` counts as completed this week, i.e. it
+/// was completed after the last weekly reset at `now`.
+pub fn completed_this_week(completed: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    completed >= last_weekly_reset(now) && completed <= now
+}
+
+fn new_york_midnight(date: NaiveDate) -> DateTime<Utc> {
+    // New York changes its clocks at 2 AM, so midnight always exists exactly once
+    New_York.from_local_datetime(&date.and_time(NaiveTime::MIN)).unwrap().with_timezone(&Utc)
+}
+
+/// Converts the timestamps returned by this crate to [`time::OffsetDateTime`], for projects
+/// that use the `time` crate rather than `chrono`.
+///
+/// This is implemented for [`DateTime`] and [`Option<DateTime>`], the return types of all
+/// timestamp getters. Timestamps outside the range supported by `time` (years `-9999` to `9999`)
+/// are converted to [`Option::None`].
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::util::time::{from_millis, IntoOffsetDateTime};
+///
+/// let time = from_millis(1650000000000).into_offset_date_time().unwrap();
+/// assert_eq!(time.unix_timestamp(), 1650000000);
+/// ```
+#[cfg(feature = "time")]
+pub trait IntoOffsetDateTime {
+    /// Converts this timestamp to a [`time::OffsetDateTime`] in UTC.
+    fn into_offset_date_time(self) -> Option<time::OffsetDateTime>;
+}
+
+#[cfg(feature = "time")]
+impl<Tz: TimeZone> IntoOffsetDateTime for DateTime<Tz> {
+    fn into_offset_date_time(self) -> Option<time::OffsetDateTime> {
+        let seconds = time::OffsetDateTime::from_unix_timestamp(self.timestamp()).ok()?;
+        seconds.checked_add(time::Duration::nanoseconds(i64::from(self.timestamp_subsec_nanos())))
+    }
+}
+
+#[cfg(feature = "time")]
+impl<Tz: TimeZone> IntoOffsetDateTime for Option<DateTime<Tz>> {
+    fn into_offset_date_time(self) -> Option<time::OffsetDateTime> {
+        self.and_then(IntoOffsetDateTime::into_offset_date_time)
+    }
+}
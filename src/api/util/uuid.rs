@@ -0,0 +1,68 @@
+//! Utilities to work with the player UUIDs returned by the Hypixel API.
+//!
+//! Most endpoints return UUIDs without dashes (`"ad8fefaa8351454bb739a4eaa872173f"`) while
+//! Mojang and most other tools use the dashed form (`"ad8fefaa-8351-454b-b739-a4eaa872173f"`).
+//! The functions in this module accept both forms.
+
+use ::uuid::Uuid;
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+
+/// Parses a UUID in either its dashed or undashed form.
+///
+/// Surrounding whitespace, braces and quotes are ignored.
+pub fn parse(uuid: &str) -> Option<Uuid> {
+    let trimmed = uuid.trim()
+        .trim_matches('"')
+        .trim_start_matches('{')
+        .trim_end_matches('}');
+    let simple: String = trimmed.chars().filter(|c| *c != '-').collect();
+    if simple.len() != 32 {
+        return None;
+    }
+    Uuid::try_parse(&simple).ok()
+}
+
+/// Converts a UUID string to its dashed, lowercase form.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::util::uuid::dashed;
+///
+/// assert_eq!(dashed("AD8FEFAA8351454BB739A4EAA872173F").unwrap(), "ad8fefaa-8351-454b-b739-a4eaa872173f");
+/// ```
+pub fn dashed(uuid: &str) -> Option<String> {
+    parse(uuid).map(|uuid| uuid.hyphenated().to_string())
+}
+
+/// Converts a UUID string to its undashed, lowercase form, as used by the Hypixel API.
+///
+/// # Examples
+/// ```rust
+/// use hypixel_api::util::uuid::undashed;
+///
+/// assert_eq!(undashed("ad8fefaa-8351-454b-b739-a4eaa872173f").unwrap(), "ad8fefaa8351454bb739a4eaa872173f");
+/// ```
+pub fn undashed(uuid: &str) -> Option<String> {
+    parse(uuid).map(|uuid| uuid.simple().to_string())
+}
+
+/// Deserializes a UUID in either its dashed or undashed form.
+///
+/// Use with `#[serde(deserialize_with = "hypixel_api::util::uuid::deserialize")]`.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+    let uuid = String::deserialize(deserializer)?;
+    parse(&uuid).ok_or_else(|| D::Error::custom(format!("invalid uuid: {}", uuid)))
+}
+
+/// Deserializes an optional UUID in either its dashed or undashed form.
+///
+/// Use with `#[serde(default, deserialize_with = "hypixel_api::util::uuid::deserialize_option")]`.
+pub fn deserialize_option<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Uuid>, D::Error> {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(uuid) => parse(&uuid)
+            .map(Some)
+            .ok_or_else(|| D::Error::custom(format!("invalid uuid: {}", uuid))),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use crate::api::error::HypixelApiError;
+
+/// A file of recorded responses, used to record and replay requests of a
+/// [`RequestHandler`](crate::RequestHandler) without network access.
+///
+/// Only successful responses are recorded, together with the path they were requested from.
+/// The `ApiKey` is never written to a cassette.
+///
+/// # Examples
+/// ```rust,no_run
+/// use hypixel_api::{Cassette, RequestHandler, StatusReply};
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), hypixel_api::error::HypixelApiError> {
+/// // Record the responses of a live run ...
+/// # let api_key = Uuid::nil();
+/// let request_handler = RequestHandler::builder(api_key).record("tests/cassettes/status.json").build();
+/// request_handler.request::<StatusReply>("status?uuid=069a79f4-44e9-4726-a5be-fca90e38aaf5", true).await.unwrap()?;
+///
+/// // ... and serve them back in CI.
+/// let request_handler = RequestHandler::builder(Uuid::nil())
+///     .replay(Cassette::load("tests/cassettes/status.json")?)
+///     .build();
+/// let reply = request_handler.request::<StatusReply>("status?uuid=069a79f4-44e9-4726-a5be-fca90e38aaf5", true).await.unwrap()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    interactions: Mutex<Vec<Interaction>>,
+    replayed: Mutex<HashMap<String, usize>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    path: String,
+    body: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Creates an empty cassette that will be written to `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Cassette {
+            path: path.into(),
+            interactions: Mutex::new(Vec::new()),
+            replayed: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Loads the cassette at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file could not be read or is not a valid cassette.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, HypixelApiError> {
+        let path = path.into();
+        let file: CassetteFile = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        let cassette = Cassette::new(path);
+        *cassette.interactions.lock() = file.interactions;
+        Ok(cassette)
+    }
+
+    /// Returns the path of the file backing this cassette.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the amount of recorded responses.
+    pub fn len(&self) -> usize {
+        self.interactions.lock().len()
+    }
+
+    /// Returns `true` if this cassette contains no responses.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends a response to this cassette and writes it to its file.
+    pub(crate) fn record(&self, path: &str, body: &str) -> Result<(), HypixelApiError> {
+        let mut interactions = self.interactions.lock();
+        interactions.push(Interaction { path: path.to_string(), body: body.to_string() });
+        let file = CassetteFile { interactions: interactions.clone() };
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&file)?)?;
+        Ok(())
+    }
+
+    /// Returns the body of the next recorded response for `path`.
+    ///
+    /// Responses of the same path are served in recorded order, the last one is repeated.
+    pub(crate) fn replay(&self, path: &str) -> Result<String, HypixelApiError> {
+        let interactions = self.interactions.lock();
+        let matching: Vec<&Interaction> = interactions.iter().filter(|i| i.path == path).collect();
+        let mut replayed = self.replayed.lock();
+        let index = replayed.entry(path.to_string()).or_insert(0);
+        let interaction = matching.get(*index).or_else(|| matching.last())
+            .ok_or_else(|| HypixelApiError::CassetteMiss(path.to_string()))?;
+        *index += 1;
+        Ok(interaction.body.clone())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum Vcr {
+    Record(Cassette),
+    Replay(Cassette),
+}
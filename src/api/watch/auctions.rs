@@ -0,0 +1,140 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use futures::Stream;
+use crate::api::error::HypixelApiError;
+use crate::api::request::HypixelApi;
+use crate::api::util::auctions::{scan_all_auctions, AuctionDiff, AuctionIndex, ItemKey};
+use crate::api::watch::{poll_stream, Poller};
+
+/// The result of a scan of an [`AuctionWatcher`].
+#[derive(Debug, Clone)]
+pub struct AuctionUpdate {
+    index: Arc<AuctionIndex>,
+    diff: AuctionDiff,
+}
+
+impl AuctionUpdate {
+    /// Returns the index of all auctions of this scan.
+    pub fn index(&self) -> &Arc<AuctionIndex> {
+        &self.index
+    }
+
+    /// Returns the auctions that were created and ended since the previous scan.
+    ///
+    /// The diff of the first scan is empty.
+    pub fn diff(&self) -> &AuctionDiff {
+        &self.diff
+    }
+}
+
+/// Scans the whole auction house periodically and yields an [`AuctionUpdate`] per scan.
+///
+/// Every scan fetches all pages of `/skyblock/auctions` with [`scan_all_auctions`] and
+/// indexes them into an [`AuctionIndex`]. Items are identified by their name unless
+/// configured otherwise, see [`AuctionWatcher::key`].
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use futures::StreamExt;
+/// use hypixel_api::RequestHandler;
+/// use hypixel_api::watch::AuctionWatcher;
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let api_key = Uuid::nil();
+/// let request_handler = Arc::new(RequestHandler::new(api_key));
+/// let watcher = AuctionWatcher::new(request_handler, Duration::from_secs(60)).concurrency(8);
+/// let mut updates = Box::pin(watcher.into_stream());
+/// while let Some(update) = updates.next().await {
+///     if let Ok(update) = update {
+///         println!("{} new auctions", update.diff().new_auctions().len());
+///         println!("{:?}", update.index().lowest_bin("Aspect of the End").map(|auction| auction.starting_bid()));
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AuctionWatcher<A> {
+    api: A,
+    interval: Duration,
+    concurrency: usize,
+    key: ItemKey,
+}
+
+impl<A: std::fmt::Debug> std::fmt::Debug for AuctionWatcher<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuctionWatcher")
+            .field("api", &self.api)
+            .field("interval", &self.interval)
+            .field("concurrency", &self.concurrency)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: HypixelApi> AuctionWatcher<A> {
+    /// Creates a watcher that scans the auction house once per `interval`.
+    ///
+    /// The auction house is updated by the API about every minute, shorter intervals only use up the rate limit.
+    pub fn new(api: A, interval: Duration) -> Self {
+        AuctionWatcher {
+            api,
+            interval,
+            concurrency: 4,
+            key: Arc::new(|auction| Some(auction.item_name().to_string())),
+        }
+    }
+
+    /// Sets the amount of pages that are requested at once, defaults to `4`.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Sets the function that identifies the item of an auction,
+    /// e.g. `util::auctions::item_id` with the `nbt` feature enabled.
+    pub fn key(mut self, key: impl Fn(&crate::api::reply::Auction) -> Option<String> + Send + Sync + 'static) -> Self {
+        self.key = Arc::new(key);
+        self
+    }
+
+    /// Starts scanning and returns the [`Stream`] of updates.
+    ///
+    /// A failed scan is yielded as an error, the next update is compared against the last
+    /// successful scan. Scanning stops when the stream is dropped.
+    pub fn into_stream(self) -> impl Stream<Item = Result<AuctionUpdate, HypixelApiError>> {
+        poll_stream(AuctionPoller {
+            watcher: self,
+            previous: None,
+        })
+    }
+}
+
+struct AuctionPoller<A> {
+    watcher: AuctionWatcher<A>,
+    previous: Option<Arc<AuctionIndex>>,
+}
+
+impl<A: HypixelApi> Poller for AuctionPoller<A> {
+    type Event = Result<AuctionUpdate, HypixelApiError>;
+
+    fn interval(&self) -> Duration {
+        self.watcher.interval
+    }
+
+    async fn poll(&mut self, events: &mut VecDeque<Self::Event>) {
+        let scan = match scan_all_auctions(&self.watcher.api, self.watcher.concurrency).await {
+            Ok(scan) => scan,
+            Err(error) => return events.push_back(Err(error)),
+        };
+        let index = Arc::new(AuctionIndex::from_scan(scan, Arc::clone(&self.watcher.key)));
+        let diff = self.previous.as_ref()
+            .map(|previous| index.diff(previous))
+            .unwrap_or_default();
+        self.previous = Some(Arc::clone(&index));
+        events.push_back(Ok(AuctionUpdate { index, diff }));
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use parking_lot::RwLock;
+use crate::api::error::HypixelApiError;
+use crate::api::reply::{BazaarQuickStatus, SkyblockBazaarReply};
+use crate::api::request::HypixelApi;
+use crate::api::watch::{poll_stream, Poller};
+
+/// The side of the bazaar a price belongs to, see [`BazaarQuickStatus`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PriceSide {
+    /// The price to instantly buy a product, [`BazaarQuickStatus::buy_price`].
+    Buy,
+    /// The price received when instantly selling a product, [`BazaarQuickStatus::sell_price`].
+    Sell,
+}
+
+/// A change of the bazaar observed by a [`BazaarTracker`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum BazaarEvent {
+    /// The price of a product moved beyond the threshold since the last event of that price.
+    PriceMoved {
+        product_id: String,
+        side: PriceSide,
+        previous: f64,
+        current: f64,
+    },
+}
+
+impl BazaarEvent {
+    /// Returns the id of the product this event belongs to.
+    pub fn product_id(&self) -> &str {
+        match self {
+            BazaarEvent::PriceMoved { product_id, .. } => product_id,
+        }
+    }
+
+    /// Returns the relative change of the price, e.g. `0.1` for a rise of 10%.
+    ///
+    /// A price that rose from `0` has a change of [`f64::INFINITY`].
+    pub fn change(&self) -> f64 {
+        match self {
+            BazaarEvent::PriceMoved { previous, current, .. } => relative_change(*previous, *current),
+        }
+    }
+}
+
+/// The latest quick status of every product seen by a [`BazaarTracker`], see [`BazaarTracker::prices`].
+///
+/// This is a cheap handle to state shared with the tracker, it is updated by every poll.
+#[derive(Debug, Clone, Default)]
+pub struct BazaarPrices {
+    inner: Arc<RwLock<PricesData>>,
+}
+
+#[derive(Debug, Default)]
+struct PricesData {
+    last_updated: Option<DateTime<Utc>>,
+    products: HashMap<String, BazaarQuickStatus>,
+}
+
+impl BazaarPrices {
+    /// Returns the latest quick status of the product `id`, if present.
+    pub fn get(&self, id: &str) -> Option<BazaarQuickStatus> {
+        self.inner.read().products.get(id).copied()
+    }
+
+    /// Returns the latest quick status of every product, keyed by product id.
+    pub fn snapshot(&self) -> HashMap<String, BazaarQuickStatus> {
+        self.inner.read().products.clone()
+    }
+
+    /// Returns the time the bazaar data of the latest poll was updated by the API.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.inner.read().last_updated
+    }
+}
+
+/// Polls `/skyblock/bazaar` periodically and yields a [`BazaarEvent`] whenever the buy or sell
+/// price of a product moves beyond a threshold.
+///
+/// Prices are compared against the price of the last event of the same product and side
+/// (or the first observed price), so slow drifts are reported as well. The threshold is a
+/// relative change and defaults to `0.05`, a change of 5%.
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use futures::StreamExt;
+/// use hypixel_api::RequestHandler;
+/// use hypixel_api::watch::{BazaarEvent, BazaarTracker};
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let api_key = Uuid::nil();
+/// let request_handler = Arc::new(RequestHandler::new(api_key));
+/// let tracker = BazaarTracker::new(request_handler, Duration::from_secs(20))
+///     .threshold(0.1)
+///     .products(["ENCHANTED_DIAMOND", "BOOSTER_COOKIE"]);
+/// let prices = tracker.prices();
+/// let mut events = Box::pin(tracker.into_stream());
+/// while let Some(event) = events.next().await {
+///     if let Ok(event) = event {
+///         println!("{} moved by {:.1}%", event.product_id(), event.change() * 100.0);
+///         println!("{:?}", prices.get(event.product_id()));
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BazaarTracker<A> {
+    api: A,
+    interval: Duration,
+    threshold: f64,
+    products: Option<HashSet<String>>,
+    prices: BazaarPrices,
+}
+
+impl<A: HypixelApi> BazaarTracker<A> {
+    /// Creates a tracker that polls the bazaar once per `interval`.
+    ///
+    /// The bazaar is updated by the API about every 20 seconds, shorter intervals only use up the rate limit.
+    pub fn new(api: A, interval: Duration) -> Self {
+        BazaarTracker {
+            api,
+            interval,
+            threshold: 0.05,
+            products: None,
+            prices: BazaarPrices::default(),
+        }
+    }
+
+    /// Sets the relative change of a price that triggers an event, e.g. `0.05` for 5%.
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Only tracks the products with the given ids, all products are tracked by default.
+    pub fn products<S: Into<String>>(mut self, ids: impl IntoIterator<Item = S>) -> Self {
+        self.products = Some(ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Returns a handle to the latest quick status of every tracked product.
+    pub fn prices(&self) -> BazaarPrices {
+        self.prices.clone()
+    }
+
+    /// Starts polling and returns the [`Stream`] of events.
+    ///
+    /// A failed request is yielded as an error. Polling stops when the stream is dropped.
+    pub fn into_stream(self) -> impl Stream<Item = Result<BazaarEvent, HypixelApiError>> {
+        poll_stream(BazaarPoller {
+            tracker: self,
+            references: HashMap::new(),
+        })
+    }
+}
+
+struct BazaarPoller<A> {
+    tracker: BazaarTracker<A>,
+    /// The price every product and side was last compared against.
+    references: HashMap<(String, PriceSide), f64>,
+}
+
+impl<A: HypixelApi> Poller for BazaarPoller<A> {
+    type Event = Result<BazaarEvent, HypixelApiError>;
+
+    fn interval(&self) -> Duration {
+        self.tracker.interval
+    }
+
+    async fn poll(&mut self, events: &mut VecDeque<Self::Event>) {
+        let reply = match self.tracker.api.request::<SkyblockBazaarReply>("skyblock/bazaar", false).await {
+            Ok(Ok(reply)) => reply,
+            Ok(Err(error)) => return events.push_back(Err(error)),
+            Err(error) => return events.push_back(Err(error.into())),
+        };
+        let tracked = |id: &str| self.tracker.products.as_ref().is_none_or(|products| products.contains(id));
+        let mut statuses: Vec<_> = reply.products().iter()
+            .filter(|(id, _)| tracked(id))
+            .filter_map(|(id, product)| product.quick_status().map(|status| (id, *status)))
+            .collect();
+        statuses.sort_by_key(|(id, _)| *id);
+        for (id, status) in &statuses {
+            for (side, price) in [(PriceSide::Buy, status.buy_price()), (PriceSide::Sell, status.sell_price())] {
+                let reference = self.references.entry((id.to_string(), side)).or_insert(price);
+                if relative_change(*reference, price).abs() > self.tracker.threshold {
+                    events.push_back(Ok(BazaarEvent::PriceMoved {
+                        product_id: id.to_string(),
+                        side,
+                        previous: *reference,
+                        current: price,
+                    }));
+                    *reference = price;
+                }
+            }
+        }
+        let mut prices = self.tracker.prices.inner.write();
+        prices.last_updated = reply.last_updated();
+        prices.products.extend(statuses.into_iter().map(|(id, status)| (id.clone(), status)));
+    }
+}
+
+fn relative_change(previous: f64, current: f64) -> f64 {
+    if previous == 0.0 {
+        if current == 0.0 { 0.0 } else { f64::INFINITY }
+    } else {
+        (current - previous) / previous.abs()
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use parking_lot::RwLock;
+use crate::api::error::HypixelApiError;
+use crate::api::reply::CountsReply;
+use crate::api::path::ApiPath;
+use crate::api::request::HypixelApi;
+use crate::api::watch::{poll_stream, Poller};
+
+/// The player counts of the network at a single point in time, see [`CountsSampler`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountsSample {
+    sampled_at: DateTime<Utc>,
+    total: u64,
+    games: HashMap<String, u64>,
+}
+
+impl CountsSample {
+    /// Returns when this sample was taken.
+    pub fn sampled_at(&self) -> DateTime<Utc> {
+        self.sampled_at
+    }
+
+    /// Returns the amount of players online on the whole network.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the amount of players of the game `name` (e.g. `"BEDWARS"`), if present.
+    pub fn game(&self, name: &str) -> Option<u64> {
+        self.games.get(name).copied()
+    }
+
+    /// Returns the amount of players of every game, keyed by type name.
+    pub fn games(&self) -> &HashMap<String, u64> {
+        &self.games
+    }
+}
+
+/// The minimum, maximum and average of the player counts within a window, see [`CountsHistory::stats`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CountsStats {
+    min: u64,
+    max: u64,
+    average: f64,
+    samples: usize,
+}
+
+impl CountsStats {
+    /// Returns the lowest count within the window.
+    pub fn min(&self) -> u64 {
+        self.min
+    }
+
+    /// Returns the highest count within the window.
+    pub fn max(&self) -> u64 {
+        self.max
+    }
+
+    /// Returns the average count within the window.
+    pub fn average(&self) -> f64 {
+        self.average
+    }
+
+    /// Returns the amount of samples within the window.
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+}
+
+/// The samples of a [`CountsSampler`], oldest first, see [`CountsSampler::history`].
+///
+/// This is a cheap handle to state shared with the sampler, it is updated by every poll.
+#[derive(Debug, Clone, Default)]
+pub struct CountsHistory {
+    inner: Arc<RwLock<VecDeque<CountsSample>>>,
+}
+
+impl CountsHistory {
+    /// Returns the most recent sample, if polled at least once.
+    pub fn latest(&self) -> Option<CountsSample> {
+        self.inner.read().back().cloned()
+    }
+
+    /// Returns the amount of players online on the whole network in the most recent sample.
+    pub fn current_total(&self) -> Option<u64> {
+        self.inner.read().back().map(CountsSample::total)
+    }
+
+    /// Returns the amount of players of the game `name` in the most recent sample.
+    pub fn current(&self, name: &str) -> Option<u64> {
+        self.inner.read().back().and_then(|sample| sample.game(name))
+    }
+
+    /// Returns all retained samples, oldest first.
+    pub fn samples(&self) -> Vec<CountsSample> {
+        self.inner.read().iter().cloned().collect()
+    }
+
+    /// Returns the amount of retained samples.
+    pub fn len(&self) -> usize {
+        self.inner.read().len()
+    }
+
+    /// Returns `true` if no samples were taken yet.
+    pub fn is_empty(&self) -> bool {
+        self.inner.read().is_empty()
+    }
+
+    /// Returns the statistics of the total player count over the samples taken within `window`
+    /// before the most recent sample.
+    pub fn total_stats(&self, window: Duration) -> Option<CountsStats> {
+        self.window_stats(window, |sample| Some(sample.total))
+    }
+
+    /// Returns the statistics of the player count of the game `name` over the samples taken
+    /// within `window` before the most recent sample.
+    ///
+    /// Samples that do not contain the game are skipped.
+    pub fn stats(&self, name: &str, window: Duration) -> Option<CountsStats> {
+        self.window_stats(window, |sample| sample.game(name))
+    }
+
+    fn window_stats(&self, window: Duration, count: impl Fn(&CountsSample) -> Option<u64>) -> Option<CountsStats> {
+        let samples = self.inner.read();
+        let start = samples.back()?.sampled_at - chrono::Duration::from_std(window).unwrap_or(chrono::Duration::MAX);
+        let counts: Vec<u64> = samples.iter()
+            .filter(|sample| sample.sampled_at >= start)
+            .filter_map(count)
+            .collect();
+        Some(CountsStats {
+            min: *counts.iter().min()?,
+            max: *counts.iter().max()?,
+            average: counts.iter().sum::<u64>() as f64 / counts.len() as f64,
+            samples: counts.len(),
+        })
+    }
+}
+
+/// Polls `/counts` periodically and retains a bounded history of the player counts.
+///
+/// Every poll yields the new [`CountsSample`], the retained samples can be queried at any time
+/// through [`CountsSampler::history`]. Once the history is full, the oldest sample is dropped.
+///
+/// With the `metrics` feature enabled, every sample is also recorded to the `PLAYERS` and
+/// `GAME_PLAYERS` gauges.
+#[cfg_attr(feature = "metrics", doc = "See [`PLAYERS`](crate::metrics::PLAYERS) and [`GAME_PLAYERS`](crate::metrics::GAME_PLAYERS).")]
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use futures::StreamExt;
+/// use hypixel_api::RequestHandler;
+/// use hypixel_api::watch::CountsSampler;
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let api_key = Uuid::nil();
+/// let request_handler = Arc::new(RequestHandler::new(api_key));
+/// let sampler = CountsSampler::new(request_handler, Duration::from_secs(60)).capacity(24 * 60);
+/// let history = sampler.history();
+/// tokio::spawn(sampler.into_stream().for_each(|_| async {}));
+///
+/// // later ...
+/// if let Some(stats) = history.stats("BEDWARS", Duration::from_secs(3600)) {
+///     println!("bedwars peaked at {} players in the past hour", stats.max());
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CountsSampler<A> {
+    api: A,
+    interval: Duration,
+    capacity: usize,
+    history: CountsHistory,
+}
+
+impl<A: HypixelApi> CountsSampler<A> {
+    /// Creates a sampler that polls the counts once per `interval`.
+    pub fn new(api: A, interval: Duration) -> Self {
+        CountsSampler {
+            api,
+            interval,
+            capacity: 1440,
+            history: CountsHistory::default(),
+        }
+    }
+
+    /// Sets the maximum amount of retained samples, defaults to `1440`.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Returns a handle to the retained samples.
+    pub fn history(&self) -> CountsHistory {
+        self.history.clone()
+    }
+
+    /// Starts polling and returns the [`Stream`] of samples.
+    ///
+    /// A failed request is yielded as an error and adds no sample. Polling stops when the
+    /// stream is dropped.
+    pub fn into_stream(self) -> impl Stream<Item = Result<CountsSample, HypixelApiError>> {
+        poll_stream(CountsPoller { sampler: self })
+    }
+}
+
+struct CountsPoller<A> {
+    sampler: CountsSampler<A>,
+}
+
+impl<A: HypixelApi> Poller for CountsPoller<A> {
+    type Event = Result<CountsSample, HypixelApiError>;
+
+    fn interval(&self) -> Duration {
+        self.sampler.interval
+    }
+
+    async fn poll(&mut self, events: &mut VecDeque<Self::Event>) {
+        let reply = match self.sampler.api.request_typed::<CountsReply>(ApiPath::Counts).await {
+            Ok(Ok(reply)) => reply,
+            Ok(Err(error)) => return events.push_back(Err(error)),
+            Err(error) => return events.push_back(Err(error.into())),
+        };
+        let sample = CountsSample {
+            sampled_at: Utc::now(),
+            total: reply.player_count(),
+            games: reply.games().iter().map(|(name, count)| (name.clone(), count.players())).collect(),
+        };
+        #[cfg(feature = "metrics")]
+        crate::api::metrics::record_counts(&sample);
+        let mut history = self.sampler.history.inner.write();
+        while history.len() >= self.sampler.capacity {
+            history.pop_front();
+        }
+        history.push_back(sample.clone());
+        events.push_back(Ok(sample));
+    }
+}
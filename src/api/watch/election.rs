@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use futures::Stream;
+use parking_lot::RwLock;
+use crate::api::error::HypixelApiError;
+use crate::api::reply::{Election, ElectionResourcesReply, Mayor, Perk};
+use crate::api::path::ApiPath;
+use crate::api::request::HypixelApi;
+use crate::api::watch::{poll_stream, Poller};
+
+/// A change of the SkyBlock election observed by an [`ElectionWatcher`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElectionEvent {
+    /// A new mayor took office.
+    MayorChanged {
+        previous: Box<Mayor>,
+        current: Box<Mayor>,
+    },
+    /// The perks of a candidate of the open election changed.
+    ///
+    /// A candidate that was not part of the open election before has no previous perks.
+    CandidatePerksChanged {
+        year: u32,
+        key: String,
+        previous: Vec<Perk>,
+        current: Vec<Perk>,
+    },
+}
+
+/// The latest state of the election seen by an [`ElectionWatcher`], see [`ElectionWatcher::state`].
+///
+/// This is a cheap handle to state shared with the watcher, it is updated by every poll.
+#[derive(Debug, Clone, Default)]
+pub struct ElectionState {
+    inner: Arc<RwLock<Option<ElectionResourcesReply>>>,
+}
+
+impl ElectionState {
+    /// Returns the latest election resources, if polled at least once.
+    pub fn get(&self) -> Option<ElectionResourcesReply> {
+        self.inner.read().clone()
+    }
+
+    /// Returns the mayor currently in office, if polled at least once.
+    pub fn mayor(&self) -> Option<Mayor> {
+        self.inner.read().as_ref().map(|reply| reply.mayor().clone())
+    }
+
+    /// Returns the election that is currently open, if any.
+    pub fn current(&self) -> Option<Election> {
+        self.inner.read().as_ref().and_then(|reply| reply.current().cloned())
+    }
+}
+
+/// Polls `/resources/skyblock/election` periodically and yields an [`ElectionEvent`] when a new
+/// mayor takes office or the perks of a candidate change.
+///
+/// The first poll only records the current state, events are yielded for the changes observed
+/// by later polls.
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use futures::StreamExt;
+/// use hypixel_api::RequestHandler;
+/// use hypixel_api::watch::{ElectionEvent, ElectionWatcher};
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let api_key = Uuid::nil();
+/// let request_handler = Arc::new(RequestHandler::new(api_key));
+/// let watcher = ElectionWatcher::new(request_handler, Duration::from_secs(600));
+/// let state = watcher.state();
+/// let mut events = Box::pin(watcher.into_stream());
+/// while let Some(event) = events.next().await {
+///     if let Ok(ElectionEvent::MayorChanged { current, .. }) = event {
+///         println!("{} took office", current.name());
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ElectionWatcher<A> {
+    api: A,
+    interval: Duration,
+    state: ElectionState,
+}
+
+impl<A: HypixelApi> ElectionWatcher<A> {
+    /// Creates a watcher that polls the election once per `interval`.
+    pub fn new(api: A, interval: Duration) -> Self {
+        ElectionWatcher {
+            api,
+            interval,
+            state: ElectionState::default(),
+        }
+    }
+
+    /// Returns a handle to the latest state of the election.
+    pub fn state(&self) -> ElectionState {
+        self.state.clone()
+    }
+
+    /// Starts polling and returns the [`Stream`] of events.
+    ///
+    /// A failed request is yielded as an error, the next poll is compared against the
+    /// last successful one. Polling stops when the stream is dropped.
+    pub fn into_stream(self) -> impl Stream<Item = Result<ElectionEvent, HypixelApiError>> {
+        poll_stream(ElectionPoller { watcher: self })
+    }
+}
+
+struct ElectionPoller<A> {
+    watcher: ElectionWatcher<A>,
+}
+
+impl<A: HypixelApi> Poller for ElectionPoller<A> {
+    type Event = Result<ElectionEvent, HypixelApiError>;
+
+    fn interval(&self) -> Duration {
+        self.watcher.interval
+    }
+
+    async fn poll(&mut self, events: &mut VecDeque<Self::Event>) {
+        let reply = match self.watcher.api.request_typed::<ElectionResourcesReply>(ApiPath::SkyblockElectionResources).await {
+            Ok(Ok(reply)) => reply,
+            Ok(Err(error)) => return events.push_back(Err(error)),
+            Err(error) => return events.push_back(Err(error.into())),
+        };
+        let mut state = self.watcher.state.inner.write();
+        if let Some(previous) = state.as_ref() {
+            let (previous_mayor, mayor) = (previous.mayor(), reply.mayor());
+            let term = |mayor: &Mayor| mayor.election().map(Election::year);
+            if previous_mayor.key() != mayor.key() || term(previous_mayor) != term(mayor) {
+                events.push_back(Ok(ElectionEvent::MayorChanged {
+                    previous: Box::new(previous_mayor.clone()),
+                    current: Box::new(mayor.clone()),
+                }));
+            }
+            if let Some(election) = reply.current() {
+                // candidates of a newly opened election are compared against no perks
+                let before = previous.current().filter(|before| before.year() == election.year());
+                for candidate in election.candidates() {
+                    let perks = before.and_then(|before| before.candidate(candidate.key())).map_or(&[][..], |before| before.perks());
+                    if perks != candidate.perks() {
+                        events.push_back(Ok(ElectionEvent::CandidatePerksChanged {
+                            year: election.year(),
+                            key: candidate.key().to_string(),
+                            previous: perks.to_vec(),
+                            current: candidate.perks().to_vec(),
+                        }));
+                    }
+                }
+            }
+        }
+        *state = Some(reply);
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use futures::Stream;
+use uuid::Uuid;
+use crate::api::error::HypixelApiError;
+use crate::api::reply::{GuildMember, GuildReply};
+use crate::api::path::ApiPath;
+use crate::api::request::HypixelApi;
+use crate::api::watch::{poll_stream, Poller};
+
+/// A change of the roster of a tracked guild, see [`GuildTracker`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuildEvent {
+    /// A player joined the guild.
+    MemberJoined {
+        member: GuildMember,
+    },
+    /// A member left or was kicked from the guild.
+    MemberLeft {
+        uuid: Uuid,
+        /// The last rank of the member.
+        rank: String,
+    },
+    /// A member was promoted or demoted.
+    RankChanged {
+        uuid: Uuid,
+        from: String,
+        to: String,
+    },
+    /// The experience a member earned for the guild in the past 7 days changed.
+    GexpUpdated {
+        uuid: Uuid,
+        previous: u64,
+        current: u64,
+    },
+}
+
+impl GuildEvent {
+    /// Returns the UUID of the member this event belongs to.
+    pub fn uuid(&self) -> Uuid {
+        match self {
+            GuildEvent::MemberJoined { member } => member.uuid(),
+            GuildEvent::MemberLeft { uuid, .. }
+            | GuildEvent::RankChanged { uuid, .. }
+            | GuildEvent::GexpUpdated { uuid, .. } => *uuid,
+        }
+    }
+}
+
+/// Refreshes a guild periodically and yields a [`GuildEvent`] for every change of its members.
+///
+/// The first refresh only records the current members, events are yielded for the changes
+/// observed by later refreshes. A refresh that does not find the guild, e.g. because it was
+/// disbanded, yields no events.
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use futures::StreamExt;
+/// use hypixel_api::RequestHandler;
+/// use hypixel_api::watch::{GuildEvent, GuildTracker};
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let api_key = Uuid::nil();
+/// let request_handler = Arc::new(RequestHandler::new(api_key));
+/// let tracker = GuildTracker::new(request_handler, "553490650cf26f12ae5bac8f", Duration::from_secs(300));
+/// let mut events = Box::pin(tracker.into_stream());
+/// while let Some(event) = events.next().await {
+///     if let Ok(GuildEvent::MemberJoined { member }) = event {
+///         println!("welcome {}!", member.uuid());
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct GuildTracker<A> {
+    api: A,
+    guild_id: String,
+    interval: Duration,
+}
+
+impl<A: HypixelApi> GuildTracker<A> {
+    /// Creates a tracker that refreshes the guild with the id `guild_id` once per `interval`.
+    pub fn new(api: A, guild_id: impl Into<String>, interval: Duration) -> Self {
+        GuildTracker {
+            api,
+            guild_id: guild_id.into(),
+            interval,
+        }
+    }
+
+    /// Returns the id of the tracked guild.
+    pub fn guild_id(&self) -> &str {
+        &self.guild_id
+    }
+
+    /// Starts refreshing and returns the [`Stream`] of events.
+    ///
+    /// A failed request is yielded as an error, the next refresh is compared against the
+    /// last successful one. Refreshing stops when the stream is dropped.
+    pub fn into_stream(self) -> impl Stream<Item = Result<GuildEvent, HypixelApiError>> {
+        poll_stream(GuildPoller {
+            tracker: self,
+            members: None,
+        })
+    }
+}
+
+/// The state of a member as far as it is relevant for [`GuildEvent`]s.
+struct MemberState {
+    rank: String,
+    weekly_exp: u64,
+}
+
+struct GuildPoller<A> {
+    tracker: GuildTracker<A>,
+    members: Option<HashMap<Uuid, MemberState>>,
+}
+
+impl<A: HypixelApi> Poller for GuildPoller<A> {
+    type Event = Result<GuildEvent, HypixelApiError>;
+
+    fn interval(&self) -> Duration {
+        self.tracker.interval
+    }
+
+    async fn poll(&mut self, events: &mut VecDeque<Self::Event>) {
+        let reply = self.tracker.api.request_typed::<GuildReply>(ApiPath::GuildById { id: self.tracker.guild_id.clone() }).await;
+        let reply = match reply {
+            Ok(Ok(reply)) => reply,
+            Ok(Err(error)) => return events.push_back(Err(error)),
+            Err(error) => return events.push_back(Err(error.into())),
+        };
+        let Some(guild) = reply.guild() else { return };
+        let current: HashMap<Uuid, MemberState> = guild.members().iter()
+            .map(|member| (member.uuid(), MemberState { rank: member.rank().to_string(), weekly_exp: member.weekly_exp() }))
+            .collect();
+        if let Some(previous) = &self.members {
+            for member in guild.members() {
+                let uuid = member.uuid();
+                let state = &current[&uuid];
+                match previous.get(&uuid) {
+                    None => events.push_back(Ok(GuildEvent::MemberJoined { member: member.clone() })),
+                    Some(before) => {
+                        if before.rank != state.rank {
+                            events.push_back(Ok(GuildEvent::RankChanged { uuid, from: before.rank.clone(), to: state.rank.clone() }));
+                        }
+                        if before.weekly_exp != state.weekly_exp {
+                            events.push_back(Ok(GuildEvent::GexpUpdated { uuid, previous: before.weekly_exp, current: state.weekly_exp }));
+                        }
+                    }
+                }
+            }
+            let mut left: Vec<_> = previous.iter().filter(|(uuid, _)| !current.contains_key(uuid)).collect();
+            left.sort_by_key(|(uuid, _)| **uuid);
+            events.extend(left.into_iter().map(|(uuid, state)| Ok(GuildEvent::MemberLeft { uuid: *uuid, rank: state.rank.clone() })));
+        }
+        self.members = Some(current);
+    }
+}
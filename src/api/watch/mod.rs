@@ -0,0 +1,58 @@
+//! Watchers that poll the API and turn changes into events.
+//!
+//! Every watcher sends its requests through a [`HypixelApi`](crate::HypixelApi), so polling
+//! stays within the rate limit of the [`RequestHandler`](crate::RequestHandler) and shares it
+//! with all other requests. Watchers take the api by value, pass a clone of the handler, an
+//! [`Arc<RequestHandler>`](std::sync::Arc) or a reference to share a handler.
+
+mod status;
+mod guild;
+mod bazaar;
+mod auctions;
+mod election;
+mod counts;
+mod resources;
+
+use std::collections::VecDeque;
+use std::time::Duration;
+use futures::Stream;
+use tokio::time::MissedTickBehavior;
+
+pub use status::{StatusEvent, StatusWatcher};
+pub use guild::{GuildEvent, GuildTracker};
+pub use bazaar::{BazaarEvent, BazaarPrices, BazaarTracker, PriceSide};
+pub use auctions::{AuctionUpdate, AuctionWatcher};
+pub use election::{ElectionEvent, ElectionState, ElectionWatcher};
+pub use counts::{CountsHistory, CountsSample, CountsSampler, CountsStats};
+pub use resources::ResourcesCache;
+
+/// The state of a watcher that is polled once per interval, see [`poll_stream`].
+pub(crate) trait Poller {
+    type Event;
+
+    /// Returns the time between two polls.
+    fn interval(&self) -> Duration;
+
+    /// Polls the API once and queues the events of the observed changes.
+    async fn poll(&mut self, events: &mut VecDeque<Self::Event>);
+}
+
+/// Polls `poller` once per interval, starting immediately, and yields the queued events.
+pub(crate) fn poll_stream<P: Poller>(poller: P) -> impl Stream<Item = P::Event> {
+    let state = (poller, None, VecDeque::new());
+    futures::stream::unfold(state, |(mut poller, mut interval, mut events)| async move {
+        loop {
+            if let Some(event) = events.pop_front() {
+                return Some((event, (poller, interval, events)));
+            }
+            // the interval needs a runtime, it is created once the stream is polled
+            let period = poller.interval();
+            interval.get_or_insert_with(|| {
+                let mut interval = tokio::time::interval(period);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                interval
+            }).tick().await;
+            poller.poll(&mut events).await;
+        }
+    })
+}
@@ -0,0 +1,154 @@
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::de::DeserializeOwned;
+use tokio::time::{Instant, MissedTickBehavior};
+use crate::api::error::HypixelApiError;
+use crate::api::reply::{AchievementsResourcesReply, CollectionsResourcesReply, GamesResourcesReply, ItemsResourcesReply, SkillsResourcesReply};
+use crate::api::request::HypixelApi;
+
+#[derive(Debug)]
+struct Resources {
+    games: Arc<GamesResourcesReply>,
+    achievements: Arc<AchievementsResourcesReply>,
+    skills: Arc<SkillsResourcesReply>,
+    collections: Arc<CollectionsResourcesReply>,
+    items: Arc<ItemsResourcesReply>,
+    refreshed_at: DateTime<Utc>,
+}
+
+/// The static catalogs of the `/resources` endpoints, fetched once and refreshed in the background.
+///
+/// [`ResourcesCache::start`] requests the games, achievements, skills, collections and items
+/// catalogs, then refreshes them once per interval until all handles are dropped. The getters
+/// are synchronous and return the latest catalogs, so computations that need them (achievement
+/// points, skill levels, ...) never wait for a request.
+///
+/// A catalog that fails to refresh keeps its previous value until the next refresh.
+///
+/// This is a cheap handle to state shared with the refresh task, clones share the catalogs.
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use hypixel_api::RequestHandler;
+/// use hypixel_api::watch::ResourcesCache;
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let api_key = Uuid::nil();
+/// let request_handler = RequestHandler::new(api_key);
+/// let resources = ResourcesCache::start(request_handler.clone(), Duration::from_secs(3600)).await.unwrap();
+///
+/// let points = resources.achievements().tiered_points("bedwars_beds", 300);
+/// let farming = resources.skills().skill("FARMING").map(|skill| skill.max_level());
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResourcesCache {
+    inner: Arc<RwLock<Resources>>,
+}
+
+impl ResourcesCache {
+    /// Requests all catalogs and starts refreshing them once per `interval`.
+    ///
+    /// Fails if any of the catalogs could not be requested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    pub async fn start<A: HypixelApi + Send + Sync + 'static>(api: A, interval: Duration) -> Result<Self, HypixelApiError> {
+        let (games, achievements, skills, collections, items) = futures::join!(
+            fetch(&api, "resources/games"),
+            fetch(&api, "resources/achievements"),
+            fetch(&api, "resources/skyblock/skills"),
+            fetch(&api, "resources/skyblock/collections"),
+            fetch(&api, "resources/skyblock/items"),
+        );
+        let resources = Resources {
+            games: games?,
+            achievements: achievements?,
+            skills: skills?,
+            collections: collections?,
+            items: items?,
+            refreshed_at: Utc::now(),
+        };
+        let cache = ResourcesCache { inner: Arc::new(RwLock::new(resources)) };
+        tokio::spawn(refresh(api, interval, Arc::downgrade(&cache.inner)));
+        Ok(cache)
+    }
+
+    /// Returns the games catalog.
+    pub fn games(&self) -> Arc<GamesResourcesReply> {
+        Arc::clone(&self.inner.read().games)
+    }
+
+    /// Returns the achievements catalog.
+    pub fn achievements(&self) -> Arc<AchievementsResourcesReply> {
+        Arc::clone(&self.inner.read().achievements)
+    }
+
+    /// Returns the SkyBlock skills catalog.
+    pub fn skills(&self) -> Arc<SkillsResourcesReply> {
+        Arc::clone(&self.inner.read().skills)
+    }
+
+    /// Returns the SkyBlock collections catalog.
+    pub fn collections(&self) -> Arc<CollectionsResourcesReply> {
+        Arc::clone(&self.inner.read().collections)
+    }
+
+    /// Returns the SkyBlock items catalog.
+    pub fn items(&self) -> Arc<ItemsResourcesReply> {
+        Arc::clone(&self.inner.read().items)
+    }
+
+    /// Returns the last time all catalogs were refreshed successfully.
+    pub fn refreshed_at(&self) -> DateTime<Utc> {
+        self.inner.read().refreshed_at
+    }
+}
+
+async fn fetch<A: HypixelApi, T: DeserializeOwned + Send + 'static>(api: &A, path: &str) -> Result<Arc<T>, HypixelApiError> {
+    Ok(Arc::new(api.request::<T>(path, false).await??))
+}
+
+/// Refreshes the catalogs of `resources` once per `interval` until all handles are dropped.
+async fn refresh<A: HypixelApi>(api: A, interval: Duration, resources: Weak<RwLock<Resources>>) {
+    let mut ticks = tokio::time::interval_at(Instant::now() + interval, interval);
+    ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        ticks.tick().await;
+        if resources.strong_count() == 0 {
+            return;
+        }
+        let (games, achievements, skills, collections, items) = futures::join!(
+            fetch(&api, "resources/games"),
+            fetch(&api, "resources/achievements"),
+            fetch(&api, "resources/skyblock/skills"),
+            fetch(&api, "resources/skyblock/collections"),
+            fetch(&api, "resources/skyblock/items"),
+        );
+        let Some(shared) = resources.upgrade() else {
+            return;
+        };
+        let mut shared = shared.write();
+        let complete = [
+            replace(&mut shared.games, games),
+            replace(&mut shared.achievements, achievements),
+            replace(&mut shared.skills, skills),
+            replace(&mut shared.collections, collections),
+            replace(&mut shared.items, items),
+        ];
+        if complete.into_iter().all(|replaced| replaced) {
+            shared.refreshed_at = Utc::now();
+        }
+    }
+}
+
+/// Replaces `catalog` with a successfully refreshed value, returns `false` if the refresh failed.
+fn replace<T>(catalog: &mut Arc<T>, refreshed: Result<Arc<T>, HypixelApiError>) -> bool {
+    refreshed.map(|refreshed| *catalog = refreshed).is_ok()
+}
@@ -0,0 +1,172 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use futures::Stream;
+use uuid::Uuid;
+use crate::api::GameType;
+use crate::api::error::HypixelApiError;
+use crate::api::reply::StatusReply;
+use crate::api::path::ApiPath;
+use crate::api::request::HypixelApi;
+use crate::api::watch::{poll_stream, Poller};
+
+/// A change of the status of a watched player, see [`StatusWatcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatusEvent {
+    /// The player came online.
+    CameOnline {
+        uuid: Uuid,
+        game_type: Option<GameType>,
+        mode: Option<String>,
+    },
+    /// The player went offline, or hid their status.
+    WentOffline {
+        uuid: Uuid,
+    },
+    /// The player, who was online before, joined another game or mode.
+    GameChanged {
+        uuid: Uuid,
+        game_type: Option<GameType>,
+        mode: Option<String>,
+    },
+}
+
+impl StatusEvent {
+    /// Returns the UUID of the player this event belongs to.
+    pub fn uuid(&self) -> Uuid {
+        match self {
+            StatusEvent::CameOnline { uuid, .. }
+            | StatusEvent::WentOffline { uuid }
+            | StatusEvent::GameChanged { uuid, .. } => *uuid,
+        }
+    }
+}
+
+/// Polls the `/status` of a set of players and yields a [`StatusEvent`] for every change.
+///
+/// The first poll only records the current status of every player, events are yielded for the
+/// changes observed by later polls. Players whose status is hidden appear offline.
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use futures::StreamExt;
+/// use hypixel_api::RequestHandler;
+/// use hypixel_api::watch::{StatusEvent, StatusWatcher};
+/// # use uuid::Uuid;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let api_key = Uuid::nil();
+/// let request_handler = Arc::new(RequestHandler::new(api_key));
+/// let friends = [Uuid::parse_str("069a79f444e94726a5befca90e38aaf5").unwrap()];
+/// let mut events = Box::pin(StatusWatcher::new(request_handler, friends, Duration::from_secs(60)).into_stream());
+/// while let Some(event) = events.next().await {
+///     match event {
+///         Ok(StatusEvent::CameOnline { uuid, .. }) => println!("{} came online", uuid),
+///         Ok(_) => {}
+///         Err(error) => eprintln!("could not poll a status: {}", error),
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StatusWatcher<A> {
+    api: A,
+    uuids: Vec<Uuid>,
+    interval: Duration,
+}
+
+/// The status of a player as far as it is relevant for [`StatusEvent`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Presence {
+    online: bool,
+    game_type: Option<GameType>,
+    mode: Option<String>,
+}
+
+impl<A: HypixelApi> StatusWatcher<A> {
+    /// Creates a watcher that polls the status of every player in `uuids` once per `interval`.
+    pub fn new(api: A, uuids: impl IntoIterator<Item = Uuid>, interval: Duration) -> Self {
+        StatusWatcher {
+            api,
+            uuids: uuids.into_iter().collect(),
+            interval,
+        }
+    }
+
+    /// Returns the players that are watched.
+    pub fn uuids(&self) -> &[Uuid] {
+        &self.uuids
+    }
+
+    /// Starts polling and returns the [`Stream`] of events.
+    ///
+    /// A failed request is yielded as an error, the player keeps their last known status.
+    /// Polling stops when the stream is dropped.
+    pub fn into_stream(self) -> impl Stream<Item = Result<StatusEvent, HypixelApiError>> {
+        poll_stream(StatusPoller {
+            watcher: self,
+            presences: HashMap::new(),
+        })
+    }
+}
+
+struct StatusPoller<A> {
+    watcher: StatusWatcher<A>,
+    presences: HashMap<Uuid, Presence>,
+}
+
+impl<A: HypixelApi> Poller for StatusPoller<A> {
+    type Event = Result<StatusEvent, HypixelApiError>;
+
+    fn interval(&self) -> Duration {
+        self.watcher.interval
+    }
+
+    async fn poll(&mut self, events: &mut VecDeque<Self::Event>) {
+        let requests = self.watcher.uuids.iter()
+            .map(|uuid| self.watcher.api.request_typed::<StatusReply>(ApiPath::Status { uuid: *uuid }));
+        let replies = futures::future::join_all(requests).await;
+        for (uuid, reply) in self.watcher.uuids.iter().copied().zip(replies) {
+            let reply = match reply {
+                Ok(Ok(reply)) => reply,
+                Ok(Err(error)) => {
+                    events.push_back(Err(error));
+                    continue;
+                }
+                Err(error) => {
+                    events.push_back(Err(error.into()));
+                    continue;
+                }
+            };
+            let presence = Presence {
+                online: reply.online(),
+                game_type: reply.game(),
+                mode: reply.mode().map(str::to_string),
+            };
+            if let Some(previous) = self.presences.get(&uuid) {
+                events.extend(change(uuid, previous, &presence).map(Ok));
+            }
+            self.presences.insert(uuid, presence);
+        }
+    }
+}
+
+/// Returns the event of a player whose status changed from `previous` to `current`, if any.
+fn change(uuid: Uuid, previous: &Presence, current: &Presence) -> Option<StatusEvent> {
+    match (previous.online, current.online) {
+        (false, true) => Some(StatusEvent::CameOnline {
+            uuid,
+            game_type: current.game_type.clone(),
+            mode: current.mode.clone(),
+        }),
+        (true, false) => Some(StatusEvent::WentOffline { uuid }),
+        (true, true) if previous != current => Some(StatusEvent::GameChanged {
+            uuid,
+            game_type: current.game_type.clone(),
+            mode: current.mode.clone(),
+        }),
+        _ => None,
+    }
+}
@@ -50,6 +50,7 @@
 //! and go fully custom.
 //!
 //! See the documentation of [`RequestHandler`] for more information on sending requests.
+#![cfg_attr(feature = "reply", doc = "[`HypixelClient`] groups the typed endpoints by domain on top of a [`RequestHandler`], e.g. `client.skyblock().auctions()`.")]
 //!
 //! #### Example with custom data structure
 //! ```rust,no_run
@@ -89,11 +90,44 @@
 #![cfg_attr(feature = "util", doc = ", see [`util`]")]
 //! - `reply` - (*depends on `util`*) - enables ready-to-use data structures as responses from the `Hypixel Public API`
 #![cfg_attr(feature = "reply", doc = ", see [`reply`]")]
+//! - `legacy-key` - (*depends on `reply`*) - enables `KeyReply` and `RequestHandler::verify_key`
+//! for the removed `/key` endpoint
+#![cfg_attr(feature = "legacy-key", doc = ", see [`KeyReply`](reply::KeyReply)")]
+//! , use [`RequestHandler::key_usage`] instead
+//! - `test-util` - (*depends on `reply`*) - enables constructors and builders to create fake replies in tests
+#![cfg_attr(feature = "test-util", doc = ", [`MockHypixelApi`] to mock [`HypixelApi`] and [`MockHypixelServer`] to imitate the API over HTTP")]
+//! - `nbt` - (*depends on `util`*) - enables decoding of the gzipped NBT data found in SkyBlock items
+#![cfg_attr(feature = "nbt", doc = ", see [`util::nbt`]")]
+//! - `fixtures` - enables anonymized response fixtures of the `Hypixel Public API` for use in tests
+#![cfg_attr(feature = "fixtures", doc = ", see [`fixtures`]")]
+//! - `diagnostics` - enables reports of fields that were ignored or failed to deserialize, and a strict mode that rejects them
+#![cfg_attr(feature = "diagnostics", doc = ", see [`diagnostics`]")]
+//! - `metrics` - enables request metrics through the `metrics` facade, e.g. to scrape with Prometheus
+#![cfg_attr(feature = "metrics", doc = ", see [`metrics`]")]
+//! - `body-capture` - enables capturing the raw bodies of failed responses for debugging
+#![cfg_attr(feature = "body-capture", doc = ", see [`capture`]")]
+//! - `simd-json` - deserializes responses with `simd-json`, which is faster for large responses
+//!   such as `/skyblock/bazaar` and falls back to `serde_json` when it fails
+//! - `tower` - implements `tower::Service` for [`RequestHandler`] to stack tower middleware on top of the throttling
+#![cfg_attr(feature = "tower", doc = ", see [`service`]")]
+//! - `native-tls` - (*enabled by default*) - connects to the API over the platform's native TLS implementation (OpenSSL on Linux)
+//! - `rustls` - connects to the API over `rustls`, for static or musl builds without OpenSSL.
+//!   Disable the default features to use it. The two are not mutually exclusive, as cargo unifies
+//!   the features a crate is depended on with (and `--all-features` enables both), `native-tls`
+//!   takes precedence if both are enabled. One of them has to be enabled.
+//! - `time` - (*depends on `util`*) - converts all timestamps to `time::OffsetDateTime`
+#![cfg_attr(feature = "time", doc = ", see [`IntoOffsetDateTime`](util::time::IntoOffsetDateTime)")]
+//! - `global` - (*depends on `reply`*) - enables a global [`RequestHandler`] and free functions
+//!   such as `get_player` for small tools and examples
+#![cfg_attr(feature = "global", doc = ", see [`global`]")]
 
 #[cfg_attr(feature = "tracing", macro_use)]
 #[cfg(feature = "tracing")]
 extern crate tracing;
 
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+compile_error!("either the `native-tls` or the `rustls` feature has to be enabled to connect to the API");
+
 mod api;
 
 pub use api::error;
@@ -101,8 +135,44 @@ pub use api::error;
 pub use api::reply;
 #[cfg(feature = "util")]
 pub use api::util;
+#[cfg(feature = "reply")]
+pub use api::watch;
+#[cfg(feature = "fixtures")]
+pub use api::fixtures;
+#[cfg(feature = "diagnostics")]
+pub use api::diagnostics;
+#[cfg(feature = "metrics")]
+pub use api::metrics;
+#[cfg(feature = "body-capture")]
+pub use api::capture;
+#[cfg(feature = "tower")]
+pub use api::service;
+#[cfg(feature = "global")]
+pub use api::global::{self, get_player, get_status, init, init_with};
 
-pub use api::request::RequestHandler;
+pub use api::request::{RequestHandler, RequestHandlerBuilder, HypixelApi, DEFAULT_BASE_URL};
+pub use api::dispatch::RequestFuture;
+#[cfg(feature = "reply")]
+pub use api::batch::StatusBatch;
+#[cfg(feature = "reply")]
+pub use api::roster::{GuildRoster, RosterMember, RosterOptions};
+#[cfg(feature = "reply")]
+pub use api::client::{HypixelClient, PlayerRequests, GuildRequests, SkyblockRequests, AuctionRequests, ResourceRequests};
+#[cfg(feature = "test-util")]
+pub use api::mock::{MockHypixelApi, MockHypixelServer};
+pub use api::key::{KeyUsage, SecretKey};
+pub use api::path::ApiPath;
+pub use api::throttler::{RateLimitFallback, RateLimitStatus};
+pub use api::retry::RetryPolicy;
+pub use api::budget::DeserializationBudget;
+pub use api::pagination::{LimitReached, PageLimits, PartialResult};
+pub use api::raw::WithRaw;
+pub use api::timed::{ResponseMeta, Timed};
+pub use api::vcr::Cassette;
+pub use api::journal::{Journal, JournalEntry};
+pub use api::events::{RequestEvent, RequestId};
+pub use api::stats::{RequestStats, TagStats};
+pub use api::cache;
 #[cfg(feature = "reply")]
 pub use api::reply::*;
-pub use api::{ColorCodes, MonthlyPackageRank, PackageRank, StaffLevel};
\ No newline at end of file
+pub use api::{ColorCodes, GameType, MonthlyPackageRank, PackageRank, StaffLevel};
\ No newline at end of file